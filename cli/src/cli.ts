@@ -35,6 +35,8 @@ import {
   getCurrentTimestamp,
   toU64,
   toIVault,
+  alphaTranche,
+  betaTranche,
   clusterToNetwork,
   toOrcaU64,
   scaleToOrcaU64,
@@ -42,7 +44,8 @@ import {
   getAquafarm,
   getDoubleDipFarm,
   findAssociatedTokenAddress,
-  IVault,
+  State,
+  TargetVaultState,
 } from "@ora-protocol/sdk";
 
 import {
@@ -83,6 +86,7 @@ export const toCoinGeckoNames = (tokens: string[]): string[] => {
 };
 
 export const DEFAULT_HURDLE_RATE = 1000;
+export const DEFAULT_MAX_SLIPPAGE_BPS = 100;
 export const DEFAULT_PERIOD_IN_SECONDS = 1 * 24 * 60 * 60;
 export const DEFAULT_TOKEN_DECIMALS = 6;
 
@@ -394,7 +398,8 @@ programCommand("init_vault")
     "-s, --strategy <pubkey>",
     "Pubkey of the strategy used by the vault to invest and redeem funds"
   )
-  .option("-aa, --alpha <pubkey>", "Mint of the vault's alpha asset")
+  .option("-aa, --alpha <pubkey>", "Mint of the vault's senior (alpha) asset")
+  .option("-sya, --symbolA <string>", "Symbol of the senior tranche's asset")
   .option(
     "-aca, --assetCapA <number>",
     "Limit the amount of asset A that can be deposited by all users"
@@ -403,7 +408,8 @@ programCommand("init_vault")
     "-uca, --userCapA <number>",
     "Limit the amount of asset A that can be deposited by a single user"
   )
-  .option("-ba, --beta <pubkey>", "Mint of the vault's beta asset")
+  .option("-ba, --beta <pubkey>", "Mint of the vault's junior (beta) asset")
+  .option("-syb, --symbolB <string>", "Symbol of the junior tranche's asset")
   .option(
     "-acb, --assetCapB <number>",
     "Limit the amount of asset B that can be deposited by all users"
@@ -416,6 +422,11 @@ programCommand("init_vault")
     "-fr, --fixedRate <number>",
     "Fixed rate the senior tranche is guaranteed to recieve, in basis points."
   )
+  .option(
+    "-ms, --maxSlippage <number>",
+    "Max slippage the vault accepts when swapping, in basis points."
+  )
+  .option("-mu, --metadataUri <string>", "URI of the tranche tokens' metadata")
   .option(
     "-sa, --startAt <number>",
     "Timestamp at which the vault starts accepting deposits. In milliseconds."
@@ -437,12 +448,16 @@ programCommand("init_vault")
       strategist,
       strategy,
       alpha,
+      symbolA,
       assetCapA,
       userCapA,
       beta,
+      symbolB,
       assetCapB,
       userCapB,
       fixedRate,
+      maxSlippage,
+      metadataUri,
       startAt,
       depositPeriod,
       livePeriod,
@@ -464,26 +479,37 @@ programCommand("init_vault")
     const _fixedRate = getOrDefault(+fixedRate, DEFAULT_HURDLE_RATE);
     const _startAt = new Date(getOrDefault(+startAt, new Date().getTime()));
 
-    const _assetCapA: u64 | null = assetCapA ? new u64(+assetCapA) : null;
-    const _userCapA: u64 | null = userCapA ? new u64(+userCapA) : null;
-    const _assetCapB: u64 | null = assetCapB ? new u64(+assetCapB) : null;
-    const _userCapB: u64 | null = userCapB ? new u64(+userCapB) : null;
+    const _assetCapA = assetCapA ? new u64(+assetCapA) : undefined;
+    const _userCapA = userCapA ? new u64(+userCapA) : undefined;
+    const _assetCapB = assetCapB ? new u64(+assetCapB) : undefined;
+    const _userCapB = userCapB ? new u64(+userCapB) : undefined;
 
+    // the senior tranche is owed the fixed rate, the junior tranche takes what remains
     const vaultConfig: VaultConfig = {
       authority: _authority,
       strategy: _strategy,
       strategist: _strategist,
-      alpha: {
-        mint: _alpha,
-        userCap: _userCapA,
-        assetCap: _assetCapA,
-      },
-      beta: {
-        mint: _beta,
-        userCap: _userCapB,
-        assetCap: _assetCapB,
-      },
-      fixedRate: _fixedRate,
+      tranches: [
+        {
+          mint: _alpha,
+          seniority: 0,
+          symbol: getOrDefault(symbolA, "A"),
+          returnTerms: {
+            floorBps: _fixedRate,
+            capBps: _fixedRate,
+            yieldShareBps: 0,
+          },
+          userCap: _userCapA,
+          assetCap: _assetCapA,
+        },
+        {
+          mint: _beta,
+          seniority: 1,
+          symbol: getOrDefault(symbolB, "B"),
+          userCap: _userCapB,
+          assetCap: _assetCapB,
+        },
+      ],
       startAt: new u64(getTimestamp(_startAt)),
       depositDuration: new u64(
         getOrDefault(+depositPeriod, DEFAULT_PERIOD_IN_SECONDS)
@@ -491,6 +517,8 @@ programCommand("init_vault")
       investDuration: new u64(
         getOrDefault(+livePeriod, DEFAULT_PERIOD_IN_SECONDS)
       ),
+      maxSlippageBps: getOrDefault(+maxSlippage, DEFAULT_MAX_SLIPPAGE_BPS),
+      metadataUri: getOrDefault(metadataUri, ""),
     };
 
     const tx = await _client.initializeVault(
@@ -540,49 +568,38 @@ programCommand("show_vault")
     log.info("Vault bump: ", _vault.bump);
     log.info("===========================================");
 
-    log.info("Alpha asset ===============================");
-    const alpha = _vault.alpha;
-    log.info("Mint: ", alpha.mint.toBase58());
-    log.info("LP Mint: ", alpha.lp.toBase58());
-    log.info(
-      "Asset cap: ",
-      alpha.assetCap ? alpha.assetCap.toNumber() : "No cap"
-    );
-    log.info("User cap: ", alpha.userCap ? alpha.userCap.toNumber() : "No cap");
-    log.info("Deposits: ", alpha.deposits.toNumber());
-    log.info("Deposited: ", alpha.deposited.toNumber());
-    log.info("Invested: ", alpha.invested.toNumber());
-    log.info("Excess: ", alpha.excess.toNumber());
-    log.info("Received: ", alpha.received.toNumber());
-
-    log.info("=== Claims ===");
-    log.info("Claims processed: ", alpha.claimsProcessed);
-    log.info(
-      "Claims index: ",
-      alpha.claimsIdx ? alpha.claimsIdx.toNumber() : "Not defined"
-    );
-
-    log.info("Beta asset ===============================");
-    const beta = _vault.beta;
-    log.info("Mint: ", beta.mint.toBase58());
-    log.info("LP Mint: ", beta.lp.toBase58());
-    log.info(
-      "Asset cap: ",
-      beta.assetCap ? beta.assetCap.toNumber() : "No cap"
-    );
-    log.info("User cap: ", beta.userCap ? beta.userCap.toNumber() : "No cap");
-    log.info("Deposits: ", beta.deposits.toNumber());
-    log.info("Deposited: ", beta.deposited.toNumber());
-    log.info("Invested: ", beta.invested.toNumber());
-    log.info("Excess: ", beta.excess.toNumber());
-    log.info("Received: ", beta.received.toNumber());
-
-    log.info("=== Claims ===");
-    log.info("Claims processed: ", beta.claimsProcessed);
-    log.info(
-      "Claims index: ",
-      beta.claimsIdx ? beta.claimsIdx.toNumber() : "Not defined"
-    );
+    for (let trancheId = 0; trancheId < _vault.numTranches; trancheId++) {
+      const tranche = _vault.tranches[trancheId];
+      log.info(
+        `Tranche ${trancheId} (seniority ${tranche.seniority}) ===============`
+      );
+      log.info("Mint: ", tranche.mint.toBase58());
+      log.info("LP Mint: ", tranche.lp.toBase58());
+      if (tranche.returnTerms) {
+        log.info("Return floor (bps): ", tranche.returnTerms.floorBps);
+        log.info("Return cap (bps): ", tranche.returnTerms.capBps);
+      }
+      log.info(
+        "Asset cap: ",
+        tranche.assetCap ? tranche.assetCap.toNumber() : "No cap"
+      );
+      log.info(
+        "User cap: ",
+        tranche.userCap ? tranche.userCap.toNumber() : "No cap"
+      );
+      log.info("Deposits: ", tranche.deposits.toNumber());
+      log.info("Deposited: ", tranche.deposited.toNumber());
+      log.info("Invested: ", tranche.invested.toNumber());
+      log.info("Excess: ", tranche.excess.toNumber());
+      log.info("Received: ", tranche.received.toNumber());
+
+      log.info("=== Claims ===");
+      log.info("Claims processed: ", tranche.claimsProcessed);
+      log.info(
+        "Claims index: ",
+        tranche.claimsIdx ? tranche.claimsIdx.toNumber() : "Not defined"
+      );
+    }
 
     if (_vault.farmVault) {
       log.info("Farm vault: ", _vault.farmVault.toBase58());
@@ -593,7 +610,6 @@ programCommand("show_vault")
     log.info("Authority: ", _vault.authority.toBase58());
     log.info("Strategy: ", _vault.strategy.toBase58());
     log.info("Strategist: ", _vault.strategist.toBase58());
-    log.info("Fixed rate: ", _vault.fixedRate);
     log.info("State: ", _vault.state);
     log.info("Vault projected start at: ", _vault.startAt.toNumber());
 
@@ -618,7 +634,10 @@ programCommand("show_vault")
 
 programCommand("transition_vault")
   .option("-v, --vault <pubkey>", "Vault address to display.")
-  .option("-t, --target <string>", "Target state to which we should transition")
+  .option(
+    "-t, --target <string>",
+    "Target state to which we should transition: Deposit, Live, Rebalance, Redeem or Withdraw"
+  )
   .option("-e, --execute <boolean>", "Execute transaction or not")
   .action(async (_, cmd) => {
     const { keypair, env, vault, target, execute } = cmd.opts();
@@ -628,9 +647,17 @@ programCommand("transition_vault")
     const _vault = new PublicKey(vault);
     const _execute = execute === "true" ? true : false;
 
+    const _target = Object.values(State).find(
+      (state) => state.toLowerCase() === `${target}`.toLowerCase()
+    );
+    invariant(
+      _target && _target !== State.Inactive,
+      `Invalid target state ${target}; use cancel_vault to deactivate a vault`
+    );
+
     const tx = await _client.transitionVault(
       _vault,
-      target,
+      _target as TargetVaultState,
       walletKeyPair,
       _execute
     );
@@ -640,31 +667,47 @@ programCommand("transition_vault")
     log.info("===========================================");
   });
 
+programCommand("cancel_vault")
+  .option("-v, --vault <pubkey>", "Vault address to cancel.")
+  .option("-e, --execute <boolean>", "Execute transaction or not")
+  .action(async (_, cmd) => {
+    const { keypair, env, vault, execute } = cmd.opts();
+
+    const walletKeyPair: Keypair = loadWalletKey(keypair);
+    const _client = createClient(env, walletKeyPair);
+    const _vault = new PublicKey(vault);
+    const _execute = execute === "true" ? true : false;
+
+    const tx = await _client.cancelVault(_vault, walletKeyPair, _execute);
+
+    log.info("===========================================");
+    log.info(`✅ Vault ${_vault.toBase58()} cancelled with TX [${tx}].`);
+    log.info("===========================================");
+  });
+
 programCommand("show_receipts")
   .option(
     "-v, --vault <pubkey>",
     "Public key of the vault into which you want to deposit funds"
   )
-  .option("-m --mint <pubkey>", "Public key of the asset you want to deposit")
-  .option("-e, --execute <boolean>", "Execute transaction or not")
+  .option("-t --tranche <number>", "Id of the tranche the receipts are for")
   .action(async (_, cmd) => {
-    const { keypair, env, vault, mint, execute } = cmd.opts();
+    const { keypair, env, vault, tranche } = cmd.opts();
 
     const walletKeyPair: Keypair = loadWalletKey(keypair);
     const _client = createClient(env, walletKeyPair);
-    const _execute = execute === "true" ? true : false;
-    const _mint = new PublicKey(mint);
+    const _trancheId = +tranche;
     const _vault = new PublicKey(vault);
     const __vault = await _client.fetchVault(_vault);
-    const _asset = _client.getAsset(toIVault(__vault), _mint);
+    const _asset = __vault.tranches[_trancheId];
     const numDeposits = _asset.deposits.toNumber();
-    console.log(`numDeposits for ${_mint.toBase58()}: ${numDeposits}`);
+    console.log(`numDeposits for tranche ${_trancheId}: ${numDeposits}`);
     console.log("===========================================");
 
     for (let i = numDeposits; i > 0; i--) {
       const { addr: receipt } = await _client.generateReceiptAddress(
         _vault,
-        _mint,
+        _trancheId,
         toU64(i)
       );
 
@@ -684,26 +727,24 @@ programCommand("show_depositors")
     "-v, --vault <pubkey>",
     "Public key of the vault into which you want to deposit funds"
   )
-  .option("-m --mint <pubkey>", "Public key of the asset you want to deposit")
+  .option("-t --tranche <number>", "Id of the tranche the deposits are for")
   .action(async (_, cmd) => {
-    const { keypair, env, vault, mint } = cmd.opts();
+    const { keypair, env, vault, tranche } = cmd.opts();
 
     const walletKeyPair: Keypair = loadWalletKey(keypair);
     const _client = createClient(env, walletKeyPair);
 
-    const _mint = new PublicKey(mint);
+    const _trancheId = +tranche;
     const _vault = new PublicKey(vault);
-    const __vault = await _client.fetchVault(_vault);
 
     const { totalDeposits, depositors } = await getDepositorsForVault(
       _vault,
-      toIVault(__vault),
-      _mint,
+      _trancheId,
       _client
     );
 
     console.log(
-      `Vault [${_vault.toBase58()}] with asset ${_mint.toBase58()} had ${totalDeposits} fromm ${
+      `Vault [${_vault.toBase58()}] tranche ${_trancheId} had ${totalDeposits} fromm ${
         depositors.size
       } unique depositors`
     );
@@ -716,20 +757,19 @@ programCommand("show_depositors")
 
 export const getDepositorsForVault = async (
   vaultKey: PublicKey,
-  vault: IVault,
-  mint: PublicKey,
+  trancheId: number,
   client: VaultClient
 ): Promise<{
   totalDeposits: number;
   depositors: Map<string, number>;
 }> => {
-  const asset = client.getAsset(vault, mint);
+  const asset = (await client.fetchVault(vaultKey)).tranches[trancheId];
   const numDeposits = asset.deposits.toNumber();
   const depositors = new Map<string, number>();
   for (let i = numDeposits; i > 0; i--) {
     const { addr: receipt } = await client.generateReceiptAddress(
       vaultKey,
-      mint,
+      trancheId,
       toU64(i)
     );
 
@@ -753,30 +793,35 @@ programCommand("show_deposit_history")
     "-v, --vault <pubkey>",
     "Public key of the vault into which you want to deposit funds"
   )
-  .option("-m --mint <pubkey>", "Public key of the asset you want to deposit")
+  .option(
+    "-t --tranche <number>",
+    "Id of the tranche; defaults to every tranche in the vault"
+  )
   .option("-d --depositor <pubkey>", "Public key of the depositor")
   .action(async (_, cmd) => {
-    const { keypair, env, vault, mint, depositor } = cmd.opts();
+    const { keypair, env, vault, tranche, depositor } = cmd.opts();
 
     const walletKeyPair: Keypair = loadWalletKey(keypair);
     const _client = createClient(env, walletKeyPair);
     const _vault = new PublicKey(vault);
     const __vault = await _client.fetchVault(_vault);
 
-    let mints = [];
-    if (mint) {
-      mints.push(new PublicKey(mint));
+    let trancheIds = [];
+    if (tranche !== undefined) {
+      trancheIds.push(+tranche);
     } else {
-      mints.push(__vault.alpha.mint, __vault.beta.mint);
+      for (let i = 0; i < __vault.numTranches; i++) {
+        trancheIds.push(i);
+      }
     }
 
-    console.log("num mints: ", mints.length);
+    console.log("num tranches: ", trancheIds.length);
 
     log.info("===========================================");
-    for (const _mint of mints) {
-      const _asset = _client.getAsset(toIVault(__vault), _mint);
+    for (const _trancheId of trancheIds) {
+      const _asset = __vault.tranches[_trancheId];
       const numDeposits = _asset.deposits.toNumber();
-      console.log(`> Num deposits for ${_mint.toBase58()}: ${numDeposits}`);
+      console.log(`> Num deposits for tranche ${_trancheId}: ${numDeposits}`);
 
       let depositors = [];
       if (depositor) {
@@ -784,8 +829,7 @@ programCommand("show_deposit_history")
       } else {
         const { depositors: _depositors } = await getDepositorsForVault(
           _vault,
-          toIVault(__vault),
-          _mint,
+          _trancheId,
           _client
         );
 
@@ -799,7 +843,7 @@ programCommand("show_deposit_history")
 
         const { addr: history, bump } = await _client.generateHistoryAddress(
           _vault,
-          _mint,
+          _trancheId,
           _depositor
         );
 
@@ -826,29 +870,45 @@ programCommand("deposit")
     "-v, --vault <pubkey>",
     "Public key of the vault into which you want to deposit funds"
   )
-  .option("-m --mint <pubkey>", "Public key of the asset you want to deposit")
+  .option("-t --tranche <number>", "Id of the tranche you want to deposit into")
   .option(
     "-a --amount <number>",
     "Amount of funds to deposit. Ignore the decimal calculation - just provide the raw amount."
   )
+  .option(
+    "-p --proof <string>",
+    "Comma separated hex encoded merkle proof, for vaults gated by a merkle root"
+  )
   .option("-e, --execute <boolean>", "Execute transaction or not")
   .action(async (_, cmd) => {
-    const { keypair, env, vault, mint, amount, execute } = cmd.opts();
+    const { keypair, env, vault, tranche, amount, proof, execute } =
+      cmd.opts();
 
     const walletKeyPair: Keypair = loadWalletKey(keypair);
     const _client = createClient(env, walletKeyPair);
     const _execute = execute === "true" ? true : false;
 
     const _vault = new PublicKey(vault);
-    const _mint = new PublicKey(mint);
+    const _trancheId = +tranche;
+    const _mint = (await _client.fetchVault(_vault)).tranches[_trancheId].mint;
     const decimals = (await _client.fetchTokenSupply(_mint)).decimals;
     const _amount = toU64(+amount * 10 ** decimals);
+    const _proof: number[][] = proof
+      ? `${proof}`.split(",").map((node) => [...Buffer.from(node, "hex")])
+      : [];
 
-    await _client.deposit(_vault, _mint, _amount, walletKeyPair, _execute);
+    await _client.deposit(
+      _vault,
+      _trancheId,
+      _amount,
+      walletKeyPair,
+      _execute,
+      _proof
+    );
 
     log.info("===========================================");
     log.info(
-      `Deposited ${_amount.toNumber()} of ${_mint.toBase58()} into vault ${_vault.toBase58()}`
+      `Deposited ${_amount.toNumber()} of ${_mint.toBase58()} into tranche ${_trancheId} of vault ${_vault.toBase58()}`
     );
     log.info("===========================================");
   });
@@ -858,22 +918,27 @@ programCommand("claim")
     "-v, --vault <pubkey>",
     "Public key of the vault into which you want to deposit funds"
   )
-  .option("-m --mint <pubkey>", "Public key of the asset you want to deposit")
+  .option("-t --tranche <number>", "Id of the tranche you deposited into")
   .option("-e, --execute <boolean>", "Execute transaction or not")
   .action(async (_, cmd) => {
-    const { keypair, env, vault, mint, execute } = cmd.opts();
+    const { keypair, env, vault, tranche, execute } = cmd.opts();
 
     const walletKeyPair: Keypair = loadWalletKey(keypair);
     const _client = createClient(env, walletKeyPair);
     const _vault = new PublicKey(vault);
-    const _mint = new PublicKey(mint);
+    const _trancheId = +tranche;
     const _execute = execute === "true" ? true : false;
 
-    const tx = await _client.claim(_vault, _mint, walletKeyPair, _execute);
+    const tx = await _client.claim(
+      _vault,
+      _trancheId,
+      walletKeyPair,
+      _execute
+    );
 
     log.info("===========================================");
     log.info(
-      `Entity attempted to invoke claim for mint ${_mint.toBase58()} in vault ${_vault.toBase58()}; Details in TX: ${tx}`
+      `Entity attempted to invoke claim for tranche ${_trancheId} in vault ${_vault.toBase58()}; Details in TX: ${tx}`
     );
     log.info("===========================================");
   });
@@ -883,20 +948,20 @@ programCommand("process_claims")
     "-v, --vault <pubkey>",
     "Public key of the vault into which you want to deposit funds"
   )
-  .option("-m --mint <pubkey>", "Public key of the asset you want to deposit")
+  .option("-t --tranche <number>", "Id of the tranche to process claims for")
   .option("-e, --execute <boolean>", "Execute transaction or not")
   .action(async (_, cmd) => {
-    const { keypair, env, vault, mint, execute } = cmd.opts();
+    const { keypair, env, vault, tranche, execute } = cmd.opts();
 
     const walletKeyPair: Keypair = loadWalletKey(keypair);
     const _client = createClient(env, walletKeyPair);
     const _vault = new PublicKey(vault);
-    const _mint = new PublicKey(mint);
+    const _trancheId = +tranche;
     const _execute = execute === "true" ? true : false;
 
     const { tx, claimsProcessed } = await _client.processClaims(
       _vault,
-      _mint,
+      _trancheId,
       walletKeyPair,
       _execute
     );
@@ -1031,7 +1096,9 @@ programCommand("get_invest_estimate")
 
     const orca = getOrca(_client.provider.connection, clusterToNetwork(env));
     const { pool } = getOrcaPool(orca, pair);
-    const __vault = await _client.fetchVault(_vault);
+    const __vault = toIVault(await _client.fetchVault(_vault));
+    const alpha = alphaTranche(__vault);
+    const beta = betaTranche(__vault);
 
     const tokenA = pool.getTokenA();
     const tokenAName = tokenA.name.toLowerCase();
@@ -1042,29 +1109,23 @@ programCommand("get_invest_estimate")
     console.log("tokenPrices: ", tokenPrices);
 
     // map tokens to tranches
-    const alphaDecimals = (await _client.fetchTokenSupply(__vault.alpha.mint))
-      .decimals;
-    const alphaAsDecimal =
-      __vault.alpha.deposited.toNumber() / 10 ** alphaDecimals;
+    const alphaAsDecimal = alpha.deposited.toNumber() / 10 ** alpha.decimals;
     console.log("alphaAsDecimal: ", alphaAsDecimal);
-    const betaDecimals = (await _client.fetchTokenSupply(__vault.beta.mint))
-      .decimals;
-    const betaAsDecimal =
-      __vault.beta.deposited.toNumber() / 10 ** betaDecimals;
+    const betaAsDecimal = beta.deposited.toNumber() / 10 ** beta.decimals;
     console.log("betaAsDecimal: ", betaAsDecimal);
 
     console.log("tokenPrices[tokenAName]: ", tokenPrices.get(tokenAName));
     console.log("tokenPrices[tokenBName]: ", tokenPrices.get(tokenBName));
 
     const alphaInUsd =
-      __vault.alpha.mint.toBase58() === tokenA.mint.toBase58()
+      alpha.mint.toBase58() === tokenA.mint.toBase58()
         ? tokenPrices.get(tokenAName)
         : tokenPrices.get(tokenBName);
     const alphaAsUsd = alphaAsDecimal * alphaInUsd;
     console.log("alphaAsUsd: ", alphaAsUsd);
 
     const betaInUsd =
-      __vault.beta.mint.toBase58() === tokenA.mint.toBase58()
+      beta.mint.toBase58() === tokenA.mint.toBase58()
         ? tokenPrices.get(tokenAName)
         : tokenPrices.get(tokenBName);
     const betaAsUsd = betaAsDecimal * betaInUsd;
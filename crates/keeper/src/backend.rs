@@ -0,0 +1,234 @@
+//! The keeper talks to the cluster through a `Backend`, so the same lifecycle logic can run against a live
+//! RPC node or a solana-program-test bank.
+
+use crate::error::{KeeperError, KeeperResult};
+use solana_client::rpc_client::RpcClient;
+use solana_program::{
+    clock::Clock, instruction::Instruction, program_pack::Pack, pubkey::Pubkey, sysvar,
+};
+use solana_sdk::{
+    account::{from_account, Account},
+    signature::{Keypair, Signature, Signer},
+    transaction::Transaction,
+};
+use vault_client::{AccountFetcher, ClientError, ClientResult};
+
+pub trait Backend: AccountFetcher {
+    /// current cluster time
+    fn unix_timestamp(&self) -> KeeperResult<u64>;
+
+    /// sign, send and confirm a transaction. the payer signs and pays fees.
+    fn send_transaction(
+        &self,
+        instructions: &[Instruction],
+        payer: &Keypair,
+        signers: &[&Keypair],
+    ) -> KeeperResult<Signature>;
+}
+
+/// Balance of an SPL token account, or None if the account does not exist
+pub fn token_balance<F: AccountFetcher + ?Sized>(
+    fetcher: &F,
+    address: &Pubkey,
+) -> KeeperResult<Option<u64>> {
+    Ok(unpack_token_account(fetcher, address)?.map(|account| account.amount))
+}
+
+/// Mint of an SPL token account
+pub fn token_mint<F: AccountFetcher + ?Sized>(
+    fetcher: &F,
+    address: &Pubkey,
+) -> KeeperResult<Pubkey> {
+    unpack_token_account(fetcher, address)?
+        .map(|account| account.mint)
+        .ok_or_else(|| ClientError::AccountNotFound(*address).into())
+}
+
+fn unpack_token_account<F: AccountFetcher + ?Sized>(
+    fetcher: &F,
+    address: &Pubkey,
+) -> KeeperResult<Option<spl_token::state::Account>> {
+    fetcher
+        .get_account(address)?
+        .map(|(_, data)| spl_token::state::Account::unpack(&data).map_err(KeeperError::from))
+        .transpose()
+}
+
+fn sign_transaction(
+    instructions: &[Instruction],
+    payer: &Keypair,
+    signers: &[&Keypair],
+    blockhash: solana_program::hash::Hash,
+) -> Transaction {
+    let mut all_signers = vec![payer];
+    all_signers.extend(
+        signers
+            .iter()
+            .filter(|signer| signer.pubkey() != payer.pubkey()),
+    );
+
+    Transaction::new_signed_with_payer(instructions, Some(&payer.pubkey()), &all_signers, blockhash)
+}
+
+// =====================================================================
+
+pub struct RpcBackend {
+    pub client: RpcClient,
+}
+
+impl RpcBackend {
+    pub fn new(rpc_url: String) -> Self {
+        RpcBackend {
+            client: RpcClient::new(rpc_url),
+        }
+    }
+}
+
+impl AccountFetcher for RpcBackend {
+    fn get_account(&self, address: &Pubkey) -> ClientResult<Option<(Pubkey, Vec<u8>)>> {
+        AccountFetcher::get_account(&self.client, address)
+    }
+
+    fn get_accounts(&self, addresses: &[Pubkey]) -> ClientResult<Vec<Option<(Pubkey, Vec<u8>)>>> {
+        AccountFetcher::get_accounts(&self.client, addresses)
+    }
+}
+
+impl Backend for RpcBackend {
+    fn unix_timestamp(&self) -> KeeperResult<u64> {
+        let account = self
+            .client
+            .get_account(&sysvar::clock::id())
+            .map_err(|e| KeeperError::Rpc(e.to_string()))?;
+        let clock: Clock = from_account(&account)
+            .ok_or_else(|| KeeperError::Rpc("invalid clock sysvar".to_string()))?;
+
+        Ok(clock.unix_timestamp as u64)
+    }
+
+    fn send_transaction(
+        &self,
+        instructions: &[Instruction],
+        payer: &Keypair,
+        signers: &[&Keypair],
+    ) -> KeeperResult<Signature> {
+        let blockhash = self
+            .client
+            .get_latest_blockhash()
+            .map_err(|e| KeeperError::Rpc(e.to_string()))?;
+        let transaction = sign_transaction(instructions, payer, signers, blockhash);
+
+        self.client
+            .send_and_confirm_transaction(&transaction)
+            .map_err(|e| KeeperError::Rpc(e.to_string()))
+    }
+}
+
+// =====================================================================
+
+/// Backend over a solana-program-test bank, for end to end tests of the keeper. Cluster time can be moved
+/// forward with `set_unix_timestamp` to step the vault through its lifecycle.
+#[cfg(feature = "program-test")]
+pub struct BanksBackend {
+    context: std::sync::Mutex<solana_program_test::ProgramTestContext>,
+    runtime: tokio::runtime::Runtime,
+}
+
+#[cfg(feature = "program-test")]
+impl BanksBackend {
+    pub fn start(program_test: solana_program_test::ProgramTest) -> Self {
+        let runtime = tokio::runtime::Runtime::new().expect("failed to start tokio runtime");
+        let context = runtime.block_on(program_test.start_with_context());
+
+        Self::new(runtime, context)
+    }
+
+    /// backend over a bank that was already started, e.g. to set up fixtures with the context first. the
+    /// context must have been started on `runtime`.
+    pub fn new(
+        runtime: tokio::runtime::Runtime,
+        context: solana_program_test::ProgramTestContext,
+    ) -> Self {
+        BanksBackend {
+            context: std::sync::Mutex::new(context),
+            runtime,
+        }
+    }
+
+    /// payer funded by the bank at genesis
+    pub fn payer(&self) -> Keypair {
+        let context = self.context.lock().unwrap();
+        Keypair::from_bytes(&context.payer.to_bytes()).unwrap()
+    }
+
+    pub fn set_unix_timestamp(&self, unix_timestamp: u64) -> KeeperResult {
+        let context = &mut *self.context.lock().unwrap();
+
+        let mut clock: Clock = self
+            .runtime
+            .block_on(context.banks_client.get_sysvar())
+            .map_err(|e| KeeperError::Rpc(e.to_string()))?;
+        clock.unix_timestamp = unix_timestamp as i64;
+        context.set_sysvar(&clock);
+
+        Ok(())
+    }
+
+    pub fn set_account(&self, address: &Pubkey, account: &Account) {
+        let context = &mut *self.context.lock().unwrap();
+        context.set_account(
+            address,
+            &solana_sdk::account::AccountSharedData::from(account.clone()),
+        );
+    }
+}
+
+#[cfg(feature = "program-test")]
+impl AccountFetcher for BanksBackend {
+    fn get_account(&self, address: &Pubkey) -> ClientResult<Option<(Pubkey, Vec<u8>)>> {
+        let context = &mut *self.context.lock().unwrap();
+
+        let account = self
+            .runtime
+            .block_on(context.banks_client.get_account(*address))
+            .map_err(|e| ClientError::Rpc(e.to_string()))?;
+
+        Ok(account.map(|account| (account.owner, account.data)))
+    }
+}
+
+#[cfg(feature = "program-test")]
+impl Backend for BanksBackend {
+    fn unix_timestamp(&self) -> KeeperResult<u64> {
+        let context = &mut *self.context.lock().unwrap();
+
+        let clock: Clock = self
+            .runtime
+            .block_on(context.banks_client.get_sysvar())
+            .map_err(|e| KeeperError::Rpc(e.to_string()))?;
+
+        Ok(clock.unix_timestamp as u64)
+    }
+
+    fn send_transaction(
+        &self,
+        instructions: &[Instruction],
+        payer: &Keypair,
+        signers: &[&Keypair],
+    ) -> KeeperResult<Signature> {
+        let context = &mut *self.context.lock().unwrap();
+
+        let blockhash = self
+            .runtime
+            .block_on(context.banks_client.get_latest_blockhash())
+            .map_err(|e| KeeperError::Rpc(e.to_string()))?;
+        let transaction = sign_transaction(instructions, payer, signers, blockhash);
+        let signature = transaction.signatures[0];
+
+        self.runtime
+            .block_on(context.banks_client.process_transaction(transaction))
+            .map_err(|e| KeeperError::Rpc(e.to_string()))?;
+
+        Ok(signature)
+    }
+}
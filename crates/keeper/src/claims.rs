@@ -0,0 +1,120 @@
+use crate::error::KeeperResult;
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use vault::state::{Receipt, Vault};
+use vault_client::{
+    fetch_accounts, get_history_address_and_bump_seed, get_receipt_address_and_bump_seed,
+    AccountFetcher,
+};
+
+/// Receipt indices to pass to the next `process_claims` call for a tranche. Receipts are indexed from 1 to
+/// the tranche's number of deposits and must be walked from the most recent deposit backwards, picking up
+/// where the last call left off.
+pub fn next_claim_indices(
+    vault: &Vault,
+    tranche_id: u8,
+    max_claims: usize,
+) -> KeeperResult<Vec<u64>> {
+    let tranche = vault.get_tranche(tranche_id)?;
+    if tranche.claims_processed {
+        return Ok(vec![]);
+    }
+
+    let start = tranche.claims_idx.unwrap_or(tranche.deposits);
+
+    Ok((1..=start).rev().take(max_claims).collect())
+}
+
+/// Remaining accounts for the next `process_claims` call for a tranche: (receipt, history) pairs in
+/// descending receipt order. Empty if the tranche has no receipts left to walk, in which case the
+/// instruction still needs to be sent to finalize the tranche's claims.
+pub fn next_claims_batch<F: AccountFetcher + ?Sized>(
+    fetcher: &F,
+    vault_address: &Pubkey,
+    vault: &Vault,
+    tranche_id: u8,
+    max_claims: usize,
+) -> KeeperResult<Vec<AccountMeta>> {
+    let receipt_addresses: Vec<Pubkey> = next_claim_indices(vault, tranche_id, max_claims)?
+        .into_iter()
+        .map(|index| get_receipt_address_and_bump_seed(vault_address, tranche_id, index).0)
+        .collect();
+    let receipts = fetch_accounts::<Receipt, _>(fetcher, &receipt_addresses)?;
+
+    let mut accounts = Vec::with_capacity(receipt_addresses.len() * 2);
+    for (address, receipt) in receipt_addresses.iter().zip(receipts) {
+        // receipts are created with every deposit, so a gap means the rpc node is behind. stop at the
+        // gap, the rest of the walk is picked up by the next batch.
+        let receipt = match receipt {
+            Some(receipt) => receipt,
+            None => break,
+        };
+        let (history, _) =
+            get_history_address_and_bump_seed(vault_address, tranche_id, &receipt.depositor);
+
+        accounts.push(AccountMeta::new_readonly(*address, false));
+        accounts.push(AccountMeta::new(history, false));
+    }
+
+    Ok(accounts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vault::state::Asset;
+
+    fn vault_with_tranche(tranche: Asset) -> Vault {
+        let mut vault = Vault::default();
+        vault.tranches[0] = tranche;
+        vault.num_tranches = 1;
+        vault
+    }
+
+    #[test]
+    fn test_first_batch_starts_at_most_recent_deposit() {
+        let vault = vault_with_tranche(Asset {
+            deposits: 7,
+            ..Asset::default()
+        });
+
+        assert_eq!(next_claim_indices(&vault, 0, 3).unwrap(), vec![7, 6, 5]);
+    }
+
+    #[test]
+    fn test_batch_resumes_at_claims_idx() {
+        let vault = vault_with_tranche(Asset {
+            deposits: 7,
+            claims_idx: Some(4),
+            ..Asset::default()
+        });
+
+        assert_eq!(next_claim_indices(&vault, 0, 3).unwrap(), vec![4, 3, 2]);
+        // the last batch stops at the first receipt
+        assert_eq!(next_claim_indices(&vault, 0, 5).unwrap(), vec![4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn test_no_indices_once_walked_or_processed() {
+        // every receipt walked, but the tranche still needs a call to finalize its claims
+        let walked = vault_with_tranche(Asset {
+            deposits: 7,
+            claims_idx: Some(0),
+            ..Asset::default()
+        });
+        assert!(next_claim_indices(&walked, 0, 3).unwrap().is_empty());
+
+        let processed = vault_with_tranche(Asset {
+            deposits: 7,
+            claims_processed: true,
+            ..Asset::default()
+        });
+        assert!(next_claim_indices(&processed, 0, 3).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_unknown_tranche() {
+        let vault = vault_with_tranche(Asset::default());
+
+        assert!(next_claim_indices(&vault, 1, 3).is_err());
+    }
+}
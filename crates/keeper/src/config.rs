@@ -0,0 +1,120 @@
+use crate::error::{KeeperError, KeeperResult};
+use serde::{de::Error as _, Deserialize, Deserializer};
+use solana_program::pubkey::Pubkey;
+use std::{path::Path, str::FromStr};
+
+/// Keeper config, read from a TOML file. See `keeper.example.toml`.
+#[derive(Debug, Deserialize)]
+pub struct KeeperConfig {
+    pub rpc_url: String,
+    /// vaults are derived from their authority
+    #[serde(deserialize_with = "pubkey")]
+    pub vault_authority: Pubkey,
+    pub authority_keypair: String,
+    pub strategist_keypair: String,
+    #[serde(default = "default_poll_interval_secs")]
+    pub poll_interval_secs: u64,
+    #[serde(default = "default_claims_per_transaction")]
+    pub claims_per_transaction: usize,
+    pub strategy: StrategyConfig,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum StrategyConfig {
+    Orca(OrcaConfig),
+    Saber(SaberConfig),
+}
+
+/// Orca pool the vault's strategy invests in. token A & B follow the pool's ordering.
+#[derive(Clone, Debug, Deserialize)]
+pub struct OrcaConfig {
+    #[serde(deserialize_with = "pubkey")]
+    pub swap_program: Pubkey,
+    #[serde(deserialize_with = "pubkey")]
+    pub pool: Pubkey,
+    #[serde(deserialize_with = "pubkey")]
+    pub pool_authority: Pubkey,
+    /// pool's reserve of token A
+    #[serde(deserialize_with = "pubkey")]
+    pub pool_token_a: Pubkey,
+    /// pool's reserve of token B
+    #[serde(deserialize_with = "pubkey")]
+    pub pool_token_b: Pubkey,
+    #[serde(deserialize_with = "pubkey")]
+    pub pool_mint: Pubkey,
+    #[serde(deserialize_with = "pubkey")]
+    pub fee_account: Pubkey,
+    /// total swap fee of the pool, in basis points
+    #[serde(default = "default_orca_fee_bps")]
+    pub fee_bps: u64,
+    /// aquafarm the vault's base LP is staked in while the vault is live, if any
+    pub farm: Option<OrcaFarmConfig>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct OrcaFarmConfig {
+    #[serde(deserialize_with = "pubkey")]
+    pub aquafarm_program: Pubkey,
+    #[serde(deserialize_with = "pubkey")]
+    pub global_farm: Pubkey,
+    #[serde(deserialize_with = "pubkey")]
+    pub global_base_token_vault: Pubkey,
+    #[serde(deserialize_with = "pubkey")]
+    pub global_reward_token_vault: Pubkey,
+    #[serde(deserialize_with = "pubkey")]
+    pub farm_token_mint: Pubkey,
+    #[serde(deserialize_with = "pubkey")]
+    pub reward_mint: Pubkey,
+    #[serde(deserialize_with = "pubkey")]
+    pub farm_authority: Pubkey,
+}
+
+/// Saber stable swap the vault's strategy invests in
+#[derive(Clone, Debug, Deserialize)]
+pub struct SaberConfig {
+    #[serde(deserialize_with = "pubkey")]
+    pub swap: Pubkey,
+    #[serde(deserialize_with = "pubkey")]
+    pub swap_authority: Pubkey,
+    #[serde(deserialize_with = "pubkey")]
+    pub reserve_a: Pubkey,
+    #[serde(deserialize_with = "pubkey")]
+    pub reserve_b: Pubkey,
+    #[serde(deserialize_with = "pubkey")]
+    pub pool_mint: Pubkey,
+    /// swap's admin fee account for token A
+    #[serde(deserialize_with = "pubkey")]
+    pub admin_fee_a: Pubkey,
+    /// swap's admin fee account for token B
+    #[serde(deserialize_with = "pubkey")]
+    pub admin_fee_b: Pubkey,
+}
+
+impl KeeperConfig {
+    pub fn load(path: &Path) -> KeeperResult<Self> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| KeeperError::Config(format!("{}: {}", path.display(), e)))?;
+
+        toml::from_str(&contents).map_err(|e| KeeperError::Config(e.to_string()))
+    }
+}
+
+fn default_poll_interval_secs() -> u64 {
+    10
+}
+
+// each pair is 2 accounts and a fair amount of compute; stay well under the transaction limits
+fn default_claims_per_transaction() -> usize {
+    5
+}
+
+// orca's standard pools charge 25 bps to LPs + 5 bps to the protocol
+fn default_orca_fee_bps() -> u64 {
+    30
+}
+
+fn pubkey<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Pubkey, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    Pubkey::from_str(&value).map_err(|e| D::Error::custom(format!("{}: {}", value, e)))
+}
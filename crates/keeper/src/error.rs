@@ -0,0 +1,23 @@
+use solana_program::program_error::ProgramError;
+use thiserror::Error;
+use vault_client::ClientError;
+
+pub type KeeperResult<T = ()> = Result<T, KeeperError>;
+
+#[derive(Debug, Error)]
+pub enum KeeperError {
+    #[error(transparent)]
+    Client(#[from] ClientError),
+
+    #[error("program error: {0}")]
+    Program(#[from] ProgramError),
+
+    #[error("rpc error: {0}")]
+    Rpc(String),
+
+    #[error("invalid config: {0}")]
+    Config(String),
+
+    #[error("unsupported by strategy: {0}")]
+    Unsupported(&'static str),
+}
@@ -0,0 +1,260 @@
+use crate::{
+    backend::Backend,
+    claims::next_claims_batch,
+    error::{KeeperError, KeeperResult},
+    strategy::{Strategy, VaultContext},
+};
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+use solana_sdk::signature::{Keypair, Signer};
+use std::{thread, time::Duration};
+use vault::{
+    accounts,
+    state::{State, Vault},
+};
+use vault_client::{fetch_vault, instructions};
+
+/// What the keeper did on a step
+#[derive(Clone, Debug, PartialEq)]
+pub enum Action {
+    Transition(State),
+    Invest,
+    ProcessClaims(u8),
+    Maintain,
+    Unwind,
+    Redeem,
+    Rebalance,
+    /// nothing to do until time passes
+    Idle,
+    /// the vault reached the withdraw state or was cancelled; depositors take it from here
+    Finished,
+}
+
+/// Drives a single vault through its lifecycle:
+///
+///   inactive -> deposit -> live (invest, process claims, farm) -> redeem -> rebalance -> withdraw
+///
+/// Each step reads the vault and the cluster time, picks the next action and sends it in one
+/// transaction. Steps are idempotent, so a failed or dropped transaction is retried on the next step.
+pub struct Keeper<B: Backend> {
+    backend: B,
+    vault: Pubkey,
+    authority: Keypair,
+    strategist: Keypair,
+    strategy: Box<dyn Strategy>,
+    claims_per_transaction: usize,
+}
+
+impl<B: Backend> Keeper<B> {
+    pub fn new(
+        backend: B,
+        vault: Pubkey,
+        authority: Keypair,
+        strategist: Keypair,
+        strategy: Box<dyn Strategy>,
+        claims_per_transaction: usize,
+    ) -> Self {
+        Keeper {
+            backend,
+            vault,
+            authority,
+            strategist,
+            strategy,
+            claims_per_transaction,
+        }
+    }
+
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+
+    /// Step until the vault reaches the withdraw state or is cancelled, waiting poll_interval between idle
+    /// steps. errors are logged and retried.
+    pub fn run(&self, poll_interval: Duration) {
+        loop {
+            match self.step() {
+                Ok(Action::Finished) => {
+                    log::info!("vault {} finished", self.vault);
+                    return;
+                }
+                Ok(Action::Idle) => thread::sleep(poll_interval),
+                Ok(action) => log::info!("{:?}", action),
+                Err(e) => {
+                    log::error!("{}", e);
+                    thread::sleep(poll_interval);
+                }
+            }
+        }
+    }
+
+    /// Take the vault's next action, if any
+    pub fn step(&self) -> KeeperResult<Action> {
+        let vault = fetch_vault(&self.backend, &self.vault)?;
+        let now = self.backend.unix_timestamp()?;
+        let ctx = VaultContext {
+            address: self.vault,
+            vault: &vault,
+            strategist: self.strategist.pubkey(),
+        };
+
+        let (action, ixs) = self.next_action(&ctx, now)?;
+        match &action {
+            Action::Transition(target) => self.transition(&vault, *target, now)?,
+            Action::Idle | Action::Finished => {}
+            _ => self.send_as_strategist(&ixs)?,
+        }
+
+        Ok(action)
+    }
+
+    fn next_action(
+        &self,
+        ctx: &VaultContext,
+        now: u64,
+    ) -> KeeperResult<(Action, Vec<Instruction>)> {
+        let vault = ctx.vault;
+        let fetcher = &self.backend;
+
+        Ok(match vault.state() {
+            // a cancelled vault only needs its claims processed for depositors to claim refunds
+            State::Inactive if vault.is_cancelled() && has_unprocessed_claims(vault) => {
+                let tranche_id = next_unprocessed_tranche(vault);
+                (
+                    Action::ProcessClaims(tranche_id),
+                    vec![self.process_claims(ctx, tranche_id)?],
+                )
+            }
+            State::Inactive if vault.is_cancelled() => (Action::Finished, vec![]),
+            State::Inactive if now >= vault.start_at => {
+                (Action::Transition(State::Deposit), vec![])
+            }
+            State::Deposit if now >= deposit_ends_at(vault) => {
+                (Action::Transition(State::Live), vec![])
+            }
+            State::Live if needs_invest(vault) => {
+                (Action::Invest, self.strategy.invest(fetcher, ctx)?)
+            }
+            State::Live if has_unprocessed_claims(vault) => {
+                let tranche_id = next_unprocessed_tranche(vault);
+                (
+                    Action::ProcessClaims(tranche_id),
+                    vec![self.process_claims(ctx, tranche_id)?],
+                )
+            }
+            State::Live if now < invest_ends_at(vault) => {
+                match self.strategy.maintain(fetcher, ctx)? {
+                    ixs if ixs.is_empty() => (Action::Idle, vec![]),
+                    ixs => (Action::Maintain, ixs),
+                }
+            }
+            State::Live => match self.strategy.unwind(fetcher, ctx)? {
+                ixs if ixs.is_empty() => (Action::Transition(State::Redeem), vec![]),
+                ixs => (Action::Unwind, ixs),
+            },
+            State::Redeem => match self.strategy.redeemable_lp(fetcher, ctx)? {
+                0 => (Action::Transition(State::Rebalance), vec![]),
+                _ => (Action::Redeem, self.strategy.redeem(fetcher, ctx)?),
+            },
+            State::Rebalance if !vault.can_disperse_funds() => {
+                (Action::Rebalance, self.strategy.rebalance(fetcher, ctx)?)
+            }
+            State::Rebalance => (Action::Transition(State::Withdraw), vec![]),
+            State::Withdraw => (Action::Finished, vec![]),
+            _ => (Action::Idle, vec![]),
+        })
+    }
+
+    fn process_claims(&self, ctx: &VaultContext, tranche_id: u8) -> KeeperResult<Instruction> {
+        let remaining_accounts = next_claims_batch(
+            &self.backend,
+            &ctx.address,
+            ctx.vault,
+            tranche_id,
+            self.claims_per_transaction,
+        )?;
+
+        Ok(instructions::process_claims(
+            accounts::ProcessClaims {
+                payer: self.strategist.pubkey(),
+                authority: ctx.vault.authority,
+                global_protocol_state: ctx.global_protocol_state(),
+                vault: ctx.address,
+            },
+            tranche_id,
+        )
+        .remaining_accounts(remaining_accounts)
+        .build())
+    }
+
+    fn transition(&self, vault: &Vault, target: State, now: u64) -> KeeperResult {
+        if vault.authority != self.authority.pubkey() {
+            return Err(KeeperError::Config(
+                "authority keypair does not match the vault's authority".to_string(),
+            ));
+        }
+
+        let ix = instructions::transition_vault(
+            accounts::TransitionVault {
+                payer: self.authority.pubkey(),
+                authority: self.authority.pubkey(),
+                global_protocol_state:
+                    vault_client::get_global_protocol_state_address_and_bump_seed().0,
+                vault: self.vault,
+            },
+            target,
+            now,
+        )
+        .build();
+
+        let signature = self.backend.send_transaction(&[ix], &self.authority, &[])?;
+        log::debug!("transition to {:?}: {}", target, signature);
+
+        Ok(())
+    }
+
+    fn send_as_strategist(&self, ixs: &[Instruction]) -> KeeperResult {
+        let signature = self.backend.send_transaction(ixs, &self.strategist, &[])?;
+        log::debug!("{}", signature);
+
+        Ok(())
+    }
+}
+
+pub fn deposit_ends_at(vault: &Vault) -> u64 {
+    vault
+        .started_at
+        .unwrap_or(vault.start_at)
+        .saturating_add(vault.deposit_duration)
+}
+
+pub fn invest_ends_at(vault: &Vault) -> u64 {
+    vault
+        .invested_at
+        .unwrap_or_else(|| deposit_ends_at(vault))
+        .saturating_add(vault.invest_duration)
+}
+
+/// the vault's deposits have not been invested yet. investing a vault with an empty tranche invests
+/// nothing and marks every deposit as excess, so excess tells the two cases apart.
+pub fn needs_invest(vault: &Vault) -> bool {
+    vault.tranches().iter().any(|tranche| tranche.deposited > 0)
+        && vault
+            .tranches()
+            .iter()
+            .all(|tranche| tranche.invested == 0 && tranche.excess == 0)
+}
+
+pub fn has_unprocessed_claims(vault: &Vault) -> bool {
+    vault
+        .tranches()
+        .iter()
+        .any(|tranche| !tranche.claims_processed)
+}
+
+fn next_unprocessed_tranche(vault: &Vault) -> u8 {
+    vault
+        .tranches()
+        .iter()
+        .find(|tranche| !tranche.claims_processed)
+        .map(|tranche| tranche.tranche_id)
+        .unwrap_or_default()
+}
@@ -0,0 +1,17 @@
+//! Keeper that runs vaults through their lifecycle without manual intervention: state transitions,
+//! investing, batched claim processing, farming, redeeming and rebalancing.
+
+pub mod backend;
+pub mod claims;
+pub mod config;
+pub mod error;
+pub mod keeper;
+pub mod rebalance;
+pub mod strategy;
+
+pub use backend::{Backend, RpcBackend};
+pub use error::{KeeperError, KeeperResult};
+pub use keeper::{Action, Keeper};
+
+#[cfg(feature = "program-test")]
+pub use backend::BanksBackend;
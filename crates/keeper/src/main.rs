@@ -0,0 +1,62 @@
+use clap::Parser;
+use keeper::{
+    config::KeeperConfig, strategy::load_strategy, Action, Keeper, KeeperError, KeeperResult,
+    RpcBackend,
+};
+use solana_sdk::signature::{read_keypair_file, Keypair};
+use std::{path::PathBuf, time::Duration};
+use vault_client::{fetch_vault, get_vault_address_and_bump_seed};
+
+#[derive(Parser)]
+#[clap(about = "Drive a vault through its lifecycle")]
+struct Args {
+    /// path to the keeper's TOML config
+    #[clap(short, long, default_value = "keeper.toml")]
+    config: PathBuf,
+
+    /// take a single step and exit instead of running until the vault reaches the withdraw state
+    #[clap(long)]
+    once: bool,
+}
+
+fn main() {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+
+    if let Err(e) = run(Args::parse()) {
+        log::error!("{}", e);
+        std::process::exit(1);
+    }
+}
+
+fn run(args: Args) -> KeeperResult {
+    let config = KeeperConfig::load(&args.config)?;
+    let backend = RpcBackend::new(config.rpc_url.clone());
+
+    let (vault_address, _) = get_vault_address_and_bump_seed(&config.vault_authority);
+    let vault = fetch_vault(&backend, &vault_address)?;
+    let strategy = load_strategy(&backend, &config.strategy, &vault)?;
+
+    let keeper = Keeper::new(
+        backend,
+        vault_address,
+        read_keypair(&config.authority_keypair)?,
+        read_keypair(&config.strategist_keypair)?,
+        strategy,
+        config.claims_per_transaction,
+    );
+    log::info!("keeping vault {}", vault_address);
+
+    match args.once {
+        true => {
+            let action: Action = keeper.step()?;
+            log::info!("{:?}", action);
+        }
+        false => keeper.run(Duration::from_secs(config.poll_interval_secs)),
+    }
+
+    Ok(())
+}
+
+fn read_keypair(path: &str) -> KeeperResult<Keypair> {
+    read_keypair_file(path).map_err(|e| KeeperError::Config(format!("{}: {}", path, e)))
+}
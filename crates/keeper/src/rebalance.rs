@@ -0,0 +1,260 @@
+use crate::error::KeeperResult;
+use solana_program::program_error::ProgramError;
+use vault::{
+    constant::MAX_BPS, error::ErrorCode, state::Vault, waterfall::compute_tranche_values,
+    SwapConfig,
+};
+
+/// Swap that moves value between the vault's two assets so that the alpha asset covers what the tranches
+/// it backs are owed, given the pool's reserves of each asset. The beta asset backs the most junior tranche
+/// and keeps whatever is left. None if no swap is needed.
+///
+/// @dev min_out is left at 0; the program raises it to the spot amount net of the pool's fees minus the
+///      vault's max slippage, which bounds the price impact of the swap.
+///
+/// @dev a deficit is covered by buying exactly the missing amount at the constant product price.
+///      overshooting would overpay the senior tranches at the expense of the junior tranche.
+///
+pub fn compute_swap_config(
+    vault: &Vault,
+    alpha_reserve: u64,
+    beta_reserve: u64,
+    fee_bps: u64,
+) -> KeeperResult<Option<SwapConfig>> {
+    if vault.is_single_asset() || alpha_reserve == 0 || beta_reserve == 0 {
+        return Ok(None);
+    }
+
+    let alpha_mint = vault.alpha().mint;
+    let alpha_received = vault.received_for(&alpha_mint)?;
+    let beta_received = vault.received_for(&vault.beta().mint)?;
+    let owed = compute_tranche_values(
+        vault,
+        alpha_received,
+        beta_received,
+        (beta_reserve, alpha_reserve),
+        MAX_BPS,
+    )?;
+
+    let alpha_owed = vault
+        .tranches()
+        .iter()
+        .zip(owed.iter())
+        .filter(|(tranche, _)| tranche.mint == alpha_mint)
+        .try_fold(0u64, |total, (_, owed)| total.checked_add(*owed))
+        .ok_or_else(|| ProgramError::from(ErrorCode::MathError))?;
+
+    // alpha has more than its tranches are owed; the surplus belongs to the junior tranche
+    if alpha_received > alpha_owed {
+        return Ok(Some(SwapConfig {
+            max_in: alpha_received - alpha_owed,
+            min_out: 0,
+            alpha_to_beta: true,
+        }));
+    }
+
+    let deficit = alpha_owed - alpha_received;
+    if deficit == 0 || beta_received == 0 {
+        return Ok(None);
+    }
+
+    // buy the deficit with beta, up to everything the junior tranche received
+    let amount_in =
+        compute_amount_in(deficit, beta_reserve, alpha_reserve, fee_bps).min(beta_received);
+
+    Ok(Some(SwapConfig {
+        max_in: amount_in,
+        min_out: 0,
+        alpha_to_beta: false,
+    }))
+}
+
+/// Amount in required to get amount_out out of a constant product pool after fees, rounded up. Capped at
+/// u64::MAX when amount_out approaches the pool's entire reserve.
+///
+/// @dev the pool takes its fee off the amount in and rounds the rest down, as orca does, so the amount in
+///      after fees is found first: amount_in_after_fee = reserve_in * amount_out / (reserve_out - amount_out)
+///      and amount_in = amount_in_after_fee / (1 - fee), each rounded up.
+///
+pub fn compute_amount_in(amount_out: u64, reserve_in: u64, reserve_out: u64, fee_bps: u64) -> u64 {
+    if amount_out == 0 {
+        return 0;
+    }
+    if amount_out >= reserve_out || fee_bps >= MAX_BPS {
+        return u64::MAX;
+    }
+
+    let amount_in_after_fee = div_ceil(
+        reserve_in as u128 * amount_out as u128,
+        (reserve_out - amount_out) as u128,
+    );
+    let amount_in = amount_in_after_fee
+        .checked_mul(MAX_BPS as u128)
+        .map(|amount| div_ceil(amount, (MAX_BPS - fee_bps) as u128))
+        .unwrap_or(u128::MAX);
+
+    amount_in.min(u64::MAX as u128) as u64
+}
+
+fn div_ceil(numerator: u128, denominator: u128) -> u128 {
+    numerator / denominator + (numerator % denominator != 0) as u128
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_program::pubkey::Pubkey;
+    use vault::state::{Asset, ReturnTerms};
+
+    // constant product swap with the fee taken off the amount in, rounded down as orca does
+    fn amount_out(amount_in: u64, reserve_in: u64, reserve_out: u64, fee_bps: u64) -> u64 {
+        let amount_in = amount_in as u128 * (MAX_BPS - fee_bps) as u128 / MAX_BPS as u128;
+        (reserve_out as u128 * amount_in / (reserve_in as u128 + amount_in)) as u64
+    }
+
+    // senior tranche owed a flat 10% on 1_000 alpha, junior tranche invested 1_000 beta
+    fn build_vault(
+        alpha_mint: Pubkey,
+        beta_mint: Pubkey,
+        alpha_received: u64,
+        beta_received: u64,
+    ) -> Vault {
+        let mut vault = Vault::default();
+        vault.tranches[0] = Asset {
+            tranche_id: 0,
+            mint: alpha_mint,
+            return_terms: Some(ReturnTerms {
+                floor_bps: 1_000,
+                cap_bps: 1_000,
+                yield_share_bps: 0,
+                first_loss_buffer_bps: None,
+            }),
+            invested: 1_000,
+            total_invested: 1_000,
+            received: alpha_received,
+            ..Asset::default()
+        };
+        vault.tranches[1] = Asset {
+            tranche_id: 1,
+            seniority: 1,
+            mint: beta_mint,
+            invested: 1_000,
+            total_invested: 1_000,
+            received: beta_received,
+            ..Asset::default()
+        };
+        vault.num_tranches = 2;
+        vault
+    }
+
+    fn pair(alpha_received: u64, beta_received: u64) -> Vault {
+        build_vault(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            alpha_received,
+            beta_received,
+        )
+    }
+
+    #[test]
+    fn test_amount_in_buys_exactly_amount_out() {
+        let amount_in = compute_amount_in(500, 2_000_000, 1_000_000, 30);
+
+        assert_eq!(amount_in, 1_005);
+        assert_eq!(amount_out(amount_in, 2_000_000, 1_000_000, 30), 500);
+        assert_eq!(amount_out(amount_in - 1, 2_000_000, 1_000_000, 30), 499);
+    }
+
+    #[test]
+    fn test_amount_in_edge_cases() {
+        assert_eq!(compute_amount_in(0, 1_000, 1_000, 30), 0);
+        // the pool can never pay out its entire reserve
+        assert_eq!(compute_amount_in(1_000, 1_000, 1_000, 30), u64::MAX);
+        assert_eq!(compute_amount_in(1, 1_000, 1_000, MAX_BPS), u64::MAX);
+        // capped rather than overflowing
+        assert_eq!(
+            compute_amount_in(u64::MAX - 1, u64::MAX, u64::MAX, 30),
+            u64::MAX
+        );
+        // exact even when reserve_in * amount_out * MAX_BPS does not fit in a u128
+        assert_eq!(
+            compute_amount_in(10u64.pow(17), 10u64.pow(18), 10u64.pow(18), 30),
+            111_445_447_453_471_527
+        );
+    }
+
+    #[test]
+    fn test_alpha_surplus_is_swapped_to_beta() {
+        let vault = pair(1_500, 1_000);
+
+        assert_eq!(
+            compute_swap_config(&vault, 1_000_000, 1_000_000, 30).unwrap(),
+            Some(SwapConfig {
+                max_in: 400,
+                min_out: 0,
+                alpha_to_beta: true,
+            })
+        );
+    }
+
+    #[test]
+    fn test_alpha_deficit_is_bought_with_beta() {
+        let vault = pair(900, 1_500);
+        let swap_config = compute_swap_config(&vault, 1_000_000, 1_000_000, 30)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            swap_config,
+            SwapConfig {
+                max_in: 202,
+                min_out: 0,
+                alpha_to_beta: false,
+            }
+        );
+        assert_eq!(
+            amount_out(swap_config.max_in, 1_000_000, 1_000_000, 30),
+            200
+        );
+    }
+
+    #[test]
+    fn test_alpha_deficit_is_capped_at_beta_received() {
+        let vault = pair(500, 100);
+
+        assert_eq!(
+            compute_swap_config(&vault, 1_000_000, 1_000_000, 30).unwrap(),
+            Some(SwapConfig {
+                max_in: 100,
+                min_out: 0,
+                alpha_to_beta: false,
+            })
+        );
+    }
+
+    #[test]
+    fn test_no_swap_needed() {
+        // alpha covers exactly what the senior tranche is owed
+        assert_eq!(
+            compute_swap_config(&pair(1_100, 1_000), 1_000_000, 1_000_000, 30).unwrap(),
+            None
+        );
+        // nothing to buy the deficit with
+        assert_eq!(
+            compute_swap_config(&pair(900, 0), 1_000_000, 1_000_000, 30).unwrap(),
+            None
+        );
+        // empty pool
+        assert_eq!(
+            compute_swap_config(&pair(1_500, 1_000), 0, 1_000_000, 30).unwrap(),
+            None
+        );
+
+        let mint = Pubkey::new_unique();
+        let single_asset = build_vault(mint, mint, 1_500, 1_000);
+        assert_eq!(
+            compute_swap_config(&single_asset, 1_000_000, 1_000_000, 30).unwrap(),
+            None
+        );
+    }
+}
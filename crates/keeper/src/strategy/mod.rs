@@ -0,0 +1,102 @@
+mod orca;
+mod saber;
+
+pub use orca::*;
+pub use saber::*;
+
+use crate::{backend::token_balance, config::StrategyConfig, error::KeeperResult};
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+use spl_associated_token_account::{create_associated_token_account, get_associated_token_address};
+use vault::state::Vault;
+use vault_client::{get_global_protocol_state_address_and_bump_seed, AccountFetcher};
+
+/// Vault the keeper is acting on, and the strategist signing for it
+pub struct VaultContext<'a> {
+    pub address: Pubkey,
+    pub vault: &'a Vault,
+    pub strategist: Pubkey,
+}
+
+impl<'a> VaultContext<'a> {
+    pub fn global_protocol_state(&self) -> Pubkey {
+        get_global_protocol_state_address_and_bump_seed().0
+    }
+}
+
+/// Strategy specific instructions the keeper needs to move a vault through its lifecycle. Each method
+/// returns the instructions for a single transaction, including any token accounts that need to be
+/// created first.
+pub trait Strategy {
+    /// invest the vault's deposits into the strategy
+    fn invest(
+        &self,
+        fetcher: &dyn AccountFetcher,
+        ctx: &VaultContext,
+    ) -> KeeperResult<Vec<Instruction>>;
+
+    /// upkeep while the vault is live, e.g. staking LP in a farm. empty when there is nothing to do.
+    fn maintain(
+        &self,
+        _fetcher: &dyn AccountFetcher,
+        _ctx: &VaultContext,
+    ) -> KeeperResult<Vec<Instruction>> {
+        Ok(vec![])
+    }
+
+    /// unwind anything that prevents the strategy's LP from being redeemed, e.g. unstaking from a farm.
+    /// must run before the vault leaves the live state. empty when there is nothing to do.
+    fn unwind(
+        &self,
+        _fetcher: &dyn AccountFetcher,
+        _ctx: &VaultContext,
+    ) -> KeeperResult<Vec<Instruction>> {
+        Ok(vec![])
+    }
+
+    /// amount of the strategy's LP the vault can redeem
+    fn redeemable_lp(&self, fetcher: &dyn AccountFetcher, ctx: &VaultContext) -> KeeperResult<u64>;
+
+    /// redeem all of the vault's LP for the pool's underlying assets
+    fn redeem(
+        &self,
+        fetcher: &dyn AccountFetcher,
+        ctx: &VaultContext,
+    ) -> KeeperResult<Vec<Instruction>>;
+
+    /// swap between the vault's assets as needed and settle the tranches
+    fn rebalance(
+        &self,
+        fetcher: &dyn AccountFetcher,
+        ctx: &VaultContext,
+    ) -> KeeperResult<Vec<Instruction>>;
+}
+
+pub fn load_strategy(
+    fetcher: &dyn AccountFetcher,
+    config: &StrategyConfig,
+    vault: &Vault,
+) -> KeeperResult<Box<dyn Strategy>> {
+    Ok(match config {
+        StrategyConfig::Orca(config) => Box::new(OrcaStrategy::load(
+            fetcher,
+            config.clone(),
+            &vault.strategy,
+        )?),
+        StrategyConfig::Saber(config) => Box::new(SaberStrategy::load(fetcher, config.clone())?),
+    })
+}
+
+/// Create `owner`'s associated token account for `mint` if it does not exist yet
+pub fn create_ata_if_missing(
+    fetcher: &dyn AccountFetcher,
+    payer: &Pubkey,
+    owner: &Pubkey,
+    mint: &Pubkey,
+) -> KeeperResult<Option<Instruction>> {
+    let address = get_associated_token_address(owner, mint);
+
+    Ok(match token_balance(fetcher, &address)? {
+        Some(_) => None,
+        None => Some(create_associated_token_account(payer, owner, mint)),
+    })
+}
@@ -0,0 +1,373 @@
+use crate::{
+    backend::token_balance,
+    config::{OrcaConfig, OrcaFarmConfig},
+    error::{KeeperError, KeeperResult},
+    rebalance::compute_swap_config,
+    strategy::{create_ata_if_missing, Strategy, VaultContext},
+};
+use solana_program::{instruction::Instruction, pubkey::Pubkey, system_program, sysvar};
+use spl_associated_token_account::get_associated_token_address;
+use vault::accounts;
+use vault_client::{fetch_orca_strategy, instructions, AccountFetcher};
+
+pub struct OrcaStrategy {
+    config: OrcaConfig,
+    token_a: Pubkey,
+    token_b: Pubkey,
+}
+
+impl OrcaStrategy {
+    pub fn load(
+        fetcher: &dyn AccountFetcher,
+        config: OrcaConfig,
+        strategy: &Pubkey,
+    ) -> KeeperResult<Self> {
+        let strategy = fetch_orca_strategy(fetcher, strategy)?;
+        if strategy.swap_program != config.swap_program || strategy.base_lp != config.pool_mint {
+            return Err(KeeperError::Config(
+                "orca pool does not match the vault's strategy".to_string(),
+            ));
+        }
+
+        Ok(OrcaStrategy {
+            config,
+            token_a: strategy.token_a,
+            token_b: strategy.token_b,
+        })
+    }
+
+    fn vault_store_ata(ctx: &VaultContext, mint: &Pubkey) -> Pubkey {
+        get_associated_token_address(&ctx.vault.vault_store, mint)
+    }
+
+    fn farm(&self) -> Option<&OrcaFarmConfig> {
+        self.config.farm.as_ref()
+    }
+
+    // https://github.com/orca-so/aquafarm-sdk/blob/9ed9db0f04cf7406f1f6e9a3e316639f3d24e68c/src/models/UserFarm.ts#L42
+    fn user_farm(farm: &OrcaFarmConfig, vault_store: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[
+                &farm.global_farm.to_bytes(),
+                &vault_store.to_bytes(),
+                &spl_token::ID.to_bytes(),
+            ],
+            &farm.aquafarm_program,
+        )
+        .0
+    }
+
+    // pool reserves as (alpha reserve, beta reserve)
+    fn reserves(
+        &self,
+        fetcher: &dyn AccountFetcher,
+        ctx: &VaultContext,
+    ) -> KeeperResult<(u64, u64)> {
+        let reserve_a = token_balance(fetcher, &self.config.pool_token_a)?.unwrap_or_default();
+        let reserve_b = token_balance(fetcher, &self.config.pool_token_b)?.unwrap_or_default();
+
+        match self.token_a == ctx.vault.alpha().mint {
+            true => Ok((reserve_a, reserve_b)),
+            false => Ok((reserve_b, reserve_a)),
+        }
+    }
+
+    fn convert_accounts(
+        &self,
+        farm: &OrcaFarmConfig,
+        ctx: &VaultContext,
+    ) -> accounts::ConvertOrcaLp {
+        let base_ata = Self::vault_store_ata(ctx, &self.config.pool_mint);
+
+        accounts::ConvertOrcaLp {
+            payer: ctx.strategist,
+            authority: ctx.vault.authority,
+            global_protocol_state: ctx.global_protocol_state(),
+            vault: ctx.address,
+            vault_store: ctx.vault.vault_store,
+            strategy: ctx.vault.strategy,
+            system_program: system_program::ID,
+            token_program: spl_token::ID,
+            rent: sysvar::rent::ID,
+            aquafarm_program: farm.aquafarm_program,
+            pool_account: base_ata,
+            user_base_ata: base_ata,
+            global_base_token_vault: farm.global_base_token_vault,
+            farm_token_mint: farm.farm_token_mint,
+            user_farm_ata: Self::vault_store_ata(ctx, &farm.farm_token_mint),
+            global_farm: farm.global_farm,
+            user_farm: Self::user_farm(farm, &ctx.vault.vault_store),
+            global_reward_token_vault: farm.global_reward_token_vault,
+            user_reward_ata: Self::vault_store_ata(ctx, &farm.reward_mint),
+            farm_authority: farm.farm_authority,
+        }
+    }
+}
+
+impl Strategy for OrcaStrategy {
+    fn invest(
+        &self,
+        fetcher: &dyn AccountFetcher,
+        ctx: &VaultContext,
+    ) -> KeeperResult<Vec<Instruction>> {
+        let source_token_a = Self::vault_store_ata(ctx, &self.token_a);
+        let source_token_b = Self::vault_store_ata(ctx, &self.token_b);
+        let investable_a = token_balance(fetcher, &source_token_a)?.unwrap_or_default();
+        let investable_b = token_balance(fetcher, &source_token_b)?.unwrap_or_default();
+
+        let mut ixs: Vec<Instruction> = create_ata_if_missing(
+            fetcher,
+            &ctx.strategist,
+            &ctx.vault.vault_store,
+            &self.config.pool_mint,
+        )?
+        .into_iter()
+        .collect();
+
+        ixs.push(
+            instructions::invest_orca(
+                accounts::InvestOrca {
+                    payer: ctx.strategist,
+                    authority: ctx.vault.authority,
+                    global_protocol_state: ctx.global_protocol_state(),
+                    vault: ctx.address,
+                    vault_store: ctx.vault.vault_store,
+                    strategy: ctx.vault.strategy,
+                    system_program: system_program::ID,
+                    token_program: spl_token::ID,
+                    rent: sysvar::rent::ID,
+                    orca_swap_program: self.config.swap_program,
+                    orca_pool: self.config.pool,
+                    orca_authority: self.config.pool_authority,
+                    source_token_a,
+                    source_token_b,
+                    into_a: self.config.pool_token_a,
+                    into_b: self.config.pool_token_b,
+                    pool_token: self.config.pool_mint,
+                    pool_account: Self::vault_store_ata(ctx, &self.config.pool_mint),
+                },
+                investable_a,
+                investable_b,
+                0,
+            )
+            .remaining_accounts(instructions::oracle_accounts(ctx.vault))
+            .build(),
+        );
+
+        Ok(ixs)
+    }
+
+    fn maintain(
+        &self,
+        fetcher: &dyn AccountFetcher,
+        ctx: &VaultContext,
+    ) -> KeeperResult<Vec<Instruction>> {
+        let farm = match self.farm() {
+            Some(farm) => farm,
+            None => return Ok(vec![]),
+        };
+
+        let base_lp = token_balance(fetcher, &Self::vault_store_ata(ctx, &self.config.pool_mint))?
+            .unwrap_or_default();
+        if base_lp == 0 {
+            return Ok(vec![]);
+        }
+
+        let mut ixs = vec![];
+        for mint in [&farm.farm_token_mint, &farm.reward_mint] {
+            ixs.extend(create_ata_if_missing(
+                fetcher,
+                &ctx.strategist,
+                &ctx.vault.vault_store,
+                mint,
+            )?);
+        }
+
+        let user_farm = Self::user_farm(farm, &ctx.vault.vault_store);
+        if fetcher.get_account(&user_farm)?.is_none() {
+            ixs.push(
+                instructions::initialize_user_farm_orca(accounts::InitializeUserFarmOrca {
+                    payer: ctx.strategist,
+                    authority: ctx.vault.authority,
+                    global_protocol_state: ctx.global_protocol_state(),
+                    vault: ctx.address,
+                    vault_store: ctx.vault.vault_store,
+                    strategy: ctx.vault.strategy,
+                    system_program: system_program::ID,
+                    rent: sysvar::rent::ID,
+                    aquafarm_program: farm.aquafarm_program,
+                    global_farm: farm.global_farm,
+                    user_farm,
+                })
+                .build(),
+            );
+        }
+
+        ixs.push(instructions::convert_orca_lp(self.convert_accounts(farm, ctx)).build());
+
+        Ok(ixs)
+    }
+
+    // harvest rewards before unstaking; reverting alone does not claim them
+    fn unwind(
+        &self,
+        fetcher: &dyn AccountFetcher,
+        ctx: &VaultContext,
+    ) -> KeeperResult<Vec<Instruction>> {
+        let farm = match self.farm() {
+            Some(farm) => farm,
+            None => return Ok(vec![]),
+        };
+
+        let farm_lp = token_balance(fetcher, &Self::vault_store_ata(ctx, &farm.farm_token_mint))?
+            .unwrap_or_default();
+        if farm_lp == 0 {
+            return Ok(vec![]);
+        }
+
+        let harvest = instructions::harvest_orca(accounts::HarvestOrcaLp {
+            payer: ctx.strategist,
+            authority: ctx.vault.authority,
+            global_protocol_state: ctx.global_protocol_state(),
+            vault: ctx.address,
+            vault_store: ctx.vault.vault_store,
+            strategy: ctx.vault.strategy,
+            token_program: spl_token::ID,
+            aquafarm_program: farm.aquafarm_program,
+            global_farm: farm.global_farm,
+            user_farm: Self::user_farm(farm, &ctx.vault.vault_store),
+            global_base_token_vault: farm.global_base_token_vault,
+            global_reward_token_vault: farm.global_reward_token_vault,
+            user_reward_ata: Self::vault_store_ata(ctx, &farm.reward_mint),
+            farm_authority: farm.farm_authority,
+        })
+        .build();
+
+        let convert = self.convert_accounts(farm, ctx);
+        let revert = instructions::revert_orca_lp(accounts::RevertOrcaLp {
+            payer: convert.payer,
+            authority: convert.authority,
+            global_protocol_state: convert.global_protocol_state,
+            vault: convert.vault,
+            vault_store: convert.vault_store,
+            strategy: convert.strategy,
+            token_program: convert.token_program,
+            system_program: convert.system_program,
+            aquafarm_program: convert.aquafarm_program,
+            pool_account: convert.pool_account,
+            user_base_ata: convert.user_base_ata,
+            global_base_token_vault: convert.global_base_token_vault,
+            farm_token_mint: convert.farm_token_mint,
+            user_farm_ata: convert.user_farm_ata,
+            global_farm: convert.global_farm,
+            user_farm: convert.user_farm,
+            global_reward_token_vault: convert.global_reward_token_vault,
+            user_reward_ata: convert.user_reward_ata,
+            farm_authority: convert.farm_authority,
+        })
+        .build();
+
+        Ok(vec![harvest, revert])
+    }
+
+    fn redeemable_lp(&self, fetcher: &dyn AccountFetcher, ctx: &VaultContext) -> KeeperResult<u64> {
+        Ok(
+            token_balance(fetcher, &Self::vault_store_ata(ctx, &self.config.pool_mint))?
+                .unwrap_or_default(),
+        )
+    }
+
+    fn redeem(
+        &self,
+        _fetcher: &dyn AccountFetcher,
+        ctx: &VaultContext,
+    ) -> KeeperResult<Vec<Instruction>> {
+        Ok(vec![instructions::redeem_orca(
+            accounts::RedeemOrca {
+                payer: ctx.strategist,
+                authority: ctx.vault.authority,
+                global_protocol_state: ctx.global_protocol_state(),
+                vault: ctx.address,
+                vault_store: ctx.vault.vault_store,
+                strategy: ctx.vault.strategy,
+                system_program: system_program::ID,
+                token_program: spl_token::ID,
+                rent: sysvar::rent::ID,
+                orca_swap_program: self.config.swap_program,
+                orca_pool: self.config.pool,
+                orca_authority: self.config.pool_authority,
+                pool_mint: self.config.pool_mint,
+                source_pool_account: Self::vault_store_ata(ctx, &self.config.pool_mint),
+                from_a: self.config.pool_token_a,
+                from_b: self.config.pool_token_b,
+                source_token_a: Self::vault_store_ata(ctx, &self.token_a),
+                source_token_b: Self::vault_store_ata(ctx, &self.token_b),
+                fee_account: self.config.fee_account,
+            },
+            0,
+            0,
+        )
+        .remaining_accounts(instructions::oracle_accounts(ctx.vault))
+        .build()])
+    }
+
+    fn rebalance(
+        &self,
+        fetcher: &dyn AccountFetcher,
+        ctx: &VaultContext,
+    ) -> KeeperResult<Vec<Instruction>> {
+        let (alpha_reserve, beta_reserve) = self.reserves(fetcher, ctx)?;
+        let swap_config =
+            compute_swap_config(ctx.vault, alpha_reserve, beta_reserve, self.config.fee_bps)?;
+        log::info!("swap config: {:?}", swap_config);
+
+        let alpha_mint = ctx.vault.alpha().mint;
+        let beta_mint = ctx.vault.beta().mint;
+        let (alpha_pool, beta_pool) = match self.token_a == alpha_mint {
+            true => (self.config.pool_token_a, self.config.pool_token_b),
+            false => (self.config.pool_token_b, self.config.pool_token_a),
+        };
+        let alpha_to_beta = swap_config
+            .as_ref()
+            .map(|config| config.alpha_to_beta)
+            .unwrap_or(true);
+        let (user_source, pool_source, pool_destination, user_destination) = match alpha_to_beta {
+            true => (
+                Self::vault_store_ata(ctx, &alpha_mint),
+                alpha_pool,
+                beta_pool,
+                Self::vault_store_ata(ctx, &beta_mint),
+            ),
+            false => (
+                Self::vault_store_ata(ctx, &beta_mint),
+                beta_pool,
+                alpha_pool,
+                Self::vault_store_ata(ctx, &alpha_mint),
+            ),
+        };
+
+        Ok(vec![instructions::rebalance_orca(
+            accounts::RebalanceOrca {
+                payer: ctx.strategist,
+                authority: ctx.vault.authority,
+                global_protocol_state: ctx.global_protocol_state(),
+                vault: ctx.address,
+                vault_store: ctx.vault.vault_store,
+                strategy: ctx.vault.strategy,
+                token_program: spl_token::ID,
+                orca_swap_program: self.config.swap_program,
+                orca_pool: self.config.pool,
+                orca_authority: self.config.pool_authority,
+                user_source,
+                pool_source,
+                pool_destination,
+                user_destination,
+                pool_mint: self.config.pool_mint,
+                fee_account: self.config.fee_account,
+            },
+            swap_config,
+        )
+        .remaining_accounts(instructions::oracle_accounts(ctx.vault))
+        .build()])
+    }
+}
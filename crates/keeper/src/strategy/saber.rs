@@ -0,0 +1,136 @@
+use crate::{
+    backend::{token_balance, token_mint},
+    config::SaberConfig,
+    error::{KeeperError, KeeperResult},
+    strategy::{create_ata_if_missing, Strategy, VaultContext},
+};
+use solana_program::{instruction::Instruction, pubkey::Pubkey, system_program, sysvar};
+use spl_associated_token_account::get_associated_token_address;
+use vault::accounts;
+use vault_client::{instructions, AccountFetcher};
+
+/// Saber strategies hold their tokens and LP in the vault's token accounts rather than the vault_store's
+pub struct SaberStrategy {
+    config: SaberConfig,
+    token_a: Pubkey,
+    token_b: Pubkey,
+}
+
+impl SaberStrategy {
+    pub fn load(fetcher: &dyn AccountFetcher, config: SaberConfig) -> KeeperResult<Self> {
+        let token_a = token_mint(fetcher, &config.reserve_a)?;
+        let token_b = token_mint(fetcher, &config.reserve_b)?;
+
+        Ok(SaberStrategy {
+            config,
+            token_a,
+            token_b,
+        })
+    }
+
+    fn vault_ata(ctx: &VaultContext, mint: &Pubkey) -> Pubkey {
+        get_associated_token_address(&ctx.address, mint)
+    }
+
+    fn saber_swap_common(&self, ctx: &VaultContext) -> accounts::SaberSwapCommon {
+        accounts::SaberSwapCommon {
+            swap: self.config.swap,
+            swap_authority: self.config.swap_authority,
+            source_token_a: Self::vault_ata(ctx, &self.token_a),
+            reserve_a: self.config.reserve_a,
+            source_token_b: Self::vault_ata(ctx, &self.token_b),
+            reserve_b: self.config.reserve_b,
+            pool_mint: self.config.pool_mint,
+            saber_program: stable_swap_anchor::ID,
+        }
+    }
+}
+
+impl Strategy for SaberStrategy {
+    fn invest(
+        &self,
+        fetcher: &dyn AccountFetcher,
+        ctx: &VaultContext,
+    ) -> KeeperResult<Vec<Instruction>> {
+        let investable_a =
+            token_balance(fetcher, &Self::vault_ata(ctx, &self.token_a))?.unwrap_or_default();
+        let investable_b =
+            token_balance(fetcher, &Self::vault_ata(ctx, &self.token_b))?.unwrap_or_default();
+
+        let mut ixs: Vec<Instruction> = create_ata_if_missing(
+            fetcher,
+            &ctx.strategist,
+            &ctx.address,
+            &self.config.pool_mint,
+        )?
+        .into_iter()
+        .collect();
+
+        ixs.push(
+            instructions::invest_saber(
+                accounts::InvestSaber {
+                    payer: ctx.strategist,
+                    authority: ctx.vault.authority,
+                    global_protocol_state: ctx.global_protocol_state(),
+                    vault: ctx.address,
+                    strategy: ctx.vault.strategy,
+                    system_program: system_program::ID,
+                    token_program: spl_token::ID,
+                    rent: sysvar::rent::ID,
+                    saber_swap_common: self.saber_swap_common(ctx),
+                    output_lp: Self::vault_ata(ctx, &self.config.pool_mint),
+                },
+                investable_a,
+                investable_b,
+                0,
+            )
+            .remaining_accounts(instructions::oracle_accounts(ctx.vault))
+            .build(),
+        );
+
+        Ok(ixs)
+    }
+
+    fn redeemable_lp(&self, fetcher: &dyn AccountFetcher, ctx: &VaultContext) -> KeeperResult<u64> {
+        Ok(
+            token_balance(fetcher, &Self::vault_ata(ctx, &self.config.pool_mint))?
+                .unwrap_or_default(),
+        )
+    }
+
+    fn redeem(
+        &self,
+        _fetcher: &dyn AccountFetcher,
+        ctx: &VaultContext,
+    ) -> KeeperResult<Vec<Instruction>> {
+        Ok(vec![instructions::redeem_saber(
+            accounts::RedeemSaber {
+                payer: ctx.strategist,
+                authority: ctx.vault.authority,
+                global_protocol_state: ctx.global_protocol_state(),
+                vault: ctx.address,
+                strategy: ctx.vault.strategy,
+                system_program: system_program::ID,
+                token_program: spl_token::ID,
+                rent: sysvar::rent::ID,
+                saber_swap_common: self.saber_swap_common(ctx),
+                input_lp: Self::vault_ata(ctx, &self.config.pool_mint),
+                output_a_fees: self.config.admin_fee_a,
+                output_b_fees: self.config.admin_fee_b,
+            },
+            0,
+            0,
+        )
+        .remaining_accounts(instructions::oracle_accounts(ctx.vault))
+        .build()])
+    }
+
+    // todo: the program has no saber rebalance instruction yet, so saber vaults cannot be settled
+    fn rebalance(
+        &self,
+        _fetcher: &dyn AccountFetcher,
+        _ctx: &VaultContext,
+    ) -> KeeperResult<Vec<Instruction>> {
+        Err(KeeperError::Unsupported("rebalance"))
+    }
+}
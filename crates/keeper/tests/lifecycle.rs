@@ -0,0 +1,165 @@
+//! The keeper driving a 2 tranche vault that invests in an Orca pool served by the mock AMM, from the
+//! inactive state to withdraw, on a solana-program-test bank. Only the deposits are sent by the test.
+//!
+//! needs the `program-test` feature: `cargo test -p keeper --features program-test`
+
+#[path = "../../../programs/vault/tests/common/mod.rs"]
+mod common;
+
+use common::*;
+use keeper::{config::OrcaConfig, strategy::OrcaStrategy, Action, Backend, BanksBackend, Keeper};
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use vault::{
+    state::{ReturnTerms, State},
+    waterfall::convert,
+};
+use vault_client::fetch_vault;
+
+const POOL_RESERVE: u64 = 100_000_000;
+const SENIOR_DEPOSIT: u64 = 1_000_000;
+const JUNIOR_DEPOSIT: u64 = 600_000;
+const MAX_SLIPPAGE_BPS: u16 = 100;
+// one receipt per process_claims call, so the junior tranche's claims take more than one batch
+const CLAIMS_PER_TRANSACTION: usize = 1;
+// guards against a keeper that never finishes
+const MAX_STEPS: usize = 32;
+
+fn clone_keypair(keypair: &Keypair) -> Keypair {
+    Keypair::from_bytes(&keypair.to_bytes()).unwrap()
+}
+
+#[test]
+fn keeper_drives_orca_vault_to_withdraw() {
+    let mut program_test = program_test();
+
+    let senior_mint = Pubkey::new_unique();
+    let junior_mint = Pubkey::new_unique();
+    for mint in [&senior_mint, &junior_mint] {
+        add_mint(&mut program_test, mint, &Pubkey::new_unique(), 0, DECIMALS);
+    }
+    let pool = add_orca_pool(
+        &mut program_test,
+        &senior_mint,
+        &junior_mint,
+        (POOL_RESERVE, POOL_RESERVE),
+        POOL_RESERVE,
+    );
+
+    let alice = add_user(&mut program_test, &[(senior_mint, SENIOR_DEPOSIT)]);
+    let juniors: Vec<Keypair> = (0..3)
+        .map(|_| add_user(&mut program_test, &[(junior_mint, JUNIOR_DEPOSIT)]))
+        .collect();
+
+    // ====== strategy + vault ======
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let mut context = runtime.block_on(program_test.start_with_context());
+    let strategist = clone_keypair(&context.payer);
+    let (strategy, fixture) = runtime.block_on(async {
+        init_global_protocol_state(&mut context).await;
+        let strategy = OrcaStrategyFixture::init(&mut context, pool).await;
+
+        let mut senior = tranche_config(0, "SNR");
+        senior.return_terms = Some(ReturnTerms {
+            floor_bps: 500,
+            cap_bps: 500,
+            yield_share_bps: 0,
+            first_loss_buffer_bps: None,
+        });
+        let fixture = VaultFixture::init(
+            &mut context,
+            strategy.address,
+            vec![
+                (senior, senior_mint),
+                (tranche_config(1, "JNR"), junior_mint),
+            ],
+            MAX_SLIPPAGE_BPS,
+        )
+        .await;
+
+        (strategy, fixture)
+    });
+
+    let backend = BanksBackend::new(runtime, context);
+    let orca = OrcaStrategy::load(
+        &backend,
+        OrcaConfig {
+            swap_program: mock_amm::ID,
+            pool: strategy.pool.address,
+            pool_authority: strategy.pool.authority,
+            pool_token_a: strategy.pool.state.token_a,
+            pool_token_b: strategy.pool.state.token_b,
+            pool_mint: strategy.pool.state.pool_mint,
+            fee_account: strategy.pool.state.fee_account,
+            fee_bps: mock_amm::processor::TRADE_FEE_BPS,
+            farm: None,
+        },
+        &strategy.address,
+    )
+    .unwrap();
+    let keeper = Keeper::new(
+        backend,
+        fixture.address,
+        clone_keypair(&fixture.authority),
+        strategist,
+        Box::new(orca),
+        CLAIMS_PER_TRANSACTION,
+    );
+
+    // ====== deposit ======
+    // the vault starts right away; deposits are open until the keeper's next step
+    assert_eq!(keeper.step().unwrap(), Action::Transition(State::Deposit));
+
+    let deposits = std::iter::once((&alice, 0, SENIOR_DEPOSIT))
+        .chain(juniors.iter().map(|user| (user, 1, JUNIOR_DEPOSIT)));
+    for (user, tranche_id, amount) in deposits {
+        let vault = fetch_vault(keeper.backend(), &fixture.address).unwrap();
+        let ix = fixture.deposit_ix(&vault, &user.pubkey(), tranche_id, amount);
+        keeper.backend().send_transaction(&[ix], user, &[]).unwrap();
+    }
+
+    // ====== lifecycle ======
+    let mut actions = vec![];
+    while actions.last() != Some(&Action::Finished) {
+        assert!(actions.len() < MAX_STEPS, "keeper stuck: {:?}", actions);
+        actions.push(keeper.step().unwrap());
+    }
+
+    assert_eq!(
+        actions,
+        vec![
+            Action::Transition(State::Live),
+            Action::Invest,
+            Action::ProcessClaims(0),
+            // the latest junior deposit is refunded in full, the next one straddles the amount invested
+            Action::ProcessClaims(1),
+            Action::ProcessClaims(1),
+            Action::Transition(State::Redeem),
+            Action::Redeem,
+            Action::Transition(State::Rebalance),
+            Action::Rebalance,
+            Action::Transition(State::Withdraw),
+            Action::Finished,
+        ]
+    );
+
+    let vault = fetch_vault(keeper.backend(), &fixture.address).unwrap();
+    assert_eq!(vault.state, State::Withdraw);
+    assert!(vault.reblanced);
+    assert!(vault
+        .tranches()
+        .iter()
+        .all(|tranche| tranche.claims_already_processed()));
+
+    // the balanced deposit is capped by the senior side, less the vault's max slippage
+    let invested = SENIOR_DEPOSIT * (10_000 - MAX_SLIPPAGE_BPS as u64) / 10_000;
+    assert_eq!(vault.tranches[0].invested, invested);
+    assert_eq!(vault.tranches[1].invested, invested);
+
+    // the keeper's swap buys back exactly what the senior tranche is owed; the junior tranche keeps the rest
+    assert_eq!(
+        vault.tranches[0].received,
+        convert(invested, 10_500, 10_000).unwrap()
+    );
+    assert!(vault.tranches[1].received > 0);
+}
@@ -0,0 +1,205 @@
+use crate::error::{CliError, CliResult};
+use serde::{de::Error as _, Deserialize, Deserializer};
+use solana_program::pubkey::Pubkey;
+use std::{path::Path, str::FromStr};
+use vault::state::{
+    AssetConfig, DepositGate, LeverageConfig, OracleConfig, OracleSource, ReturnTerms, VaultConfig,
+};
+
+/// Vault config, read from a TOML file. Maps to the program's `VaultConfig`; see `vault.example.toml`.
+#[derive(Debug, Deserialize)]
+pub struct VaultToml {
+    #[serde(deserialize_with = "pubkey")]
+    pub strategy: Pubkey,
+    #[serde(deserialize_with = "pubkey")]
+    pub strategist: Pubkey,
+    pub start_at: u64,
+    pub deposit_duration: u64,
+    pub invest_duration: u64,
+    pub deposit_gate: Option<DepositGateToml>,
+    pub max_slippage_bps: u16,
+    pub oracle: Option<OracleToml>,
+    pub leverage: Option<LeverageToml>,
+    pub metadata_uri: String,
+    pub early_exit_penalty_bps: Option<u16>,
+    /// ordered from most senior to most junior
+    pub tranches: Vec<TrancheToml>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum DepositGateToml {
+    /// "open" or "allowlist"
+    Named(String),
+    /// hex encoded root of the merkle tree of allowed depositors
+    MerkleRoot { merkle_root: String },
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OracleToml {
+    /// "pyth" or "switchboard"
+    pub source: String,
+    #[serde(deserialize_with = "pubkey")]
+    pub alpha_oracle: Pubkey,
+    #[serde(deserialize_with = "pubkey")]
+    pub beta_oracle: Pubkey,
+    pub max_deviation_bps: u16,
+    pub max_staleness_slots: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LeverageToml {
+    pub target_ratio_bps: u32,
+    pub min_ratio_bps: u32,
+    pub max_ratio_bps: u32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TrancheToml {
+    /// mint of the tranche's asset
+    #[serde(deserialize_with = "pubkey")]
+    pub mint: Pubkey,
+    pub seniority: u8,
+    pub symbol: String,
+    pub return_terms: Option<ReturnTermsToml>,
+    pub user_cap: Option<u64>,
+    pub asset_cap: Option<u64>,
+    pub min_deposit: Option<u64>,
+    pub max_deposits_per_user: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReturnTermsToml {
+    pub floor_bps: u16,
+    pub cap_bps: u16,
+    pub yield_share_bps: u16,
+    pub first_loss_buffer_bps: Option<u16>,
+}
+
+impl VaultToml {
+    pub fn load(path: &Path) -> CliResult<Self> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| CliError::Config(format!("{}: {}", path.display(), e)))?;
+
+        toml::from_str(&contents).map_err(|e| CliError::Config(e.to_string()))
+    }
+
+    /// mints of the tranches' assets, in tranche order
+    pub fn asset_mints(&self) -> Vec<Pubkey> {
+        self.tranches.iter().map(|tranche| tranche.mint).collect()
+    }
+
+    pub fn to_vault_config(&self, authority: Pubkey) -> CliResult<VaultConfig> {
+        Ok(VaultConfig {
+            strategy: self.strategy,
+            authority,
+            strategist: self.strategist,
+            tranches: self
+                .tranches
+                .iter()
+                .map(TrancheToml::to_asset_config)
+                .collect(),
+            start_at: self.start_at,
+            deposit_duration: self.deposit_duration,
+            invest_duration: self.invest_duration,
+            deposit_gate: self
+                .deposit_gate
+                .as_ref()
+                .map(DepositGateToml::to_deposit_gate)
+                .transpose()?,
+            max_slippage_bps: self.max_slippage_bps,
+            oracle: self
+                .oracle
+                .as_ref()
+                .map(OracleToml::to_oracle_config)
+                .transpose()?,
+            leverage: self.leverage.as_ref().map(|leverage| LeverageConfig {
+                target_ratio_bps: leverage.target_ratio_bps,
+                min_ratio_bps: leverage.min_ratio_bps,
+                max_ratio_bps: leverage.max_ratio_bps,
+            }),
+            metadata_uri: self.metadata_uri.clone(),
+            early_exit_penalty_bps: self.early_exit_penalty_bps,
+        })
+    }
+}
+
+impl DepositGateToml {
+    fn to_deposit_gate(&self) -> CliResult<DepositGate> {
+        match self {
+            DepositGateToml::Named(name) => match name.as_str() {
+                "open" => Ok(DepositGate::Open),
+                "allowlist" => Ok(DepositGate::Allowlist),
+                _ => Err(CliError::Config(format!("unknown deposit gate: {}", name))),
+            },
+            DepositGateToml::MerkleRoot { merkle_root } => {
+                Ok(DepositGate::MerkleRoot(parse_merkle_root(merkle_root)?))
+            }
+        }
+    }
+}
+
+impl OracleToml {
+    fn to_oracle_config(&self) -> CliResult<OracleConfig> {
+        let source = match self.source.as_str() {
+            "pyth" => OracleSource::Pyth,
+            "switchboard" => OracleSource::Switchboard,
+            _ => {
+                return Err(CliError::Config(format!(
+                    "unknown oracle source: {}",
+                    self.source
+                )))
+            }
+        };
+
+        Ok(OracleConfig {
+            source,
+            alpha_oracle: self.alpha_oracle,
+            beta_oracle: self.beta_oracle,
+            max_deviation_bps: self.max_deviation_bps,
+            max_staleness_slots: self.max_staleness_slots,
+        })
+    }
+}
+
+impl TrancheToml {
+    fn to_asset_config(&self) -> AssetConfig {
+        AssetConfig {
+            seniority: self.seniority,
+            symbol: self.symbol.clone(),
+            return_terms: self.return_terms.as_ref().map(|terms| ReturnTerms {
+                floor_bps: terms.floor_bps,
+                cap_bps: terms.cap_bps,
+                yield_share_bps: terms.yield_share_bps,
+                first_loss_buffer_bps: terms.first_loss_buffer_bps,
+            }),
+            user_cap: self.user_cap,
+            asset_cap: self.asset_cap,
+            min_deposit: self.min_deposit,
+            max_deposits_per_user: self.max_deposits_per_user,
+        }
+    }
+}
+
+fn parse_merkle_root(value: &str) -> CliResult<[u8; 32]> {
+    let value = value.trim_start_matches("0x");
+    if value.len() != 64 {
+        return Err(CliError::Config(format!(
+            "merkle root must be 32 hex encoded bytes: {}",
+            value
+        )));
+    }
+
+    let mut root = [0u8; 32];
+    for (idx, byte) in root.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&value[idx * 2..idx * 2 + 2], 16)
+            .map_err(|e| CliError::Config(format!("{}: {}", value, e)))?;
+    }
+
+    Ok(root)
+}
+
+fn pubkey<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Pubkey, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    Pubkey::from_str(&value).map_err(|e| D::Error::custom(format!("{}: {}", value, e)))
+}
@@ -0,0 +1,26 @@
+use solana_program::program_error::ProgramError;
+use thiserror::Error;
+use vault_client::ClientError;
+
+pub type CliResult<T = ()> = Result<T, CliError>;
+
+#[derive(Debug, Error)]
+pub enum CliError {
+    #[error(transparent)]
+    Client(#[from] ClientError),
+
+    #[error("program error: {0}")]
+    Program(#[from] ProgramError),
+
+    #[error("rpc error: {0}")]
+    Rpc(String),
+
+    #[error("invalid config: {0}")]
+    Config(String),
+
+    #[error("invalid keypair {0}: {1}")]
+    Keypair(String, String),
+
+    #[error("account not found: {0}")]
+    AccountNotFound(String),
+}
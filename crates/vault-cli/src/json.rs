@@ -0,0 +1,133 @@
+//! JSON views of the vault program's accounts. Pubkeys are printed as base58 strings and enums by name,
+//! so the output can be piped straight into `jq` or other scripts.
+
+use serde_json::{json, Value};
+use solana_program::pubkey::Pubkey;
+use vault::state::{
+    Asset, DepositGate, GlobalProtocolState, History, LeverageConfig, OracleConfig, Receipt,
+    ReturnTerms, Vault,
+};
+
+pub fn global_protocol_state(address: &Pubkey, state: &GlobalProtocolState) -> Value {
+    json!({
+        "address": address.to_string(),
+        "authority": state.authority.to_string(),
+        "active": state.active,
+        "treasury": state.treasury.to_string(),
+    })
+}
+
+pub fn vault(address: &Pubkey, vault: &Vault) -> Value {
+    json!({
+        "address": address.to_string(),
+        "authority": vault.authority.to_string(),
+        "vault_store": vault.vault_store.to_string(),
+        "strategy": vault.strategy.to_string(),
+        "strategist": vault.strategist.to_string(),
+        "state": format!("{:?}", vault.state).to_lowercase(),
+        "tranches": vault.tranches().iter().map(asset).collect::<Vec<_>>(),
+        "start_at": vault.start_at,
+        "started_at": vault.started_at,
+        "deposit_duration": vault.deposit_duration,
+        "invested_at": vault.invested_at,
+        "invest_duration": vault.invest_duration,
+        "redeemed_at": vault.redeemed_at,
+        "rebalanced_at": vault.rebalanced_at,
+        "rebalanced": vault.reblanced,
+        "rollover_vault": vault.rollover_vault.map(|vault| vault.to_string()),
+        "deposit_gate": deposit_gate(&vault.deposit_gate),
+        "max_slippage_bps": vault.max_slippage_bps,
+        "oracle": vault.oracle.as_ref().map(oracle),
+        "leverage": vault.leverage.as_ref().map(leverage),
+        "tranche_lp_frozen": vault.tranche_lp_frozen,
+        "early_exit_penalty_bps": vault.early_exit_penalty_bps,
+        "cancelled_at": vault.cancelled_at,
+        "keeper": vault.keeper.map(|keeper| keeper.to_string()),
+        "keeper_roles": vault.keeper_roles,
+    })
+}
+
+pub fn asset(asset: &Asset) -> Value {
+    json!({
+        "tranche_id": asset.tranche_id,
+        "seniority": asset.seniority,
+        "return_terms": asset.return_terms.as_ref().map(return_terms),
+        "mint": asset.mint.to_string(),
+        "lp": asset.lp.to_string(),
+        "decimals": asset.decimals,
+        "asset_cap": asset.asset_cap,
+        "user_cap": asset.user_cap,
+        "min_deposit": asset.min_deposit,
+        "max_deposits_per_user": asset.max_deposits_per_user,
+        "deposits": asset.deposits,
+        "deposited": asset.deposited,
+        "invested": asset.invested,
+        "excess": asset.excess,
+        "received": asset.received,
+        "total_invested": asset.total_invested,
+        "rollover_deposited": asset.rollover_deposited,
+        "exit_credit": asset.exit_credit,
+        "claims_processed": asset.claims_processed,
+        "claims_idx": asset.claims_idx,
+    })
+}
+
+pub fn history(address: &Pubkey, history: &History) -> Value {
+    json!({
+        "address": address.to_string(),
+        "deposits": history.deposits,
+        "cumulative": history.cumulative,
+        "claim": history.claim,
+        "can_claim_tranche_lp": history.can_claim_tranche_lp,
+        "rollover": history.rollover,
+        "live_deposits": history.live_deposits,
+        "live_cumulative": history.live_cumulative,
+    })
+}
+
+pub fn receipt(address: &Pubkey, index: u64, receipt: &Receipt) -> Value {
+    json!({
+        "address": address.to_string(),
+        "index": index,
+        "depositor": receipt.depositor.to_string(),
+        "amount": receipt.amount,
+        "cumulative": receipt.cumulative,
+    })
+}
+
+fn return_terms(terms: &ReturnTerms) -> Value {
+    json!({
+        "floor_bps": terms.floor_bps,
+        "cap_bps": terms.cap_bps,
+        "yield_share_bps": terms.yield_share_bps,
+        "first_loss_buffer_bps": terms.first_loss_buffer_bps,
+    })
+}
+
+fn deposit_gate(gate: &DepositGate) -> Value {
+    match gate {
+        DepositGate::Open => json!("open"),
+        DepositGate::Allowlist => json!("allowlist"),
+        DepositGate::MerkleRoot(root) => json!({
+            "merkle_root": root.iter().map(|byte| format!("{:02x}", byte)).collect::<String>(),
+        }),
+    }
+}
+
+fn oracle(oracle: &OracleConfig) -> Value {
+    json!({
+        "source": format!("{:?}", oracle.source).to_lowercase(),
+        "alpha_oracle": oracle.alpha_oracle.to_string(),
+        "beta_oracle": oracle.beta_oracle.to_string(),
+        "max_deviation_bps": oracle.max_deviation_bps,
+        "max_staleness_slots": oracle.max_staleness_slots,
+    })
+}
+
+fn leverage(leverage: &LeverageConfig) -> Value {
+    json!({
+        "target_ratio_bps": leverage.target_ratio_bps,
+        "min_ratio_bps": leverage.min_ratio_bps,
+        "max_ratio_bps": leverage.max_ratio_bps,
+    })
+}
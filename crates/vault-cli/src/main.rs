@@ -0,0 +1,528 @@
+//! Operator CLI for the vault program. Initializes the global protocol state, strategies and vaults,
+//! transitions vaults between states and prints decoded program accounts. All output is JSON.
+
+mod config;
+mod error;
+mod json;
+
+use crate::{
+    config::VaultToml,
+    error::{CliError, CliResult},
+};
+use clap::{ArgEnum, Parser, Subcommand};
+use serde_json::{json, Value};
+use solana_client::rpc_client::RpcClient;
+use solana_program::{instruction::Instruction, pubkey::Pubkey, system_program, sysvar};
+use solana_sdk::{
+    commitment_config::CommitmentConfig,
+    signature::{read_keypair_file, Keypair, Signature},
+    signer::Signer,
+    transaction::Transaction,
+};
+use std::{
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+use vault::{
+    accounts,
+    state::{State, StrategistRole, StrategyFlag},
+};
+use vault_client::{
+    fetch_accounts, fetch_global_protocol_state, fetch_history, fetch_receipt, fetch_vault,
+    get_global_protocol_state_address_and_bump_seed, get_history_address_and_bump_seed,
+    get_orca_strategy_address_and_bump_seed, get_receipt_address_and_bump_seed,
+    get_saber_strategy_address_and_bump_seed, get_vault_address_and_bump_seed,
+    get_vault_store_address_and_bump_seed,
+    instructions::{self, tranche_init_accounts},
+};
+
+// receipts are fetched with getMultipleAccounts, which accepts at most 100 accounts per request
+const MAX_ACCOUNTS_PER_REQUEST: usize = 100;
+
+#[derive(Parser)]
+#[clap(about = "Initialize, transition and inspect vaults")]
+struct Args {
+    /// rpc url of the cluster, e.g. a local solana-test-validator
+    #[clap(short, long, default_value = "http://127.0.0.1:8899")]
+    url: String,
+
+    /// keypair paying for and signing transactions. defaults to the solana cli's default keypair.
+    #[clap(short, long)]
+    keypair: Option<PathBuf>,
+
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Initialize the global protocol state; the keypair becomes the protocol authority
+    InitGlobalState {
+        #[clap(long)]
+        treasury: Pubkey,
+    },
+    /// Initialize an orca LP strategy; must be signed by the protocol authority
+    InitOrcaStrategy {
+        #[clap(long)]
+        token_a: Pubkey,
+        #[clap(long)]
+        token_b: Pubkey,
+        #[clap(long)]
+        swap_program: Pubkey,
+        #[clap(long)]
+        farm_program: Pubkey,
+        #[clap(long)]
+        pool: Pubkey,
+        #[clap(long)]
+        base_lp: Pubkey,
+        #[clap(long)]
+        farm: Pubkey,
+        #[clap(long)]
+        farm_lp: Pubkey,
+        /// omit if the pool has no double dip farm
+        #[clap(long)]
+        double_dip_farm_lp: Option<Pubkey>,
+        #[clap(long, default_value = "0")]
+        version: u16,
+    },
+    /// Initialize a saber LP strategy; must be signed by the protocol authority
+    InitSaberStrategy {
+        #[clap(long)]
+        token_a: Pubkey,
+        #[clap(long)]
+        token_b: Pubkey,
+        #[clap(long)]
+        base_pool: Pubkey,
+        #[clap(long)]
+        pool_lp: Pubkey,
+        #[clap(long, default_value = "0")]
+        version: u16,
+    },
+    /// Initialize a vault from a TOML config; co-signed by the protocol authority
+    InitVault {
+        /// path to the vault's TOML config, see `vault.example.toml`
+        #[clap(long)]
+        config: PathBuf,
+        /// keypair of the vault authority. defaults to the signing keypair.
+        #[clap(long)]
+        authority_keypair: Option<PathBuf>,
+    },
+    /// Transition a vault to the given state; must be signed by the vault authority or strategist, or by
+    /// anyone once the vault is past the deadline for leaving its current state
+    Transition {
+        #[clap(long, arg_enum)]
+        state: TargetState,
+        /// unix timestamp recorded for the transition. defaults to now; the program records the cluster
+        /// time instead unless signed by the vault authority or strategist.
+        #[clap(long)]
+        timestamp: Option<u64>,
+        /// authority of the vault to transition. defaults to the signing keypair.
+        #[clap(long)]
+        authority: Option<Pubkey>,
+    },
+    /// Cancel a vault before its deposits are invested; must be signed by the vault authority. depositors
+    /// are refunded once claims are processed.
+    Cancel {
+        /// keypair of the vault authority. defaults to the signing keypair.
+        #[clap(long)]
+        authority_keypair: Option<PathBuf>,
+    },
+    /// Delegate some of the strategist's roles to a keeper, replacing any previous keeper; must be signed by
+    /// the vault authority
+    SetKeeper {
+        /// omit to revoke the current keeper
+        #[clap(long)]
+        keeper: Option<Pubkey>,
+        /// role delegated to the keeper, repeat for each role
+        #[clap(long = "role", arg_enum)]
+        roles: Vec<KeeperRole>,
+        /// keypair of the vault authority. defaults to the signing keypair.
+        #[clap(long)]
+        authority_keypair: Option<PathBuf>,
+    },
+    /// Print the global protocol state
+    ShowGlobalState,
+    /// Print a vault
+    ShowVault { vault: Pubkey },
+    /// Print a user's deposit history for a tranche
+    ShowHistory {
+        vault: Pubkey,
+        tranche_id: u8,
+        user: Pubkey,
+    },
+    /// Print a deposit receipt for a tranche; receipts are indexed from 1
+    ShowReceipt {
+        vault: Pubkey,
+        tranche_id: u8,
+        index: u64,
+    },
+    /// Print every deposit receipt for a tranche, in deposit order
+    ListReceipts { vault: Pubkey, tranche_id: u8 },
+}
+
+/// States a vault can be transitioned into; a vault returns to inactive only by being cancelled
+#[derive(Clone, ArgEnum)]
+enum TargetState {
+    Deposit,
+    Live,
+    Redeem,
+    Rebalance,
+    Withdraw,
+}
+
+impl From<TargetState> for State {
+    fn from(target: TargetState) -> Self {
+        match target {
+            TargetState::Deposit => State::Deposit,
+            TargetState::Live => State::Live,
+            TargetState::Redeem => State::Redeem,
+            TargetState::Rebalance => State::Rebalance,
+            TargetState::Withdraw => State::Withdraw,
+        }
+    }
+}
+
+/// Strategist roles the vault authority can delegate to a keeper
+#[derive(Clone, ArgEnum)]
+enum KeeperRole {
+    Invest,
+    Redeem,
+    Rebalance,
+    Swap,
+    Convert,
+    Harvest,
+    Revert,
+}
+
+impl From<KeeperRole> for StrategistRole {
+    fn from(role: KeeperRole) -> Self {
+        match role {
+            KeeperRole::Invest => StrategistRole::Invest,
+            KeeperRole::Redeem => StrategistRole::Redeem,
+            KeeperRole::Rebalance => StrategistRole::Rebalance,
+            KeeperRole::Swap => StrategistRole::Swap,
+            KeeperRole::Convert => StrategistRole::Convert,
+            KeeperRole::Harvest => StrategistRole::Harvest,
+            KeeperRole::Revert => StrategistRole::Revert,
+        }
+    }
+}
+
+fn main() {
+    match run(Args::parse()) {
+        Ok(output) => println!("{}", serde_json::to_string_pretty(&output).unwrap()),
+        Err(e) => {
+            eprintln!("{}", json!({ "error": e.to_string() }));
+            std::process::exit(1);
+        }
+    }
+}
+
+fn run(args: Args) -> CliResult<Value> {
+    let client = RpcClient::new_with_commitment(args.url, CommitmentConfig::confirmed());
+    let keypair_path = args.keypair.unwrap_or_else(default_keypair_path);
+
+    match args.command {
+        Command::InitGlobalState { treasury } => {
+            let payer = read_keypair(&keypair_path)?;
+            let (global_protocol_state, bump) = get_global_protocol_state_address_and_bump_seed();
+
+            let ix = instructions::initialize_global_protocol_state(
+                accounts::InitializeGlobalProtocolState {
+                    authority: payer.pubkey(),
+                    global_protocol_state,
+                    treasury,
+                    system_program: system_program::ID,
+                    rent: sysvar::rent::ID,
+                },
+                bump,
+            )
+            .build();
+
+            let signature = send_transaction(&client, &[ix], &payer, &[])?;
+            Ok(transaction_output(signature, global_protocol_state))
+        }
+        Command::InitOrcaStrategy {
+            token_a,
+            token_b,
+            swap_program,
+            farm_program,
+            pool,
+            base_lp,
+            farm,
+            farm_lp,
+            double_dip_farm_lp,
+            version,
+        } => {
+            let payer = read_keypair(&keypair_path)?;
+            let flag = StrategyFlag::OrcaLpStrategyV0 as u64;
+            let (strategy, bump) = get_orca_strategy_address_and_bump_seed(
+                flag, version, &token_a, &token_b, &pool, &base_lp, &farm, &farm_lp,
+            );
+
+            let ix = instructions::initialize_orca(
+                accounts::InitializeOrca {
+                    authority: payer.pubkey(),
+                    global_protocol_state: get_global_protocol_state_address_and_bump_seed().0,
+                    strategy,
+                    token_a,
+                    token_b,
+                    swap_program,
+                    farm_program,
+                    pool,
+                    base_lp,
+                    farm,
+                    farm_lp,
+                    // the program reads the default pubkey as no double dip farm
+                    double_dip_farm_lp: double_dip_farm_lp.unwrap_or_default(),
+                    system_program: system_program::ID,
+                    rent: sysvar::rent::ID,
+                },
+                bump,
+                flag,
+                version,
+            )
+            .build();
+
+            let signature = send_transaction(&client, &[ix], &payer, &[])?;
+            Ok(transaction_output(signature, strategy))
+        }
+        Command::InitSaberStrategy {
+            token_a,
+            token_b,
+            base_pool,
+            pool_lp,
+            version,
+        } => {
+            let payer = read_keypair(&keypair_path)?;
+            let flag = StrategyFlag::SaberLpStrategyV0 as u64;
+            let (strategy, bump) = get_saber_strategy_address_and_bump_seed(
+                flag, version, &token_a, &token_b, &base_pool, &pool_lp,
+            );
+
+            let ix = instructions::initialize_saber(
+                accounts::InitializeSaber {
+                    authority: payer.pubkey(),
+                    global_protocol_state: get_global_protocol_state_address_and_bump_seed().0,
+                    strategy,
+                    token_a,
+                    token_b,
+                    base_pool,
+                    pool_lp,
+                    system_program: system_program::ID,
+                    rent: sysvar::rent::ID,
+                },
+                bump,
+                flag,
+                version,
+            )
+            .build();
+
+            let signature = send_transaction(&client, &[ix], &payer, &[])?;
+            Ok(transaction_output(signature, strategy))
+        }
+        Command::InitVault {
+            config,
+            authority_keypair,
+        } => {
+            let gps_authority = read_keypair(&keypair_path)?;
+            let authority = read_keypair(authority_keypair.as_ref().unwrap_or(&keypair_path))?;
+            let config = VaultToml::load(&config)?;
+
+            let (vault, vault_bump) = get_vault_address_and_bump_seed(&authority.pubkey());
+            let (vault_store, vault_store_bump) = get_vault_store_address_and_bump_seed(&vault);
+
+            let ix = instructions::initialize_vault(
+                accounts::InitializeVault {
+                    authority: authority.pubkey(),
+                    gps_authority: gps_authority.pubkey(),
+                    global_protocol_state: get_global_protocol_state_address_and_bump_seed().0,
+                    vault,
+                    vault_store,
+                    system_program: system_program::ID,
+                    token_program: spl_token::ID,
+                    token_metadata_program: mpl_token_metadata::ID,
+                    rent: sysvar::rent::ID,
+                },
+                vault_bump,
+                vault_store_bump,
+                config.to_vault_config(authority.pubkey())?,
+            )
+            .remaining_accounts(tranche_init_accounts(&vault, &config.asset_mints()))
+            .build();
+
+            let signature = send_transaction(&client, &[ix], &authority, &[&gps_authority])?;
+            Ok(transaction_output(signature, vault))
+        }
+        Command::Transition {
+            state,
+            timestamp,
+            authority,
+        } => {
+            let payer = read_keypair(&keypair_path)?;
+            let authority = authority.unwrap_or_else(|| payer.pubkey());
+            let (vault, _) = get_vault_address_and_bump_seed(&authority);
+            let timestamp = timestamp.unwrap_or_else(unix_timestamp);
+
+            let ix = instructions::transition_vault(
+                accounts::TransitionVault {
+                    payer: payer.pubkey(),
+                    authority,
+                    global_protocol_state: get_global_protocol_state_address_and_bump_seed().0,
+                    vault,
+                },
+                state.into(),
+                timestamp,
+            )
+            .build();
+
+            let signature = send_transaction(&client, &[ix], &payer, &[])?;
+            Ok(transaction_output(signature, vault))
+        }
+        Command::Cancel { authority_keypair } => {
+            let authority = read_keypair(authority_keypair.as_ref().unwrap_or(&keypair_path))?;
+            let (vault, _) = get_vault_address_and_bump_seed(&authority.pubkey());
+
+            let ix = instructions::cancel_vault(accounts::CancelVault {
+                authority: authority.pubkey(),
+                global_protocol_state: get_global_protocol_state_address_and_bump_seed().0,
+                vault,
+            })
+            .build();
+
+            let signature = send_transaction(&client, &[ix], &authority, &[])?;
+            Ok(transaction_output(signature, vault))
+        }
+        Command::SetKeeper {
+            keeper,
+            roles,
+            authority_keypair,
+        } => {
+            let authority = read_keypair(authority_keypair.as_ref().unwrap_or(&keypair_path))?;
+            let (vault, _) = get_vault_address_and_bump_seed(&authority.pubkey());
+            let roles = roles
+                .into_iter()
+                .fold(0, |bits, role| bits | StrategistRole::from(role) as u64);
+
+            let ix = instructions::set_keeper(
+                accounts::SetKeeper {
+                    authority: authority.pubkey(),
+                    global_protocol_state: get_global_protocol_state_address_and_bump_seed().0,
+                    vault,
+                },
+                keeper,
+                roles,
+            )
+            .build();
+
+            let signature = send_transaction(&client, &[ix], &authority, &[])?;
+            Ok(transaction_output(signature, vault))
+        }
+        Command::ShowGlobalState => {
+            let (address, _) = get_global_protocol_state_address_and_bump_seed();
+            let state = fetch_global_protocol_state(&client)?;
+
+            Ok(json::global_protocol_state(&address, &state))
+        }
+        Command::ShowVault { vault } => Ok(json::vault(&vault, &fetch_vault(&client, &vault)?)),
+        Command::ShowHistory {
+            vault,
+            tranche_id,
+            user,
+        } => {
+            let (address, _) = get_history_address_and_bump_seed(&vault, tranche_id, &user);
+            let history = fetch_history(&client, &vault, tranche_id, &user)?
+                .ok_or_else(|| CliError::AccountNotFound(address.to_string()))?;
+
+            Ok(json::history(&address, &history))
+        }
+        Command::ShowReceipt {
+            vault,
+            tranche_id,
+            index,
+        } => {
+            let (address, _) = get_receipt_address_and_bump_seed(&vault, tranche_id, index);
+            let receipt = fetch_receipt(&client, &vault, tranche_id, index)?
+                .ok_or_else(|| CliError::AccountNotFound(address.to_string()))?;
+
+            Ok(json::receipt(&address, index, &receipt))
+        }
+        Command::ListReceipts { vault, tranche_id } => {
+            let deposits = fetch_vault(&client, &vault)?
+                .get_tranche(tranche_id)?
+                .deposits;
+
+            // receipts are indexed from 1 up to and including the tranche's number of deposits
+            let indices = (1..=deposits).collect::<Vec<u64>>();
+            let mut receipts = vec![];
+            for chunk in indices.chunks(MAX_ACCOUNTS_PER_REQUEST) {
+                let addresses = chunk
+                    .iter()
+                    .map(|index| get_receipt_address_and_bump_seed(&vault, tranche_id, *index).0)
+                    .collect::<Vec<Pubkey>>();
+                let accounts = fetch_accounts(&client, &addresses)?;
+
+                for ((index, address), receipt) in chunk.iter().zip(&addresses).zip(accounts) {
+                    let receipt =
+                        receipt.ok_or_else(|| CliError::AccountNotFound(address.to_string()))?;
+                    receipts.push(json::receipt(address, *index, &receipt));
+                }
+            }
+
+            Ok(Value::Array(receipts))
+        }
+    }
+}
+
+fn send_transaction(
+    client: &RpcClient,
+    instructions: &[Instruction],
+    payer: &Keypair,
+    signers: &[&Keypair],
+) -> CliResult<Signature> {
+    let blockhash = client
+        .get_latest_blockhash()
+        .map_err(|e| CliError::Rpc(e.to_string()))?;
+
+    // the vault authority and protocol authority can be the same keypair on localnet
+    let mut all_signers = vec![payer];
+    all_signers.extend(
+        signers
+            .iter()
+            .filter(|signer| signer.pubkey() != payer.pubkey()),
+    );
+    let transaction = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&payer.pubkey()),
+        &all_signers,
+        blockhash,
+    );
+
+    client
+        .send_and_confirm_transaction(&transaction)
+        .map_err(|e| CliError::Rpc(e.to_string()))
+}
+
+fn transaction_output(signature: Signature, address: Pubkey) -> Value {
+    json!({
+        "signature": signature.to_string(),
+        "address": address.to_string(),
+    })
+}
+
+fn read_keypair(path: &Path) -> CliResult<Keypair> {
+    read_keypair_file(path)
+        .map_err(|e| CliError::Keypair(path.display().to_string(), e.to_string()))
+}
+
+fn default_keypair_path() -> PathBuf {
+    let home = std::env::var("HOME").unwrap_or_else(|_| ".".to_string());
+    PathBuf::from(home).join(".config/solana/id.json")
+}
+
+fn unix_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
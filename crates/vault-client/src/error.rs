@@ -0,0 +1,19 @@
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use thiserror::Error;
+
+pub type ClientResult<T = ()> = Result<T, ClientError>;
+
+#[derive(Debug, Error)]
+pub enum ClientError {
+    #[error("account {0} does not exist")]
+    AccountNotFound(Pubkey),
+
+    #[error("account {0} is not owned by the vault program")]
+    InvalidAccountOwner(Pubkey),
+
+    #[error("failed to deserialize account {0}: {1}")]
+    InvalidAccountData(Pubkey, ProgramError),
+
+    #[error("rpc error: {0}")]
+    Rpc(String),
+}
@@ -0,0 +1,175 @@
+//! Fetch and deserialize vault program accounts. Fetching goes through the `AccountFetcher` trait so the
+//! helpers work against any RPC client, a test bank or a cache of account snapshots.
+
+use crate::{
+    error::{ClientError, ClientResult},
+    pda::{
+        get_allowlist_address_and_bump_seed, get_global_protocol_state_address_and_bump_seed,
+        get_history_address_and_bump_seed, get_receipt_address_and_bump_seed,
+        get_rollover_address_and_bump_seed,
+    },
+};
+use anchor_lang::AccountDeserialize;
+use solana_program::pubkey::Pubkey;
+use vault::{
+    state::{Allowlist, GlobalProtocolState, History, Receipt, Rollover, Vault},
+    OrcaStrategyDataV0, SaberStrategyDataV0, ID,
+};
+
+/// Raw account access. Implement this for whichever client the caller already has.
+pub trait AccountFetcher {
+    /// owner and data of an account, or None if the account does not exist
+    fn get_account(&self, address: &Pubkey) -> ClientResult<Option<(Pubkey, Vec<u8>)>>;
+
+    /// owner and data of several accounts at once. clients that support batched requests should
+    /// override this.
+    fn get_accounts(&self, addresses: &[Pubkey]) -> ClientResult<Vec<Option<(Pubkey, Vec<u8>)>>> {
+        addresses
+            .iter()
+            .map(|address| self.get_account(address))
+            .collect()
+    }
+}
+
+#[cfg(feature = "rpc")]
+impl AccountFetcher for solana_client::rpc_client::RpcClient {
+    fn get_account(&self, address: &Pubkey) -> ClientResult<Option<(Pubkey, Vec<u8>)>> {
+        let account = self
+            .get_account_with_commitment(address, self.commitment())
+            .map_err(|e| ClientError::Rpc(e.to_string()))?
+            .value;
+
+        Ok(account.map(|account| (account.owner, account.data)))
+    }
+
+    fn get_accounts(&self, addresses: &[Pubkey]) -> ClientResult<Vec<Option<(Pubkey, Vec<u8>)>>> {
+        let accounts = self
+            .get_multiple_accounts(addresses)
+            .map_err(|e| ClientError::Rpc(e.to_string()))?;
+
+        Ok(accounts
+            .into_iter()
+            .map(|account| account.map(|account| (account.owner, account.data)))
+            .collect())
+    }
+}
+
+/// Deserialize an anchor account owned by the vault program, checking the owner and discriminator
+pub fn deserialize_account<T: AccountDeserialize>(
+    address: &Pubkey,
+    owner: &Pubkey,
+    data: &[u8],
+) -> ClientResult<T> {
+    if *owner != ID {
+        return Err(ClientError::InvalidAccountOwner(*address));
+    }
+
+    let mut data = data;
+    T::try_deserialize(&mut data).map_err(|e| ClientError::InvalidAccountData(*address, e))
+}
+
+/// Fetch and deserialize a vault program account
+pub fn fetch_account<T: AccountDeserialize, F: AccountFetcher + ?Sized>(
+    fetcher: &F,
+    address: &Pubkey,
+) -> ClientResult<T> {
+    let (owner, data) = fetcher
+        .get_account(address)?
+        .ok_or(ClientError::AccountNotFound(*address))?;
+
+    deserialize_account(address, &owner, &data)
+}
+
+/// Fetch a vault program account that may not be initialized yet, e.g. a depositor's history
+pub fn fetch_optional_account<T: AccountDeserialize, F: AccountFetcher + ?Sized>(
+    fetcher: &F,
+    address: &Pubkey,
+) -> ClientResult<Option<T>> {
+    fetcher
+        .get_account(address)?
+        .map(|(owner, data)| deserialize_account(address, &owner, &data))
+        .transpose()
+}
+
+/// Fetch and deserialize several accounts of the same type in one round trip
+pub fn fetch_accounts<T: AccountDeserialize, F: AccountFetcher + ?Sized>(
+    fetcher: &F,
+    addresses: &[Pubkey],
+) -> ClientResult<Vec<Option<T>>> {
+    fetcher
+        .get_accounts(addresses)?
+        .into_iter()
+        .zip(addresses)
+        .map(|(account, address)| {
+            account
+                .map(|(owner, data)| deserialize_account(address, &owner, &data))
+                .transpose()
+        })
+        .collect()
+}
+
+pub fn fetch_global_protocol_state<F: AccountFetcher + ?Sized>(
+    fetcher: &F,
+) -> ClientResult<GlobalProtocolState> {
+    fetch_account(
+        fetcher,
+        &get_global_protocol_state_address_and_bump_seed().0,
+    )
+}
+
+pub fn fetch_vault<F: AccountFetcher + ?Sized>(fetcher: &F, vault: &Pubkey) -> ClientResult<Vault> {
+    fetch_account(fetcher, vault)
+}
+
+pub fn fetch_receipt<F: AccountFetcher + ?Sized>(
+    fetcher: &F,
+    vault: &Pubkey,
+    tranche_id: u8,
+    index: u64,
+) -> ClientResult<Option<Receipt>> {
+    let (address, _) = get_receipt_address_and_bump_seed(vault, tranche_id, index);
+    fetch_optional_account(fetcher, &address)
+}
+
+pub fn fetch_history<F: AccountFetcher + ?Sized>(
+    fetcher: &F,
+    vault: &Pubkey,
+    tranche_id: u8,
+    user: &Pubkey,
+) -> ClientResult<Option<History>> {
+    let (address, _) = get_history_address_and_bump_seed(vault, tranche_id, user);
+    fetch_optional_account(fetcher, &address)
+}
+
+pub fn fetch_rollover<F: AccountFetcher + ?Sized>(
+    fetcher: &F,
+    vault: &Pubkey,
+    tranche_id: u8,
+    depositor: &Pubkey,
+) -> ClientResult<Option<Rollover>> {
+    let (address, _) = get_rollover_address_and_bump_seed(vault, tranche_id, depositor);
+    fetch_optional_account(fetcher, &address)
+}
+
+pub fn fetch_allowlist<F: AccountFetcher + ?Sized>(
+    fetcher: &F,
+    vault: &Pubkey,
+    user: &Pubkey,
+) -> ClientResult<Option<Allowlist>> {
+    let (address, _) = get_allowlist_address_and_bump_seed(vault, user);
+    fetch_optional_account(fetcher, &address)
+}
+
+pub fn fetch_orca_strategy<F: AccountFetcher + ?Sized>(
+    fetcher: &F,
+    strategy: &Pubkey,
+) -> ClientResult<OrcaStrategyDataV0> {
+    fetch_account(fetcher, strategy)
+}
+
+pub fn fetch_saber_strategy<F: AccountFetcher + ?Sized>(
+    fetcher: &F,
+    strategy: &Pubkey,
+) -> ClientResult<SaberStrategyDataV0> {
+    fetch_account(fetcher, strategy)
+}
@@ -0,0 +1,220 @@
+//! Typed instruction builders. Each builder takes the instruction's anchor accounts struct and arguments,
+//! so a missing account or a mistyped argument is a compile error rather than a failed transaction.
+//!
+//! ```ignore
+//! let ix = instructions::claim(accounts, tranche_id).build();
+//! let ix = instructions::invest_orca(accounts, investable_a, investable_b, min_tokens_back)
+//!     .remaining_accounts(oracle_accounts(&vault))
+//!     .build();
+//! ```
+
+use crate::pda::{get_tranche_metadata_address, get_tranche_mint_address_and_bump_seed};
+use anchor_lang::{InstructionData, ToAccountMetas};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+use vault::{
+    accounts, instruction,
+    state::{State, Vault, VaultConfig},
+    SwapConfig, ID,
+};
+
+pub struct InstructionBuilder<A, D> {
+    accounts: A,
+    data: D,
+    remaining_accounts: Vec<AccountMeta>,
+}
+
+impl<A: ToAccountMetas, D: InstructionData> InstructionBuilder<A, D> {
+    pub fn new(accounts: A, data: D) -> Self {
+        InstructionBuilder {
+            accounts,
+            data,
+            remaining_accounts: vec![],
+        }
+    }
+
+    /// Append accounts the instruction reads from `ctx.remaining_accounts`, e.g. oracle price accounts
+    pub fn remaining_accounts(mut self, remaining_accounts: Vec<AccountMeta>) -> Self {
+        self.remaining_accounts.extend(remaining_accounts);
+        self
+    }
+
+    pub fn build(self) -> Instruction {
+        let mut accounts = self.accounts.to_account_metas(None);
+        accounts.extend(self.remaining_accounts);
+
+        Instruction {
+            program_id: ID,
+            accounts,
+            data: self.data.data(),
+        }
+    }
+}
+
+macro_rules! instruction_builders {
+    ($($(#[$doc:meta])* $name:ident($accounts:ident => $data:ident $(, $arg:ident: $ty:ty)*);)*) => {
+        $(
+            $(#[$doc])*
+            pub fn $name(
+                accounts: accounts::$accounts,
+                $($arg: $ty),*
+            ) -> InstructionBuilder<accounts::$accounts, instruction::$data> {
+                InstructionBuilder::new(accounts, instruction::$data { $($arg),* })
+            }
+        )*
+    };
+}
+
+instruction_builders! {
+    // ========= [NATIVE VAULT] =========
+    initialize_global_protocol_state(
+        InitializeGlobalProtocolState => InitializeGlobalProtocolState, bump: u8
+    );
+    /// needs `tranche_init_accounts` as remaining accounts
+    initialize_vault(
+        InitializeVault => InitializeVault,
+        vault_bump: u8,
+        vault_store_bump: u8,
+        vault_config: VaultConfig
+    );
+    transition_vault(TransitionVault => TransitionVault, target_state: State, timestamp: u64);
+    cancel_vault(CancelVault => CancelVault);
+    deposit(
+        Deposit => Deposit,
+        tranche_id: u8,
+        deposit_index: u64,
+        receipt_bump: u8,
+        history_bump: u8,
+        amount: u64,
+        proof: Vec<[u8; 32]>
+    );
+    add_to_allowlist(AddToAllowlist => AddToAllowlist, allowlist_bump: u8);
+    remove_from_allowlist(RemoveFromAllowlist => RemoveFromAllowlist);
+    /// needs the tranche's receipts and histories as remaining accounts
+    process_claims(ProcessClaims => ProcessClaims, tranche_id: u8);
+    claim(Claim => Claim, tranche_id: u8);
+    withdraw(Withdraw => Withdraw, amount: u64);
+    claim_and_withdraw(ClaimAndWithdraw => ClaimAndWithdraw, tranche_id: u8);
+    set_keeper(SetKeeper => SetKeeper, keeper: Option<Pubkey>, roles: u64);
+    set_rollover_vault(SetRolloverVault => SetRolloverVault);
+    /// needs the tranche token accounts to freeze or thaw as remaining accounts
+    set_tranche_lp_frozen(SetTrancheLpFrozen => SetTrancheLpFrozen, frozen: bool);
+    /// needs the tranche token accounts to thaw as remaining accounts
+    thaw_tranche_lp(ThawTrancheLp => ThawTrancheLp);
+    opt_into_rollover(
+        OptIntoRollover => OptIntoRollover,
+        tranche_id: u8,
+        rollover_bump: u8,
+        amount: u64
+    );
+    process_rollover(ProcessRollover => ProcessRollover, tranche_id: u8, history_bump: u8);
+    withdraw_rollover(WithdrawRollover => WithdrawRollover, tranche_id: u8);
+
+    // ========= [ORCA] =========
+    initialize_orca(InitializeOrca => InitializeOrca, bump: u8, flag: u64, version: u16);
+    /// needs `oracle_accounts` as remaining accounts if the vault has an oracle
+    rebalance_orca(RebalanceOrca => RebalanceOrca, swap_config: Option<SwapConfig>);
+    /// needs `oracle_accounts` as remaining accounts if the vault has an oracle
+    invest_orca(
+        InvestOrca => InvestOrca,
+        investable_a: u64,
+        investable_b: u64,
+        min_tokens_back: u64
+    );
+    /// needs `oracle_accounts` as remaining accounts, followed by the depositor's allowlist PDA if the vault
+    /// is gated by an allowlist
+    deposit_live_orca(
+        DepositLiveOrca => DepositLiveOrca,
+        tranche_id: u8,
+        history_bump: u8,
+        amount: u64,
+        min_tokens_back: u64,
+        proof: Vec<[u8; 32]>
+    );
+    /// needs `oracle_accounts` as remaining accounts if the vault has an oracle
+    early_exit_orca(
+        EarlyExitOrca => EarlyExitOrca,
+        lp_amount: u64,
+        min_token_a: u64,
+        min_token_b: u64
+    );
+    /// needs `oracle_accounts` as remaining accounts if the vault has an oracle
+    redeem_orca(RedeemOrca => RedeemOrca, min_token_a: u64, min_token_b: u64);
+    /// needs `oracle_accounts` as remaining accounts if the vault has an oracle
+    swap_orca(SwapOrca => SwapOrca, amount_in: u64, min_amount_out: u64);
+    get_vault_nav_orca(GetVaultNavOrca => GetVaultNavOrca);
+    initialize_user_farm_orca(InitializeUserFarmOrca => InitializeUserFarmOrca);
+    convert_orca_lp(ConvertOrcaLp => ConvertOrcaLp);
+    harvest_orca(HarvestOrcaLp => HarvestOrca);
+    revert_orca_lp(RevertOrcaLp => RevertOrcaLp);
+
+    // ========= [SABER] =========
+    initialize_saber(InitializeSaber => InitializeSaber, bump: u8, flag: u64, version: u16);
+    /// needs `oracle_accounts` as remaining accounts if the vault has an oracle
+    invest_saber(
+        InvestSaber => InvestSaber,
+        investable_a: u64,
+        investable_b: u64,
+        min_tokens_back: u64
+    );
+    /// needs `oracle_accounts` as remaining accounts, followed by the depositor's allowlist PDA if the vault
+    /// is gated by an allowlist
+    deposit_live_saber(
+        DepositLiveSaber => DepositLiveSaber,
+        tranche_id: u8,
+        history_bump: u8,
+        amount: u64,
+        min_tokens_back: u64,
+        proof: Vec<[u8; 32]>
+    );
+    /// needs `oracle_accounts` as remaining accounts if the vault has an oracle
+    early_exit_saber(
+        EarlyExitSaber => EarlyExitSaber,
+        lp_amount: u64,
+        min_token_a: u64,
+        min_token_b: u64
+    );
+    /// needs `oracle_accounts` as remaining accounts if the vault has an oracle
+    redeem_saber(RedeemSaber => RedeemSaber, min_token_a: u64, min_token_b: u64);
+    get_vault_nav_saber(GetVaultNavSaber => GetVaultNavSaber);
+}
+
+/// Remaining accounts for `initialize_vault`: the asset mint, tranche mint PDA and tranche metadata
+/// account of each tranche, in tranche order
+pub fn tranche_init_accounts(vault: &Pubkey, asset_mints: &[Pubkey]) -> Vec<AccountMeta> {
+    asset_mints
+        .iter()
+        .enumerate()
+        .flat_map(|(tranche_id, mint)| {
+            let (tranche_mint, _) = get_tranche_mint_address_and_bump_seed(vault, tranche_id as u8);
+
+            vec![
+                AccountMeta::new_readonly(*mint, false),
+                AccountMeta::new(tranche_mint, false),
+                AccountMeta::new(get_tranche_metadata_address(&tranche_mint), false),
+            ]
+        })
+        .collect()
+}
+
+/// Remaining accounts for instructions that verify the pool price against the vault's oracle. Empty if
+/// the vault has no oracle configured.
+pub fn oracle_accounts(vault: &Vault) -> Vec<AccountMeta> {
+    match &vault.oracle {
+        Some(oracle) => vec![
+            AccountMeta::new_readonly(oracle.alpha_oracle, false),
+            AccountMeta::new_readonly(oracle.beta_oracle, false),
+        ],
+        None => vec![],
+    }
+}
+
+/// Remaining accounts for `set_tranche_lp_frozen` and `thaw_tranche_lp`
+pub fn tranche_token_accounts(token_accounts: &[Pubkey]) -> Vec<AccountMeta> {
+    token_accounts
+        .iter()
+        .map(|account| AccountMeta::new(*account, false))
+        .collect()
+}
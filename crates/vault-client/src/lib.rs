@@ -0,0 +1,15 @@
+//! Off-chain client for the vault program. Builds typed instructions, derives the program's PDAs and
+//! fetches + deserializes program accounts, so Rust keepers and tools don't have to hand-roll any of it.
+
+pub mod error;
+pub mod fetch;
+pub mod instructions;
+pub mod pda;
+
+pub use error::{ClientError, ClientResult};
+pub use fetch::*;
+pub use instructions::InstructionBuilder;
+pub use pda::*;
+
+/// vault program id
+pub use vault::ID as VAULT_PROGRAM_ID;
@@ -0,0 +1,153 @@
+//! PDA derivations for the vault program. These mirror the seeds used in the program's account
+//! constraints and `util::get_*_address_and_bump_seed`.
+
+use solana_program::pubkey::Pubkey;
+use vault::{
+    constant::{
+        ALLOWLIST_SEED, GLOBAL_STATE_SEED, HISTORY_SEED, RECEIPT_SEED, ROLLOVER_SEED,
+        STRATEGY_SEED, TRANCHE_MINT_SEED, VAULT_SEED, VAULT_STORE_SEED,
+    },
+    ID,
+};
+
+pub fn get_global_protocol_state_address_and_bump_seed() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[GLOBAL_STATE_SEED.as_bytes()], &ID)
+}
+
+/// vaults are keyed by their authority, so an authority can manage a single vault at a time
+pub fn get_vault_address_and_bump_seed(authority: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VAULT_SEED.as_bytes(), &authority.to_bytes()], &ID)
+}
+
+pub fn get_vault_store_address_and_bump_seed(vault: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VAULT_STORE_SEED.as_bytes(), &vault.to_bytes()], &ID)
+}
+
+pub fn get_receipt_address_and_bump_seed(
+    vault: &Pubkey,
+    tranche_id: u8,
+    index: u64,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            RECEIPT_SEED.as_bytes(),
+            &vault.to_bytes(),
+            &tranche_id.to_le_bytes(),
+            &index.to_le_bytes(),
+        ],
+        &ID,
+    )
+}
+
+pub fn get_history_address_and_bump_seed(
+    vault: &Pubkey,
+    tranche_id: u8,
+    user: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            HISTORY_SEED.as_bytes(),
+            &vault.to_bytes(),
+            &tranche_id.to_le_bytes(),
+            &user.to_bytes(),
+        ],
+        &ID,
+    )
+}
+
+pub fn get_rollover_address_and_bump_seed(
+    vault: &Pubkey,
+    tranche_id: u8,
+    depositor: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            ROLLOVER_SEED.as_bytes(),
+            &vault.to_bytes(),
+            &tranche_id.to_le_bytes(),
+            &depositor.to_bytes(),
+        ],
+        &ID,
+    )
+}
+
+pub fn get_allowlist_address_and_bump_seed(vault: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            ALLOWLIST_SEED.as_bytes(),
+            &vault.to_bytes(),
+            &user.to_bytes(),
+        ],
+        &ID,
+    )
+}
+
+pub fn get_tranche_mint_address_and_bump_seed(vault: &Pubkey, tranche_id: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            TRANCHE_MINT_SEED.as_bytes(),
+            &vault.to_bytes(),
+            &tranche_id.to_le_bytes(),
+        ],
+        &ID,
+    )
+}
+
+/// Metaplex metadata account for a tranche mint
+pub fn get_tranche_metadata_address(tranche_mint: &Pubkey) -> Pubkey {
+    mpl_token_metadata::pda::find_metadata_account(tranche_mint).0
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn get_orca_strategy_address_and_bump_seed(
+    flag: u64,
+    version: u16,
+    token_a: &Pubkey,
+    token_b: &Pubkey,
+    pool: &Pubkey,
+    base_lp: &Pubkey,
+    farm: &Pubkey,
+    farm_lp: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            STRATEGY_SEED.as_bytes(),
+            &flag.to_le_bytes(),
+            &version.to_le_bytes(),
+            &token_a.to_bytes(),
+            &token_b.to_bytes(),
+            &pool.to_bytes(),
+            &base_lp.to_bytes(),
+            &farm.to_bytes(),
+            &farm_lp.to_bytes(),
+        ],
+        &ID,
+    )
+}
+
+pub fn get_saber_strategy_address_and_bump_seed(
+    flag: u64,
+    version: u16,
+    token_a: &Pubkey,
+    token_b: &Pubkey,
+    base_pool: &Pubkey,
+    pool_lp: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            STRATEGY_SEED.as_bytes(),
+            &flag.to_le_bytes(),
+            &version.to_le_bytes(),
+            &token_a.to_bytes(),
+            &token_b.to_bytes(),
+            &base_pool.to_bytes(),
+            &pool_lp.to_bytes(),
+        ],
+        &ID,
+    )
+}
+
+/// associated token account of `owner` for `mint`, e.g. the vault_store's account for a tranche asset
+pub fn get_associated_token_address(owner: &Pubkey, mint: &Pubkey) -> Pubkey {
+    spl_associated_token_account::get_associated_token_address(owner, mint)
+}
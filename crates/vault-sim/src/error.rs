@@ -0,0 +1,23 @@
+use keeper::KeeperError;
+use solana_program::program_error::ProgramError;
+use thiserror::Error;
+
+pub type SimResult<T = ()> = Result<T, SimError>;
+
+#[derive(Debug, Error)]
+pub enum SimError {
+    #[error("program error: {0}")]
+    Program(#[from] ProgramError),
+
+    #[error(transparent)]
+    Keeper(#[from] KeeperError),
+
+    #[error("invalid scenario: {0}")]
+    Scenario(String),
+
+    #[error("pool error: {0}")]
+    Pool(&'static str),
+
+    #[error("output error: {0}")]
+    Output(String),
+}
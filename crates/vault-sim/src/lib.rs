@@ -0,0 +1,15 @@
+//! Deterministic simulator for a vault's lifecycle. A scenario describes a vault's config, its deposits and the
+//! pool's price path while the vault is live; the simulator runs it through the program's own state and math
+//! and reports per-tranche returns and per-user payouts, so rate and cap settings can be tested before a vault
+//! is deployed.
+
+pub mod error;
+pub mod pool;
+pub mod report;
+pub mod scenario;
+pub mod simulator;
+
+pub use error::{SimError, SimResult};
+pub use report::Report;
+pub use scenario::Scenario;
+pub use simulator::Simulator;
@@ -0,0 +1,59 @@
+use clap::{ArgEnum, Parser};
+use solana_program::program_stubs::{set_syscall_stubs, SyscallStubs};
+use std::{io::stdout, path::PathBuf};
+use vault_sim::{Scenario, SimResult, Simulator};
+
+#[derive(Parser)]
+#[clap(about = "Simulate a vault's lifecycle and report tranche returns and user payouts")]
+struct Args {
+    /// path to the scenario's TOML file
+    scenario: PathBuf,
+
+    #[clap(long, arg_enum, default_value = "json")]
+    format: Format,
+
+    /// rows to output as CSV
+    #[clap(long, arg_enum, default_value = "tranches")]
+    report: ReportKind,
+}
+
+#[derive(Clone, ArgEnum)]
+enum Format {
+    Json,
+    Csv,
+}
+
+#[derive(Clone, ArgEnum)]
+enum ReportKind {
+    Tranches,
+    Users,
+}
+
+// the program logs via `msg!`, which prints to stdout off-chain and would be mixed into the report
+struct SilentStubs;
+
+impl SyscallStubs for SilentStubs {
+    fn sol_log(&self, _message: &str) {}
+
+    fn sol_log_data(&self, _fields: &[&[u8]]) {}
+}
+
+fn main() {
+    set_syscall_stubs(Box::new(SilentStubs));
+
+    if let Err(e) = run(Args::parse()) {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    }
+}
+
+fn run(args: Args) -> SimResult {
+    let scenario = Scenario::load(&args.scenario)?;
+    let report = Simulator::new(&scenario)?.run()?;
+
+    match (args.format, args.report) {
+        (Format::Json, _) => report.write_json(stdout()),
+        (Format::Csv, ReportKind::Tranches) => report.write_tranches_csv(stdout()),
+        (Format::Csv, ReportKind::Users) => report.write_users_csv(stdout()),
+    }
+}
@@ -0,0 +1,198 @@
+//! Constant product pool modeled after orca's token swap. Deposits and withdrawals are proportional to the
+//! reserves, deposits rounding up in the pool's favor, and swap fees stay in the pool.
+
+use crate::{
+    error::{SimError, SimResult},
+    scenario::PoolToml,
+};
+use serde::Serialize;
+use vault::{constant::MAX_BPS, waterfall::convert};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize)]
+pub struct Pool {
+    pub alpha_reserve: u64,
+    pub beta_reserve: u64,
+    pub lp_supply: u64,
+    pub fee_bps: u64,
+}
+
+impl From<&PoolToml> for Pool {
+    fn from(pool: &PoolToml) -> Self {
+        Pool {
+            alpha_reserve: pool.alpha_reserve,
+            beta_reserve: pool.beta_reserve,
+            lp_supply: pool.lp_supply,
+            fee_bps: pool.fee_bps,
+        }
+    }
+}
+
+impl Pool {
+    /// price of alpha in beta, (beta amount, alpha amount), as the program's adapters report it
+    pub fn price(&self) -> (u64, u64) {
+        (self.beta_reserve, self.alpha_reserve)
+    }
+
+    fn reserves(&self, alpha_to_beta: bool) -> (u64, u64) {
+        match alpha_to_beta {
+            true => (self.alpha_reserve, self.beta_reserve),
+            false => (self.beta_reserve, self.alpha_reserve),
+        }
+    }
+
+    fn set_reserves(&mut self, alpha_to_beta: bool, reserve_in: u64, reserve_out: u64) {
+        match alpha_to_beta {
+            true => {
+                self.alpha_reserve = reserve_in;
+                self.beta_reserve = reserve_out;
+            }
+            false => {
+                self.beta_reserve = reserve_in;
+                self.alpha_reserve = reserve_out;
+            }
+        }
+    }
+
+    /// Number of LP tokens a balanced deposit of at most the given amounts receives at the spot price
+    pub fn spot_lp(&self, alpha_amount: u64, beta_amount: u64) -> SimResult<u64> {
+        if self.alpha_reserve == 0 || self.beta_reserve == 0 {
+            return Ok(0);
+        }
+
+        Ok(
+            convert(alpha_amount, self.lp_supply, self.alpha_reserve)?.min(convert(
+                beta_amount,
+                self.lp_supply,
+                self.beta_reserve,
+            )?),
+        )
+    }
+
+    /// Mint exactly lp_amount, returning the (alpha, beta) amounts deposited
+    pub fn deposit(&mut self, lp_amount: u64) -> SimResult<(u64, u64)> {
+        if self.lp_supply == 0 {
+            return Err(SimError::Pool(
+                "cannot deposit into a pool without liquidity",
+            ));
+        }
+
+        let alpha_amount = mul_div_ceil(lp_amount, self.alpha_reserve, self.lp_supply)?;
+        let beta_amount = mul_div_ceil(lp_amount, self.beta_reserve, self.lp_supply)?;
+        self.alpha_reserve = checked_add(self.alpha_reserve, alpha_amount)?;
+        self.beta_reserve = checked_add(self.beta_reserve, beta_amount)?;
+        self.lp_supply = checked_add(self.lp_supply, lp_amount)?;
+
+        Ok((alpha_amount, beta_amount))
+    }
+
+    /// Burn lp_amount for its share of the reserves, returning the (alpha, beta) amounts withdrawn
+    pub fn withdraw(&mut self, lp_amount: u64) -> SimResult<(u64, u64)> {
+        if lp_amount > self.lp_supply {
+            return Err(SimError::Pool(
+                "cannot withdraw more LP than the pool's supply",
+            ));
+        }
+
+        let alpha_amount = convert(lp_amount, self.alpha_reserve, self.lp_supply)?;
+        let beta_amount = convert(lp_amount, self.beta_reserve, self.lp_supply)?;
+        self.alpha_reserve -= alpha_amount;
+        self.beta_reserve -= beta_amount;
+        self.lp_supply -= lp_amount;
+
+        Ok((alpha_amount, beta_amount))
+    }
+
+    /// Amount out of a swap at the spot price, ignoring fees and price impact
+    pub fn spot_amount_out(&self, alpha_to_beta: bool, amount_in: u64) -> SimResult<u64> {
+        let (reserve_in, reserve_out) = self.reserves(alpha_to_beta);
+
+        Ok(convert(amount_in, reserve_out, reserve_in)?)
+    }
+
+    /// Amount out of a swap at the spot price, net of the pool's fee but ignoring price impact. matches the
+    /// program's quote for orca pools.
+    pub fn quote(&self, alpha_to_beta: bool, amount_in: u64) -> SimResult<u64> {
+        let amount_in_after_fee = convert(amount_in, MAX_BPS - self.fee_bps, MAX_BPS)?;
+
+        self.spot_amount_out(alpha_to_beta, amount_in_after_fee)
+    }
+
+    /// Amount out of a swap of amount_in of alpha for beta, or beta for alpha, net of the pool's fee and price
+    /// impact
+    ///
+    /// @dev amount_out = reserve_out * amount_in_after_fee / (reserve_in + amount_in_after_fee)
+    pub fn amount_out(&self, alpha_to_beta: bool, amount_in: u64) -> SimResult<u64> {
+        let (reserve_in, reserve_out) = self.reserves(alpha_to_beta);
+        let amount_in_after_fee = convert(amount_in, MAX_BPS - self.fee_bps, MAX_BPS)?;
+
+        Ok(convert(
+            reserve_out,
+            amount_in_after_fee,
+            checked_add(reserve_in, amount_in_after_fee)?,
+        )?)
+    }
+
+    /// Swap amount_in of alpha for beta, or beta for alpha, returning the amount out
+    pub fn swap(&mut self, alpha_to_beta: bool, amount_in: u64) -> SimResult<u64> {
+        let (reserve_in, reserve_out) = self.reserves(alpha_to_beta);
+        let amount_out = self.amount_out(alpha_to_beta, amount_in)?;
+
+        self.set_reserves(
+            alpha_to_beta,
+            checked_add(reserve_in, amount_in)?,
+            reserve_out - amount_out,
+        );
+
+        Ok(amount_out)
+    }
+
+    /// Trade volume of alpha through the pool in each direction at the spot price. the fee on both legs
+    /// stays in the pool, growing the value of each LP token.
+    pub fn accrue_fees(&mut self, volume: u64) -> SimResult {
+        let alpha_fee = convert(volume, self.fee_bps, MAX_BPS)?;
+        let beta_fee = self.spot_amount_out(true, alpha_fee)?;
+        self.alpha_reserve = checked_add(self.alpha_reserve, alpha_fee)?;
+        self.beta_reserve = checked_add(self.beta_reserve, beta_fee)?;
+
+        Ok(())
+    }
+
+    /// Move the pool to the given price of alpha in beta while keeping its invariant, as arbitrageurs
+    /// would, ignoring the fees they pay
+    ///
+    /// @dev only the new reserves are found with floating point math: alpha = sqrt(k / price) and
+    ///      beta = sqrt(k * price). everything downstream is integer math, as it is on-chain.
+    pub fn move_to_price(&mut self, price: f64) -> SimResult {
+        if !(price.is_finite() && price > 0.0) {
+            return Err(SimError::Pool("price must be positive"));
+        }
+
+        let invariant = self.alpha_reserve as f64 * self.beta_reserve as f64;
+        let alpha_reserve = (invariant / price).sqrt().round();
+        let beta_reserve = (invariant * price).sqrt().round();
+        if alpha_reserve >= u64::MAX as f64 || beta_reserve >= u64::MAX as f64 {
+            return Err(SimError::Pool("reserves overflow at the given price"));
+        }
+
+        self.alpha_reserve = alpha_reserve as u64;
+        self.beta_reserve = beta_reserve as u64;
+
+        Ok(())
+    }
+}
+
+fn checked_add(a: u64, b: u64) -> SimResult<u64> {
+    a.checked_add(b).ok_or(SimError::Pool("reserves overflow"))
+}
+
+// amount * numerator / denominator, rounded up
+fn mul_div_ceil(amount: u64, numerator: u64, denominator: u64) -> SimResult<u64> {
+    let product = amount as u128 * numerator as u128;
+    let quotient = (product + denominator as u128 - 1) / denominator as u128;
+
+    if quotient > u64::MAX as u128 {
+        return Err(SimError::Pool("reserves overflow"));
+    }
+
+    Ok(quotient as u64)
+}
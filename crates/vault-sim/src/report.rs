@@ -0,0 +1,83 @@
+//! Outcome of a simulated vault, per tranche and per user. Amounts are in base units of the tranche's asset
+//! and returns in basis points, negative for a loss.
+
+use crate::{
+    error::{SimError, SimResult},
+    pool::Pool,
+};
+use serde::Serialize;
+use std::io::Write;
+use vault::state::State;
+
+#[derive(Debug, Serialize)]
+pub struct Report {
+    /// state the vault ended in. a vault with an empty tranche is never invested and stops before redeem.
+    pub state: String,
+    /// pool after the vault redeemed and rebalanced
+    pub pool: Pool,
+    pub tranches: Vec<TrancheReport>,
+    pub users: Vec<UserReport>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TrancheReport {
+    pub tranche_id: u8,
+    pub symbol: String,
+    pub seniority: u8,
+    pub deposited: u64,
+    pub invested: u64,
+    /// deposits refunded because the other side of the vault could not match them
+    pub excess: u64,
+    /// amount the tranche's holders withdraw after the waterfall
+    pub received: u64,
+    /// received relative to invested
+    pub return_bps: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UserReport {
+    pub user: String,
+    pub tranche_id: u8,
+    pub deposited: u64,
+    pub refunded: u64,
+    pub tranche_tokens: u64,
+    pub withdrawn: u64,
+    /// refunded + withdrawn
+    pub payout: u64,
+    /// withdrawn relative to the tranche tokens, i.e. the amount invested on the user's behalf
+    pub return_bps: i64,
+}
+
+impl Report {
+    pub fn new(state: State, pool: Pool) -> Self {
+        Report {
+            state: format!("{:?}", state).to_lowercase(),
+            pool,
+            tranches: vec![],
+            users: vec![],
+        }
+    }
+
+    pub fn write_json<W: Write>(&self, writer: W) -> SimResult {
+        serde_json::to_writer_pretty(writer, self).map_err(|e| SimError::Output(e.to_string()))
+    }
+
+    pub fn write_tranches_csv<W: Write>(&self, writer: W) -> SimResult {
+        write_csv(writer, &self.tranches)
+    }
+
+    pub fn write_users_csv<W: Write>(&self, writer: W) -> SimResult {
+        write_csv(writer, &self.users)
+    }
+}
+
+fn write_csv<W: Write, T: Serialize>(writer: W, rows: &[T]) -> SimResult {
+    let mut writer = csv::Writer::from_writer(writer);
+    for row in rows {
+        writer
+            .serialize(row)
+            .map_err(|e| SimError::Output(e.to_string()))?;
+    }
+
+    writer.flush().map_err(|e| SimError::Output(e.to_string()))
+}
@@ -0,0 +1,229 @@
+use crate::error::{SimError, SimResult};
+use serde::Deserialize;
+use solana_program::pubkey::Pubkey;
+use std::path::Path;
+use vault::{
+    constant::MAX_BPS,
+    state::{AssetConfig, LeverageConfig, ReturnTerms, VaultConfig},
+};
+
+/// Top level keys of vault features the simulator does not model. a scenario configuring any of them is
+/// rejected rather than simulated without them.
+pub const UNSUPPORTED_KEYS: [&str; 5] = [
+    "live_deposits",
+    "early_exits",
+    "early_exit_penalty_bps",
+    "rollovers",
+    "rollover_vault",
+];
+
+/// Scenario to simulate, read from a TOML file; see `scenario.example.toml`. The vault's config maps to the
+/// program's `VaultConfig`, with tranches backed by one of the pool's two assets. unknown keys are rejected.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Scenario {
+    pub start_at: u64,
+    pub deposit_duration: u64,
+    pub invest_duration: u64,
+    pub max_slippage_bps: u16,
+    pub leverage: Option<LeverageToml>,
+    /// asset backing the most senior tranche
+    pub alpha: AssetToml,
+    /// asset backing the most junior tranche
+    pub beta: AssetToml,
+    /// ordered from most senior to most junior
+    pub tranches: Vec<TrancheToml>,
+    pub pool: PoolToml,
+    /// ordered by time of deposit
+    #[serde(default)]
+    pub deposits: Vec<DepositToml>,
+    /// moves of the pool while the vault is live, in order
+    #[serde(default)]
+    pub path: Vec<PathStepToml>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AssetToml {
+    pub symbol: String,
+    pub decimals: u8,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Side {
+    Alpha,
+    Beta,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TrancheToml {
+    /// "alpha" or "beta"
+    pub asset: Side,
+    pub seniority: u8,
+    pub return_terms: Option<ReturnTermsToml>,
+    pub user_cap: Option<u64>,
+    pub asset_cap: Option<u64>,
+    pub min_deposit: Option<u64>,
+    pub max_deposits_per_user: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ReturnTermsToml {
+    pub floor_bps: u16,
+    pub cap_bps: u16,
+    pub yield_share_bps: u16,
+    pub first_loss_buffer_bps: Option<u16>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LeverageToml {
+    pub target_ratio_bps: u32,
+    pub min_ratio_bps: u32,
+    pub max_ratio_bps: u32,
+}
+
+/// Constant product pool the vault invests in, e.g. an orca pool
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PoolToml {
+    pub alpha_reserve: u64,
+    pub beta_reserve: u64,
+    pub lp_supply: u64,
+    pub fee_bps: u64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DepositToml {
+    /// name of the depositor, used to group deposits and in the per-user report
+    pub user: String,
+    pub tranche: u8,
+    pub amount: u64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PathStepToml {
+    /// price of alpha in beta at the end of the step, relative to the pool's initial price, in basis points
+    pub price_bps: u64,
+    /// amount of alpha traded through the pool in each direction during the step. the pool's fee on it
+    /// accrues to LPs.
+    #[serde(default)]
+    pub volume: u64,
+}
+
+impl Scenario {
+    pub fn load(path: &Path) -> SimResult<Self> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| SimError::Scenario(format!("{}: {}", path.display(), e)))?;
+
+        Self::parse(&contents)
+    }
+
+    pub fn parse(contents: &str) -> SimResult<Self> {
+        let value: toml::Value =
+            toml::from_str(contents).map_err(|e| SimError::Scenario(e.to_string()))?;
+
+        if let Some(key) = UNSUPPORTED_KEYS
+            .iter()
+            .find(|key| value.get(**key).is_some())
+        {
+            return Err(SimError::Scenario(format!(
+                "{}: live deposits, early exits and rollovers are not simulated",
+                key
+            )));
+        }
+
+        value
+            .try_into()
+            .map_err(|e| SimError::Scenario(e.to_string()))
+    }
+
+    /// the pool pairs the most senior tranche's asset with the most junior tranche's asset, so those
+    /// tranches must be backed by alpha and beta respectively. tranche configs are verified by the program.
+    pub fn verify(&self) -> SimResult {
+        if self.max_slippage_bps as u64 > MAX_BPS {
+            return Err(SimError::Scenario(
+                "max slippage must be at most 100%".into(),
+            ));
+        }
+
+        let pool = &self.pool;
+        if pool.alpha_reserve == 0 || pool.beta_reserve == 0 || pool.lp_supply == 0 {
+            return Err(SimError::Scenario("the pool must have liquidity".into()));
+        }
+        if pool.fee_bps >= MAX_BPS {
+            return Err(SimError::Scenario(
+                "the pool's fee must be under 100%".into(),
+            ));
+        }
+
+        match (self.tranches.first(), self.tranches.last()) {
+            (Some(senior), Some(junior))
+                if senior.asset == Side::Alpha && junior.asset == Side::Beta =>
+            {
+                Ok(())
+            }
+            _ => Err(SimError::Scenario(
+                "the most senior tranche must be backed by alpha and the most junior by beta"
+                    .into(),
+            )),
+        }
+    }
+
+    pub fn asset(&self, side: Side) -> &AssetToml {
+        match side {
+            Side::Alpha => &self.alpha,
+            Side::Beta => &self.beta,
+        }
+    }
+
+    pub fn to_vault_config(&self, authority: Pubkey, strategist: Pubkey) -> VaultConfig {
+        VaultConfig {
+            strategy: Pubkey::default(),
+            authority,
+            strategist,
+            tranches: self
+                .tranches
+                .iter()
+                .map(|tranche| tranche.to_asset_config(&self.asset(tranche.asset).symbol))
+                .collect(),
+            start_at: self.start_at,
+            deposit_duration: self.deposit_duration,
+            invest_duration: self.invest_duration,
+            deposit_gate: None,
+            max_slippage_bps: self.max_slippage_bps,
+            oracle: None,
+            leverage: self.leverage.as_ref().map(|leverage| LeverageConfig {
+                target_ratio_bps: leverage.target_ratio_bps,
+                min_ratio_bps: leverage.min_ratio_bps,
+                max_ratio_bps: leverage.max_ratio_bps,
+            }),
+            metadata_uri: String::new(),
+            early_exit_penalty_bps: None,
+        }
+    }
+}
+
+impl TrancheToml {
+    fn to_asset_config(&self, symbol: &str) -> AssetConfig {
+        AssetConfig {
+            seniority: self.seniority,
+            symbol: symbol.to_string(),
+            return_terms: self.return_terms.as_ref().map(|terms| ReturnTerms {
+                floor_bps: terms.floor_bps,
+                cap_bps: terms.cap_bps,
+                yield_share_bps: terms.yield_share_bps,
+                first_loss_buffer_bps: terms.first_loss_buffer_bps,
+            }),
+            user_cap: self.user_cap,
+            asset_cap: self.asset_cap,
+            min_deposit: self.min_deposit,
+            max_deposits_per_user: self.max_deposits_per_user,
+        }
+    }
+}
@@ -0,0 +1,399 @@
+//! Runs a scenario through the vault's lifecycle using the program's own state and math:
+//!
+//!   inactive -> deposit (deposits) -> live (invest, process claims, price path) -> redeem -> rebalance -> withdraw
+//!
+//! The strategist invests every deposit, as the keeper does, and rebalances with the keeper's swap config.
+//!
+//! Live deposits, early exits and rollovers are not simulated; `Scenario::parse` rejects scenarios that configure
+//! them.
+
+use crate::{
+    error::{SimError, SimResult},
+    pool::Pool,
+    report::{Report, TrancheReport, UserReport},
+    scenario::{Scenario, Side},
+};
+use keeper::rebalance::compute_swap_config;
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use std::collections::BTreeMap;
+use vault::{
+    constant::MAX_BPS,
+    error::ErrorCode,
+    math::{compute_claim_amount, compute_withdrawal_amount},
+    state::{Asset, History, Receipt, State, Vault},
+    verify_deposit_for_user, verify_leverage, verify_tranche_configs,
+    waterfall::{compute_realized_yield_bps, convert, settle_vault},
+};
+
+// kinds of simulated addresses
+const MINT: u8 = 0;
+const TRANCHE_MINT: u8 = 1;
+const USER: u8 = 2;
+const AUTHORITY: u8 = 3;
+
+// deterministic address so that reruns of a scenario are identical
+fn address(kind: u8, idx: usize) -> Pubkey {
+    let mut bytes = [0u8; 32];
+    bytes[0] = kind;
+    bytes[1..9].copy_from_slice(&(idx as u64).to_le_bytes());
+
+    Pubkey::new_from_array(bytes)
+}
+
+fn mint(side: Side) -> Pubkey {
+    match side {
+        Side::Alpha => address(MINT, 0),
+        Side::Beta => address(MINT, 1),
+    }
+}
+
+fn math_error() -> SimError {
+    ProgramError::from(ErrorCode::MathError).into()
+}
+
+pub struct Simulator<'a> {
+    scenario: &'a Scenario,
+    vault: Vault,
+    pool: Pool,
+    /// price of alpha in beta before the vault is live, which the price path is relative to
+    initial_price: f64,
+    /// LP tokens held by the vault's strategy
+    strategy_lp: u64,
+    /// depositors by name, in order of their first deposit
+    users: Vec<String>,
+    /// receipts per tranche, in deposit order
+    receipts: Vec<Vec<Receipt>>,
+    /// histories per (user, tranche_id)
+    histories: BTreeMap<(Pubkey, u8), History>,
+}
+
+impl<'a> Simulator<'a> {
+    pub fn new(scenario: &'a Scenario) -> SimResult<Self> {
+        scenario.verify()?;
+
+        let authority = address(AUTHORITY, 0);
+        let config = scenario.to_vault_config(authority, authority);
+        verify_tranche_configs(&config.tranches)?;
+
+        let mut tranches: Vec<Asset> = Vec::with_capacity(scenario.tranches.len());
+        for (idx, (tranche_config, tranche)) in config
+            .tranches
+            .iter()
+            .zip(scenario.tranches.iter())
+            .enumerate()
+        {
+            tranches.push(
+                Asset::builder()
+                    .tranche_id(idx as u8)
+                    .seniority(tranche_config.seniority)
+                    .return_terms(tranche_config.return_terms)
+                    .mint(mint(tranche.asset))
+                    .lp(address(TRANCHE_MINT, idx))
+                    .decimals(scenario.asset(tranche.asset).decimals)
+                    .asset_cap(tranche_config.asset_cap)
+                    .user_cap(tranche_config.user_cap)
+                    .min_deposit(tranche_config.min_deposit)
+                    .max_deposits_per_user(tranche_config.max_deposits_per_user)
+                    .build()?,
+            );
+        }
+
+        let mut vault = Vault::default();
+        vault.init(0, authority, Pubkey::default(), 0, config, tranches);
+
+        Ok(Simulator {
+            scenario,
+            vault,
+            pool: Pool::from(&scenario.pool),
+            initial_price: scenario.pool.beta_reserve as f64 / scenario.pool.alpha_reserve as f64,
+            strategy_lp: 0,
+            users: vec![],
+            receipts: vec![vec![]; scenario.tranches.len()],
+            histories: BTreeMap::new(),
+        })
+    }
+
+    /// Run the scenario to the end of the vault's lifecycle. a vault with an empty tranche is never invested,
+    /// so it stops once claims are processed and every deposit is refunded.
+    pub fn run(mut self) -> SimResult<Report> {
+        let scenario = self.scenario;
+
+        self.vault.transition(State::Deposit, scenario.start_at)?;
+        self.deposit()?;
+
+        let invested_at = scenario.start_at.saturating_add(scenario.deposit_duration);
+        self.vault.transition(State::Live, invested_at)?;
+        self.invest()?;
+        self.process_claims()?;
+
+        if self
+            .vault
+            .tranches()
+            .iter()
+            .any(|tranche| tranche.invested > 0)
+        {
+            self.follow_path()?;
+
+            let redeemed_at = invested_at.saturating_add(scenario.invest_duration);
+            self.vault.transition(State::Redeem, redeemed_at)?;
+            self.redeem()?;
+
+            self.vault.transition(State::Rebalance, redeemed_at)?;
+            self.rebalance()?;
+
+            self.vault.transition(State::Withdraw, redeemed_at)?;
+        }
+
+        self.report()
+    }
+
+    fn user(&mut self, name: &str) -> Pubkey {
+        let idx = match self.users.iter().position(|user| user == name) {
+            Some(idx) => idx,
+            None => {
+                self.users.push(name.to_string());
+                self.users.len() - 1
+            }
+        };
+
+        address(USER, idx)
+    }
+
+    // each deposit is checked against the tranche's caps as the deposit instruction would
+    fn deposit(&mut self) -> SimResult {
+        let scenario = self.scenario;
+
+        for (idx, deposit) in scenario.deposits.iter().enumerate() {
+            let user = self.user(&deposit.user);
+            let asset = self.vault.get_tranche(deposit.tranche)?;
+
+            let history = self.histories.entry((user, deposit.tranche)).or_default();
+            history.init_if_needed(0);
+            let mut receipt = Receipt::default();
+            receipt.init(0, deposit.amount, asset.user_deposited()?, &user)?;
+
+            verify_deposit_for_user(history, &asset, deposit.amount).map_err(|e| {
+                SimError::Scenario(format!(
+                    "deposit {} by {} into tranche {} rejected: {}",
+                    idx, deposit.user, deposit.tranche, e
+                ))
+            })?;
+
+            self.vault.update_deposit(deposit.tranche, deposit.amount)?;
+            self.receipts[deposit.tranche as usize].push(receipt);
+        }
+
+        Ok(())
+    }
+
+    fn deposited_for(&self, side: Side) -> SimResult<u64> {
+        let mut deposited: u64 = 0;
+        for tranche in self.vault.tranches() {
+            if tranche.mint == mint(side) {
+                deposited = deposited
+                    .checked_add(tranche.deposited)
+                    .ok_or_else(math_error)?;
+            }
+        }
+
+        Ok(deposited)
+    }
+
+    // the strategist offers every deposit to the pool and the program requests the spot amount of LP minus the
+    // vault's max slippage. the pool takes what that amount of LP costs and the rest is refunded as excess.
+    fn invest(&mut self) -> SimResult {
+        if !self.vault.has_deposits_in_all_tranches() {
+            for tranche in self.vault.tranches_mut() {
+                tranche.make_investment(0)?;
+            }
+
+            return Ok(());
+        }
+
+        let spot_lp = self.pool.spot_lp(
+            self.deposited_for(Side::Alpha)?,
+            self.deposited_for(Side::Beta)?,
+        )?;
+        let min_out = convert(
+            spot_lp,
+            MAX_BPS - self.vault.max_slippage_bps as u64,
+            MAX_BPS,
+        )?;
+
+        let (alpha_invested, beta_invested) = self.pool.deposit(min_out)?;
+        self.strategy_lp = min_out;
+
+        self.vault.make_investments(alpha_invested, beta_invested)?;
+        verify_leverage(&self.vault, self.pool.price())?;
+
+        Ok(())
+    }
+
+    // walk each tranche's receipts from the latest deposit, refunding what was not invested
+    fn process_claims(&mut self) -> SimResult {
+        for (tranche, receipts) in self.vault.tranches_mut().iter_mut().zip(&self.receipts) {
+            let invested = tranche.user_invested();
+
+            let mut processed: u64 = 0;
+            for receipt in receipts.iter().rev() {
+                let (amount, is_complete) =
+                    compute_claim_amount(invested, receipt.cumulative, receipt.amount)
+                        .ok_or_else(math_error)?;
+                self.histories
+                    .entry((receipt.depositor, tranche.tranche_id))
+                    .or_default()
+                    .add_claim(amount)?;
+
+                processed += 1;
+                if is_complete {
+                    break;
+                }
+            }
+
+            tranche.update_claims_index(tranche.deposits - processed);
+            tranche.finalize_claims();
+        }
+
+        Ok(())
+    }
+
+    // move the pool along the scenario's price path while the vault is live, accruing fees on each step's volume
+    fn follow_path(&mut self) -> SimResult {
+        for step in &self.scenario.path {
+            self.pool.accrue_fees(step.volume)?;
+            self.pool
+                .move_to_price(self.initial_price * step.price_bps as f64 / MAX_BPS as f64)?;
+        }
+
+        Ok(())
+    }
+
+    fn redeem(&mut self) -> SimResult {
+        let (alpha_amount, beta_amount) = self.pool.withdraw(self.strategy_lp)?;
+        self.strategy_lp = 0;
+
+        self.vault.add_receipts(alpha_amount, beta_amount)?;
+
+        Ok(())
+    }
+
+    // swap like the keeper so that alpha covers what its tranches are owed, then settle the tranches via the
+    // waterfall at the pool's price after the swap
+    fn rebalance(&mut self) -> SimResult {
+        let swap_config = compute_swap_config(
+            &self.vault,
+            self.pool.alpha_reserve,
+            self.pool.beta_reserve,
+            self.pool.fee_bps,
+        )?;
+
+        if let Some(swap_config) = swap_config.filter(|config| config.max_in > 0) {
+            let alpha_to_beta = swap_config.alpha_to_beta;
+            let alpha_before = self.vault.received_for(&mint(Side::Alpha))?;
+            let beta_before = self.vault.received_for(&mint(Side::Beta))?;
+
+            // the program's max slippage applies to its spot quote net of the pool's fee, so it also bounds
+            // price impact
+            let expected_out = self.pool.quote(alpha_to_beta, swap_config.max_in)?;
+            let min_out = swap_config.min_out.max(convert(
+                expected_out,
+                MAX_BPS - self.vault.max_slippage_bps as u64,
+                MAX_BPS,
+            )?);
+
+            let amount_out = self.pool.swap(alpha_to_beta, swap_config.max_in)?;
+            if amount_out < min_out {
+                return Err(ProgramError::from(ErrorCode::SlippageTooHigh).into());
+            }
+
+            let (alpha_after, beta_after) = match alpha_to_beta {
+                true => (
+                    alpha_before
+                        .checked_sub(swap_config.max_in)
+                        .ok_or_else(math_error)?,
+                    beta_before.checked_add(amount_out).ok_or_else(math_error)?,
+                ),
+                false => (
+                    alpha_before
+                        .checked_add(amount_out)
+                        .ok_or_else(math_error)?,
+                    beta_before
+                        .checked_sub(swap_config.max_in)
+                        .ok_or_else(math_error)?,
+                ),
+            };
+
+            self.vault.record_swap(
+                alpha_to_beta,
+                alpha_before,
+                alpha_after,
+                beta_before,
+                beta_after,
+            )?;
+        }
+
+        settle_vault(&mut self.vault, self.pool.price())?;
+
+        Ok(())
+    }
+
+    fn report(&self) -> SimResult<Report> {
+        let mut report = Report::new(self.vault.state(), self.pool);
+
+        for (tranche, config) in self.vault.tranches().iter().zip(&self.scenario.tranches) {
+            report.tranches.push(TrancheReport {
+                tranche_id: tranche.tranche_id,
+                symbol: self.scenario.asset(config.asset).symbol.clone(),
+                seniority: tranche.seniority,
+                deposited: tranche.deposited,
+                invested: tranche.invested,
+                excess: tranche.excess,
+                received: tranche.received,
+                return_bps: compute_realized_yield_bps(tranche.total_invested, tranche.received)
+                    as i64,
+            });
+        }
+
+        for (idx, name) in self.users.iter().enumerate() {
+            let user = address(USER, idx);
+            for tranche in self.vault.tranches() {
+                let history = match self.histories.get(&(user, tranche.tranche_id)) {
+                    Some(history) => history,
+                    None => continue,
+                };
+
+                // every deposit not refunded is exchanged 1:1 for tranche tokens
+                let tranche_tokens = history
+                    .cumulative
+                    .checked_sub(history.claim)
+                    .ok_or_else(math_error)?;
+                let withdrawn = match self.vault.state() {
+                    State::Withdraw if tranche_tokens > 0 => compute_withdrawal_amount(
+                        tranche.received,
+                        tranche.total_invested,
+                        tranche_tokens,
+                        tranche.decimals,
+                    )
+                    .ok_or_else(math_error)?,
+                    _ => 0,
+                };
+
+                report.users.push(UserReport {
+                    user: name.clone(),
+                    tranche_id: tranche.tranche_id,
+                    deposited: history.cumulative,
+                    refunded: history.claim,
+                    tranche_tokens,
+                    withdrawn,
+                    payout: history
+                        .claim
+                        .checked_add(withdrawn)
+                        .ok_or_else(math_error)?,
+                    return_bps: compute_realized_yield_bps(tranche_tokens, withdrawn) as i64,
+                });
+            }
+        }
+
+        Ok(report)
+    }
+}
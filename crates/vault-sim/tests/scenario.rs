@@ -0,0 +1,76 @@
+//! Scenarios run end to end against fixed reports, so changes to the program's math or the simulator show up as
+//! a diff of the expected output in `tests/fixtures`.
+
+use vault_sim::{Report, Scenario, SimError, Simulator};
+
+const TWO_TRANCHE: &str = include_str!("fixtures/two_tranche.toml");
+
+fn run(scenario: &str) -> Report {
+    let scenario = Scenario::parse(scenario).unwrap();
+    Simulator::new(&scenario).unwrap().run().unwrap()
+}
+
+fn output(write: impl FnOnce(&mut Vec<u8>)) -> String {
+    let mut buf = vec![];
+    write(&mut buf);
+    String::from_utf8(buf).unwrap()
+}
+
+#[test]
+fn two_tranche_json() {
+    let report = run(TWO_TRANCHE);
+
+    assert_eq!(
+        output(|buf| report.write_json(buf).unwrap()).trim_end(),
+        include_str!("fixtures/two_tranche.json").trim_end()
+    );
+}
+
+#[test]
+fn two_tranche_csv() {
+    let report = run(TWO_TRANCHE);
+
+    assert_eq!(
+        output(|buf| report.write_tranches_csv(buf).unwrap()),
+        include_str!("fixtures/two_tranche.tranches.csv")
+    );
+    assert_eq!(
+        output(|buf| report.write_users_csv(buf).unwrap()),
+        include_str!("fixtures/two_tranche.users.csv")
+    );
+}
+
+#[test]
+fn unsupported_features_are_rejected() {
+    for config in [
+        "early_exit_penalty_bps = 100\n",
+        "rollover_vault = \"11111111111111111111111111111111\"\n",
+        "[[live_deposits]]\nuser = \"dave\"\ntranche = 1\namount = 1000000000\n",
+        "[[early_exits]]\nuser = \"alice\"\ntranche = 0\namount = 1000000\n",
+        "[[rollovers]]\nuser = \"alice\"\ntranche = 0\n",
+    ] {
+        // top level keys go before the scenario's tables, tables after them
+        let scenario = match config.starts_with('[') {
+            true => format!("{}\n{}", TWO_TRANCHE, config),
+            false => format!("{}{}", config, TWO_TRANCHE),
+        };
+
+        match Scenario::parse(&scenario) {
+            Err(SimError::Scenario(message)) => {
+                assert!(message.contains("not simulated"), "{}", message)
+            }
+            result => panic!("{:?} accepted: {:?}", config, result.map(|_| ())),
+        }
+    }
+}
+
+#[test]
+fn unknown_keys_are_rejected() {
+    // e.g. a pool fee the simulator would silently ignore
+    let scenario = TWO_TRANCHE.replace("fee_bps = 30", "fee_bps = 30\nprotocol_fee_bps = 5");
+
+    assert!(matches!(
+        Scenario::parse(&scenario),
+        Err(SimError::Scenario(_))
+    ));
+}
@@ -0,0 +1,23 @@
+use solana_program::program_error::ProgramError;
+
+/// Errors returned by the mock AMM. Mirrors the failure modes of the real programs that the vault reacts
+/// to, not their error codes.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MockAmmError {
+    /// instruction tag or data does not match a supported layout
+    InvalidInstruction,
+    /// reserve or mint account does not match the pool state
+    InvalidPoolAccount,
+    /// amount out or LP out is below the caller's minimum, or amount in is above the caller's maximum
+    ExceededSlippage,
+    /// pool has no liquidity to price against
+    EmptyPool,
+    /// checked math failed
+    MathOverflow,
+}
+
+impl From<MockAmmError> for ProgramError {
+    fn from(e: MockAmmError) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
@@ -0,0 +1,307 @@
+//! Instruction layouts the mock understands, plus builders for the user-side instructions tests send
+//! directly to a pool, e.g. to move its price or to seed liquidity.
+
+use crate::{
+    error::MockAmmError,
+    pool_authority,
+    state::{OrcaPool, StableSwapPool},
+};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use std::convert::TryInto;
+
+/// Orca token swap instructions, tag byte followed by little endian u64 arguments
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OrcaInstruction {
+    /// accounts: pool, authority, user_transfer_authority (signer), user_source, pool_source,
+    /// pool_destination, user_destination, pool_mint, fee_account, token_program
+    Swap {
+        amount_in: u64,
+        minimum_amount_out: u64,
+    },
+    /// accounts: pool, authority, user_transfer_authority (signer), source_a, source_b, into_a, into_b,
+    /// pool_mint, pool_account, token_program
+    DepositAllTokenTypes {
+        pool_token_amount: u64,
+        maximum_token_a_amount: u64,
+        maximum_token_b_amount: u64,
+    },
+    /// accounts: pool, authority, user_transfer_authority (signer), pool_mint, source_pool_account, from_a,
+    /// from_b, user_a, user_b, fee_account, token_program
+    WithdrawAllTokenTypes {
+        pool_token_amount: u64,
+        minimum_token_a_amount: u64,
+        minimum_token_b_amount: u64,
+    },
+    /// accounts: pool, authority, user_transfer_authority (signer), source, into_a, into_b, pool_mint,
+    /// pool_account, token_program
+    DepositSingleTokenTypeExactAmountIn {
+        source_token_amount: u64,
+        minimum_pool_token_amount: u64,
+    },
+}
+
+/// StableSwap instructions, tag byte followed by little endian u64 arguments
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StableSwapInstruction {
+    /// accounts: swap, swap_authority, user_authority (signer), source, swap_source, swap_destination,
+    /// destination, admin_fee_destination, token_program
+    Swap {
+        amount_in: u64,
+        minimum_amount_out: u64,
+    },
+    /// accounts: swap, swap_authority, user_authority (signer), user_a, user_b, reserve_a, reserve_b,
+    /// pool_mint, output_lp, token_program
+    Deposit {
+        token_a_amount: u64,
+        token_b_amount: u64,
+        min_mint_amount: u64,
+    },
+    /// accounts: swap, swap_authority, user_authority (signer), pool_mint, input_lp, reserve_a, reserve_b,
+    /// user_a, user_b, admin_fee_a, admin_fee_b, token_program
+    Withdraw {
+        pool_token_amount: u64,
+        minimum_token_a_amount: u64,
+        minimum_token_b_amount: u64,
+    },
+}
+
+fn unpack_args<const N: usize>(data: &[u8]) -> Result<[u64; N], ProgramError> {
+    if data.len() < 8 * N {
+        return Err(MockAmmError::InvalidInstruction.into());
+    }
+
+    let mut args = [0u64; N];
+    for (idx, arg) in args.iter_mut().enumerate() {
+        *arg = u64::from_le_bytes(data[idx * 8..idx * 8 + 8].try_into().unwrap());
+    }
+
+    Ok(args)
+}
+
+fn pack_args(tag: u8, args: &[u64]) -> Vec<u8> {
+    let mut data = Vec::with_capacity(1 + 8 * args.len());
+    data.push(tag);
+    for arg in args {
+        data.extend_from_slice(&arg.to_le_bytes());
+    }
+
+    data
+}
+
+impl OrcaInstruction {
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        let (tag, rest) = data.split_first().ok_or(MockAmmError::InvalidInstruction)?;
+
+        Ok(match tag {
+            1 => {
+                let [amount_in, minimum_amount_out] = unpack_args::<2>(rest)?;
+                OrcaInstruction::Swap {
+                    amount_in,
+                    minimum_amount_out,
+                }
+            }
+            2 => {
+                let [pool_token_amount, maximum_token_a_amount, maximum_token_b_amount] =
+                    unpack_args::<3>(rest)?;
+                OrcaInstruction::DepositAllTokenTypes {
+                    pool_token_amount,
+                    maximum_token_a_amount,
+                    maximum_token_b_amount,
+                }
+            }
+            3 => {
+                let [pool_token_amount, minimum_token_a_amount, minimum_token_b_amount] =
+                    unpack_args::<3>(rest)?;
+                OrcaInstruction::WithdrawAllTokenTypes {
+                    pool_token_amount,
+                    minimum_token_a_amount,
+                    minimum_token_b_amount,
+                }
+            }
+            4 => {
+                let [source_token_amount, minimum_pool_token_amount] = unpack_args::<2>(rest)?;
+                OrcaInstruction::DepositSingleTokenTypeExactAmountIn {
+                    source_token_amount,
+                    minimum_pool_token_amount,
+                }
+            }
+            _ => return Err(MockAmmError::InvalidInstruction.into()),
+        })
+    }
+
+    pub fn pack(&self) -> Vec<u8> {
+        match *self {
+            OrcaInstruction::Swap {
+                amount_in,
+                minimum_amount_out,
+            } => pack_args(1, &[amount_in, minimum_amount_out]),
+            OrcaInstruction::DepositAllTokenTypes {
+                pool_token_amount,
+                maximum_token_a_amount,
+                maximum_token_b_amount,
+            } => pack_args(
+                2,
+                &[
+                    pool_token_amount,
+                    maximum_token_a_amount,
+                    maximum_token_b_amount,
+                ],
+            ),
+            OrcaInstruction::WithdrawAllTokenTypes {
+                pool_token_amount,
+                minimum_token_a_amount,
+                minimum_token_b_amount,
+            } => pack_args(
+                3,
+                &[
+                    pool_token_amount,
+                    minimum_token_a_amount,
+                    minimum_token_b_amount,
+                ],
+            ),
+            OrcaInstruction::DepositSingleTokenTypeExactAmountIn {
+                source_token_amount,
+                minimum_pool_token_amount,
+            } => pack_args(4, &[source_token_amount, minimum_pool_token_amount]),
+        }
+    }
+}
+
+impl StableSwapInstruction {
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        let (tag, rest) = data.split_first().ok_or(MockAmmError::InvalidInstruction)?;
+
+        Ok(match tag {
+            1 => {
+                let [amount_in, minimum_amount_out] = unpack_args::<2>(rest)?;
+                StableSwapInstruction::Swap {
+                    amount_in,
+                    minimum_amount_out,
+                }
+            }
+            2 => {
+                let [token_a_amount, token_b_amount, min_mint_amount] = unpack_args::<3>(rest)?;
+                StableSwapInstruction::Deposit {
+                    token_a_amount,
+                    token_b_amount,
+                    min_mint_amount,
+                }
+            }
+            3 => {
+                let [pool_token_amount, minimum_token_a_amount, minimum_token_b_amount] =
+                    unpack_args::<3>(rest)?;
+                StableSwapInstruction::Withdraw {
+                    pool_token_amount,
+                    minimum_token_a_amount,
+                    minimum_token_b_amount,
+                }
+            }
+            _ => return Err(MockAmmError::InvalidInstruction.into()),
+        })
+    }
+
+    pub fn pack(&self) -> Vec<u8> {
+        match *self {
+            StableSwapInstruction::Swap {
+                amount_in,
+                minimum_amount_out,
+            } => pack_args(1, &[amount_in, minimum_amount_out]),
+            StableSwapInstruction::Deposit {
+                token_a_amount,
+                token_b_amount,
+                min_mint_amount,
+            } => pack_args(2, &[token_a_amount, token_b_amount, min_mint_amount]),
+            StableSwapInstruction::Withdraw {
+                pool_token_amount,
+                minimum_token_a_amount,
+                minimum_token_b_amount,
+            } => pack_args(
+                3,
+                &[
+                    pool_token_amount,
+                    minimum_token_a_amount,
+                    minimum_token_b_amount,
+                ],
+            ),
+        }
+    }
+}
+
+/// Swap against an Orca pool hosted by the mock at `crate::ID`. `a_to_b` swaps token A for token B.
+#[allow(clippy::too_many_arguments)]
+pub fn orca_swap(
+    pool: &Pubkey,
+    state: &OrcaPool,
+    user_authority: &Pubkey,
+    user_source: &Pubkey,
+    user_destination: &Pubkey,
+    a_to_b: bool,
+    amount_in: u64,
+    minimum_amount_out: u64,
+) -> Instruction {
+    let (pool_source, pool_destination) = match a_to_b {
+        true => (state.token_a, state.token_b),
+        false => (state.token_b, state.token_a),
+    };
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*pool, false),
+            AccountMeta::new_readonly(pool_authority(&crate::ID, pool).0, false),
+            AccountMeta::new_readonly(*user_authority, true),
+            AccountMeta::new(*user_source, false),
+            AccountMeta::new(pool_source, false),
+            AccountMeta::new(pool_destination, false),
+            AccountMeta::new(*user_destination, false),
+            AccountMeta::new(state.pool_mint, false),
+            AccountMeta::new(state.fee_account, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+        ],
+        data: OrcaInstruction::Swap {
+            amount_in,
+            minimum_amount_out,
+        }
+        .pack(),
+    }
+}
+
+/// Deposit both tokens into a StableSwap pool hosted by the mock at `stable_swap::ID`
+#[allow(clippy::too_many_arguments)]
+pub fn stable_swap_deposit(
+    pool: &Pubkey,
+    state: &StableSwapPool,
+    user_authority: &Pubkey,
+    user_a: &Pubkey,
+    user_b: &Pubkey,
+    output_lp: &Pubkey,
+    token_a_amount: u64,
+    token_b_amount: u64,
+    min_mint_amount: u64,
+) -> Instruction {
+    Instruction {
+        program_id: crate::stable_swap::ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*pool, false),
+            AccountMeta::new_readonly(pool_authority(&crate::stable_swap::ID, pool).0, false),
+            AccountMeta::new_readonly(*user_authority, true),
+            AccountMeta::new(*user_a, false),
+            AccountMeta::new(*user_b, false),
+            AccountMeta::new(state.token_a, false),
+            AccountMeta::new(state.token_b, false),
+            AccountMeta::new(state.pool_mint, false),
+            AccountMeta::new(*output_lp, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+        ],
+        data: StableSwapInstruction::Deposit {
+            token_a_amount,
+            token_b_amount,
+            min_mint_amount,
+        }
+        .pack(),
+    }
+}
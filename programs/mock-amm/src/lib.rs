@@ -0,0 +1,38 @@
+//! Minimal constant product / stable AMM used by the vault's integration tests. It understands the subset of
+//! the Orca token swap and Saber StableSwap instruction layouts the vault CPIs into, so the full vault
+//! lifecycle can run under `solana-program-test` without the real programs' `.so` files.
+//!
+//! @dev the same processor is registered at several program ids. the id it is invoked as decides which
+//!      instruction layout it speaks: the StableSwap program id speaks StableSwap, the Metaplex token metadata
+//!      program id accepts and ignores everything, and any other id speaks Orca token swap.
+//!
+//! @dev pools are not created through an instruction. tests write the pool state, mints and reserves at
+//!      genesis with the helpers in `state`, with the pool authority PDA from `pool_authority` as the
+//!      reserves' owner and the pool mint's authority.
+
+pub mod error;
+pub mod instruction;
+pub mod processor;
+pub mod state;
+
+use solana_program::{declare_id, pubkey::Pubkey};
+
+#[cfg(not(feature = "no-entrypoint"))]
+solana_program::entrypoint!(processor::process_instruction);
+
+declare_id!("2UEpZ2R8fGz9seaR4iamkBtqgJjKLNkFDR7Z95wEswFn");
+
+/// the StableSwap program id the vault's saber adapter expects
+pub mod stable_swap {
+    solana_program::declare_id!("SSwpkEEcbUqx4vtoEByFjSkhKdCT862DNVb52nZg1UZ");
+}
+
+/// the Metaplex token metadata program id the vault creates tranche metadata with
+pub mod token_metadata {
+    solana_program::declare_id!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+}
+
+/// PDA that owns a pool's reserves and mints its LP tokens
+pub fn pool_authority(program_id: &Pubkey, pool: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[pool.as_ref()], program_id)
+}
@@ -0,0 +1,628 @@
+//! Instruction processing. Orca pools price with a constant product curve and a fixed trade fee, StableSwap
+//! pools price 1-1 with the same fee. LP is minted and burned pro rata to the pool's reserves, which is
+//! what the vault's adapters assume when they compute spot amounts.
+
+use crate::{
+    error::MockAmmError,
+    instruction::{OrcaInstruction, StableSwapInstruction},
+    pool_authority, stable_swap,
+    state::{OrcaPool, StableSwapPool},
+    token_metadata,
+};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    program_pack::Pack,
+    pubkey::Pubkey,
+};
+use spl_token::state::{Account as TokenAccount, Mint};
+
+/// trade fee charged on swaps, in basis points
+pub const TRADE_FEE_BPS: u64 = 30;
+pub(crate) const MAX_BPS: u64 = 10_000;
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    if *program_id == token_metadata::ID {
+        // the vault never reads tranche metadata back, so there is nothing to create
+        msg!("mock token metadata: ignoring instruction");
+        return Ok(());
+    }
+
+    if *program_id == stable_swap::ID {
+        return process_stable_swap(program_id, accounts, StableSwapInstruction::unpack(data)?);
+    }
+
+    process_orca(program_id, accounts, OrcaInstruction::unpack(data)?)
+}
+
+fn process_orca(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction: OrcaInstruction,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let pool_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+    let user_authority_info = next_account_info(account_info_iter)?;
+
+    if pool_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let pool = OrcaPool::unpack(&pool_info.try_borrow_data()?)?;
+    let bump = verify_pool_authority(program_id, pool_info, authority_info)?;
+    let bump_seed = [bump];
+    let pool_seeds: &[&[u8]] = &[pool_info.key.as_ref(), &bump_seed];
+
+    if !user_authority_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    match instruction {
+        OrcaInstruction::Swap {
+            amount_in,
+            minimum_amount_out,
+        } => {
+            msg!("mock orca: swap");
+            let user_source_info = next_account_info(account_info_iter)?;
+            let pool_source_info = next_account_info(account_info_iter)?;
+            let pool_destination_info = next_account_info(account_info_iter)?;
+            let user_destination_info = next_account_info(account_info_iter)?;
+            let pool_mint_info = next_account_info(account_info_iter)?;
+            let _fee_account_info = next_account_info(account_info_iter)?;
+            let token_program_info = next_account_info(account_info_iter)?;
+
+            verify_pool_mint(&pool.pool_mint, pool_mint_info)?;
+            if pool
+                .verify_reserves(pool_source_info.key, pool_destination_info.key)
+                .is_err()
+            {
+                pool.verify_reserves(pool_destination_info.key, pool_source_info.key)?;
+            }
+
+            let amount_out = constant_product_amount_out(
+                amount_in,
+                token_amount(pool_source_info)?,
+                token_amount(pool_destination_info)?,
+            )?;
+            msg!("amount_out: {}", amount_out);
+            if amount_out < minimum_amount_out {
+                return Err(MockAmmError::ExceededSlippage.into());
+            }
+
+            transfer(
+                token_program_info,
+                user_source_info,
+                pool_source_info,
+                user_authority_info,
+                &[],
+                amount_in,
+            )?;
+            transfer(
+                token_program_info,
+                pool_destination_info,
+                user_destination_info,
+                authority_info,
+                &[pool_seeds],
+                amount_out,
+            )
+        }
+        OrcaInstruction::DepositAllTokenTypes {
+            pool_token_amount,
+            maximum_token_a_amount,
+            maximum_token_b_amount,
+        } => {
+            msg!("mock orca: deposit all token types");
+            let source_a_info = next_account_info(account_info_iter)?;
+            let source_b_info = next_account_info(account_info_iter)?;
+            let into_a_info = next_account_info(account_info_iter)?;
+            let into_b_info = next_account_info(account_info_iter)?;
+            let pool_mint_info = next_account_info(account_info_iter)?;
+            let pool_account_info = next_account_info(account_info_iter)?;
+            let token_program_info = next_account_info(account_info_iter)?;
+
+            pool.verify_reserves(into_a_info.key, into_b_info.key)?;
+            verify_pool_mint(&pool.pool_mint, pool_mint_info)?;
+
+            let (amount_a, amount_b) = deposit_amounts(
+                pool_token_amount,
+                token_amount(into_a_info)?,
+                token_amount(into_b_info)?,
+                mint_supply(pool_mint_info)?,
+                (maximum_token_a_amount, maximum_token_b_amount),
+            )?;
+            msg!("amount_a: {}, amount_b: {}", amount_a, amount_b);
+            if amount_a > maximum_token_a_amount || amount_b > maximum_token_b_amount {
+                return Err(MockAmmError::ExceededSlippage.into());
+            }
+
+            transfer(
+                token_program_info,
+                source_a_info,
+                into_a_info,
+                user_authority_info,
+                &[],
+                amount_a,
+            )?;
+            transfer(
+                token_program_info,
+                source_b_info,
+                into_b_info,
+                user_authority_info,
+                &[],
+                amount_b,
+            )?;
+            mint_to(
+                token_program_info,
+                pool_mint_info,
+                pool_account_info,
+                authority_info,
+                &[pool_seeds],
+                pool_token_amount,
+            )
+        }
+        OrcaInstruction::WithdrawAllTokenTypes {
+            pool_token_amount,
+            minimum_token_a_amount,
+            minimum_token_b_amount,
+        } => {
+            msg!("mock orca: withdraw all token types");
+            let pool_mint_info = next_account_info(account_info_iter)?;
+            let source_pool_account_info = next_account_info(account_info_iter)?;
+            let from_a_info = next_account_info(account_info_iter)?;
+            let from_b_info = next_account_info(account_info_iter)?;
+            let user_a_info = next_account_info(account_info_iter)?;
+            let user_b_info = next_account_info(account_info_iter)?;
+            let _fee_account_info = next_account_info(account_info_iter)?;
+            let token_program_info = next_account_info(account_info_iter)?;
+
+            pool.verify_reserves(from_a_info.key, from_b_info.key)?;
+            verify_pool_mint(&pool.pool_mint, pool_mint_info)?;
+
+            withdraw(
+                token_program_info,
+                pool_mint_info,
+                source_pool_account_info,
+                user_authority_info,
+                (from_a_info, from_b_info),
+                (user_a_info, user_b_info),
+                authority_info,
+                pool_seeds,
+                pool_token_amount,
+                (minimum_token_a_amount, minimum_token_b_amount),
+            )
+        }
+        OrcaInstruction::DepositSingleTokenTypeExactAmountIn {
+            source_token_amount,
+            minimum_pool_token_amount,
+        } => {
+            msg!("mock orca: deposit single token type");
+            let source_info = next_account_info(account_info_iter)?;
+            let into_a_info = next_account_info(account_info_iter)?;
+            let into_b_info = next_account_info(account_info_iter)?;
+            let pool_mint_info = next_account_info(account_info_iter)?;
+            let pool_account_info = next_account_info(account_info_iter)?;
+            let token_program_info = next_account_info(account_info_iter)?;
+
+            pool.verify_reserves(into_a_info.key, into_b_info.key)?;
+            verify_pool_mint(&pool.pool_mint, pool_mint_info)?;
+
+            let into_info = match token_mint(source_info)? {
+                mint if mint == pool.token_a_mint => into_a_info,
+                mint if mint == pool.token_b_mint => into_b_info,
+                _ => return Err(MockAmmError::InvalidPoolAccount.into()),
+            };
+
+            // a single sided deposit is half a swap and half a balanced deposit. for amounts small relative to
+            // the pool, that is close to supply * amount / (2 * reserve), which is good enough for tests.
+            let supply = mint_supply(pool_mint_info)?;
+            let reserve = token_amount(into_info)?;
+            if supply == 0 || reserve == 0 {
+                return Err(MockAmmError::EmptyPool.into());
+            }
+            let pool_token_amount = mul_div(
+                source_token_amount,
+                supply,
+                reserve.checked_mul(2).ok_or(MockAmmError::MathOverflow)?,
+            )?;
+            msg!("pool_token_amount: {}", pool_token_amount);
+            if pool_token_amount < minimum_pool_token_amount {
+                return Err(MockAmmError::ExceededSlippage.into());
+            }
+
+            transfer(
+                token_program_info,
+                source_info,
+                into_info,
+                user_authority_info,
+                &[],
+                source_token_amount,
+            )?;
+            mint_to(
+                token_program_info,
+                pool_mint_info,
+                pool_account_info,
+                authority_info,
+                &[pool_seeds],
+                pool_token_amount,
+            )
+        }
+    }
+}
+
+fn process_stable_swap(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction: StableSwapInstruction,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let swap_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+    let user_authority_info = next_account_info(account_info_iter)?;
+
+    if swap_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let pool = StableSwapPool::unpack(&swap_info.try_borrow_data()?)?;
+    let bump = verify_pool_authority(program_id, swap_info, authority_info)?;
+    let bump_seed = [bump];
+    let pool_seeds: &[&[u8]] = &[swap_info.key.as_ref(), &bump_seed];
+
+    if !user_authority_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    match instruction {
+        StableSwapInstruction::Swap {
+            amount_in,
+            minimum_amount_out,
+        } => {
+            msg!("mock stable swap: swap");
+            let source_info = next_account_info(account_info_iter)?;
+            let swap_source_info = next_account_info(account_info_iter)?;
+            let swap_destination_info = next_account_info(account_info_iter)?;
+            let destination_info = next_account_info(account_info_iter)?;
+            let _admin_fee_destination_info = next_account_info(account_info_iter)?;
+            let token_program_info = next_account_info(account_info_iter)?;
+
+            if pool
+                .verify_reserves(swap_source_info.key, swap_destination_info.key)
+                .is_err()
+            {
+                pool.verify_reserves(swap_destination_info.key, swap_source_info.key)?;
+            }
+
+            let amount_out = amount_in
+                .checked_sub(mul_div(amount_in, TRADE_FEE_BPS, MAX_BPS)?)
+                .ok_or(MockAmmError::MathOverflow)?;
+            msg!("amount_out: {}", amount_out);
+            if amount_out < minimum_amount_out || amount_out > token_amount(swap_destination_info)?
+            {
+                return Err(MockAmmError::ExceededSlippage.into());
+            }
+
+            transfer(
+                token_program_info,
+                source_info,
+                swap_source_info,
+                user_authority_info,
+                &[],
+                amount_in,
+            )?;
+            transfer(
+                token_program_info,
+                swap_destination_info,
+                destination_info,
+                authority_info,
+                &[pool_seeds],
+                amount_out,
+            )
+        }
+        StableSwapInstruction::Deposit {
+            token_a_amount,
+            token_b_amount,
+            min_mint_amount,
+        } => {
+            msg!("mock stable swap: deposit");
+            let user_a_info = next_account_info(account_info_iter)?;
+            let user_b_info = next_account_info(account_info_iter)?;
+            let reserve_a_info = next_account_info(account_info_iter)?;
+            let reserve_b_info = next_account_info(account_info_iter)?;
+            let pool_mint_info = next_account_info(account_info_iter)?;
+            let output_lp_info = next_account_info(account_info_iter)?;
+            let token_program_info = next_account_info(account_info_iter)?;
+
+            pool.verify_reserves(reserve_a_info.key, reserve_b_info.key)?;
+            verify_pool_mint(&pool.pool_mint, pool_mint_info)?;
+
+            // 1-1 pricing, so LP is minted against the sum of both reserves
+            let deposit_value = token_a_amount
+                .checked_add(token_b_amount)
+                .ok_or(MockAmmError::MathOverflow)?;
+            let reserves_value = token_amount(reserve_a_info)?
+                .checked_add(token_amount(reserve_b_info)?)
+                .ok_or(MockAmmError::MathOverflow)?;
+            let supply = mint_supply(pool_mint_info)?;
+            let mint_amount = match supply {
+                0 => deposit_value,
+                _ => mul_div(deposit_value, supply, reserves_value)?,
+            };
+            msg!("mint_amount: {}", mint_amount);
+            if mint_amount < min_mint_amount {
+                return Err(MockAmmError::ExceededSlippage.into());
+            }
+
+            transfer(
+                token_program_info,
+                user_a_info,
+                reserve_a_info,
+                user_authority_info,
+                &[],
+                token_a_amount,
+            )?;
+            transfer(
+                token_program_info,
+                user_b_info,
+                reserve_b_info,
+                user_authority_info,
+                &[],
+                token_b_amount,
+            )?;
+            mint_to(
+                token_program_info,
+                pool_mint_info,
+                output_lp_info,
+                authority_info,
+                &[pool_seeds],
+                mint_amount,
+            )
+        }
+        StableSwapInstruction::Withdraw {
+            pool_token_amount,
+            minimum_token_a_amount,
+            minimum_token_b_amount,
+        } => {
+            msg!("mock stable swap: withdraw");
+            let pool_mint_info = next_account_info(account_info_iter)?;
+            let input_lp_info = next_account_info(account_info_iter)?;
+            let reserve_a_info = next_account_info(account_info_iter)?;
+            let reserve_b_info = next_account_info(account_info_iter)?;
+            let user_a_info = next_account_info(account_info_iter)?;
+            let user_b_info = next_account_info(account_info_iter)?;
+            let _admin_fee_a_info = next_account_info(account_info_iter)?;
+            let _admin_fee_b_info = next_account_info(account_info_iter)?;
+            let token_program_info = next_account_info(account_info_iter)?;
+
+            pool.verify_reserves(reserve_a_info.key, reserve_b_info.key)?;
+            verify_pool_mint(&pool.pool_mint, pool_mint_info)?;
+
+            withdraw(
+                token_program_info,
+                pool_mint_info,
+                input_lp_info,
+                user_authority_info,
+                (reserve_a_info, reserve_b_info),
+                (user_a_info, user_b_info),
+                authority_info,
+                pool_seeds,
+                pool_token_amount,
+                (minimum_token_a_amount, minimum_token_b_amount),
+            )
+        }
+    }
+}
+
+// burn the user's LP and pay out both reserves pro rata
+#[allow(clippy::too_many_arguments)]
+fn withdraw<'a>(
+    token_program_info: &AccountInfo<'a>,
+    pool_mint_info: &AccountInfo<'a>,
+    source_lp_info: &AccountInfo<'a>,
+    user_authority_info: &AccountInfo<'a>,
+    (reserve_a_info, reserve_b_info): (&AccountInfo<'a>, &AccountInfo<'a>),
+    (user_a_info, user_b_info): (&AccountInfo<'a>, &AccountInfo<'a>),
+    authority_info: &AccountInfo<'a>,
+    pool_seeds: &[&[u8]],
+    pool_token_amount: u64,
+    (minimum_a, minimum_b): (u64, u64),
+) -> ProgramResult {
+    let supply = mint_supply(pool_mint_info)?;
+    if supply == 0 {
+        return Err(MockAmmError::EmptyPool.into());
+    }
+
+    let amount_a = mul_div(pool_token_amount, token_amount(reserve_a_info)?, supply)?;
+    let amount_b = mul_div(pool_token_amount, token_amount(reserve_b_info)?, supply)?;
+    msg!("amount_a: {}, amount_b: {}", amount_a, amount_b);
+    if amount_a < minimum_a || amount_b < minimum_b {
+        return Err(MockAmmError::ExceededSlippage.into());
+    }
+
+    invoke(
+        &spl_token::instruction::burn(
+            token_program_info.key,
+            source_lp_info.key,
+            pool_mint_info.key,
+            user_authority_info.key,
+            &[],
+            pool_token_amount,
+        )?,
+        &[
+            source_lp_info.clone(),
+            pool_mint_info.clone(),
+            user_authority_info.clone(),
+            token_program_info.clone(),
+        ],
+    )?;
+    transfer(
+        token_program_info,
+        reserve_a_info,
+        user_a_info,
+        authority_info,
+        &[pool_seeds],
+        amount_a,
+    )?;
+    transfer(
+        token_program_info,
+        reserve_b_info,
+        user_b_info,
+        authority_info,
+        &[pool_seeds],
+        amount_b,
+    )
+}
+
+// token amounts a balanced deposit needs for `pool_token_amount` LP, rounded up in the pool's favor. the
+// first deposit into an empty pool sets the price, so it takes the maximum amounts as is.
+fn deposit_amounts(
+    pool_token_amount: u64,
+    reserve_a: u64,
+    reserve_b: u64,
+    supply: u64,
+    maximum_amounts: (u64, u64),
+) -> Result<(u64, u64), ProgramError> {
+    if supply == 0 {
+        return Ok(maximum_amounts);
+    }
+
+    Ok((
+        mul_div_ceil(pool_token_amount, reserve_a, supply)?,
+        mul_div_ceil(pool_token_amount, reserve_b, supply)?,
+    ))
+}
+
+// amount out of a constant product swap after the trade fee
+fn constant_product_amount_out(
+    amount_in: u64,
+    reserve_in: u64,
+    reserve_out: u64,
+) -> Result<u64, ProgramError> {
+    if reserve_in == 0 || reserve_out == 0 {
+        return Err(MockAmmError::EmptyPool.into());
+    }
+
+    let amount_in_after_fee = mul_div(amount_in, MAX_BPS - TRADE_FEE_BPS, MAX_BPS)?;
+    mul_div(
+        reserve_out,
+        amount_in_after_fee,
+        reserve_in
+            .checked_add(amount_in_after_fee)
+            .ok_or(MockAmmError::MathOverflow)?,
+    )
+}
+
+fn mul_div(a: u64, b: u64, c: u64) -> Result<u64, ProgramError> {
+    let value = (a as u128)
+        .checked_mul(b as u128)
+        .and_then(|value| value.checked_div(c as u128))
+        .ok_or(MockAmmError::MathOverflow)?;
+
+    Ok(value as u64)
+}
+
+fn mul_div_ceil(a: u64, b: u64, c: u64) -> Result<u64, ProgramError> {
+    let value = (a as u128)
+        .checked_mul(b as u128)
+        .and_then(|value| value.checked_add((c as u128).checked_sub(1)?))
+        .and_then(|value| value.checked_div(c as u128))
+        .ok_or(MockAmmError::MathOverflow)?;
+
+    Ok(value as u64)
+}
+
+fn verify_pool_authority(
+    program_id: &Pubkey,
+    pool_info: &AccountInfo,
+    authority_info: &AccountInfo,
+) -> Result<u8, ProgramError> {
+    let (authority, bump) = pool_authority(program_id, pool_info.key);
+    if authority != *authority_info.key {
+        return Err(MockAmmError::InvalidPoolAccount.into());
+    }
+
+    Ok(bump)
+}
+
+fn verify_pool_mint(expected: &Pubkey, pool_mint_info: &AccountInfo) -> ProgramResult {
+    if expected != pool_mint_info.key {
+        return Err(MockAmmError::InvalidPoolAccount.into());
+    }
+
+    Ok(())
+}
+
+fn token_amount(info: &AccountInfo) -> Result<u64, ProgramError> {
+    Ok(TokenAccount::unpack(&info.try_borrow_data()?)?.amount)
+}
+
+fn token_mint(info: &AccountInfo) -> Result<Pubkey, ProgramError> {
+    Ok(TokenAccount::unpack(&info.try_borrow_data()?)?.mint)
+}
+
+fn mint_supply(info: &AccountInfo) -> Result<u64, ProgramError> {
+    Ok(Mint::unpack(&info.try_borrow_data()?)?.supply)
+}
+
+fn transfer<'a>(
+    token_program_info: &AccountInfo<'a>,
+    source_info: &AccountInfo<'a>,
+    destination_info: &AccountInfo<'a>,
+    authority_info: &AccountInfo<'a>,
+    signer_seeds: &[&[&[u8]]],
+    amount: u64,
+) -> ProgramResult {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            source_info.key,
+            destination_info.key,
+            authority_info.key,
+            &[],
+            amount,
+        )?,
+        &[
+            source_info.clone(),
+            destination_info.clone(),
+            authority_info.clone(),
+            token_program_info.clone(),
+        ],
+        signer_seeds,
+    )
+}
+
+fn mint_to<'a>(
+    token_program_info: &AccountInfo<'a>,
+    mint_info: &AccountInfo<'a>,
+    destination_info: &AccountInfo<'a>,
+    authority_info: &AccountInfo<'a>,
+    signer_seeds: &[&[&[u8]]],
+    amount: u64,
+) -> ProgramResult {
+    invoke_signed(
+        &spl_token::instruction::mint_to(
+            token_program_info.key,
+            mint_info.key,
+            destination_info.key,
+            authority_info.key,
+            &[],
+            amount,
+        )?,
+        &[
+            mint_info.clone(),
+            destination_info.clone(),
+            authority_info.clone(),
+            token_program_info.clone(),
+        ],
+        signer_seeds,
+    )
+}
@@ -0,0 +1,177 @@
+//! Pool state layouts. Only the fields the vault and the mock read are meaningful, the rest of each account
+//! is zeroed so the data has the same length and offsets as the real programs' accounts.
+
+use crate::{
+    error::MockAmmError,
+    processor::{MAX_BPS, TRADE_FEE_BPS},
+};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+// orca token swap `SwapVersion::SwapV1`: version (1) | is_initialized (1) | bump_seed (1) | token_program_id (32)
+// | token_a (32) | token_b (32) | pool_mint (32) | token_a_mint (32) | token_b_mint (32) | pool_fee_account (32)
+// | fees (64) | swap_curve (33)
+pub const ORCA_SWAP_STATE_LEN: usize = 324;
+const ORCA_VERSION: u8 = 1;
+const ORCA_TOKEN_A_OFFSET: usize = 35;
+const ORCA_TOKEN_B_OFFSET: usize = 67;
+const ORCA_POOL_MINT_OFFSET: usize = 99;
+const ORCA_TOKEN_A_MINT_OFFSET: usize = 131;
+const ORCA_TOKEN_B_MINT_OFFSET: usize = 163;
+const ORCA_FEE_ACCOUNT_OFFSET: usize = 195;
+// fees are packed as (numerator, denominator) pairs, the trade fee first
+const ORCA_TRADE_FEE_OFFSET: usize = 227;
+
+// stable swap `SwapInfo`: is_initialized (1) | is_paused (1) | nonce (1) | amp factors, ramp timestamps and
+// admin deadline (5 * 8) | future_admin_key (32) | admin_key (32) | token_a (96) | token_b (96) | pool_mint (32)
+// | fees (64). each token info is packed as (reserves, mint, admin_fees).
+pub const STABLE_SWAP_INFO_LEN: usize = 395;
+const STABLE_SWAP_NONCE_OFFSET: usize = 2;
+const STABLE_SWAP_AMP_FACTOR_OFFSET: usize = 3;
+const STABLE_SWAP_TOKEN_A_OFFSET: usize = 107;
+const STABLE_SWAP_TOKEN_B_OFFSET: usize = 203;
+const STABLE_SWAP_POOL_MINT_OFFSET: usize = 299;
+
+// amp factor written to the initial and target amp fields; the mock prices stable swaps 1-1 regardless
+const STABLE_SWAP_AMP_FACTOR: u64 = 100;
+
+fn write_pubkey(data: &mut [u8], offset: usize, key: &Pubkey) {
+    data[offset..offset + 32].copy_from_slice(key.as_ref());
+}
+
+fn read_pubkey(data: &[u8], offset: usize) -> Pubkey {
+    Pubkey::new(&data[offset..offset + 32])
+}
+
+/// Orca token swap pool
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct OrcaPool {
+    pub bump: u8,
+    /// token A reserve
+    pub token_a: Pubkey,
+    /// token B reserve
+    pub token_b: Pubkey,
+    pub pool_mint: Pubkey,
+    pub token_a_mint: Pubkey,
+    pub token_b_mint: Pubkey,
+    pub fee_account: Pubkey,
+}
+
+impl OrcaPool {
+    pub fn pack(&self) -> Vec<u8> {
+        let mut data = vec![0; ORCA_SWAP_STATE_LEN];
+        data[0] = ORCA_VERSION;
+        data[1] = 1;
+        data[2] = self.bump;
+        write_pubkey(&mut data, 3, &spl_token::ID);
+        write_pubkey(&mut data, ORCA_TOKEN_A_OFFSET, &self.token_a);
+        write_pubkey(&mut data, ORCA_TOKEN_B_OFFSET, &self.token_b);
+        write_pubkey(&mut data, ORCA_POOL_MINT_OFFSET, &self.pool_mint);
+        write_pubkey(&mut data, ORCA_TOKEN_A_MINT_OFFSET, &self.token_a_mint);
+        write_pubkey(&mut data, ORCA_TOKEN_B_MINT_OFFSET, &self.token_b_mint);
+        write_pubkey(&mut data, ORCA_FEE_ACCOUNT_OFFSET, &self.fee_account);
+        data[ORCA_TRADE_FEE_OFFSET..ORCA_TRADE_FEE_OFFSET + 8]
+            .copy_from_slice(&TRADE_FEE_BPS.to_le_bytes());
+        data[ORCA_TRADE_FEE_OFFSET + 8..ORCA_TRADE_FEE_OFFSET + 16]
+            .copy_from_slice(&MAX_BPS.to_le_bytes());
+
+        data
+    }
+
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != ORCA_SWAP_STATE_LEN || data[0] != ORCA_VERSION || data[1] != 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(OrcaPool {
+            bump: data[2],
+            token_a: read_pubkey(data, ORCA_TOKEN_A_OFFSET),
+            token_b: read_pubkey(data, ORCA_TOKEN_B_OFFSET),
+            pool_mint: read_pubkey(data, ORCA_POOL_MINT_OFFSET),
+            token_a_mint: read_pubkey(data, ORCA_TOKEN_A_MINT_OFFSET),
+            token_b_mint: read_pubkey(data, ORCA_TOKEN_B_MINT_OFFSET),
+            fee_account: read_pubkey(data, ORCA_FEE_ACCOUNT_OFFSET),
+        })
+    }
+
+    /// errors unless the accounts are this pool's (token a, token b) reserves
+    pub fn verify_reserves(&self, token_a: &Pubkey, token_b: &Pubkey) -> Result<(), ProgramError> {
+        if self.token_a != *token_a || self.token_b != *token_b {
+            return Err(MockAmmError::InvalidPoolAccount.into());
+        }
+
+        Ok(())
+    }
+}
+
+/// Saber StableSwap pool
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct StableSwapPool {
+    pub nonce: u8,
+    /// token A reserve
+    pub token_a: Pubkey,
+    pub token_a_mint: Pubkey,
+    pub admin_fee_a: Pubkey,
+    /// token B reserve
+    pub token_b: Pubkey,
+    pub token_b_mint: Pubkey,
+    pub admin_fee_b: Pubkey,
+    pub pool_mint: Pubkey,
+}
+
+impl StableSwapPool {
+    pub fn pack(&self) -> Vec<u8> {
+        let mut data = vec![0; STABLE_SWAP_INFO_LEN];
+        data[0] = 1;
+        data[STABLE_SWAP_NONCE_OFFSET] = self.nonce;
+        for offset in [
+            STABLE_SWAP_AMP_FACTOR_OFFSET,
+            STABLE_SWAP_AMP_FACTOR_OFFSET + 8,
+        ] {
+            data[offset..offset + 8].copy_from_slice(&STABLE_SWAP_AMP_FACTOR.to_le_bytes());
+        }
+
+        for (offset, (reserves, mint, admin_fees)) in [
+            (
+                STABLE_SWAP_TOKEN_A_OFFSET,
+                (self.token_a, self.token_a_mint, self.admin_fee_a),
+            ),
+            (
+                STABLE_SWAP_TOKEN_B_OFFSET,
+                (self.token_b, self.token_b_mint, self.admin_fee_b),
+            ),
+        ] {
+            write_pubkey(&mut data, offset, &reserves);
+            write_pubkey(&mut data, offset + 32, &mint);
+            write_pubkey(&mut data, offset + 64, &admin_fees);
+        }
+        write_pubkey(&mut data, STABLE_SWAP_POOL_MINT_OFFSET, &self.pool_mint);
+
+        data
+    }
+
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != STABLE_SWAP_INFO_LEN || data[0] != 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(StableSwapPool {
+            nonce: data[STABLE_SWAP_NONCE_OFFSET],
+            token_a: read_pubkey(data, STABLE_SWAP_TOKEN_A_OFFSET),
+            token_a_mint: read_pubkey(data, STABLE_SWAP_TOKEN_A_OFFSET + 32),
+            admin_fee_a: read_pubkey(data, STABLE_SWAP_TOKEN_A_OFFSET + 64),
+            token_b: read_pubkey(data, STABLE_SWAP_TOKEN_B_OFFSET),
+            token_b_mint: read_pubkey(data, STABLE_SWAP_TOKEN_B_OFFSET + 32),
+            admin_fee_b: read_pubkey(data, STABLE_SWAP_TOKEN_B_OFFSET + 64),
+            pool_mint: read_pubkey(data, STABLE_SWAP_POOL_MINT_OFFSET),
+        })
+    }
+
+    /// errors unless the accounts are this pool's (token a, token b) reserves
+    pub fn verify_reserves(&self, token_a: &Pubkey, token_b: &Pubkey) -> Result<(), ProgramError> {
+        if self.token_a != *token_a || self.token_b != *token_b {
+            return Err(MockAmmError::InvalidPoolAccount.into());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,24 @@
+//! Random sequences of vault instructions and pool trades against the program-test harness, checking the
+//! vault's invariants after each one. See `Harness`.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use vault_fuzz::{Action, Harness};
+
+// every run starts a fresh bank, keep sequences short enough for the fuzzer to stay fast
+const MAX_ACTIONS: usize = 64;
+
+fuzz_target!(|actions: Vec<Action>| {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+
+    runtime.block_on(async {
+        let mut harness = Harness::new().await;
+        for action in actions.into_iter().take(MAX_ACTIONS) {
+            harness.apply(action).await;
+        }
+    });
+});
@@ -0,0 +1,75 @@
+//! Arbitrary transitions and cancellations of a vault's state, interleaved with the receipts, investments and
+//! settlement the lifecycle checks for. An accepted transition must follow the lifecycle, an accepted
+//! cancellation must make every deposit refundable, and a rejected one must leave the vault as it was.
+
+#![no_main]
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use vault::state::{State, Vault};
+use vault_fuzz::{is_cancellation, is_lifecycle_transition, Target, NUM_TRANCHES};
+
+#[derive(Arbitrary, Debug)]
+enum Step {
+    Transition { target: Target, ts: u64 },
+    Cancel { ts: u64 },
+    Deposit { tranche_id: u8, amount: u64 },
+    Invest { tranche_id: u8, amount: u64 },
+    Receive { tranche_id: u8, amount: u64 },
+    MarkRebalanced,
+}
+
+fuzz_target!(|steps: Vec<Step>| {
+    let mut vault = Vault::default();
+    vault.num_tranches = NUM_TRANCHES as u8;
+
+    for step in steps {
+        match step {
+            Step::Transition { target, ts } => {
+                let before = vault.clone();
+                match vault.transition(target.into(), ts) {
+                    Ok(()) => {
+                        assert!(
+                            is_lifecycle_transition(before.state, vault.state),
+                            "{:?} skipped to {:?}",
+                            before.state,
+                            vault.state
+                        );
+
+                        match vault.state {
+                            State::Rebalance => {
+                                assert!(vault.tranches().iter().any(|tranche| tranche.received > 0))
+                            }
+                            State::Withdraw => assert!(vault.reblanced),
+                            _ => {}
+                        }
+                    }
+                    Err(_) => assert_eq!(vault, before, "rejected transition changed the vault"),
+                }
+            }
+            Step::Cancel { ts } => {
+                let before = vault.clone();
+                match vault.cancel(ts) {
+                    Ok(()) => {
+                        assert!(is_cancellation(&before, &vault), "{:?}", before.state);
+                        assert!(vault
+                            .tranches()
+                            .iter()
+                            .all(|tranche| tranche.excess == tranche.deposited));
+                    }
+                    Err(_) => assert_eq!(vault, before, "rejected cancellation changed the vault"),
+                }
+            }
+            Step::Deposit { tranche_id, amount } => {
+                vault.tranches_mut()[tranche_id as usize % NUM_TRANCHES].deposited = amount;
+            }
+            Step::Invest { tranche_id, amount } => {
+                vault.tranches_mut()[tranche_id as usize % NUM_TRANCHES].invested = amount;
+            }
+            Step::Receive { tranche_id, amount } => {
+                vault.tranches_mut()[tranche_id as usize % NUM_TRANCHES].received = amount;
+            }
+            Step::MarkRebalanced => vault.mark_rebalanced(),
+        }
+    }
+});
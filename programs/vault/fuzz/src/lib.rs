@@ -0,0 +1,782 @@
+//! Shared pieces of the vault's fuzz targets. `Harness` runs a 2 tranche vault over the mock Orca pool of the
+//! program-test suite and checks the vault's invariants after every action:
+//!
+//!   - the vault store holds every deposit, refund and redeemed amount still owed to depositors
+//!   - the vault only moves along its lifecycle, or back to inactive when cancelled before investing
+//!   - depositors claim their refund and tranche tokens at most once, and a cancelled vault refunds every
+//!     deposit in full
+//!   - live deposits and early exits mint and burn exactly the tranche tokens they account for
+//!   - a rollover position is paid out once, either into the next vault's store or to the depositor
+
+#[path = "../../tests/common/mod.rs"]
+mod common;
+
+use arbitrary::Arbitrary;
+use common::*;
+use solana_program::{clock::Clock, pubkey::Pubkey};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_associated_token_account::get_associated_token_address;
+use vault::{
+    state::{History, Rollover, State, Vault},
+    SwapConfig,
+};
+use vault_client::{get_history_address_and_bump_seed, get_rollover_address_and_bump_seed};
+
+pub const NUM_USERS: usize = 3;
+pub const NUM_TRANCHES: usize = 2;
+
+const USER_BALANCE: u64 = 10_000_000_000;
+const POOL_RESERVE: u64 = 100_000_000_000;
+const MAX_SLIPPAGE_BPS: u16 = 100;
+// wide enough for trades to move the pool's price without locking the vault out of the pool
+const MAX_DEVIATION_BPS: u16 = 1_000;
+const EXIT_PENALTY_BPS: u16 = 100;
+
+/// Target of a vault transition, mirroring the program's `State`
+#[derive(Arbitrary, Clone, Copy, Debug)]
+pub enum Target {
+    Inactive,
+    Deposit,
+    Live,
+    Redeem,
+    Rebalance,
+    Withdraw,
+}
+
+impl From<Target> for State {
+    fn from(target: Target) -> Self {
+        match target {
+            Target::Inactive => State::Inactive,
+            Target::Deposit => State::Deposit,
+            Target::Live => State::Live,
+            Target::Redeem => State::Redeem,
+            Target::Rebalance => State::Rebalance,
+            Target::Withdraw => State::Withdraw,
+        }
+    }
+}
+
+/// Whether the vault can move between the given states, including staying put. States follow
+/// inactive -> deposit -> live -> redeem -> rebalance -> withdraw; see `is_cancellation` for the way back.
+pub fn is_lifecycle_transition(from: State, to: State) -> bool {
+    from == to
+        || matches!(
+            (from, to),
+            (State::Inactive, State::Deposit)
+                | (State::Deposit, State::Live)
+                | (State::Live, State::Redeem)
+                | (State::Redeem, State::Rebalance)
+                | (State::Rebalance, State::Withdraw)
+        )
+}
+
+/// Whether the vault went from `before` to `after` by being cancelled, which only happens before its deposits
+/// are invested
+pub fn is_cancellation(before: &Vault, after: &Vault) -> bool {
+    !before.is_cancelled()
+        && after.is_cancelled()
+        && after.state == State::Inactive
+        && matches!(before.state, State::Inactive | State::Deposit | State::Live)
+        && before
+            .tranches()
+            .iter()
+            .all(|tranche| tranche.invested == 0)
+}
+
+// whether the strategist has invested the vault's deposits, or found a tranche without deposits
+fn is_invested(vault: &Vault) -> bool {
+    vault
+        .tranches()
+        .iter()
+        .any(|tranche| tranche.invested > 0 || tranche.excess > 0)
+}
+
+/// Instruction sent to the vault, or a trade moving the pool's price. Users and tranches are picked modulo
+/// their count; raw claim accounts are passed as is.
+#[derive(Arbitrary, Debug)]
+pub enum Action {
+    Transition(Target),
+    Cancel,
+    Deposit {
+        user: u8,
+        tranche_id: u8,
+        amount: u32,
+    },
+    Invest,
+    /// process the next batch of receipts, from the tranche's claims index down
+    ProcessClaims {
+        tranche_id: u8,
+        batch: u8,
+    },
+    /// process claims with arbitrary (deposit index, user) pairs as the receipt and history accounts
+    ProcessClaimsWith {
+        tranche_id: u8,
+        claims: Vec<(u8, u8)>,
+    },
+    Claim {
+        user: u8,
+        tranche_id: u8,
+    },
+    Swap {
+        a_to_b: bool,
+        amount: u32,
+    },
+    Redeem,
+    /// settle the vault, swapping max_in of one asset for the other first if given
+    Rebalance {
+        swap: Option<(u32, bool)>,
+    },
+    Withdraw {
+        user: u8,
+        tranche_id: u8,
+    },
+    ClaimAndWithdraw {
+        user: u8,
+        tranche_id: u8,
+    },
+    DepositLive {
+        user: u8,
+        tranche_id: u8,
+        amount: u32,
+    },
+    EarlyExit {
+        user: u8,
+        tranche_id: u8,
+        lp_amount: u32,
+    },
+    /// commit tranche tokens to the rollover fund, all of the user's if amount is 0
+    OptIntoRollover {
+        user: u8,
+        tranche_id: u8,
+        amount: u32,
+    },
+    ProcessRollover {
+        user: u8,
+        tranche_id: u8,
+    },
+    WithdrawRollover {
+        user: u8,
+        tranche_id: u8,
+    },
+    /// move the vault the rollover fund is deposited into, e.g. past the deposit state
+    TransitionNextVault(Target),
+}
+
+pub struct Harness {
+    context: ProgramTestContext,
+    strategy: OrcaStrategyFixture,
+    vault: VaultFixture,
+    /// vault for the next epoch, in the deposit state until moved by the fuzzer. it is never invested.
+    next_vault: VaultFixture,
+    users: Vec<Keypair>,
+    trader: Keypair,
+    slot: u64,
+    /// depositors of each tranche, in deposit order
+    depositors: [Vec<Pubkey>; NUM_TRANCHES],
+    /// refunds paid out of the vault store, per tranche
+    refunded: [u64; NUM_TRANCHES],
+    /// withdrawals and rollovers paid out of the vault store, per tranche
+    withdrawn: [u64; NUM_TRANCHES],
+    /// rollover positions deposited into the next vault's store, per tranche
+    rolled_over: [u64; NUM_TRANCHES],
+}
+
+impl Harness {
+    pub async fn new() -> Self {
+        let mut program_test = program_test();
+
+        let senior_mint = Pubkey::new_unique();
+        let junior_mint = Pubkey::new_unique();
+        for mint in [&senior_mint, &junior_mint] {
+            add_mint(&mut program_test, mint, &Pubkey::new_unique(), 0, DECIMALS);
+        }
+        let pool = add_orca_pool(
+            &mut program_test,
+            &senior_mint,
+            &junior_mint,
+            (POOL_RESERVE, POOL_RESERVE),
+            POOL_RESERVE,
+        );
+
+        let balances = [(senior_mint, USER_BALANCE), (junior_mint, USER_BALANCE)];
+        let users = (0..NUM_USERS)
+            .map(|_| add_user(&mut program_test, &balances))
+            .collect();
+        let trader = add_user(&mut program_test, &balances);
+        let oracle = oracle_config(
+            add_pyth_price(&mut program_test, 100_000_000, -8),
+            add_pyth_price(&mut program_test, 100_000_000, -8),
+            MAX_DEVIATION_BPS,
+        );
+
+        let mut context = program_test.start_with_context().await;
+        init_global_protocol_state(&mut context).await;
+        let strategy = OrcaStrategyFixture::init(&mut context, pool).await;
+
+        let tranches = || {
+            vec![
+                (senior_tranche_config(0, "SNR", 500), senior_mint),
+                (tranche_config(1, "JNR"), junior_mint),
+            ]
+        };
+        let vault = VaultFixture::init_with(
+            &mut context,
+            strategy.address,
+            tranches(),
+            MAX_SLIPPAGE_BPS,
+            |config| {
+                config.oracle = Some(oracle);
+                config.early_exit_penalty_bps = Some(EXIT_PENALTY_BPS);
+            },
+        )
+        .await;
+        strategy
+            .create_lp_store(&mut context, &vault)
+            .await
+            .unwrap();
+
+        let next_vault =
+            VaultFixture::init(&mut context, strategy.address, tranches(), MAX_SLIPPAGE_BPS).await;
+        vault
+            .set_rollover_vault(&mut context, &next_vault)
+            .await
+            .unwrap();
+        next_vault
+            .transition(&mut context, State::Deposit)
+            .await
+            .unwrap();
+
+        // warps are relative to the working bank, which is ahead of the root
+        let clock: Clock = context.banks_client.get_sysvar().await.unwrap();
+        let slot = clock.slot;
+
+        Harness {
+            context,
+            strategy,
+            vault,
+            next_vault,
+            users,
+            trader,
+            slot,
+            depositors: [vec![], vec![]],
+            refunded: [0; NUM_TRANCHES],
+            withdrawn: [0; NUM_TRANCHES],
+            rolled_over: [0; NUM_TRANCHES],
+        }
+    }
+
+    /// Apply the action and check the vault's invariants. the program rejects most random actions, which is
+    /// expected, but a rejected action must not break an invariant either.
+    pub async fn apply(&mut self, action: Action) {
+        let before = self.vault.vault(&mut self.context).await;
+        let next_before = self.next_vault.vault(&mut self.context).await;
+        self.next_blockhash().await;
+
+        match action {
+            Action::Transition(target) => {
+                let _ = self
+                    .vault
+                    .transition(&mut self.context, target.into())
+                    .await;
+            }
+            Action::Cancel => {
+                let _ = self.vault.cancel(&mut self.context).await;
+            }
+            Action::Deposit {
+                user,
+                tranche_id,
+                amount,
+            } => {
+                let (user, tranche_id) = pick(user, tranche_id);
+                let deposited = self
+                    .vault
+                    .deposit(
+                        &mut self.context,
+                        &self.users[user],
+                        tranche_id as u8,
+                        amount as u64,
+                    )
+                    .await;
+                if deposited.is_ok() {
+                    self.depositors[tranche_id].push(self.users[user].pubkey());
+                }
+            }
+            Action::Invest => {
+                // the keeper invests every deposit once.
+                // todo: the program does not stop the strategist from investing the vault's excess later on.
+                if !is_invested(&before) {
+                    let investable_a = self.store_balance(0).await;
+                    let investable_b = self.store_balance(1).await;
+                    let _ = self
+                        .strategy
+                        .invest(&mut self.context, &self.vault, investable_a, investable_b)
+                        .await;
+                }
+            }
+            Action::ProcessClaims { tranche_id, batch } => {
+                let tranche_id = tranche_id as usize % NUM_TRANCHES;
+                let tranche = before.tranches[tranche_id];
+                let start = tranche.claims_idx.unwrap_or(tranche.deposits);
+                let claims: Vec<(u64, Pubkey)> = (1..=start)
+                    .rev()
+                    .take(batch as usize)
+                    .map(|idx| (idx, self.depositors[tranche_id][idx as usize - 1]))
+                    .collect();
+
+                let _ = self
+                    .vault
+                    .process_claims_with(&mut self.context, tranche_id as u8, &claims)
+                    .await;
+            }
+            Action::ProcessClaimsWith { tranche_id, claims } => {
+                let claims: Vec<(u64, Pubkey)> = claims
+                    .into_iter()
+                    .map(|(idx, user)| (idx as u64, self.users[user as usize % NUM_USERS].pubkey()))
+                    .collect();
+
+                let _ = self
+                    .vault
+                    .process_claims_with(&mut self.context, tranche_id, &claims)
+                    .await;
+            }
+            Action::Claim { user, tranche_id } => {
+                let (user, tranche_id) = pick(user, tranche_id);
+                self.claim(user, tranche_id).await;
+            }
+            Action::Swap { a_to_b, amount } => {
+                let _ = self
+                    .strategy
+                    .swap(&mut self.context, &self.trader, a_to_b, amount as u64)
+                    .await;
+            }
+            Action::Redeem => {
+                let _ = self.strategy.redeem(&mut self.context, &self.vault).await;
+            }
+            Action::Rebalance { swap } => {
+                let swap_config = swap.map(|(max_in, alpha_to_beta)| SwapConfig {
+                    max_in: max_in as u64,
+                    min_out: 0,
+                    alpha_to_beta,
+                });
+                let _ = self
+                    .strategy
+                    .rebalance(&mut self.context, &self.vault, swap_config)
+                    .await;
+            }
+            Action::Withdraw { user, tranche_id } => {
+                let (user, tranche_id) = pick(user, tranche_id);
+                let destination = self.user_ata(user, tranche_id);
+
+                let balance_before = self.balance(&destination).await;
+                let withdrawn = self
+                    .vault
+                    .withdraw(&mut self.context, &self.users[user], tranche_id as u8)
+                    .await;
+                if withdrawn.is_ok() {
+                    self.withdrawn[tranche_id] += self.balance(&destination).await - balance_before;
+                }
+            }
+            Action::ClaimAndWithdraw { user, tranche_id } => {
+                let (user, tranche_id) = pick(user, tranche_id);
+                self.claim_and_withdraw(user, tranche_id).await;
+            }
+            Action::DepositLive {
+                user,
+                tranche_id,
+                amount,
+            } => {
+                let (user, tranche_id) = pick(user, tranche_id);
+                let lp_before = self.lp_balance(user, tranche_id).await;
+                let deposited = self
+                    .strategy
+                    .deposit_live(
+                        &mut self.context,
+                        &self.vault,
+                        &self.users[user],
+                        tranche_id as u8,
+                        amount as u64,
+                    )
+                    .await;
+                if deposited.is_ok() {
+                    let minted = self.lp_balance(user, tranche_id).await - lp_before;
+                    let after = self.vault.vault(&mut self.context).await;
+                    assert_eq!(
+                        after.tranches[tranche_id].total_invested,
+                        before.tranches[tranche_id].total_invested + minted,
+                        "live deposit minted tranche tokens it did not account for"
+                    );
+                }
+            }
+            Action::EarlyExit {
+                user,
+                tranche_id,
+                lp_amount,
+            } => {
+                let (user, tranche_id) = pick(user, tranche_id);
+                let lp_before = self.lp_balance(user, tranche_id).await;
+                let exited = self
+                    .strategy
+                    .early_exit(
+                        &mut self.context,
+                        &self.vault,
+                        &self.users[user],
+                        tranche_id as u8,
+                        lp_amount as u64,
+                    )
+                    .await;
+                if exited.is_ok() {
+                    assert_eq!(
+                        lp_before - self.lp_balance(user, tranche_id).await,
+                        lp_amount as u64,
+                        "early exit burned a different amount of tranche tokens"
+                    );
+                }
+            }
+            Action::OptIntoRollover {
+                user,
+                tranche_id,
+                amount,
+            } => {
+                let (user, tranche_id) = pick(user, tranche_id);
+                self.opt_into_rollover(user, tranche_id, amount as u64)
+                    .await;
+            }
+            Action::ProcessRollover { user, tranche_id } => {
+                let (user, tranche_id) = pick(user, tranche_id);
+                self.process_rollover(user, tranche_id).await;
+            }
+            Action::WithdrawRollover { user, tranche_id } => {
+                let (user, tranche_id) = pick(user, tranche_id);
+                self.withdraw_rollover(user, tranche_id).await;
+            }
+            Action::TransitionNextVault(target) => {
+                let _ = self
+                    .next_vault
+                    .transition(&mut self.context, target.into())
+                    .await;
+            }
+        }
+
+        self.check_invariants(&before, &next_before).await;
+    }
+
+    // pays out exactly the user's claim plus the withdrawal for their unclaimed tranche tokens, once
+    async fn claim_and_withdraw(&mut self, user: usize, tranche_id: usize) {
+        let history = self.history(user, tranche_id).await;
+        let destination = self.user_ata(user, tranche_id);
+
+        let balance_before = self.balance(&destination).await;
+        let paid = self
+            .vault
+            .claim_and_withdraw(&mut self.context, &self.users[user], tranche_id as u8)
+            .await;
+        if paid.is_err() {
+            return;
+        }
+
+        let history = history.expect("claimed without a deposit history");
+        let payout = self.balance(&destination).await - balance_before;
+        let withdrawal = payout
+            .checked_sub(history.claim)
+            .expect("payout is less than the user's claim");
+        self.refunded[tranche_id] += history.claim;
+        self.withdrawn[tranche_id] += withdrawal;
+
+        if !history.can_claim_tranche_lp {
+            assert_eq!(withdrawal, 0, "tranche tokens withdrawn twice");
+        }
+        let history = self.history(user, tranche_id).await.unwrap();
+        assert_eq!(history.claim, 0, "claim paid out but not reset");
+        assert!(
+            !history.can_claim_tranche_lp,
+            "tranche tokens claimable after a withdrawal"
+        );
+    }
+
+    // the tranche tokens burned are exactly the ones committed to the user's rollover position
+    async fn opt_into_rollover(&mut self, user: usize, tranche_id: usize, amount: u64) {
+        let committed_before = self
+            .rollover(user, tranche_id)
+            .await
+            .map_or(0, |rollover| rollover.lp_amount);
+        let lp_before = self.lp_balance(user, tranche_id).await;
+
+        let opted_in = self
+            .vault
+            .opt_into_rollover(
+                &mut self.context,
+                &self.users[user],
+                tranche_id as u8,
+                amount,
+            )
+            .await;
+        if opted_in.is_err() {
+            return;
+        }
+
+        let burned = lp_before - self.lp_balance(user, tranche_id).await;
+        let committed = self.rollover(user, tranche_id).await.unwrap().lp_amount;
+        assert!(
+            amount == 0 || burned == amount,
+            "burned a different amount of tranche tokens than asked"
+        );
+        assert_eq!(
+            committed - committed_before,
+            burned,
+            "committed a different amount of tranche tokens than burned"
+        );
+    }
+
+    // a rollover position moves from the vault store into the next vault's store, once
+    async fn process_rollover(&mut self, user: usize, tranche_id: usize) {
+        let rollover = self.rollover(user, tranche_id).await;
+        let next_store = self
+            .next_vault
+            .vault_store_ata(&self.next_vault.mints[tranche_id]);
+        let store_before = self.store_balance(tranche_id).await;
+        let next_store_before = self.balance(&next_store).await;
+
+        let depositor = self.users[user].pubkey();
+        let processed = self
+            .vault
+            .process_rollover(
+                &mut self.context,
+                &self.next_vault,
+                &depositor,
+                tranche_id as u8,
+            )
+            .await;
+        if processed.is_err() {
+            return;
+        }
+
+        assert!(
+            !rollover.expect("rolled over without a position").processed,
+            "rollover position paid out twice"
+        );
+        let moved = store_before - self.store_balance(tranche_id).await;
+        assert_eq!(
+            self.balance(&next_store).await - next_store_before,
+            moved,
+            "rollover position did not arrive in the next vault's store"
+        );
+        self.withdrawn[tranche_id] += moved;
+        self.rolled_over[tranche_id] += moved;
+    }
+
+    // a rollover position the next vault can't take is paid out to the depositor, once
+    async fn withdraw_rollover(&mut self, user: usize, tranche_id: usize) {
+        let rollover = self.rollover(user, tranche_id).await;
+        let destination = self.user_ata(user, tranche_id);
+        let store_before = self.store_balance(tranche_id).await;
+        let balance_before = self.balance(&destination).await;
+
+        let depositor = self.users[user].pubkey();
+        let withdrawn = self
+            .vault
+            .withdraw_rollover(
+                &mut self.context,
+                &self.next_vault,
+                &depositor,
+                tranche_id as u8,
+            )
+            .await;
+        if withdrawn.is_err() {
+            return;
+        }
+
+        assert!(
+            !rollover
+                .expect("withdrew a rollover without a position")
+                .processed,
+            "rollover position paid out twice"
+        );
+        let moved = store_before - self.store_balance(tranche_id).await;
+        assert_eq!(
+            self.balance(&destination).await - balance_before,
+            moved,
+            "rollover position was not paid out to the depositor"
+        );
+        self.withdrawn[tranche_id] += moved;
+    }
+
+    // a claim refunds exactly the user's claim and mints tranche tokens for the rest of their deposits, once
+    async fn claim(&mut self, user: usize, tranche_id: usize) {
+        let history = self.history(user, tranche_id).await;
+        let destination = self.user_ata(user, tranche_id);
+        let destination_lp = get_associated_token_address(
+            &self.users[user].pubkey(),
+            &self.vault.lp(tranche_id as u8),
+        );
+
+        let balance_before = self.balance(&destination).await;
+        let lp_before = self.balance(&destination_lp).await;
+        let claimed = self
+            .vault
+            .claim(&mut self.context, &self.users[user], tranche_id as u8)
+            .await;
+        if claimed.is_err() {
+            return;
+        }
+
+        let history = history.expect("claimed without a deposit history");
+        let refund = self.balance(&destination).await - balance_before;
+        let lp = self.balance(&destination_lp).await - lp_before;
+        self.refunded[tranche_id] += refund;
+
+        assert_eq!(
+            refund, history.claim,
+            "refund differs from the user's claim"
+        );
+        match history.can_claim_tranche_lp {
+            true => assert_eq!(lp, history.cumulative - history.claim),
+            false => assert_eq!(lp, 0, "tranche tokens claimed twice"),
+        }
+        let cancelled = self.vault.vault(&mut self.context).await.is_cancelled();
+        if cancelled && history.can_claim_tranche_lp {
+            assert_eq!(
+                refund, history.cumulative,
+                "cancelled vault did not refund every deposit"
+            );
+            assert_eq!(lp, 0, "cancelled vault minted tranche tokens");
+        }
+        assert!(
+            !self
+                .history(user, tranche_id)
+                .await
+                .unwrap()
+                .can_claim_tranche_lp,
+            "tranche tokens claimable after a claim"
+        );
+    }
+
+    async fn check_invariants(&mut self, before: &Vault, next_before: &Vault) {
+        let vault = self.vault.vault(&mut self.context).await;
+        assert!(
+            is_lifecycle_transition(before.state, vault.state) || is_cancellation(before, &vault),
+            "vault skipped from {:?} to {:?}",
+            before.state,
+            vault.state
+        );
+
+        // before the vault is invested every deposit sits in the vault store. afterwards, the store holds the
+        // excess not yet refunded and whatever was received from the strategy but not yet withdrawn.
+        let invested = is_invested(&vault);
+        for (idx, tranche) in vault.tranches().iter().enumerate() {
+            let owed = match invested {
+                false => tranche.deposited,
+                true => {
+                    let excess = tranche
+                        .excess
+                        .checked_sub(self.refunded[idx])
+                        .expect("refunded more than the tranche's excess");
+                    let received = tranche
+                        .received
+                        .checked_sub(self.withdrawn[idx])
+                        .expect("withdrew more than the tranche received");
+
+                    excess + received
+                }
+            };
+
+            let balance = self.store_balance(idx).await;
+            assert!(
+                balance >= owed,
+                "vault store holds {} of tranche {}'s asset but owes {}",
+                balance,
+                idx,
+                owed
+            );
+        }
+
+        // the next vault is never invested, so its store holds every rollover deposit
+        let next_vault = self.next_vault.vault(&mut self.context).await;
+        assert!(
+            is_lifecycle_transition(next_before.state, next_vault.state)
+                || is_cancellation(next_before, &next_vault),
+            "next vault skipped from {:?} to {:?}",
+            next_before.state,
+            next_vault.state
+        );
+        for (idx, tranche) in next_vault.tranches().iter().enumerate() {
+            assert_eq!(
+                tranche.rollover_deposited, self.rolled_over[idx],
+                "next vault recorded a different rollover deposit than it received"
+            );
+
+            let store = self.next_vault.vault_store_ata(&self.next_vault.mints[idx]);
+            let balance = self.balance(&store).await;
+            assert!(
+                balance >= tranche.deposited,
+                "next vault store holds {} of tranche {}'s asset but owes {}",
+                balance,
+                idx,
+                tranche.deposited
+            );
+        }
+    }
+
+    // identical actions would send identical transactions, which the bank rejects as already processed.
+    // a warp freezes a bank one slot before the target, so the target must be 2 slots past the working bank.
+    async fn next_blockhash(&mut self) {
+        self.slot += 2;
+        self.context.warp_to_slot(self.slot).unwrap();
+        self.context.last_blockhash = self
+            .context
+            .banks_client
+            .get_latest_blockhash()
+            .await
+            .unwrap();
+    }
+
+    async fn balance(&mut self, address: &Pubkey) -> u64 {
+        try_token_balance(&mut self.context, address)
+            .await
+            .unwrap_or_default()
+    }
+
+    async fn store_balance(&mut self, tranche_id: usize) -> u64 {
+        let store = self.vault.vault_store_ata(&self.vault.mints[tranche_id]);
+        self.balance(&store).await
+    }
+
+    fn user_ata(&self, user: usize, tranche_id: usize) -> Pubkey {
+        get_associated_token_address(&self.users[user].pubkey(), &self.vault.mints[tranche_id])
+    }
+
+    async fn lp_balance(&mut self, user: usize, tranche_id: usize) -> u64 {
+        let lp = get_associated_token_address(
+            &self.users[user].pubkey(),
+            &self.vault.lp(tranche_id as u8),
+        );
+        self.balance(&lp).await
+    }
+
+    async fn history(&mut self, user: usize, tranche_id: usize) -> Option<History> {
+        let (address, _) = get_history_address_and_bump_seed(
+            &self.vault.address,
+            tranche_id as u8,
+            &self.users[user].pubkey(),
+        );
+
+        try_fetch(&mut self.context, &address).await
+    }
+
+    async fn rollover(&mut self, user: usize, tranche_id: usize) -> Option<Rollover> {
+        let (address, _) = get_rollover_address_and_bump_seed(
+            &self.vault.address,
+            tranche_id as u8,
+            &self.users[user].pubkey(),
+        );
+
+        try_fetch(&mut self.context, &address).await
+    }
+}
+
+fn pick(user: u8, tranche_id: u8) -> (usize, usize) {
+    (
+        user as usize % NUM_USERS,
+        tranche_id as usize % NUM_TRANCHES,
+    )
+}
@@ -1,9 +1,11 @@
 use {
+    crate::util::PoolFee,
     anchor_lang::prelude::*,
     borsh::{BorshDeserialize, BorshSerialize},
     solana_program::{
         instruction::Instruction, program::invoke_signed, system_program::ID as SYSTEM_PROGRAM_ID,
     },
+    std::convert::TryInto,
 };
 
 #[repr(C)]
@@ -239,6 +241,114 @@ pub fn deposit<'a>(
     Ok(())
 }
 
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub struct OrcaDepositSingleInstructionData {
+    pub instruction: u8,
+    pub source_token_amount: u64,
+    pub minimum_pool_token_amount: u64,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn create_deposit_single_instruction(
+    program_id: Pubkey,
+    orca_pool: Pubkey,
+    orca_authority: Pubkey,
+    user_transfer_authority: Pubkey,
+    source: Pubkey,
+    into_a: Pubkey,
+    into_b: Pubkey,
+    pool_token: Pubkey,
+    pool_account: Pubkey,
+    source_token_amount: u64,
+    minimum_pool_token_amount: u64,
+) -> Instruction {
+    Instruction {
+        program_id,
+        // https://github.com/solana-labs/solana-program-library/blob/fe20132b07708226f0a6377d62b4fc8f0e5d5420/token-swap/js/src/index.ts#L839-L849
+        accounts: vec![
+            AccountMeta::new_readonly(orca_pool, false),
+            AccountMeta::new_readonly(orca_authority, false),
+            AccountMeta::new_readonly(user_transfer_authority, true),
+            AccountMeta::new(source, false),
+            AccountMeta::new(into_a, false),
+            AccountMeta::new(into_b, false),
+            AccountMeta::new(pool_token, false),
+            AccountMeta::new(pool_account, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data: OrcaDepositSingleInstructionData {
+            // https://github.com/solana-labs/solana-program-library/blob/fe20132b07708226f0a6377d62b4fc8f0e5d5420/token-swap/js/src/index.ts#L831
+            instruction: 4,
+            source_token_amount,
+            minimum_pool_token_amount,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+#[derive(Accounts)]
+pub struct CreatePoolDepositSingle<'info> {
+    /// CHECK: verified via orca CPI call
+    pub orca_swap_program: AccountInfo<'info>,
+    /// CHECK: verified via orca CPI call
+    pub orca_pool: AccountInfo<'info>,
+    /// CHECK: verified via orca CPI call
+    pub orca_authority: AccountInfo<'info>,
+    /// CHECK: verified via orca CPI call
+    pub user_transfer_authority: AccountInfo<'info>,
+    /// CHECK: verified via orca CPI call
+    pub source: AccountInfo<'info>,
+    /// CHECK: verified via orca CPI call
+    pub into_a: AccountInfo<'info>,
+    /// CHECK: verified via orca CPI call
+    pub into_b: AccountInfo<'info>,
+    /// CHECK: verified via orca CPI call
+    pub pool_token: AccountInfo<'info>,
+    /// CHECK: verified via orca CPI call
+    pub pool_account: AccountInfo<'info>,
+    /// CHECK: verified via orca CPI call
+    pub token_program: AccountInfo<'info>,
+}
+
+pub fn deposit_single<'a>(
+    ctx: CreatePoolDepositSingle<'a>,
+    source_token_amount: u64,
+    minimum_pool_token_amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    invoke_signed(
+        &create_deposit_single_instruction(
+            *ctx.orca_swap_program.key,
+            *ctx.orca_pool.key,
+            *ctx.orca_authority.key,
+            *ctx.user_transfer_authority.key,
+            *ctx.source.key,
+            *ctx.into_a.key,
+            *ctx.into_b.key,
+            *ctx.pool_token.key,
+            *ctx.pool_account.key,
+            source_token_amount,
+            minimum_pool_token_amount,
+        ),
+        &[
+            ctx.orca_pool.to_account_info(),
+            ctx.orca_authority.to_account_info(),
+            ctx.user_transfer_authority.to_account_info(),
+            ctx.source.to_account_info(),
+            ctx.into_a.to_account_info(),
+            ctx.into_b.to_account_info(),
+            ctx.pool_token.to_account_info(),
+            ctx.pool_account.to_account_info(),
+            ctx.token_program.to_account_info(),
+        ],
+        signer_seeds,
+    )?;
+
+    Ok(())
+}
+
 #[repr(C)]
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
 pub struct OrcaWithdrawInstructionData {
@@ -765,3 +875,58 @@ pub fn harvest<'a>(ctx: AquafarmHarvest<'a>, signer_seeds: &[&[&[u8]]]) -> Progr
 
     Ok(())
 }
+
+// ====================================================
+// swap state
+// ====================================================
+
+/// Subset of the token swap program's `SwapV1` state needed to verify pool accounts and price trades.
+/// https://github.com/solana-labs/solana-program-library/blob/fe20132b07708226f0a6377d62b4fc8f0e5d5420/token-swap/program/src/state.rs#L80
+pub struct OrcaSwapState {
+    pub token_a: Pubkey,
+    pub token_b: Pubkey,
+    pub pool_mint: Pubkey,
+    /// fee on the amount in of a swap, paid to liquidity providers
+    pub trade_fee: PoolFee,
+    /// fee on the amount in of a swap, paid to the pool's owner
+    pub owner_trade_fee: PoolFee,
+    /// fee on the pool tokens burned by a withdrawal, paid to the pool's owner
+    pub owner_withdraw_fee: PoolFee,
+}
+
+// 1 byte for the swap version, followed by is_initialized, bump_seed and token_program_id
+const SWAP_STATE_IS_INITIALIZED_OFFSET: usize = 1;
+const SWAP_STATE_TOKEN_A_OFFSET: usize = 35;
+const SWAP_STATE_TOKEN_B_OFFSET: usize = 67;
+const SWAP_STATE_POOL_MINT_OFFSET: usize = 99;
+// the token mints and pool fee account are followed by the fees, packed as (numerator, denominator) pairs
+const SWAP_STATE_TRADE_FEE_OFFSET: usize = 227;
+const SWAP_STATE_OWNER_TRADE_FEE_OFFSET: usize = 243;
+const SWAP_STATE_OWNER_WITHDRAW_FEE_OFFSET: usize = 259;
+
+pub fn unpack_swap_state(
+    orca_pool: &AccountInfo,
+) -> std::result::Result<OrcaSwapState, ProgramError> {
+    let data = orca_pool.try_borrow_data()?;
+    if data.len() < SWAP_STATE_OWNER_WITHDRAW_FEE_OFFSET + 16
+        || data[SWAP_STATE_IS_INITIALIZED_OFFSET] != 1
+    {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let read_pubkey = |offset: usize| Pubkey::new(&data[offset..offset + 32]);
+    let read_u64 = |offset: usize| u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+    let read_fee = |offset: usize| PoolFee {
+        numerator: read_u64(offset),
+        denominator: read_u64(offset + 8),
+    };
+
+    Ok(OrcaSwapState {
+        token_a: read_pubkey(SWAP_STATE_TOKEN_A_OFFSET),
+        token_b: read_pubkey(SWAP_STATE_TOKEN_B_OFFSET),
+        pool_mint: read_pubkey(SWAP_STATE_POOL_MINT_OFFSET),
+        trade_fee: read_fee(SWAP_STATE_TRADE_FEE_OFFSET),
+        owner_trade_fee: read_fee(SWAP_STATE_OWNER_TRADE_FEE_OFFSET),
+        owner_withdraw_fee: read_fee(SWAP_STATE_OWNER_WITHDRAW_FEE_OFFSET),
+    })
+}
@@ -3,24 +3,34 @@ pub mod external;
 // todo: figure out how to get rid of the double error code import (right now * is due to saber's unwrap_or_err method)
 use crate::error::{ErrorCode, OraResult};
 use crate::{
-    constant::{GLOBAL_STATE_SEED, STRATEGY_SEED, VAULT_SEED, VAULT_STORE_SEED},
+    constant::{GLOBAL_STATE_SEED, HISTORY_SEED, STRATEGY_SEED, VAULT_SEED, VAULT_STORE_SEED},
     convert_lp::Converter,
+    deposit_live::LiveDeposit,
+    early_exit::EarlyExit,
     error::ErrorCode::*,
+    get_vault_nav::Valuation,
     harvest::Harvester,
     init_strategy::StrategyInitializer,
     init_user_farm::FarmInitializer,
-    invest::Invest,
+    invest::{verify_leverage, Invest},
+    oracle::PoolPrice,
     rebalance::{Rebalance, SwapConfig},
     redeem::Redeem,
     revert_lp::Reverter,
-    state::{GlobalProtocolState, HasVault, StrategyFlag, Vault},
+    state::{GlobalProtocolState, HasVault, History, StrategistRole, StrategyFlag, Vault},
     swap::Swapper,
-    util::{get_spl_amount, get_spl_mint, transfer_from_signer},
+    util::{
+        assert_keys_equal, assert_owned_by, compute_lp_for_deposit, compute_lp_value,
+        compute_min_out, compute_spot_amount_out, get_spl_account, get_spl_amount, get_spl_mint,
+        get_spl_supply, mint_with_verified_ata, set_token_account_frozen, spl_token_transfer,
+        transfer_from_signer,
+    },
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token::TokenAccount;
-use anchor_spl::token::{Mint, Token};
+use anchor_spl::token::{burn, Burn, Mint, Token};
 use external::*;
+use spl_associated_token_account::get_associated_token_address;
 use std::mem::size_of;
 use std::ops::{Deref, DerefMut};
 
@@ -74,6 +84,58 @@ pub fn into_pool_endpoints<'info>(
     Ok((alpha_asset, beta_asset))
 }
 
+// read the pool's reserves for the given pool token accounts and the pool's LP supply. the accounts are
+// verified against the pool's swap state so that the strategist cannot pass in arbitrary token accounts
+// to skew the spot price. reserves are returned in the same order as the accounts passed in.
+pub fn get_pool_reserves<'info>(
+    swap_program: &Pubkey,
+    orca_pool: &AccountInfo<'info>,
+    pool_token_x: &AccountInfo<'info>,
+    pool_token_y: &AccountInfo<'info>,
+    pool_mint: &AccountInfo<'info>,
+) -> OraResult<(u64, u64, u64)> {
+    assert_owned_by(orca_pool, swap_program)?;
+    let swap_state = unpack_swap_state(orca_pool)?;
+
+    let (x, y) = (pool_token_x.key(), pool_token_y.key());
+    require!(
+        (x == swap_state.token_a && y == swap_state.token_b)
+            || (x == swap_state.token_b && y == swap_state.token_a),
+        PublicKeyMismatch
+    );
+    require!(pool_mint.key() == swap_state.pool_mint, PublicKeyMismatch);
+
+    Ok((
+        get_spl_amount(pool_token_x)?,
+        get_spl_amount(pool_token_y)?,
+        get_spl_supply(pool_mint)?,
+    ))
+}
+
+// pool-implied price of the vault's alpha asset in the beta asset, as (beta reserve, alpha reserve)
+pub fn get_pool_price<'info>(
+    vault: &Vault,
+    swap_program: &Pubkey,
+    orca_pool: &AccountInfo<'info>,
+    pool_token_x: &AccountInfo<'info>,
+    pool_token_y: &AccountInfo<'info>,
+    pool_mint: &AccountInfo<'info>,
+) -> OraResult<(u64, u64)> {
+    let (reserve_x, reserve_y, _) = get_pool_reserves(
+        swap_program,
+        orca_pool,
+        pool_token_x,
+        pool_token_y,
+        pool_mint,
+    )?;
+
+    match (get_spl_mint(pool_token_x)?, get_spl_mint(pool_token_y)?) {
+        (x, y) if x == vault.alpha().mint && y == vault.beta().mint => Ok((reserve_y, reserve_x)),
+        (x, y) if x == vault.beta().mint && y == vault.alpha().mint => Ok((reserve_x, reserve_y)),
+        _ => Err(PublicKeyMismatch.into()),
+    }
+}
+
 #[repr(C)]
 pub struct OrcaConfig {
     pub swap_program: Pubkey,
@@ -253,7 +315,7 @@ pub struct InvestOrca<'info> {
         bump,
         constraint = vault.strategy == strategy.key(),
         constraint = vault.authority == authority.key(),
-        constraint = vault.strategist == payer.key()
+        constraint = vault.has_strategist_role(&payer.key(), StrategistRole::Invest) @ ErrorCode::MissingStrategistRole
     )]
     pub vault: Box<Account<'info, Vault>>,
 
@@ -310,6 +372,19 @@ pub struct InvestOrca<'info> {
 
 impl_has_vault!(InvestOrca<'_>);
 
+impl<'info> PoolPrice for InvestOrca<'info> {
+    fn pool_price(&self) -> OraResult<(u64, u64)> {
+        get_pool_price(
+            &self.vault,
+            &self.strategy.swap_program,
+            &self.orca_pool.to_account_info(),
+            &self.into_a.to_account_info(),
+            &self.into_b.to_account_info(),
+            &self.pool_token.to_account_info(),
+        )
+    }
+}
+
 impl<'info> Invest<'info> for InvestOrca<'info> {
     fn invest(&mut self, amount_a: u64, amount_b: u64, min_out: u64) -> OraResult<(u64, u64)> {
         let orca_swap_program_account_info = self.orca_swap_program.to_account_info();
@@ -319,8 +394,8 @@ impl<'info> Invest<'info> for InvestOrca<'info> {
             PublicKeyMismatch
         );
 
-        let vault_alpha_mint = self.vault.alpha.mint;
-        let vault_beta_mint = self.vault.beta.mint;
+        let vault_alpha_mint = self.vault.alpha().mint;
+        let vault_beta_mint = self.vault.beta().mint;
 
         msg!("get pool inputs");
         let (alpha_asset, beta_asset) = into_pool_endpoints(
@@ -337,6 +412,18 @@ impl<'info> Invest<'info> for InvestOrca<'info> {
         msg!("alpha_amount_before: {}", alpha_amount_before);
         msg!("beta_amount_before: {}", beta_amount_before);
 
+        // the number of LP tokens requested cannot be less than the spot amount minus the vault's max slippage
+        let (reserve_a, reserve_b, supply) = get_pool_reserves(
+            &self.strategy.swap_program,
+            &self.orca_pool.to_account_info(),
+            &self.into_a.to_account_info(),
+            &self.into_b.to_account_info(),
+            &self.pool_token.to_account_info(),
+        )?;
+        let spot_lp = compute_lp_for_deposit(amount_a, amount_b, reserve_a, reserve_b, supply)?;
+        let min_out = compute_min_out(min_out, spot_lp, self.vault.max_slippage_bps)?;
+        msg!("min_out: {}", min_out);
+
         let vault_key = self.vault.key();
         let vault_store_signer_seeds =
             generate_vault_store_seeds!(*vault_key.as_ref(), self.vault.vault_store_bump);
@@ -379,13 +466,9 @@ impl<'info> Invest<'info> for InvestOrca<'info> {
             .ok_or_else(math_error!())?;
         msg!("beta_invested_amount: {}", beta_invested_amount);
 
-        let mutable_vault = self.vault_mut();
-        mutable_vault
-            .get_alpha_mut()?
-            .make_investment(alpha_invested_amount)?;
-        mutable_vault
-            .get_beta_mut()?
-            .make_investment(beta_invested_amount)?;
+        self.vault_mut()
+            .make_investments(alpha_invested_amount, beta_invested_amount)?;
+        verify_leverage(&self.vault, self.pool_price()?)?;
 
         Ok((alpha_invested_amount, beta_invested_amount))
     }
@@ -393,6 +476,325 @@ impl<'info> Invest<'info> for InvestOrca<'info> {
 
 // =====================================================================
 
+#[derive(Accounts)]
+#[instruction(tranche_id: u8)]
+pub struct DepositLiveOrca<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: read-only account to validate vault address
+    pub authority: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [GLOBAL_STATE_SEED.as_bytes()],
+        bump,
+    )]
+    pub global_protocol_state: Box<Account<'info, GlobalProtocolState>>,
+
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED.as_bytes(),
+            authority.key().to_bytes().as_ref()
+        ],
+        bump,
+        constraint = vault.strategy == strategy.key(),
+        constraint = vault.authority == authority.key(),
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    /// CHECK: verified via instruction access_control
+    #[account(mut)]
+    pub vault_store: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        seeds = [
+            HISTORY_SEED.as_bytes(),
+            vault.key().to_bytes().as_ref(),
+            &tranche_id.to_le_bytes(),
+            payer.key().to_bytes().as_ref(),
+        ],
+        bump,
+        payer = payer,
+        space = 8 + size_of::<History>(),
+    )]
+    pub history: Box<Account<'info, History>>,
+
+    pub strategy: Box<Account<'info, OrcaStrategyDataV0>>,
+
+    pub mint: Box<Account<'info, Mint>>,
+
+    #[account(mut)]
+    pub lp: Box<Account<'info, Mint>>,
+
+    /// CHECK: depositor's token account. Validation done via Token Program CPI.
+    #[account(mut)]
+    pub source_ata: UncheckedAccount<'info>,
+
+    /// CHECK: create and validate JIT in instruction. Validation done via Token Program CPI.
+    #[account(mut)]
+    pub destination_lp_ata: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// CHECK: validate expected vs actual address
+    #[account(address = spl_associated_token_account::ID)]
+    pub ata_program: UncheckedAccount<'info>,
+
+    pub rent: Sysvar<'info, Rent>,
+
+    // ====================================================
+    // orca accounts
+    // ====================================================
+    /// CHECK: verfied via orca CPI
+    pub orca_swap_program: UncheckedAccount<'info>,
+
+    /// CHECK: verfied via orca CPI
+    pub orca_pool: UncheckedAccount<'info>,
+
+    /// CHECK: verfied via orca CPI
+    pub orca_authority: UncheckedAccount<'info>,
+
+    /// CHECK: verfied via orca CPI
+    #[account(mut)]
+    pub source_token_a: UncheckedAccount<'info>,
+
+    /// CHECK: verfied via orca CPI
+    #[account(mut)]
+    pub source_token_b: UncheckedAccount<'info>,
+
+    /// CHECK: verfied via orca CPI
+    #[account(mut)]
+    pub into_a: UncheckedAccount<'info>,
+
+    /// CHECK: verfied via orca CPI
+    #[account(mut)]
+    pub into_b: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = pool_token.key() == strategy.base_lp,
+    )]
+    pub pool_token: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = pool_token.key(),
+        associated_token::authority = vault_store,
+    )]
+    pub pool_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: vault_store's aquafarm token account; can be uninitialized if base LP was never converted.
+    /// verified in instruction.
+    pub farm_account: UncheckedAccount<'info>,
+
+    /// CHECK: vault_store's double dip aquafarm token account; can be uninitialized. verified in instruction.
+    pub double_dip_farm_account: UncheckedAccount<'info>,
+}
+
+impl_has_vault!(DepositLiveOrca<'_>);
+
+impl<'info> PoolPrice for DepositLiveOrca<'info> {
+    fn pool_price(&self) -> OraResult<(u64, u64)> {
+        get_pool_price(
+            &self.vault,
+            &self.strategy.swap_program,
+            &self.orca_pool.to_account_info(),
+            &self.into_a.to_account_info(),
+            &self.into_b.to_account_info(),
+            &self.pool_token.to_account_info(),
+        )
+    }
+}
+
+impl<'info> Invest<'info> for DepositLiveOrca<'info> {
+    fn invest(&mut self, amount_a: u64, amount_b: u64, min_out: u64) -> OraResult<(u64, u64)> {
+        let orca_swap_program_account_info = self.orca_swap_program.to_account_info();
+        // root orca swap program ID, now we can make assume Orca will correctly verify orca related accounts during CPI
+        require!(
+            self.strategy.swap_program == *orca_swap_program_account_info.key,
+            PublicKeyMismatch
+        );
+
+        // mid-cycle deposits are single-sided, only 1 of the pool's tokens is moved
+        let (source, source_amount) = match (amount_a, amount_b) {
+            (amount, 0) => (self.source_token_a.to_account_info(), amount),
+            (0, amount) => (self.source_token_b.to_account_info(), amount),
+            _ => return Err(ImpossibleTokenRatioRequested.into()),
+        };
+
+        let vault_key = self.vault.key();
+        let vault_store_signer_seeds =
+            generate_vault_store_seeds!(*vault_key.as_ref(), self.vault.vault_store_bump);
+
+        deposit_single(
+            CreatePoolDepositSingle {
+                orca_swap_program: orca_swap_program_account_info,
+                orca_pool: self.orca_pool.to_account_info(),
+                orca_authority: self.orca_authority.to_account_info(),
+                user_transfer_authority: self.vault_store.to_account_info(),
+                source,
+                into_a: self.into_a.to_account_info(),
+                into_b: self.into_b.to_account_info(),
+                pool_token: self.pool_token.to_account_info(),
+                pool_account: self.pool_account.to_account_info(),
+                token_program: self.token_program.to_account_info(),
+            },
+            source_amount,
+            min_out,
+            &[vault_store_signer_seeds],
+        )?;
+
+        Ok((amount_a, amount_b))
+    }
+}
+
+impl<'info> Valuation<'info> for DepositLiveOrca<'info> {
+    fn strategy_value(&self) -> OraResult<(u64, u64)> {
+        let lp_amount = get_strategy_lp_amount(
+            &self.strategy,
+            &self.vault_store.key(),
+            self.pool_account.amount,
+            &self.farm_account.to_account_info(),
+            &self.double_dip_farm_account.to_account_info(),
+        )?;
+
+        get_strategy_value(
+            &self.vault,
+            &self.strategy,
+            lp_amount,
+            &self.orca_pool.to_account_info(),
+            &self.into_a.to_account_info(),
+            &self.into_b.to_account_info(),
+            &self.pool_token.to_account_info(),
+        )
+    }
+}
+
+impl<'info> LiveDeposit<'info> for DepositLiveOrca<'info> {
+    fn deposit_mint(&self) -> Pubkey {
+        self.mint.key()
+    }
+
+    fn deposit_lp(&self) -> Pubkey {
+        self.lp.key()
+    }
+
+    fn depositor(&self) -> Pubkey {
+        self.payer.key()
+    }
+
+    fn vault_key(&self) -> Pubkey {
+        self.vault.key()
+    }
+
+    fn history_mut(&mut self) -> &mut History {
+        self.history.deref_mut()
+    }
+
+    fn into_pool_amounts(&self, mint: &Pubkey, amount: u64) -> OraResult<(u64, u64)> {
+        let token_a_mint = get_spl_mint(&self.source_token_a.to_account_info())?;
+        let token_b_mint = get_spl_mint(&self.source_token_b.to_account_info())?;
+
+        match *mint {
+            m if m == token_a_mint => Ok((amount, 0)),
+            m if m == token_b_mint => Ok((0, amount)),
+            _ => Err(PublicKeyMismatch.into()),
+        }
+    }
+
+    fn transfer_deposit(&self, amount: u64) -> ProgramResult {
+        let (destination, _) = self.pool_accounts_for(&self.mint.key())?;
+
+        spl_token_transfer(
+            self.token_program.to_account_info(),
+            self.source_ata.to_account_info(),
+            destination,
+            self.payer.to_account_info(),
+            &[], // user is signer
+            amount,
+        )
+    }
+
+    fn mint_tranche_lp(&self, amount: u64) -> ProgramResult {
+        let authority = self.authority.key();
+        let vault_signer_seeds = generate_vault_seeds!(authority.as_ref(), self.vault.bump);
+
+        mint_with_verified_ata(
+            self.destination_lp_ata.to_account_info(),
+            self.payer.to_account_info(),
+            self.lp.to_account_info(),
+            self.payer.to_account_info(),
+            self.ata_program.to_account_info(),
+            self.token_program.to_account_info(),
+            self.system_program.to_account_info(),
+            self.rent.to_account_info(),
+            &[],
+            self.vault.to_account_info(),
+            vault_signer_seeds,
+            amount,
+            !self.vault.tranche_lp_transferable(),
+        )
+    }
+}
+
+impl<'info> DepositLiveOrca<'info> {
+    // vault_store token account + pool reserve for a tranche's asset
+    pub fn pool_accounts_for(
+        &self,
+        mint: &Pubkey,
+    ) -> OraResult<(AccountInfo<'info>, AccountInfo<'info>)> {
+        let vault_alpha_mint = self.vault.alpha().mint;
+        let vault_beta_mint = self.vault.beta().mint;
+
+        let (alpha_asset, beta_asset) = into_pool_endpoints(
+            &vault_alpha_mint,
+            &vault_beta_mint,
+            self.source_token_a.to_account_info(),
+            self.into_a.to_account_info(),
+            self.source_token_b.to_account_info(),
+            self.into_b.to_account_info(),
+        )?;
+
+        match *mint {
+            m if m == vault_alpha_mint => Ok((alpha_asset.user, alpha_asset.pool)),
+            m if m == vault_beta_mint => Ok((beta_asset.user, beta_asset.pool)),
+            _ => Err(NonexistentAsset.into()),
+        }
+    }
+}
+
+// balance of a vault_store token account that may not be initialized yet, e.g. aquafarm tokens before
+// base LP is ever converted. the account must be the vault_store's ATA, otherwise any empty account would
+// hide the vault's farm position.
+pub fn get_vault_store_lp_amount<'info>(
+    token_account_info: &AccountInfo<'info>,
+    vault_store: &Pubkey,
+    mint: &Pubkey,
+) -> OraResult<u64> {
+    assert_keys_equal(
+        get_associated_token_address(vault_store, mint),
+        *token_account_info.key,
+    )?;
+    if token_account_info.data_is_empty() {
+        return Ok(0);
+    }
+
+    assert_owned_by(token_account_info, &spl_token::id())?;
+    let token_account = get_spl_account(token_account_info)?;
+    require!(
+        token_account.owner == *vault_store && token_account.mint == *mint,
+        PublicKeyMismatch
+    );
+
+    Ok(token_account.amount)
+}
+
+// =====================================================================
+
 #[derive(Accounts)]
 pub struct RedeemOrca<'info> {
     #[account(mut)]
@@ -416,7 +818,7 @@ pub struct RedeemOrca<'info> {
         bump,
         constraint = vault.strategy == strategy.key(),
         constraint = vault.authority == authority.key(),
-        constraint = vault.strategist == payer.key()
+        constraint = vault.has_strategist_role(&payer.key(), StrategistRole::Redeem) @ ErrorCode::MissingStrategistRole
     )]
     pub vault: Box<Account<'info, Vault>>,
 
@@ -477,87 +879,170 @@ pub struct RedeemOrca<'info> {
 
 impl_has_vault!(RedeemOrca<'_>);
 
-impl<'info> Redeem<'info> for RedeemOrca<'info> {
-    // todo: split redeem into redeem + rebalance
-    fn redeem(&mut self, min_token_a: u64, min_token_b: u64) -> ProgramResult {
-        let orca_swap_program_account_info = self.orca_swap_program.to_account_info();
-        // root orca swap program ID, now we can make assume Orca will correctly verify orca related accounts during CPI
-        require!(
-            self.strategy.swap_program == *orca_swap_program_account_info.key,
-            PublicKeyMismatch
-        );
-
-        msg!(
-            "Burn {:?} pool LP tokens in exchange for {:?} A and {:?} B",
-            self.source_pool_account.amount,
-            min_token_a,
-            min_token_b
-        );
-
-        // map pool tokens A & B to vault tranche assets
-        let vault_alpha_mint = self.vault.alpha.mint;
-        let vault_beta_mint = self.vault.beta.mint;
-
-        let (alpha_asset, beta_asset) = into_pool_endpoints(
-            &vault_alpha_mint,
-            &vault_beta_mint,
-            self.source_token_a.to_account_info(),
-            self.from_a.to_account_info(),
-            self.source_token_b.to_account_info(),
-            self.from_b.to_account_info(),
-        )?;
-
-        let alpha_amount_before = get_spl_amount(&alpha_asset.user)?;
-        let beta_amount_before = get_spl_amount(&beta_asset.user)?;
+impl<'info> PoolPrice for RedeemOrca<'info> {
+    fn pool_price(&self) -> OraResult<(u64, u64)> {
+        get_pool_price(
+            &self.vault,
+            &self.strategy.swap_program,
+            &self.orca_pool.to_account_info(),
+            &self.from_a.to_account_info(),
+            &self.from_b.to_account_info(),
+            &self.pool_mint.to_account_info(),
+        )
+    }
+}
 
-        let vault_key = self.vault.key();
-        let vault_store_signer_seeds =
-            generate_vault_store_seeds!(*vault_key.as_ref(), self.vault.vault_store_bump);
+impl<'info> Redeem<'info> for RedeemOrca<'info> {
+    fn strategy_lp(&self) -> u64 {
+        self.source_pool_account.amount
+    }
 
-        // burn LP tokens for underlying liquidity in orca pool
-        withdraw(
-            CreatePoolWithdrawal {
-                orca_swap_program: orca_swap_program_account_info,
+    // todo: split redeem into redeem + rebalance
+    fn redeem(
+        &mut self,
+        lp_amount: u64,
+        min_token_a: u64,
+        min_token_b: u64,
+    ) -> OraResult<(u64, u64)> {
+        withdraw_from_pool(
+            &self.vault,
+            &self.strategy,
+            self.vault_store.to_account_info(),
+            OrcaPoolWithdrawal {
+                orca_swap_program: self.orca_swap_program.to_account_info(),
                 orca_pool: self.orca_pool.to_account_info(),
                 orca_authority: self.orca_authority.to_account_info(),
-                user_transfer_authority: self.vault_store.to_account_info(),
                 pool_mint: self.pool_mint.to_account_info(),
                 source_pool_account: self.source_pool_account.to_account_info(),
                 from_a: self.from_a.to_account_info(),
                 from_b: self.from_b.to_account_info(),
-                user_account_a: self.source_token_a.to_account_info(),
-                user_account_b: self.source_token_b.to_account_info(),
+                source_token_a: self.source_token_a.to_account_info(),
+                source_token_b: self.source_token_b.to_account_info(),
                 fee_account: self.fee_account.to_account_info(),
                 token_program: self.token_program.to_account_info(),
             },
-            self.source_pool_account.amount,
+            lp_amount,
             min_token_a,
             min_token_b,
-            &[vault_store_signer_seeds],
-        )?;
-
-        let alpha_amount_after = get_spl_amount(&alpha_asset.user)?;
-        let beta_amount_after = get_spl_amount(&beta_asset.user)?;
-
-        // after - before because we redeem funds, so after > before
-        let alpha_withdrawal_amount = alpha_amount_after
-            .checked_sub(alpha_amount_before)
-            .ok_or_else(math_error!())?;
-
-        let beta_withdrawal_amount = beta_amount_after
-            .checked_sub(beta_amount_before)
-            .ok_or_else(math_error!())?;
+        )
+    }
+}
 
-        let mutable_vault = self.vault_mut();
-        mutable_vault
-            .get_alpha_mut()?
-            .add_receipt(alpha_withdrawal_amount)?;
-        mutable_vault
-            .get_beta_mut()?
-            .add_receipt(beta_withdrawal_amount)?;
+/// accounts used to burn the vault's base LP for the pool's underlying tokens
+pub struct OrcaPoolWithdrawal<'info> {
+    pub orca_swap_program: AccountInfo<'info>,
+    pub orca_pool: AccountInfo<'info>,
+    pub orca_authority: AccountInfo<'info>,
+    pub pool_mint: AccountInfo<'info>,
+    pub source_pool_account: AccountInfo<'info>,
+    pub from_a: AccountInfo<'info>,
+    pub from_b: AccountInfo<'info>,
+    pub source_token_a: AccountInfo<'info>,
+    pub source_token_b: AccountInfo<'info>,
+    pub fee_account: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+}
 
-        Ok(())
-    }
+// burn lp_amount of the vault_store's base LP for the pool's underlying tokens, returned as (alpha, beta)
+// amounts received by the vault_store
+pub fn withdraw_from_pool<'info>(
+    vault: &Account<'info, Vault>,
+    strategy: &OrcaStrategyDataV0,
+    vault_store: AccountInfo<'info>,
+    accounts: OrcaPoolWithdrawal<'info>,
+    lp_amount: u64,
+    min_token_a: u64,
+    min_token_b: u64,
+) -> OraResult<(u64, u64)> {
+    // root orca swap program ID, now we can make assume Orca will correctly verify orca related accounts during CPI
+    require!(
+        strategy.swap_program == *accounts.orca_swap_program.key,
+        PublicKeyMismatch
+    );
+
+    msg!(
+        "Burn {:?} pool LP tokens in exchange for {:?} A and {:?} B",
+        lp_amount,
+        min_token_a,
+        min_token_b
+    );
+
+    // map pool tokens A & B to vault tranche assets
+    let (alpha_asset, beta_asset) = into_pool_endpoints(
+        &vault.alpha().mint,
+        &vault.beta().mint,
+        accounts.source_token_a.clone(),
+        accounts.from_a.clone(),
+        accounts.source_token_b.clone(),
+        accounts.from_b.clone(),
+    )?;
+
+    let alpha_amount_before = get_spl_amount(&alpha_asset.user)?;
+    let beta_amount_before = get_spl_amount(&beta_asset.user)?;
+
+    // amounts out cannot be less than the spot amounts for the LP left after the pool's withdrawal fee
+    // minus the vault's max slippage
+    let (reserve_a, reserve_b, supply) = get_pool_reserves(
+        &strategy.swap_program,
+        &accounts.orca_pool,
+        &accounts.from_a,
+        &accounts.from_b,
+        &accounts.pool_mint,
+    )?;
+    let lp_less_fee = unpack_swap_state(&accounts.orca_pool)?
+        .owner_withdraw_fee
+        .apply(lp_amount)?;
+    let min_token_a = compute_min_out(
+        min_token_a,
+        compute_lp_value(lp_less_fee, reserve_a, supply)?,
+        vault.max_slippage_bps,
+    )?;
+    let min_token_b = compute_min_out(
+        min_token_b,
+        compute_lp_value(lp_less_fee, reserve_b, supply)?,
+        vault.max_slippage_bps,
+    )?;
+    msg!("min_token_a: {}, min_token_b: {}", min_token_a, min_token_b);
+
+    let vault_key = vault.key();
+    let vault_store_signer_seeds =
+        generate_vault_store_seeds!(*vault_key.as_ref(), vault.vault_store_bump);
+
+    // burn LP tokens for underlying liquidity in orca pool
+    withdraw(
+        CreatePoolWithdrawal {
+            orca_swap_program: accounts.orca_swap_program,
+            orca_pool: accounts.orca_pool,
+            orca_authority: accounts.orca_authority,
+            user_transfer_authority: vault_store,
+            pool_mint: accounts.pool_mint,
+            source_pool_account: accounts.source_pool_account,
+            from_a: accounts.from_a,
+            from_b: accounts.from_b,
+            user_account_a: accounts.source_token_a,
+            user_account_b: accounts.source_token_b,
+            fee_account: accounts.fee_account,
+            token_program: accounts.token_program,
+        },
+        lp_amount,
+        min_token_a,
+        min_token_b,
+        &[vault_store_signer_seeds],
+    )?;
+
+    let alpha_amount_after = get_spl_amount(&alpha_asset.user)?;
+    let beta_amount_after = get_spl_amount(&beta_asset.user)?;
+
+    // after - before because we redeem funds, so after > before
+    let alpha_withdrawal_amount = alpha_amount_after
+        .checked_sub(alpha_amount_before)
+        .ok_or_else(math_error!())?;
+
+    let beta_withdrawal_amount = beta_amount_after
+        .checked_sub(beta_amount_before)
+        .ok_or_else(math_error!())?;
+
+    Ok((alpha_withdrawal_amount, beta_withdrawal_amount))
 }
 
 // =====================================================================
@@ -585,7 +1070,7 @@ pub struct RebalanceOrca<'info> {
         bump,
         constraint = vault.strategy == strategy.key(),
         constraint = vault.authority == authority.key(),
-        constraint = vault.strategist == payer.key()
+        constraint = vault.has_strategist_role(&payer.key(), StrategistRole::Rebalance) @ ErrorCode::MissingStrategistRole
     )]
     pub vault: Box<Account<'info, Vault>>,
 
@@ -636,12 +1121,31 @@ pub struct RebalanceOrca<'info> {
 
 impl_has_vault!(RebalanceOrca<'_>);
 
+impl<'info> PoolPrice for RebalanceOrca<'info> {
+    fn pool_price(&self) -> OraResult<(u64, u64)> {
+        get_pool_price(
+            &self.vault,
+            &self.strategy.swap_program,
+            &self.orca_pool.to_account_info(),
+            &self.pool_source.to_account_info(),
+            &self.pool_destination.to_account_info(),
+            &self.pool_mint.to_account_info(),
+        )
+    }
+}
+
 impl<'info> Rebalance<'info> for RebalanceOrca<'info> {
     // question: how much of this code can be re-used across adapters? probably at least the pool assets -> vault tranche
     fn rebalance(&mut self, swap_config: SwapConfig) -> OraResult<(u64, u64, u64, u64)> {
+        // root orca swap program ID, now we can make assume Orca will correctly verify orca related accounts during CPI
+        require!(
+            self.strategy.swap_program == self.orca_swap_program.key(),
+            PublicKeyMismatch
+        );
+
         // map pool tokens A & B to vault tranche assets
-        let vault_alpha_mint = self.vault.alpha.mint;
-        let vault_beta_mint = self.vault.beta.mint;
+        let vault_alpha_mint = self.vault.alpha().mint;
+        let vault_beta_mint = self.vault.beta().mint;
 
         let (alpha_asset, beta_asset) = into_pool_endpoints(
             &vault_alpha_mint,
@@ -655,6 +1159,27 @@ impl<'info> Rebalance<'info> for RebalanceOrca<'info> {
         let alpha_amount_before = get_spl_amount(&alpha_asset.user)?;
         let beta_amount_before = get_spl_amount(&beta_asset.user)?;
 
+        // amount out cannot be less than the pool's spot quote net of fees minus the vault's max slippage
+        let (reserve_in, reserve_out, _) = get_pool_reserves(
+            &self.strategy.swap_program,
+            &self.orca_pool.to_account_info(),
+            &self.pool_source.to_account_info(),
+            &self.pool_destination.to_account_info(),
+            &self.pool_mint.to_account_info(),
+        )?;
+        let swap_state = unpack_swap_state(&self.orca_pool.to_account_info())?;
+        let min_out = compute_min_out(
+            swap_config.min_out,
+            compute_spot_amount_out(
+                swap_config.max_in,
+                reserve_in,
+                reserve_out,
+                &[swap_state.trade_fee, swap_state.owner_trade_fee],
+            )?,
+            self.vault.max_slippage_bps,
+        )?;
+        msg!("min_out: {}", min_out);
+
         let vault_key = self.vault.key();
         let vault_store_signer_seeds =
             generate_vault_store_seeds!(*vault_key.as_ref(), self.vault.vault_store_bump);
@@ -674,7 +1199,7 @@ impl<'info> Rebalance<'info> for RebalanceOrca<'info> {
                 token_program: self.token_program.to_account_info(),
             },
             swap_config.max_in,
-            swap_config.min_out,
+            min_out,
             &[vault_store_signer_seeds],
         )?;
 
@@ -692,6 +1217,401 @@ impl<'info> Rebalance<'info> for RebalanceOrca<'info> {
 
 // =====================================================================
 
+#[derive(Accounts)]
+pub struct GetVaultNavOrca<'info> {
+    /// CHECK: read-only account to validate vault address
+    pub authority: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            VAULT_SEED.as_bytes(),
+            authority.key().to_bytes().as_ref()
+        ],
+        bump,
+        constraint = vault.strategy == strategy.key(),
+        constraint = vault.authority == authority.key(),
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    /// CHECK: verified via instruction access_control
+    pub vault_store: UncheckedAccount<'info>,
+
+    pub strategy: Box<Account<'info, OrcaStrategyDataV0>>,
+
+    // ====================================================
+    // orca accounts
+    // ====================================================
+    /// CHECK: verified against the swap program in instruction
+    pub orca_pool: UncheckedAccount<'info>,
+
+    /// CHECK: verified against the pool's swap state in instruction
+    pub pool_token_a: UncheckedAccount<'info>,
+
+    /// CHECK: verified against the pool's swap state in instruction
+    pub pool_token_b: UncheckedAccount<'info>,
+
+    #[account(constraint = pool_mint.key() == strategy.base_lp)]
+    pub pool_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        associated_token::mint = pool_mint.key(),
+        associated_token::authority = vault_store,
+    )]
+    pub pool_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: vault_store's aquafarm token account; can be uninitialized. verified in instruction.
+    pub farm_account: UncheckedAccount<'info>,
+
+    /// CHECK: vault_store's double dip aquafarm token account; can be uninitialized. verified in instruction.
+    pub double_dip_farm_account: UncheckedAccount<'info>,
+}
+
+impl_has_vault!(GetVaultNavOrca<'_>);
+
+impl<'info> PoolPrice for GetVaultNavOrca<'info> {
+    fn pool_price(&self) -> OraResult<(u64, u64)> {
+        get_pool_price(
+            &self.vault,
+            &self.strategy.swap_program,
+            &self.orca_pool.to_account_info(),
+            &self.pool_token_a.to_account_info(),
+            &self.pool_token_b.to_account_info(),
+            &self.pool_mint.to_account_info(),
+        )
+    }
+}
+
+impl<'info> Valuation<'info> for GetVaultNavOrca<'info> {
+    fn strategy_value(&self) -> OraResult<(u64, u64)> {
+        let lp_amount = get_strategy_lp_amount(
+            &self.strategy,
+            &self.vault_store.key(),
+            self.pool_account.amount,
+            &self.farm_account.to_account_info(),
+            &self.double_dip_farm_account.to_account_info(),
+        )?;
+
+        get_strategy_value(
+            &self.vault,
+            &self.strategy,
+            lp_amount,
+            &self.orca_pool.to_account_info(),
+            &self.pool_token_a.to_account_info(),
+            &self.pool_token_b.to_account_info(),
+            &self.pool_mint.to_account_info(),
+        )
+    }
+}
+
+// total base LP backing the vault's position. base LP is converted into aquafarm tokens at a 1-1 rate, which
+// are then converted into double dip aquafarm tokens at a 1-1 rate. all 3 are backed by the base pool's reserves.
+pub fn get_strategy_lp_amount<'info>(
+    strategy: &OrcaStrategyDataV0,
+    vault_store: &Pubkey,
+    pool_amount: u64,
+    farm_account: &AccountInfo<'info>,
+    double_dip_farm_account: &AccountInfo<'info>,
+) -> OraResult<u64> {
+    let farm_amount = get_vault_store_lp_amount(farm_account, vault_store, &strategy.farm_lp)?;
+    let double_dip_amount = match strategy.double_dip_lp {
+        Some(double_dip_lp) => {
+            get_vault_store_lp_amount(double_dip_farm_account, vault_store, &double_dip_lp)?
+        }
+        None => 0,
+    };
+    let lp_amount = pool_amount
+        .checked_add(farm_amount)
+        .ok_or_else(math_error!())?
+        .checked_add(double_dip_amount)
+        .ok_or_else(math_error!())?;
+    msg!("lp_amount: {}", lp_amount);
+
+    Ok(lp_amount)
+}
+
+// amount of each tranche asset lp_amount of base LP can be redeemed for, as (alpha amount, beta amount)
+pub fn get_strategy_value<'info>(
+    vault: &Vault,
+    strategy: &OrcaStrategyDataV0,
+    lp_amount: u64,
+    orca_pool: &AccountInfo<'info>,
+    pool_token_a: &AccountInfo<'info>,
+    pool_token_b: &AccountInfo<'info>,
+    pool_mint: &AccountInfo<'info>,
+) -> OraResult<(u64, u64)> {
+    let (reserve_a, reserve_b, supply) = get_pool_reserves(
+        &strategy.swap_program,
+        orca_pool,
+        pool_token_a,
+        pool_token_b,
+        pool_mint,
+    )?;
+    let value_a = compute_lp_value(lp_amount, reserve_a, supply)?;
+    let value_b = compute_lp_value(lp_amount, reserve_b, supply)?;
+
+    match get_spl_mint(pool_token_a)? {
+        m if m == vault.alpha().mint => Ok((value_a, value_b)),
+        m if m == vault.beta().mint => Ok((value_b, value_a)),
+        _ => Err(PublicKeyMismatch.into()),
+    }
+}
+
+// =====================================================================
+
+#[derive(Accounts)]
+pub struct EarlyExitOrca<'info> {
+    /// tranche token holder exiting the vault
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: read-only account to validate vault address
+    pub authority: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [GLOBAL_STATE_SEED.as_bytes()],
+        bump,
+    )]
+    pub global_protocol_state: Box<Account<'info, GlobalProtocolState>>,
+
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED.as_bytes(),
+            authority.key().to_bytes().as_ref()
+        ],
+        bump,
+        constraint = vault.strategy == strategy.key(),
+        constraint = vault.authority == authority.key(),
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    /// CHECK: verified via instruction access_control
+    #[account(mut)]
+    pub vault_store: UncheckedAccount<'info>,
+
+    pub strategy: Box<Account<'info, OrcaStrategyDataV0>>,
+
+    pub system_program: Program<'info, System>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub rent: Sysvar<'info, Rent>,
+
+    // ====================================================
+    // tranche accounts
+    // ====================================================
+    #[account(mut)]
+    pub lp: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint = source_lp.mint == lp.key(),
+        constraint = source_lp.owner == payer.key(),
+    )]
+    pub source_lp: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: holder's token account for pool token A. Validation done via Token Program CPI.
+    #[account(mut)]
+    pub destination_token_a: UncheckedAccount<'info>,
+
+    /// CHECK: holder's token account for pool token B. Validation done via Token Program CPI.
+    #[account(mut)]
+    pub destination_token_b: UncheckedAccount<'info>,
+
+    // ====================================================
+    // orca accounts
+    // ====================================================
+    /// CHECK: verfied via orca CPI
+    pub orca_swap_program: UncheckedAccount<'info>,
+
+    /// CHECK: verfied via orca CPI
+    pub orca_pool: UncheckedAccount<'info>,
+
+    /// CHECK: verfied via orca CPI
+    pub orca_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = pool_mint.key() == strategy.base_lp,
+    )]
+    pub pool_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = pool_mint.key(),
+        associated_token::authority = vault_store,
+    )]
+    pub source_pool_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: verfied via orca CPI
+    #[account(mut)]
+    pub from_a: UncheckedAccount<'info>,
+
+    /// CHECK: verfied via orca CPI
+    #[account(mut)]
+    pub from_b: UncheckedAccount<'info>,
+
+    /// CHECK: verfied via orca CPI
+    #[account(mut)]
+    pub source_token_a: UncheckedAccount<'info>,
+
+    /// CHECK: verfied via orca CPI
+    #[account(mut)]
+    pub source_token_b: UncheckedAccount<'info>,
+
+    /// CHECK: verfied via orca CPI
+    #[account(mut)]
+    pub fee_account: UncheckedAccount<'info>,
+
+    /// CHECK: vault_store's aquafarm token account; can be uninitialized. verified in instruction.
+    pub farm_account: UncheckedAccount<'info>,
+
+    /// CHECK: vault_store's double dip aquafarm token account; can be uninitialized. verified in instruction.
+    pub double_dip_farm_account: UncheckedAccount<'info>,
+}
+
+impl_has_vault!(EarlyExitOrca<'_>);
+
+impl<'info> PoolPrice for EarlyExitOrca<'info> {
+    fn pool_price(&self) -> OraResult<(u64, u64)> {
+        get_pool_price(
+            &self.vault,
+            &self.strategy.swap_program,
+            &self.orca_pool.to_account_info(),
+            &self.from_a.to_account_info(),
+            &self.from_b.to_account_info(),
+            &self.pool_mint.to_account_info(),
+        )
+    }
+}
+
+impl<'info> Valuation<'info> for EarlyExitOrca<'info> {
+    fn strategy_value(&self) -> OraResult<(u64, u64)> {
+        get_strategy_value(
+            &self.vault,
+            &self.strategy,
+            self.total_strategy_lp()?,
+            &self.orca_pool.to_account_info(),
+            &self.from_a.to_account_info(),
+            &self.from_b.to_account_info(),
+            &self.pool_mint.to_account_info(),
+        )
+    }
+}
+
+impl<'info> Redeem<'info> for EarlyExitOrca<'info> {
+    fn strategy_lp(&self) -> u64 {
+        self.source_pool_account.amount
+    }
+
+    fn redeem(
+        &mut self,
+        lp_amount: u64,
+        min_token_a: u64,
+        min_token_b: u64,
+    ) -> OraResult<(u64, u64)> {
+        withdraw_from_pool(
+            &self.vault,
+            &self.strategy,
+            self.vault_store.to_account_info(),
+            OrcaPoolWithdrawal {
+                orca_swap_program: self.orca_swap_program.to_account_info(),
+                orca_pool: self.orca_pool.to_account_info(),
+                orca_authority: self.orca_authority.to_account_info(),
+                pool_mint: self.pool_mint.to_account_info(),
+                source_pool_account: self.source_pool_account.to_account_info(),
+                from_a: self.from_a.to_account_info(),
+                from_b: self.from_b.to_account_info(),
+                source_token_a: self.source_token_a.to_account_info(),
+                source_token_b: self.source_token_b.to_account_info(),
+                fee_account: self.fee_account.to_account_info(),
+                token_program: self.token_program.to_account_info(),
+            },
+            lp_amount,
+            min_token_a,
+            min_token_b,
+        )
+    }
+}
+
+impl<'info> EarlyExit<'info> for EarlyExitOrca<'info> {
+    fn exit_lp(&self) -> Pubkey {
+        self.lp.key()
+    }
+
+    fn total_strategy_lp(&self) -> OraResult<u64> {
+        get_strategy_lp_amount(
+            &self.strategy,
+            &self.vault_store.key(),
+            self.source_pool_account.amount,
+            &self.farm_account.to_account_info(),
+            &self.double_dip_farm_account.to_account_info(),
+        )
+    }
+
+    fn burn_tranche_lp(&self, amount: u64) -> ProgramResult {
+        let authority = self.authority.key();
+        let vault_signer_seeds = generate_vault_seeds!(authority.as_ref(), self.vault.bump);
+
+        set_token_account_frozen(
+            self.token_program.to_account_info(),
+            self.source_lp.to_account_info(),
+            self.lp.to_account_info(),
+            self.vault.to_account_info(),
+            vault_signer_seeds,
+            false,
+        )?;
+
+        burn(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                Burn {
+                    mint: self.lp.to_account_info(),
+                    to: self.source_lp.to_account_info(),
+                    authority: self.payer.to_account_info(),
+                },
+            ),
+            amount,
+        )
+    }
+
+    fn transfer_exit(&self, alpha_amount: u64, beta_amount: u64) -> ProgramResult {
+        // map the vault_store's and holder's accounts for pool tokens A & B to vault tranche assets. the user
+        // endpoint is the vault_store's account, the pool endpoint is the holder's account.
+        let (alpha_asset, beta_asset) = into_pool_endpoints(
+            &self.vault.alpha().mint,
+            &self.vault.beta().mint,
+            self.source_token_a.to_account_info(),
+            self.destination_token_a.to_account_info(),
+            self.source_token_b.to_account_info(),
+            self.destination_token_b.to_account_info(),
+        )?;
+
+        let vault_key = self.vault.key();
+        let vault_store_signer_seeds =
+            generate_vault_store_seeds!(*vault_key.as_ref(), self.vault.vault_store_bump);
+
+        for (endpoints, amount) in [(alpha_asset, alpha_amount), (beta_asset, beta_amount)] {
+            if amount == 0 {
+                continue;
+            }
+
+            spl_token_transfer(
+                self.token_program.to_account_info(),
+                endpoints.user,
+                endpoints.pool,
+                self.vault_store.to_account_info(),
+                &[vault_store_signer_seeds],
+                amount,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+// =====================================================================
+
 // FarmInitializer
 #[derive(Accounts)]
 pub struct InitializeUserFarmOrca<'info> {
@@ -716,7 +1636,7 @@ pub struct InitializeUserFarmOrca<'info> {
         bump,
         constraint = vault.strategy == strategy.key(),
         constraint = vault.authority == authority.key(),
-        constraint = vault.strategist == payer.key()
+        constraint = vault.has_strategist_role(&payer.key(), StrategistRole::Convert) @ ErrorCode::MissingStrategistRole
     )]
     pub vault: Box<Account<'info, Vault>>,
 
@@ -811,7 +1731,7 @@ pub struct ConvertOrcaLp<'info> {
         bump,
         constraint = vault.strategy == strategy.key(),
         constraint = vault.authority == authority.key(),
-        constraint = vault.strategist == payer.key()
+        constraint = vault.has_strategist_role(&payer.key(), StrategistRole::Convert) @ ErrorCode::MissingStrategistRole
     )]
     pub vault: Box<Account<'info, Vault>>,
 
@@ -941,7 +1861,7 @@ pub struct HarvestOrcaLp<'info> {
         bump,
         constraint = vault.strategy == strategy.key(),
         constraint = vault.authority == authority.key(),
-        constraint = vault.strategist == payer.key()
+        constraint = vault.has_strategist_role(&payer.key(), StrategistRole::Harvest) @ ErrorCode::MissingStrategistRole
     )]
     pub vault: Box<Account<'info, Vault>>,
 
@@ -1051,7 +1971,7 @@ pub struct RevertOrcaLp<'info> {
         bump,
         constraint = vault.strategy == strategy.key(),
         constraint = vault.authority == authority.key(),
-        constraint = vault.strategist == payer.key()
+        constraint = vault.has_strategist_role(&payer.key(), StrategistRole::Revert) @ ErrorCode::MissingStrategistRole
     )]
     pub vault: Box<Account<'info, Vault>>,
 
@@ -1181,7 +2101,7 @@ pub struct SwapOrca<'info> {
         bump,
         constraint = vault.strategy == strategy.key(),
         constraint = vault.authority == authority.key(),
-        constraint = vault.strategist == payer.key()
+        constraint = vault.has_strategist_role(&payer.key(), StrategistRole::Swap) @ ErrorCode::MissingStrategistRole
     )]
     pub vault: Box<Account<'info, Vault>>,
 
@@ -1235,6 +2155,33 @@ impl_has_vault!(SwapOrca<'_>);
 impl<'info> Swapper<'info> for SwapOrca<'info> {
     // @dev: assumption is that we'll only ever swap from vault_store
     fn swap(&mut self, amount_in: u64, min_amount_out: u64) -> ProgramResult {
+        // root orca swap program ID, now we can make assume Orca will correctly verify orca related accounts during CPI
+        require!(
+            self.strategy.swap_program == self.orca_swap_program.key(),
+            PublicKeyMismatch
+        );
+
+        // amount out cannot be less than the pool's spot quote net of fees minus the vault's max slippage
+        let (reserve_in, reserve_out, _) = get_pool_reserves(
+            &self.strategy.swap_program,
+            &self.orca_pool.to_account_info(),
+            &self.pool_source.to_account_info(),
+            &self.pool_destination.to_account_info(),
+            &self.pool_mint.to_account_info(),
+        )?;
+        let swap_state = unpack_swap_state(&self.orca_pool.to_account_info())?;
+        let min_amount_out = compute_min_out(
+            min_amount_out,
+            compute_spot_amount_out(
+                amount_in,
+                reserve_in,
+                reserve_out,
+                &[swap_state.trade_fee, swap_state.owner_trade_fee],
+            )?,
+            self.vault.max_slippage_bps,
+        )?;
+        msg!("min_amount_out: {}", min_amount_out);
+
         let vault_key = self.vault.key();
         let vault_store_signer_seeds =
             generate_vault_store_seeds!(*vault_key.as_ref(), self.vault.vault_store_bump);
@@ -1,15 +1,24 @@
 use crate::{
-    constant::{GLOBAL_STATE_SEED, STRATEGY_SEED, VAULT_SEED},
-    error::OraResult,
+    constant::{GLOBAL_STATE_SEED, HISTORY_SEED, STRATEGY_SEED, VAULT_SEED},
+    deposit_live::LiveDeposit,
+    early_exit::EarlyExit,
+    error::{ErrorCode, OraResult},
+    get_vault_nav::Valuation,
     init_strategy::StrategyInitializer,
-    invest::Invest,
+    invest::{verify_leverage, Invest},
+    oracle::PoolPrice,
     redeem::{verify_received, Redeem},
-    state::{GlobalProtocolState, HasVault, StrategyFlag, Vault},
+    state::{GlobalProtocolState, HasVault, History, StrategistRole, StrategyFlag, Vault},
+    util::{
+        assert_owned_by, compute_lp_for_deposit, compute_lp_value, compute_min_out,
+        mint_with_verified_ata, set_token_account_frozen, spl_token_transfer, PoolFee,
+    },
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token::TokenAccount;
-use anchor_spl::token::{Mint, Token};
+use anchor_spl::token::{burn, Burn, Mint, Token};
 use stable_swap_anchor::{Deposit, SwapOutput, SwapToken, SwapUserContext, Withdraw};
+use std::convert::TryInto;
 use std::mem::size_of;
 use std::ops::{Deref, DerefMut};
 
@@ -132,7 +141,7 @@ pub struct InvestSaber<'info> {
         bump,
         constraint = vault.strategy == strategy.key(),
         constraint = vault.authority == authority.key(),
-        constraint = vault.strategist == payer.key()
+        constraint = vault.has_strategist_role(&payer.key(), StrategistRole::Invest) @ ErrorCode::MissingStrategistRole
     )]
     pub vault: Box<Account<'info, Vault>>,
 
@@ -158,8 +167,20 @@ pub struct InvestSaber<'info> {
 
 impl_has_vault!(InvestSaber<'_>);
 
+impl<'info> PoolPrice for InvestSaber<'info> {
+    fn pool_price(&self) -> OraResult<(u64, u64)> {
+        pegged_pool_price(&self.vault)
+    }
+}
+
 impl<'info> Invest<'info> for InvestSaber<'info> {
     fn invest(&mut self, amount_a: u64, amount_b: u64, min_out: u64) -> OraResult<(u64, u64)> {
+        // the number of LP tokens minted cannot be less than the spot amount minus the vault's max slippage
+        let (reserve_a, reserve_b, supply) = self.saber_swap_common.get_pool_reserves()?;
+        let spot_lp = compute_lp_for_deposit(amount_a, amount_b, reserve_a, reserve_b, supply)?;
+        let min_out = compute_min_out(min_out, spot_lp, self.vault.max_slippage_bps)?;
+        msg!("min_out: {}", min_out);
+
         let vault_signer_seeds =
             generate_vault_seeds!(*self.authority.key.as_ref(), self.vault.bump);
 
@@ -171,6 +192,16 @@ impl<'info> Invest<'info> for InvestSaber<'info> {
             min_out,
         )?;
 
+        // stable swap deposits the full amounts, map pool tokens A & B to the vault's alpha & beta assets
+        let (alpha_invested_amount, beta_invested_amount) =
+            match self.saber_swap_common.source_token_a.mint == self.vault.alpha().mint {
+                true => (amount_a, amount_b),
+                false => (amount_b, amount_a),
+            };
+        self.vault_mut()
+            .make_investments(alpha_invested_amount, beta_invested_amount)?;
+        verify_leverage(&self.vault, self.pool_price()?)?;
+
         Ok((amount_a, amount_b))
     }
 }
@@ -218,7 +249,8 @@ impl<'info> InvestSaber<'info> {
 // =====================================================================
 
 #[derive(Accounts)]
-pub struct RedeemSaber<'info> {
+#[instruction(tranche_id: u8)]
+pub struct DepositLiveSaber<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
@@ -240,18 +272,50 @@ pub struct RedeemSaber<'info> {
         bump,
         constraint = vault.strategy == strategy.key(),
         constraint = vault.authority == authority.key(),
-        constraint = vault.strategist == payer.key()
     )]
     pub vault: Box<Account<'info, Vault>>,
 
-    /// CHECK: pubkey matched in context, validation done in instruction
-    // todo: update this to check for seeds
-    pub strategy: UncheckedAccount<'info>,
+    /// CHECK: verified via instruction access_control
+    pub vault_store: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        seeds = [
+            HISTORY_SEED.as_bytes(),
+            vault.key().to_bytes().as_ref(),
+            &tranche_id.to_le_bytes(),
+            payer.key().to_bytes().as_ref(),
+        ],
+        bump,
+        payer = payer,
+        space = 8 + size_of::<History>(),
+    )]
+    pub history: Box<Account<'info, History>>,
+
+    #[account(constraint = strategy.base_lp == saber_swap_common.pool_mint.key())]
+    pub strategy: Box<Account<'info, SaberStrategyDataV0>>,
+
+    pub mint: Box<Account<'info, Mint>>,
+
+    #[account(mut)]
+    pub lp: Box<Account<'info, Mint>>,
+
+    /// CHECK: depositor's token account. Validation done via Token Program CPI.
+    #[account(mut)]
+    pub source_ata: UncheckedAccount<'info>,
+
+    /// CHECK: create and validate JIT in instruction. Validation done via Token Program CPI.
+    #[account(mut)]
+    pub destination_lp_ata: UncheckedAccount<'info>,
 
     pub system_program: Program<'info, System>,
 
     pub token_program: Program<'info, Token>,
 
+    /// CHECK: validate expected vs actual address
+    #[account(address = spl_associated_token_account::ID)]
+    pub ata_program: UncheckedAccount<'info>,
+
     pub rent: Sysvar<'info, Rent>,
 
     // ====================================================
@@ -260,53 +324,132 @@ pub struct RedeemSaber<'info> {
     /// custom struct to encapsulate all common saber swap accounts
     pub saber_swap_common: SaberSwapCommon<'info>,
 
-    /// The output account for LP tokens
-    #[account(mut)]
-    pub input_lp: Box<Account<'info, TokenAccount>>,
+    /// The vault's account for LP tokens
+    #[account(
+        mut,
+        constraint = output_lp.owner == vault.key(),
+        constraint = output_lp.mint == saber_swap_common.pool_mint.key(),
+    )]
+    pub output_lp: Box<Account<'info, TokenAccount>>,
+}
 
-    /// The token account for the fees associated with token "B"
-    #[account(mut)]
-    pub output_a_fees: Box<Account<'info, TokenAccount>>,
+impl_has_vault!(DepositLiveSaber<'_>);
 
-    /// The token account for the fees associated with token "A"
-    #[account(mut)]
-    pub output_b_fees: Box<Account<'info, TokenAccount>>,
+impl<'info> PoolPrice for DepositLiveSaber<'info> {
+    fn pool_price(&self) -> OraResult<(u64, u64)> {
+        pegged_pool_price(&self.vault)
+    }
 }
 
-impl_has_vault!(RedeemSaber<'_>);
-
-impl<'info> Redeem<'info> for RedeemSaber<'info> {
-    fn redeem(&mut self, min_token_a: u64, min_token_b: u64) -> ProgramResult {
+impl<'info> Invest<'info> for DepositLiveSaber<'info> {
+    fn invest(&mut self, amount_a: u64, amount_b: u64, min_out: u64) -> OraResult<(u64, u64)> {
         let vault_signer_seeds =
             generate_vault_seeds!(*self.authority.key.as_ref(), self.vault.bump);
 
-        stable_swap_anchor::withdraw(
-            self.into_saber_swap_withdraw_context()
+        // stable swap supports imbalanced deposits, so 1 side can be 0
+        stable_swap_anchor::deposit(
+            self.into_saber_swap_deposit_context()
                 .with_signer(&[vault_signer_seeds]),
-            self.input_lp.amount,
-            0,
-            0,
+            amount_a,
+            amount_b,
+            min_out,
         )?;
 
-        let received_a = verify_received(&mut self.saber_swap_common.source_token_a, min_token_a)?;
-        self.vault
-            .update_receipt(&self.saber_swap_common.source_token_a.mint, received_a)?;
+        Ok((amount_a, amount_b))
+    }
+}
 
-        let received_b = verify_received(&mut self.saber_swap_common.source_token_b, min_token_b)?;
-        self.vault
-            .update_receipt(&self.saber_swap_common.source_token_b.mint, received_b)?;
+impl<'info> Valuation<'info> for DepositLiveSaber<'info> {
+    fn strategy_value(&self) -> OraResult<(u64, u64)> {
+        let (reserve_a, reserve_b, supply) = self.saber_swap_common.get_pool_reserves()?;
+        let value_a = compute_lp_value(self.output_lp.amount, reserve_a, supply)?;
+        let value_b = compute_lp_value(self.output_lp.amount, reserve_b, supply)?;
+
+        match self.saber_swap_common.reserve_a.mint {
+            m if m == self.vault.alpha().mint => Ok((value_a, value_b)),
+            m if m == self.vault.beta().mint => Ok((value_b, value_a)),
+            _ => Err(ErrorCode::PublicKeyMismatch.into()),
+        }
+    }
+}
 
-        Ok(())
+impl<'info> LiveDeposit<'info> for DepositLiveSaber<'info> {
+    fn deposit_mint(&self) -> Pubkey {
+        self.mint.key()
+    }
+
+    fn deposit_lp(&self) -> Pubkey {
+        self.lp.key()
+    }
+
+    fn depositor(&self) -> Pubkey {
+        self.payer.key()
+    }
+
+    fn vault_key(&self) -> Pubkey {
+        self.vault.key()
+    }
+
+    fn history_mut(&mut self) -> &mut History {
+        self.history.deref_mut()
+    }
+
+    fn into_pool_amounts(&self, mint: &Pubkey, amount: u64) -> OraResult<(u64, u64)> {
+        match *mint {
+            m if m == self.saber_swap_common.source_token_a.mint => Ok((amount, 0)),
+            m if m == self.saber_swap_common.source_token_b.mint => Ok((0, amount)),
+            _ => Err(ErrorCode::PublicKeyMismatch.into()),
+        }
+    }
+
+    fn transfer_deposit(&self, amount: u64) -> ProgramResult {
+        let destination = match self.mint.key() {
+            m if m == self.saber_swap_common.source_token_a.mint => {
+                &self.saber_swap_common.source_token_a
+            }
+            m if m == self.saber_swap_common.source_token_b.mint => {
+                &self.saber_swap_common.source_token_b
+            }
+            _ => return Err(ErrorCode::PublicKeyMismatch.into()),
+        };
+
+        spl_token_transfer(
+            self.token_program.to_account_info(),
+            self.source_ata.to_account_info(),
+            destination.to_account_info(),
+            self.payer.to_account_info(),
+            &[], // user is signer
+            amount,
+        )
+    }
+
+    fn mint_tranche_lp(&self, amount: u64) -> ProgramResult {
+        let vault_signer_seeds =
+            generate_vault_seeds!(*self.authority.key.as_ref(), self.vault.bump);
+
+        mint_with_verified_ata(
+            self.destination_lp_ata.to_account_info(),
+            self.payer.to_account_info(),
+            self.lp.to_account_info(),
+            self.payer.to_account_info(),
+            self.ata_program.to_account_info(),
+            self.token_program.to_account_info(),
+            self.system_program.to_account_info(),
+            self.rent.to_account_info(),
+            &[],
+            self.vault.to_account_info(),
+            vault_signer_seeds,
+            amount,
+            !self.vault.tranche_lp_transferable(),
+        )
     }
 }
 
-impl<'info> RedeemSaber<'info> {
-    pub fn into_saber_swap_withdraw_context(
-        &self,
-    ) -> CpiContext<'_, '_, '_, 'info, Withdraw<'info>> {
+impl<'info> DepositLiveSaber<'info> {
+    pub fn into_saber_swap_deposit_context(&self) -> CpiContext<'_, '_, '_, 'info, Deposit<'info>> {
         let cpi_program = self.saber_swap_common.saber_program.to_account_info();
 
-        let cpi_accounts = Withdraw {
+        let cpi_accounts = Deposit {
             /// The context of the user
             user: SwapUserContext {
                 /// The spl_token program
@@ -318,38 +461,504 @@ impl<'info> RedeemSaber<'info> {
                 /// The pool's swap account
                 swap: self.saber_swap_common.swap.to_account_info(),
             },
-            /// The withdrawer's LP ATA
-            input_lp: self.input_lp.to_account_info(),
-            /// The pool's LP mint
-            pool_mint: self.saber_swap_common.pool_mint.to_account_info(),
             /// The "A" token of the swap
-            output_a: SwapOutput {
-                user_token: SwapToken {
-                    /// The withdrawer's token A ATA
-                    user: self.saber_swap_common.source_token_a.to_account_info(),
-                    /// The pool’s token A ATA
-                    reserve: self.saber_swap_common.reserve_a.to_account_info(),
-                },
-                // The token account for the fees associated with the token
-                fees: self.output_a_fees.to_account_info(),
+            input_a: SwapToken {
+                /// The depositor's token A ATA
+                user: self.saber_swap_common.source_token_a.to_account_info(),
+                /// The pool’s token A ATA
+                reserve: self.saber_swap_common.reserve_a.to_account_info(),
             },
             /// The "B" token of the swap
-            output_b: SwapOutput {
-                user_token: SwapToken {
-                    /// The withdrawer's token B ATA
-                    user: self.saber_swap_common.source_token_b.to_account_info(),
-                    /// The pool’s token B ATA
-                    reserve: self.saber_swap_common.reserve_b.to_account_info(),
-                },
-                // The token account for the fees associated with the token
-                fees: self.output_b_fees.to_account_info(),
+            input_b: SwapToken {
+                /// The depositor's token B ATA
+                user: self.saber_swap_common.source_token_b.to_account_info(),
+                /// The pool’s token B ATA
+                reserve: self.saber_swap_common.reserve_b.to_account_info(),
             },
+            /// The pool's LP mint
+            pool_mint: self.saber_swap_common.pool_mint.to_account_info(),
+            /// The output account for LP tokens
+            output_lp: self.output_lp.to_account_info(),
         };
 
         CpiContext::new(cpi_program, cpi_accounts)
     }
 }
 
+// =====================================================================
+
+#[derive(Accounts)]
+pub struct RedeemSaber<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: read-only account to validate vault address
+    pub authority: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [GLOBAL_STATE_SEED.as_bytes()],
+        bump,
+    )]
+    pub global_protocol_state: Box<Account<'info, GlobalProtocolState>>,
+
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED.as_bytes(),
+            authority.key().to_bytes().as_ref()
+        ],
+        bump,
+        constraint = vault.strategy == strategy.key(),
+        constraint = vault.authority == authority.key(),
+        constraint = vault.has_strategist_role(&payer.key(), StrategistRole::Redeem) @ ErrorCode::MissingStrategistRole
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    /// CHECK: pubkey matched in context, validation done in instruction
+    // todo: update this to check for seeds
+    pub strategy: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub rent: Sysvar<'info, Rent>,
+
+    // ====================================================
+    // saber accounts
+    // ====================================================
+    /// custom struct to encapsulate all common saber swap accounts
+    pub saber_swap_common: SaberSwapCommon<'info>,
+
+    /// The output account for LP tokens
+    #[account(mut)]
+    pub input_lp: Box<Account<'info, TokenAccount>>,
+
+    /// The token account for the fees associated with token "B"
+    #[account(mut)]
+    pub output_a_fees: Box<Account<'info, TokenAccount>>,
+
+    /// The token account for the fees associated with token "A"
+    #[account(mut)]
+    pub output_b_fees: Box<Account<'info, TokenAccount>>,
+}
+
+impl_has_vault!(RedeemSaber<'_>);
+
+impl<'info> PoolPrice for RedeemSaber<'info> {
+    fn pool_price(&self) -> OraResult<(u64, u64)> {
+        pegged_pool_price(&self.vault)
+    }
+}
+
+impl<'info> Redeem<'info> for RedeemSaber<'info> {
+    fn strategy_lp(&self) -> u64 {
+        self.input_lp.amount
+    }
+
+    fn redeem(
+        &mut self,
+        lp_amount: u64,
+        min_token_a: u64,
+        min_token_b: u64,
+    ) -> OraResult<(u64, u64)> {
+        let vault_signer_seeds =
+            generate_vault_seeds!(*self.authority.key.as_ref(), self.vault.bump);
+        let cpi_ctx = into_saber_swap_withdraw_context(
+            &self.saber_swap_common,
+            self.token_program.to_account_info(),
+            self.vault.to_account_info(),
+            self.input_lp.to_account_info(),
+            self.output_a_fees.to_account_info(),
+            self.output_b_fees.to_account_info(),
+        );
+
+        withdraw_from_pool(
+            &self.vault,
+            &mut self.saber_swap_common,
+            cpi_ctx.with_signer(&[vault_signer_seeds]),
+            lp_amount,
+            min_token_a,
+            min_token_b,
+        )
+    }
+}
+
+// burn lp_amount of the vault's saber LP for the pool's underlying tokens, returned as (alpha, beta) amounts
+// received by the vault
+pub fn withdraw_from_pool<'info>(
+    vault: &Vault,
+    saber_swap_common: &mut SaberSwapCommon<'info>,
+    cpi_ctx: CpiContext<'_, '_, '_, 'info, Withdraw<'info>>,
+    lp_amount: u64,
+    min_token_a: u64,
+    min_token_b: u64,
+) -> OraResult<(u64, u64)> {
+    // amounts out cannot be less than the spot amounts net of the pool's withdrawal fee minus the vault's
+    // max slippage
+    let (reserve_a, reserve_b, supply) = saber_swap_common.get_pool_reserves()?;
+    let withdraw_fee = saber_swap_common.get_withdraw_fee()?;
+    let min_token_a = compute_min_out(
+        min_token_a,
+        withdraw_fee.apply(compute_lp_value(lp_amount, reserve_a, supply)?)?,
+        vault.max_slippage_bps,
+    )?;
+    let min_token_b = compute_min_out(
+        min_token_b,
+        withdraw_fee.apply(compute_lp_value(lp_amount, reserve_b, supply)?)?,
+        vault.max_slippage_bps,
+    )?;
+    msg!("min_token_a: {}, min_token_b: {}", min_token_a, min_token_b);
+
+    stable_swap_anchor::withdraw(cpi_ctx, lp_amount, min_token_a, min_token_b)?;
+
+    let received_a = verify_received(&mut saber_swap_common.source_token_a, min_token_a)?;
+    let received_b = verify_received(&mut saber_swap_common.source_token_b, min_token_b)?;
+
+    // map pool tokens A & B to vault tranche assets
+    match saber_swap_common.source_token_a.mint == vault.alpha().mint {
+        true => Ok((received_a, received_b)),
+        false => Ok((received_b, received_a)),
+    }
+}
+
+pub fn into_saber_swap_withdraw_context<'a, 'b, 'c, 'info>(
+    saber_swap_common: &SaberSwapCommon<'info>,
+    token_program: AccountInfo<'info>,
+    user_authority: AccountInfo<'info>,
+    input_lp: AccountInfo<'info>,
+    output_a_fees: AccountInfo<'info>,
+    output_b_fees: AccountInfo<'info>,
+) -> CpiContext<'a, 'b, 'c, 'info, Withdraw<'info>> {
+    let cpi_program = saber_swap_common.saber_program.to_account_info();
+
+    let cpi_accounts = Withdraw {
+        /// The context of the user
+        user: SwapUserContext {
+            /// The spl_token program
+            token_program,
+            /// The authority of the swap
+            swap_authority: saber_swap_common.swap_authority.to_account_info(),
+            /// The authority of the user
+            user_authority,
+            /// The pool's swap account
+            swap: saber_swap_common.swap.to_account_info(),
+        },
+        /// The withdrawer's LP ATA
+        input_lp,
+        /// The pool's LP mint
+        pool_mint: saber_swap_common.pool_mint.to_account_info(),
+        /// The "A" token of the swap
+        output_a: SwapOutput {
+            user_token: SwapToken {
+                /// The withdrawer's token A ATA
+                user: saber_swap_common.source_token_a.to_account_info(),
+                /// The pool’s token A ATA
+                reserve: saber_swap_common.reserve_a.to_account_info(),
+            },
+            // The token account for the fees associated with the token
+            fees: output_a_fees,
+        },
+        /// The "B" token of the swap
+        output_b: SwapOutput {
+            user_token: SwapToken {
+                /// The withdrawer's token B ATA
+                user: saber_swap_common.source_token_b.to_account_info(),
+                /// The pool’s token B ATA
+                reserve: saber_swap_common.reserve_b.to_account_info(),
+            },
+            // The token account for the fees associated with the token
+            fees: output_b_fees,
+        },
+    };
+
+    CpiContext::new(cpi_program, cpi_accounts)
+}
+
+// =====================================================================
+
+#[derive(Accounts)]
+pub struct EarlyExitSaber<'info> {
+    /// tranche token holder exiting the vault
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: read-only account to validate vault address
+    pub authority: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [GLOBAL_STATE_SEED.as_bytes()],
+        bump,
+    )]
+    pub global_protocol_state: Box<Account<'info, GlobalProtocolState>>,
+
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED.as_bytes(),
+            authority.key().to_bytes().as_ref()
+        ],
+        bump,
+        constraint = vault.strategy == strategy.key(),
+        constraint = vault.authority == authority.key(),
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    /// CHECK: verified via instruction access_control
+    pub vault_store: UncheckedAccount<'info>,
+
+    /// CHECK: pubkey matched in context, validation done in instruction
+    pub strategy: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub rent: Sysvar<'info, Rent>,
+
+    // ====================================================
+    // tranche accounts
+    // ====================================================
+    #[account(mut)]
+    pub lp: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint = source_lp.mint == lp.key(),
+        constraint = source_lp.owner == payer.key(),
+    )]
+    pub source_lp: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: holder's token account for pool token A. Validation done via Token Program CPI.
+    #[account(mut)]
+    pub destination_token_a: UncheckedAccount<'info>,
+
+    /// CHECK: holder's token account for pool token B. Validation done via Token Program CPI.
+    #[account(mut)]
+    pub destination_token_b: UncheckedAccount<'info>,
+
+    // ====================================================
+    // saber accounts
+    // ====================================================
+    /// custom struct to encapsulate all common saber swap accounts
+    pub saber_swap_common: SaberSwapCommon<'info>,
+
+    /// The vault's account for LP tokens
+    #[account(
+        mut,
+        constraint = input_lp.owner == vault.key(),
+        constraint = input_lp.mint == saber_swap_common.pool_mint.key(),
+    )]
+    pub input_lp: Box<Account<'info, TokenAccount>>,
+
+    /// The token account for the fees associated with token "A"
+    #[account(mut)]
+    pub output_a_fees: Box<Account<'info, TokenAccount>>,
+
+    /// The token account for the fees associated with token "B"
+    #[account(mut)]
+    pub output_b_fees: Box<Account<'info, TokenAccount>>,
+}
+
+impl_has_vault!(EarlyExitSaber<'_>);
+
+impl<'info> PoolPrice for EarlyExitSaber<'info> {
+    fn pool_price(&self) -> OraResult<(u64, u64)> {
+        pegged_pool_price(&self.vault)
+    }
+}
+
+impl<'info> Valuation<'info> for EarlyExitSaber<'info> {
+    fn strategy_value(&self) -> OraResult<(u64, u64)> {
+        let (reserve_a, reserve_b, supply) = self.saber_swap_common.get_pool_reserves()?;
+        let value_a = compute_lp_value(self.input_lp.amount, reserve_a, supply)?;
+        let value_b = compute_lp_value(self.input_lp.amount, reserve_b, supply)?;
+
+        match self.saber_swap_common.reserve_a.mint {
+            m if m == self.vault.alpha().mint => Ok((value_a, value_b)),
+            m if m == self.vault.beta().mint => Ok((value_b, value_a)),
+            _ => Err(ErrorCode::PublicKeyMismatch.into()),
+        }
+    }
+}
+
+impl<'info> Redeem<'info> for EarlyExitSaber<'info> {
+    fn strategy_lp(&self) -> u64 {
+        self.input_lp.amount
+    }
+
+    fn redeem(
+        &mut self,
+        lp_amount: u64,
+        min_token_a: u64,
+        min_token_b: u64,
+    ) -> OraResult<(u64, u64)> {
+        let vault_signer_seeds =
+            generate_vault_seeds!(*self.authority.key.as_ref(), self.vault.bump);
+        let cpi_ctx = into_saber_swap_withdraw_context(
+            &self.saber_swap_common,
+            self.token_program.to_account_info(),
+            self.vault.to_account_info(),
+            self.input_lp.to_account_info(),
+            self.output_a_fees.to_account_info(),
+            self.output_b_fees.to_account_info(),
+        );
+
+        withdraw_from_pool(
+            &self.vault,
+            &mut self.saber_swap_common,
+            cpi_ctx.with_signer(&[vault_signer_seeds]),
+            lp_amount,
+            min_token_a,
+            min_token_b,
+        )
+    }
+}
+
+impl<'info> EarlyExit<'info> for EarlyExitSaber<'info> {
+    fn exit_lp(&self) -> Pubkey {
+        self.lp.key()
+    }
+
+    fn total_strategy_lp(&self) -> OraResult<u64> {
+        Ok(self.input_lp.amount)
+    }
+
+    fn burn_tranche_lp(&self, amount: u64) -> ProgramResult {
+        let authority = self.authority.key();
+        let vault_signer_seeds = generate_vault_seeds!(authority.as_ref(), self.vault.bump);
+
+        set_token_account_frozen(
+            self.token_program.to_account_info(),
+            self.source_lp.to_account_info(),
+            self.lp.to_account_info(),
+            self.vault.to_account_info(),
+            vault_signer_seeds,
+            false,
+        )?;
+
+        burn(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                Burn {
+                    mint: self.lp.to_account_info(),
+                    to: self.source_lp.to_account_info(),
+                    authority: self.payer.to_account_info(),
+                },
+            ),
+            amount,
+        )
+    }
+
+    fn transfer_exit(&self, alpha_amount: u64, beta_amount: u64) -> ProgramResult {
+        // map pool tokens A & B to vault tranche assets
+        let (amount_a, amount_b) =
+            match self.saber_swap_common.source_token_a.mint == self.vault.alpha().mint {
+                true => (alpha_amount, beta_amount),
+                false => (beta_amount, alpha_amount),
+            };
+
+        let authority = self.authority.key();
+        let vault_signer_seeds = generate_vault_seeds!(authority.as_ref(), self.vault.bump);
+
+        for (source, destination, amount) in [
+            (
+                self.saber_swap_common.source_token_a.to_account_info(),
+                self.destination_token_a.to_account_info(),
+                amount_a,
+            ),
+            (
+                self.saber_swap_common.source_token_b.to_account_info(),
+                self.destination_token_b.to_account_info(),
+                amount_b,
+            ),
+        ] {
+            if amount == 0 {
+                continue;
+            }
+
+            spl_token_transfer(
+                self.token_program.to_account_info(),
+                source,
+                destination,
+                self.vault.to_account_info(),
+                &[vault_signer_seeds],
+                amount,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+// =====================================================================
+
+#[derive(Accounts)]
+pub struct GetVaultNavSaber<'info> {
+    /// CHECK: read-only account to validate vault address
+    pub authority: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            VAULT_SEED.as_bytes(),
+            authority.key().to_bytes().as_ref()
+        ],
+        bump,
+        constraint = vault.strategy == strategy.key(),
+        constraint = vault.authority == authority.key(),
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    /// CHECK: pubkey matched in context, validation done in instruction
+    pub strategy: UncheckedAccount<'info>,
+
+    // ====================================================
+    // saber accounts
+    // ====================================================
+    /// CHECK: verified against the saber program in instruction
+    pub swap: UncheckedAccount<'info>,
+
+    pub reserve_a: Box<Account<'info, TokenAccount>>,
+
+    pub reserve_b: Box<Account<'info, TokenAccount>>,
+
+    pub pool_mint: Box<Account<'info, Mint>>,
+
+    /// The vault's account for LP tokens
+    #[account(
+        constraint = output_lp.owner == vault.key(),
+        constraint = output_lp.mint == pool_mint.key(),
+    )]
+    pub output_lp: Box<Account<'info, TokenAccount>>,
+}
+
+impl_has_vault!(GetVaultNavSaber<'_>);
+
+impl<'info> PoolPrice for GetVaultNavSaber<'info> {
+    fn pool_price(&self) -> OraResult<(u64, u64)> {
+        pegged_pool_price(&self.vault)
+    }
+}
+
+impl<'info> Valuation<'info> for GetVaultNavSaber<'info> {
+    fn strategy_value(&self) -> OraResult<(u64, u64)> {
+        let (reserve_a, reserve_b, supply) = get_pool_reserves(
+            &self.swap.to_account_info(),
+            &self.reserve_a,
+            &self.reserve_b,
+            &self.pool_mint,
+        )?;
+        let value_a = compute_lp_value(self.output_lp.amount, reserve_a, supply)?;
+        let value_b = compute_lp_value(self.output_lp.amount, reserve_b, supply)?;
+
+        match self.reserve_a.mint {
+            m if m == self.vault.alpha().mint => Ok((value_a, value_b)),
+            m if m == self.vault.beta().mint => Ok((value_b, value_a)),
+            _ => Err(ErrorCode::PublicKeyMismatch.into()),
+        }
+    }
+}
+
 // ==========================================================
 // saber common contexts
 // ==========================================================
@@ -387,3 +996,82 @@ pub struct SaberSwapCommon<'info> {
     #[account(address = stable_swap_anchor::ID)]
     pub saber_program: UncheckedAccount<'info>,
 }
+
+// stable swap pools trade pegged assets near 1:1 regardless of the reserve ratio. comparing this against
+// the oracle checks that neither asset has lost its peg.
+pub fn pegged_pool_price(vault: &Vault) -> OraResult<(u64, u64)> {
+    let beta_amount = 10u64
+        .checked_pow(vault.beta().decimals as u32)
+        .ok_or_else(math_error!())?;
+    let alpha_amount = 10u64
+        .checked_pow(vault.alpha().decimals as u32)
+        .ok_or_else(math_error!())?;
+
+    Ok((beta_amount, alpha_amount))
+}
+
+// offsets into saber's `SwapInfo` state. the state is packed as is_initialized, is_paused, nonce,
+// 5 u64 amp + ramp + admin deadline fields, future_admin_key and admin_key, followed by the
+// token A and token B infos and the pool mint. each token info is packed as (reserves, mint, admin_fees),
+// so token B's reserves sit 96 bytes after token A's.
+const SWAP_INFO_IS_INITIALIZED_OFFSET: usize = 0;
+const SWAP_INFO_RESERVE_A_OFFSET: usize = 107;
+const SWAP_INFO_RESERVE_B_OFFSET: usize = 203;
+const SWAP_INFO_POOL_MINT_OFFSET: usize = 299;
+// the pool mint is followed by the fees, packed as (numerator, denominator) pairs. the withdrawal fee is
+// the last of the admin trade, admin withdraw, trade and withdraw fees.
+const SWAP_INFO_WITHDRAW_FEE_OFFSET: usize = 379;
+
+impl<'info> SaberSwapCommon<'info> {
+    pub fn get_pool_reserves(&self) -> OraResult<(u64, u64, u64)> {
+        get_pool_reserves(
+            &self.swap.to_account_info(),
+            &self.reserve_a,
+            &self.reserve_b,
+            &self.pool_mint,
+        )
+    }
+
+    pub fn get_withdraw_fee(&self) -> OraResult<PoolFee> {
+        let data = self.swap.try_borrow_data()?;
+        require!(
+            data.len() >= SWAP_INFO_WITHDRAW_FEE_OFFSET + 16,
+            ErrorCode::UninitializedAccount
+        );
+
+        let read_u64 =
+            |offset: usize| u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+
+        Ok(PoolFee {
+            numerator: read_u64(SWAP_INFO_WITHDRAW_FEE_OFFSET),
+            denominator: read_u64(SWAP_INFO_WITHDRAW_FEE_OFFSET + 8),
+        })
+    }
+}
+
+// read the pool's reserves and LP supply. the accounts are verified against the pool's swap info
+// so that the strategist cannot pass in arbitrary token accounts to skew the spot price.
+pub fn get_pool_reserves<'info>(
+    swap_info: &AccountInfo<'info>,
+    reserve_a: &Account<'info, TokenAccount>,
+    reserve_b: &Account<'info, TokenAccount>,
+    pool_mint: &Account<'info, Mint>,
+) -> OraResult<(u64, u64, u64)> {
+    assert_owned_by(swap_info, &stable_swap_anchor::ID)?;
+
+    let data = swap_info.try_borrow_data()?;
+    require!(
+        data.len() >= SWAP_INFO_POOL_MINT_OFFSET + 32 && data[SWAP_INFO_IS_INITIALIZED_OFFSET] == 1,
+        ErrorCode::UninitializedAccount
+    );
+
+    let read_pubkey = |offset: usize| Pubkey::new(&data[offset..offset + 32]);
+    require!(
+        read_pubkey(SWAP_INFO_RESERVE_A_OFFSET) == reserve_a.key()
+            && read_pubkey(SWAP_INFO_RESERVE_B_OFFSET) == reserve_b.key()
+            && read_pubkey(SWAP_INFO_POOL_MINT_OFFSET) == pool_mint.key(),
+        ErrorCode::PublicKeyMismatch
+    );
+
+    Ok((reserve_a.amount, reserve_b.amount, pool_mint.supply))
+}
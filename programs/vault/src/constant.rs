@@ -8,14 +8,18 @@ pub const VAULT_STORE_SEED: &str = "vaultstore";
 pub const STRATEGY_SEED: &str = "strategy";
 pub const RECEIPT_SEED: &str = "receipt";
 pub const HISTORY_SEED: &str = "history";
+pub const ROLLOVER_SEED: &str = "rollover";
+pub const ALLOWLIST_SEED: &str = "allowlist";
+pub const TRANCHE_MINT_SEED: &str = "tranchemint";
 
 pub const SOL_PUBKEY: Pubkey = pubkey!("So11111111111111111111111111111111111111112");
 pub const SOL_DECIMALS: u8 = 9;
 
-// vault states
-pub const INACTIVE_STATE: &str = "inactive";
-pub const DEPOSIT_STATE: &str = "deposit";
-pub const LIVE_STATE: &str = "live";
-pub const REDEEM_STATE: &str = "redeem";
-pub const REBALANCE_STATE: &str = "rebalance";
-pub const WITHDRAW_STATE: &str = "withdraw";
+/// 100% denominated in basis points
+pub const MAX_BPS: u64 = 10_000;
+
+/// max number of tranches a vault can have, e.g. senior, mezzanine, junior
+pub const MAX_TRANCHES: usize = 3;
+
+/// seconds in a day, used to derive a vault's maturity date
+pub const SECONDS_PER_DAY: u64 = 86_400;
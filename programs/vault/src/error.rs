@@ -92,4 +92,52 @@ pub enum ErrorCode {
     UnableToWriteToRemainingAccount,
     #[msg("Expected non-zero returns")]
     ExpectedNonzeroReturns,
+    #[msg("Invalid rollover vault")]
+    InvalidRolloverVault,
+    #[msg("Rollover already processed")]
+    RolloverAlreadyProcessed,
+    #[msg("Depositor not allowlisted")]
+    DepositorNotAllowlisted,
+    #[msg("Invalid merkle proof")]
+    InvalidMerkleProof,
+    #[msg("Deposit below minimum")]
+    DepositBelowMinimum,
+    #[msg("Deposit exceeds max deposits per user")]
+    DepositExceedsMaxDepositsPerUser,
+    #[msg("Invalid slippage bps")]
+    InvalidSlippageBps,
+    #[msg("Invalid oracle account")]
+    InvalidOracleAccount,
+    #[msg("Stale oracle price")]
+    StaleOraclePrice,
+    #[msg("Pool price deviates from oracle price")]
+    OraclePriceDeviationExceeded,
+    #[msg("Invalid tranche config")]
+    InvalidTrancheConfig,
+    #[msg("Invalid leverage config")]
+    InvalidLeverageConfig,
+    #[msg("Senior-to-junior ratio outside of the vault's leverage band")]
+    LeverageRatioOutOfBounds,
+    #[msg("Invalid tranche metadata")]
+    InvalidTrancheMetadata,
+    #[msg("Tranche tokens are frozen")]
+    TrancheLpFrozen,
+    #[msg("Invalid early exit penalty")]
+    InvalidEarlyExitPenalty,
+    #[msg("Early exits are disabled for this vault")]
+    EarlyExitDisabled,
+    #[msg("Signer may not trigger this state transition")]
+    UnauthorizedStateTransition,
+    #[msg("Vault has been cancelled")]
+    VaultCancelled,
+    #[msg("Vault can only be cancelled before its deposits are invested")]
+    VaultNotCancellable,
+    #[msg("Invalid strategist roles")]
+    InvalidStrategistRoles,
+    #[msg("Signer does not hold the strategist role for this operation")]
+    MissingStrategistRole,
+    #[msg("Rollover vault can still accept the position")]
+    RolloverVaultAcceptingDeposits,
+    #[msg("Vault must have an oracle configured")]
+    OracleRequired,
 }
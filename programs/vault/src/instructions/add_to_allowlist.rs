@@ -0,0 +1,64 @@
+use crate::{
+    constant::{ALLOWLIST_SEED, GLOBAL_STATE_SEED, VAULT_SEED},
+    state::{Allowlist, GlobalProtocolState, Vault},
+};
+use anchor_lang::prelude::*;
+use std::mem::size_of;
+
+#[derive(Accounts)]
+pub struct AddToAllowlist<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GLOBAL_STATE_SEED.as_bytes()],
+        bump,
+    )]
+    pub global_protocol_state: Box<Account<'info, GlobalProtocolState>>,
+
+    #[account(
+        seeds = [
+            VAULT_SEED.as_bytes(),
+            authority.key().to_bytes().as_ref()
+        ],
+        bump,
+        constraint = vault.authority == authority.key(),
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    /// CHECK: read-only account to derive allowlist address
+    pub user: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        seeds = [
+            ALLOWLIST_SEED.as_bytes(),
+            vault.key().to_bytes().as_ref(),
+            user.key().to_bytes().as_ref(),
+        ],
+        bump,
+        payer = authority,
+        space = 8 + size_of::<Allowlist>(),
+    )]
+    pub allowlist: Box<Account<'info, Allowlist>>,
+
+    /// =============== PROGRAM ACCOUNTS ===============
+    pub system_program: Program<'info, System>,
+
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Approve a user to deposit into a vault whose deposit gate is `DepositGate::Allowlist`.
+///
+/// @dev the allowlist account can be created regardless of the vault's deposit gate. it is only
+///      checked when the gate is set to allowlist.
+///
+pub fn handle(ctx: Context<AddToAllowlist>, allowlist_bump: u8) -> ProgramResult {
+    ctx.accounts.allowlist.init(
+        allowlist_bump,
+        &ctx.accounts.vault.key(),
+        &ctx.accounts.user.key(),
+    );
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use crate::{
+    constant::{GLOBAL_STATE_SEED, VAULT_SEED},
+    state::{vault::Vault, GlobalProtocolState},
+    util::get_current_timestamp,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CancelVault<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GLOBAL_STATE_SEED.as_bytes()],
+        bump,
+    )]
+    pub global_protocol_state: Box<Account<'info, GlobalProtocolState>>,
+
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED.as_bytes(),
+            authority.key().to_bytes().as_ref()
+        ],
+        bump,
+        constraint = vault.authority == authority.key(),
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+}
+
+/// Cancel a vault before any of its deposits are invested, i.e. in the inactive or deposit state, or in the
+/// live state before the strategist invests. Depositors get their full deposits back through the usual
+/// flow: `process_claims` for each tranche, then `claim`.
+///
+/// @dev the vault moves back to the inactive state and can't transition again.
+///
+pub fn handle(ctx: Context<CancelVault>) -> ProgramResult {
+    ctx.accounts.vault.cancel(get_current_timestamp()?)?;
+
+    Ok(())
+}
@@ -8,6 +8,7 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, Token, TokenAccount};
 
 #[derive(Accounts)]
+#[instruction(tranche_id: u8)]
 pub struct Claim<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
@@ -41,7 +42,7 @@ pub struct Claim<'info> {
         seeds = [
             HISTORY_SEED.as_bytes(),
             vault.key().to_bytes().as_ref(),
-            mint.key().to_bytes().as_ref(),
+            &tranche_id.to_le_bytes(),
             payer.key().to_bytes().as_ref(),
         ],
         bump,
@@ -88,11 +89,19 @@ pub struct Claim<'info> {
 ///     assets. This is a tokenized position that allows the depositor to leverage across the ecosystem.
 ///     These LP tokens will also be referenced when a user decides to withdraw assets from the vault.
 ///
-/// This instruction can only be invoked after the funds are invested and the claims are processed.
+/// This instruction can only be invoked after the funds are invested, or the vault is cancelled, and the
+/// claims are processed.
+///
+/// @dev tranche tokens are minted frozen until every tranche's claims are processed. once they are, the
+///      `thaw_tranche_lp` instruction can thaw any holder's tranche token account.
 ///
 // todo: check this thoroughly
-pub fn handle(ctx: Context<Claim>) -> ProgramResult {
-    let asset = ctx.accounts.vault.get_asset(&ctx.accounts.mint.key())?;
+pub fn handle(ctx: Context<Claim>, tranche_id: u8) -> ProgramResult {
+    let asset = ctx.accounts.vault.get_tranche(tranche_id)?;
+    require!(
+        asset.mint == ctx.accounts.mint.key(),
+        ErrorCode::PublicKeyMismatch
+    );
     require!(
         ctx.accounts.vault.in_claimable_state(&asset),
         ErrorCode::InvalidVaultState
@@ -101,6 +110,11 @@ pub fn handle(ctx: Context<Claim>) -> ProgramResult {
     // verify mint and lp from vault vs instruction accounts
     require!(ctx.accounts.lp.key() == asset.lp, ErrorCode::InvalidLpMint);
 
+    // refund any portion of the user's rollover deposit that was not invested
+    ctx.accounts
+        .history
+        .settle_rollover(asset.rollover_excess(), asset.rollover_deposited)?;
+
     let claim_amount = ctx.accounts.history.claim;
     let deposit_amount = ctx.accounts.history.cumulative;
 
@@ -134,20 +148,24 @@ pub fn handle(ctx: Context<Claim>) -> ProgramResult {
             .ok_or_else(math_error!())?;
         msg!("Tranche token amount: {:?}", lp_amount);
 
-        mint_with_verified_ata(
-            ctx.accounts.destination_lp_ata.to_account_info(),
-            ctx.accounts.payer.to_account_info(),
-            ctx.accounts.lp.to_account_info(),
-            ctx.accounts.payer.to_account_info(),
-            ctx.accounts.ata_program.to_account_info(),
-            ctx.accounts.token_program.to_account_info(),
-            ctx.accounts.system_program.to_account_info(),
-            ctx.accounts.rent.to_account_info(),
-            &[],
-            ctx.accounts.vault.to_account_info(),
-            vault_signer_seeds,
-            lp_amount, // 1-1 asset to LP amount
-        )?;
+        // nothing to mint when every deposit is refunded, e.g. for a cancelled vault
+        if lp_amount > 0 {
+            mint_with_verified_ata(
+                ctx.accounts.destination_lp_ata.to_account_info(),
+                ctx.accounts.payer.to_account_info(),
+                ctx.accounts.lp.to_account_info(),
+                ctx.accounts.payer.to_account_info(),
+                ctx.accounts.ata_program.to_account_info(),
+                ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+                ctx.accounts.rent.to_account_info(),
+                &[],
+                ctx.accounts.vault.to_account_info(),
+                vault_signer_seeds,
+                lp_amount, // 1-1 asset to LP amount
+                !ctx.accounts.vault.tranche_lp_transferable(),
+            )?;
+        }
 
         ctx.accounts.history.claim_tranche_lp();
     }
@@ -0,0 +1,157 @@
+use crate::{
+    constant::{GLOBAL_STATE_SEED, HISTORY_SEED, VAULT_SEED, VAULT_STORE_SEED},
+    error::ErrorCode,
+    math::compute_withdrawal_amount,
+    state::{vault::State, vault::Vault, GlobalProtocolState, History},
+    util::spl_token_transfer,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token};
+
+#[derive(Accounts)]
+#[instruction(tranche_id: u8)]
+pub struct ClaimAndWithdraw<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: read-only account to validate vault address
+    pub authority: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [GLOBAL_STATE_SEED.as_bytes()],
+        bump,
+    )]
+    pub global_protocol_state: Box<Account<'info, GlobalProtocolState>>,
+
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED.as_bytes(),
+            authority.key().to_bytes().as_ref()
+        ],
+        bump,
+        constraint = vault.authority == authority.key(),
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    /// CHECK: verified via instruction access_control
+    #[account(mut)]
+    pub vault_store: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            HISTORY_SEED.as_bytes(),
+            vault.key().to_bytes().as_ref(),
+            &tranche_id.to_le_bytes(),
+            payer.key().to_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub history: Box<Account<'info, History>>,
+
+    pub mint: Box<Account<'info, Mint>>,
+
+    /// read-only, only used to verify the tranche and its decimals
+    pub lp: Box<Account<'info, Mint>>,
+
+    /// CHECK: can be wrapped wSOL, so not a TokenAccount. Validation done via Token Program CPI.
+    #[account(mut)]
+    pub source_ata: UncheckedAccount<'info>,
+
+    /// CHECK: can be wrapped wSOL, so not a TokenAccount. Validation done via Token Program CPI.
+    #[account(mut)]
+    pub destination_ata: UncheckedAccount<'info>,
+
+    /// =============== PROGRAM ACCOUNTS ===============
+    pub system_program: Program<'info, System>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Shortcut for depositors that never want to hold the tranche token. Instead of calling `claim`
+/// to refund any excess + mint tranche LP and then `withdraw` to burn that LP, this instruction
+/// will, in a single step:
+///
+///   - return any excess funds recorded on the user's history
+///   - compute the number of tranche LP tokens the user would have been minted, `cumulative - claim`,
+///     and pay out the underlying assets those LP tokens are entitled to.
+///
+/// No LP tokens are minted or burned. The user's history is marked as having claimed their tranche
+/// LP so that neither `claim` nor this instruction can be used to double claim.
+///
+/// This instruction can only be invoked after funds are rebalanced and the vault is in the withdraw state.
+///
+pub fn handle(ctx: Context<ClaimAndWithdraw>, tranche_id: u8) -> ProgramResult {
+    ctx.accounts.vault.try_transition()?;
+    require!(
+        ctx.accounts.vault.state == State::Withdraw,
+        ErrorCode::InvalidVaultState
+    );
+
+    let asset = ctx.accounts.vault.get_tranche(tranche_id)?;
+    require!(
+        asset.mint == ctx.accounts.mint.key(),
+        ErrorCode::PublicKeyMismatch
+    );
+    require!(
+        ctx.accounts.vault.in_claimable_state(&asset),
+        ErrorCode::InvalidVaultState
+    );
+
+    // verify mint and lp from vault vs instruction accounts
+    require!(ctx.accounts.lp.key() == asset.lp, ErrorCode::InvalidLpMint);
+
+    // refund any portion of the user's rollover deposit that was not invested
+    ctx.accounts
+        .history
+        .settle_rollover(asset.rollover_excess(), asset.rollover_deposited)?;
+
+    let claim_amount = ctx.accounts.history.claim;
+    let deposit_amount = ctx.accounts.history.cumulative;
+
+    // amount of LP tokens the user would have received via `claim`
+    let mut withdrawal_amount = 0;
+    if ctx.accounts.history.can_claim_tranche_lp {
+        let lp_amount = deposit_amount
+            .checked_sub(claim_amount)
+            .ok_or_else(math_error!())?;
+        msg!("Tranche token amount: {:?}", lp_amount);
+
+        if lp_amount > 0 {
+            withdrawal_amount = compute_withdrawal_amount(
+                asset.received,
+                asset.total_invested,
+                lp_amount,
+                ctx.accounts.lp.decimals,
+            )
+            .ok_or_else(math_error!())?;
+        }
+
+        ctx.accounts.history.claim_tranche_lp();
+    }
+    msg!("withdrawal amount: {:?}", withdrawal_amount);
+
+    // excess + withdrawal are denominated in the same asset, so we can transfer both at once
+    let payout = claim_amount
+        .checked_add(withdrawal_amount)
+        .ok_or_else(math_error!())?;
+    require!(payout > 0, ErrorCode::CannotWithdrawWithoutLpTokens);
+
+    let vault_key = ctx.accounts.vault.key();
+    let vault_store_signer_seeds =
+        generate_vault_store_seeds!(*vault_key.as_ref(), ctx.accounts.vault.vault_store_bump);
+
+    spl_token_transfer(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.source_ata.to_account_info(),
+        ctx.accounts.destination_ata.to_account_info(),
+        ctx.accounts.vault_store.to_account_info(),
+        &[vault_store_signer_seeds],
+        payout,
+    )?;
+
+    ctx.accounts.history.reset_claim();
+
+    Ok(())
+}
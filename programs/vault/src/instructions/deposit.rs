@@ -1,16 +1,20 @@
 use crate::{
     constant::{GLOBAL_STATE_SEED, HISTORY_SEED, RECEIPT_SEED, VAULT_SEED},
     error::ErrorCode,
-    state::{Asset, GlobalProtocolState, History, Receipt, State, Vault},
-    util::transfer_with_verified_ata,
+    state::{Allowlist, Asset, DepositGate, GlobalProtocolState, History, Receipt, State, Vault},
+    util::{
+        assert_valid_pda, get_allowlist_address_and_bump_seed, transfer_with_verified_ata,
+        verify_merkle_proof,
+    },
 };
 
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
 use anchor_spl::token::Token;
 use std::mem::size_of;
 
 #[derive(Accounts)]
-#[instruction(deposit_index: u64)]
+#[instruction(tranche_id: u8, deposit_index: u64)]
 pub struct Deposit<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
@@ -44,7 +48,7 @@ pub struct Deposit<'info> {
         seeds = [
             RECEIPT_SEED.as_bytes(),
             vault.key().to_bytes().as_ref(),
-            mint.key().to_bytes().as_ref(),
+            &tranche_id.to_le_bytes(),
             &deposit_index.to_le_bytes()
         ],
         bump,
@@ -58,7 +62,7 @@ pub struct Deposit<'info> {
         seeds = [
             HISTORY_SEED.as_bytes(),
             vault.key().to_bytes().as_ref(),
-            mint.key().to_bytes().as_ref(),
+            &tranche_id.to_le_bytes(),
             payer.key().to_bytes().as_ref(),
         ],
         bump,
@@ -96,10 +100,14 @@ pub struct Deposit<'info> {
 /// to the following conditions:
 ///
 ///   - vault is in the correct state, deposit.
-///   - the mint attempting to be deposited must match either the alpha or beta asset mint.
+///   - the mint attempting to be deposited must match the asset mint of the tranche being deposited into.
 ///   - the receipt account for a given deposit index cannot cannot yet exist. if the account
 ///     already exists with valid data, this means the deposit belongs to someone else.
 ///   - the source ATA must have a sufficient balance to successfully perform the deposit.
+///   - the user must pass the vault's deposit gate. an allowlist gate expects the user's allowlist
+///     PDA as the first remaining account; a merkle root gate expects a proof of the user's pubkey.
+///   - the deposit must be at least the optional minimum deposit size.
+///   - the user's number of deposits must remain under the optional max deposits per user.
 ///   - the user's cumulative deposits must remain under the optional user cap.
 ///   - the tranche's cumulative deposits must remain under the optional asset cap.
 ///
@@ -116,27 +124,39 @@ pub struct Deposit<'info> {
 /// We will verify the ATA address matches the what we expect. Then, we will
 /// proceed to transfer the tokens to that ATA.
 ///
-pub fn handle(
-    ctx: Context<Deposit>,
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, Deposit<'info>>,
+    tranche_id: u8,
     deposit_index: u64,
     receipt_bump: u8,
     history_bump: u8,
     amount: u64,
+    proof: Vec<[u8; 32]>,
 ) -> ProgramResult {
-    let mint_key = &ctx.accounts.mint.key();
-
     require!(
         ctx.accounts.vault.state == State::Deposit,
         ErrorCode::InvalidVaultState
     );
 
-    let asset = ctx.accounts.vault.get_asset(mint_key)?;
+    verify_deposit_gate(
+        &ctx.accounts.vault,
+        &ctx.accounts.vault.key(),
+        &ctx.accounts.payer.key(),
+        ctx.remaining_accounts,
+        &proof,
+    )?;
+
+    let asset = ctx.accounts.vault.get_tranche(tranche_id)?;
+    require!(
+        asset.mint == ctx.accounts.mint.key(),
+        ErrorCode::PublicKeyMismatch
+    );
 
     ctx.accounts.history.init_if_needed(history_bump);
     ctx.accounts.receipt.init(
         receipt_bump,
         amount,
-        asset.deposited,
+        asset.user_deposited()?,
         &ctx.accounts.payer.key(),
     )?;
 
@@ -158,13 +178,13 @@ pub fn handle(
         amount,
     )?;
 
-    ctx.accounts.vault.update_deposit(mint_key, amount)?;
+    ctx.accounts.vault.update_deposit(tranche_id, amount)?;
 
     // this makes sure the number of deposits on the vault's asset matches the seed
     // used to derive the PDA address. If it's not equal, the caller tried to move
     // forward or backwards in the deposit sequence.
     require!(
-        deposit_index == ctx.accounts.vault.get_deposits_for(mint_key)?,
+        deposit_index == ctx.accounts.vault.get_deposits_for(tranche_id)?,
         ErrorCode::InvalidDepositForVault
     );
 
@@ -172,16 +192,47 @@ pub fn handle(
 }
 
 pub fn verify_deposit_for_user(
-    history: &mut Account<History>,
+    history: &mut History,
     asset: &Asset,
     amount: u64,
 ) -> std::result::Result<(), ProgramError> {
     history.deposit(amount)?;
+    verify_deposit_limits(history, asset, amount)
+}
+
+/// Same as `verify_deposit_for_user`, but records the amount as a live deposit. Tranche tokens for
+/// a live deposit are minted up front, so the amount must not be visible to `claim`.
+pub fn verify_live_deposit_for_user(
+    history: &mut History,
+    asset: &Asset,
+    amount: u64,
+) -> std::result::Result<(), ProgramError> {
+    history.live_deposit(amount)?;
+    verify_deposit_limits(history, asset, amount)
+}
+
+fn verify_deposit_limits(
+    history: &History,
+    asset: &Asset,
+    amount: u64,
+) -> std::result::Result<(), ProgramError> {
+    // every deposit creates a receipt that must be walked when processing claims; prevent dust deposits
+    // and users spamming the receipt sequence.
+    if let Some(min_deposit) = asset.min_deposit {
+        require!(amount >= min_deposit, ErrorCode::DepositBelowMinimum);
+    }
+
+    if let Some(max_deposits_per_user) = asset.max_deposits_per_user {
+        require!(
+            history.total_deposits()? <= max_deposits_per_user,
+            ErrorCode::DepositExceedsMaxDepositsPerUser
+        );
+    }
 
     match asset.user_cap {
         Some(user_cap) => {
             require!(
-                history.cumulative <= user_cap,
+                history.total_cumulative()? <= user_cap,
                 ErrorCode::DepositExceedsUserCap
             );
 
@@ -190,3 +241,45 @@ pub fn verify_deposit_for_user(
         None => Ok(()),
     }
 }
+
+pub fn verify_deposit_gate<'info>(
+    vault: &Vault,
+    vault_key: &Pubkey,
+    user: &Pubkey,
+    remaining_accounts: &[AccountInfo<'info>],
+    proof: &[[u8; 32]],
+) -> ProgramResult {
+    match vault.deposit_gate {
+        DepositGate::Open => Ok(()),
+        DepositGate::Allowlist => {
+            let allowlist_info = remaining_accounts
+                .get(0)
+                .ok_or(ErrorCode::DepositorNotAllowlisted)?;
+            // the PDA of a user that was never allowlisted, or was removed, holds no data
+            require!(
+                !allowlist_info.data_is_empty(),
+                ErrorCode::DepositorNotAllowlisted
+            );
+            let allowlist = Account::<Allowlist>::try_from(allowlist_info)?;
+
+            let (allowlist_address, allowlist_bump) =
+                get_allowlist_address_and_bump_seed(vault_key, user);
+            assert_valid_pda(
+                allowlist_info,
+                &allowlist_address,
+                allowlist_bump == allowlist.bump,
+            )?;
+
+            Ok(())
+        }
+        DepositGate::MerkleRoot(root) => {
+            let leaf = keccak::hash(&user.to_bytes()).0;
+            require!(
+                verify_merkle_proof(proof, root, leaf),
+                ErrorCode::InvalidMerkleProof
+            );
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,229 @@
+use crate::{
+    deposit::{verify_deposit_gate, verify_live_deposit_for_user},
+    error::{ErrorCode, OraResult},
+    get_vault_nav::Valuation,
+    invest::{verify_leverage, Invest},
+    oracle::{verify_pool_price, PoolPrice},
+    state::{HasVault, History, State},
+    util::get_current_timestamp,
+    waterfall::compute_tranche_values,
+};
+use anchor_lang::prelude::*;
+use std::convert::TryFrom;
+
+pub trait LiveDeposit<'info>: Invest<'info> + Valuation<'info> {
+    /// tranche asset the depositor is adding to the vault
+    fn deposit_mint(&self) -> Pubkey;
+
+    /// tranche token that will be minted to the depositor
+    fn deposit_lp(&self) -> Pubkey;
+
+    /// user making the deposit
+    fn depositor(&self) -> Pubkey;
+
+    /// vault the deposit is made into
+    fn vault_key(&self) -> Pubkey;
+
+    /// depositor's history for the tranche
+    fn history_mut(&mut self) -> &mut History;
+
+    /// Map a single-sided deposit into the pool's (token A, token B) ordering
+    fn into_pool_amounts(&self, mint: &Pubkey, amount: u64) -> OraResult<(u64, u64)>;
+
+    /// Move the depositor's assets into the account the strategy invests from
+    fn transfer_deposit(&self, amount: u64) -> ProgramResult;
+
+    /// Mint tranche tokens to the depositor
+    fn mint_tranche_lp(&self, amount: u64) -> ProgramResult;
+}
+
+/// Allow a user to deposit a tranche asset after the vault has gone live. The deposit is invested
+/// immediately via the strategy's `Invest` implementation, and the user receives tranche LP priced
+/// at the tranche's current NAV:
+///
+///   NAV = tranche value / tranche tokens outstanding
+///
+/// where tranche value is what the waterfall entitles the tranche to out of the strategy's position,
+/// with returns accrued over the elapsed part of the invest period, same as for an early exit. tranche
+/// tokens outstanding is `total_invested`, which is then increased by the number of tranche tokens
+/// minted so that later withdrawals are priced against the full tranche supply.
+///
+/// @dev deposits are only accepted once the initial investment has been made and the tranche's
+///      claims have been processed; before that, the tranche supply is not final.
+///
+/// @dev the strategy's position is valued at the pool's spot price, so the vault must have an oracle
+///      to bound how far that price can be pushed before depositing. the alpha and beta oracle price
+///      accounts are expected as the first 2 remaining accounts.
+///
+/// @dev a single-sided deposit into an AMM is split across both sides of the pool. when the vault
+///      redeems, part of the deposit will show up as the other tranche's asset. the rebalance step
+///      is expected to account for this when computing the swap config.
+///
+/// @dev the vault's position after the deposit must stay within the vault's leverage band.
+///
+/// @dev the user must pass the vault's deposit gate, same as an initial deposit. an allowlist gate
+///      expects the user's allowlist PDA after the oracle accounts.
+///
+/// @dev the deposit counts towards the user's history for the tranche, so it is subject to the
+///      tranche's minimum deposit, max deposits per user and user cap. it is tracked separately from
+///      the user's initial deposits, which `claim` mints tranche tokens for.
+///
+pub fn handle<'info, T: LiveDeposit<'info> + PoolPrice + HasVault>(
+    ctx: Context<'_, '_, '_, 'info, T>,
+    tranche_id: u8,
+    history_bump: u8,
+    amount: u64,
+    min_tokens_back: u64,
+    proof: Vec<[u8; 32]>,
+) -> ProgramResult {
+    require!(
+        ctx.accounts.vault().state() == State::Live,
+        ErrorCode::InvalidVaultState
+    );
+    msg!("vault state verified");
+    require!(
+        ctx.accounts.vault().oracle.is_some(),
+        ErrorCode::OracleRequired
+    );
+
+    verify_deposit_gate(
+        ctx.accounts.vault(),
+        &ctx.accounts.vault_key(),
+        &ctx.accounts.depositor(),
+        ctx.remaining_accounts.get(2..).unwrap_or_default(),
+        &proof,
+    )?;
+    msg!("deposit gate verified");
+
+    let mint = ctx.accounts.deposit_mint();
+    let asset = ctx
+        .accounts
+        .vault()
+        .get_tranche_by_lp(&ctx.accounts.deposit_lp())?;
+    require!(asset.mint == mint, ErrorCode::PublicKeyMismatch);
+    require!(asset.tranche_id == tranche_id, ErrorCode::InvalidLpMint);
+    require!(
+        asset.claims_already_processed() && asset.total_invested > 0,
+        ErrorCode::InvalidVaultState
+    );
+
+    let pool_price = ctx.accounts.pool_price()?;
+    verify_pool_price(ctx.accounts.vault(), ctx.remaining_accounts, pool_price)?;
+    msg!("pool price verified");
+
+    let (alpha_value, beta_value) = ctx.accounts.strategy_value()?;
+    msg!("alpha value: {}, beta value: {}", alpha_value, beta_value);
+
+    let vault = ctx.accounts.vault();
+    let accrual_bps = vault.accrual_bps(get_current_timestamp()?);
+    msg!("accrual bps: {}", accrual_bps);
+    let tranche_value =
+        compute_tranche_values(vault, alpha_value, beta_value, pool_price, accrual_bps)?
+            [asset.tranche_id as usize];
+    msg!("tranche_value: {}", tranche_value);
+    msg!("total_invested: {}", asset.total_invested);
+
+    let lp_amount = compute_live_lp_amount(amount, asset.total_invested, tranche_value)?;
+    msg!("lp_amount: {}", lp_amount);
+    require!(lp_amount > 0, ErrorCode::DepositBelowMinimum);
+
+    let history = ctx.accounts.history_mut();
+    history.init_if_needed(history_bump);
+    verify_live_deposit_for_user(history, &asset, amount)?;
+
+    ctx.accounts.transfer_deposit(amount)?;
+
+    let (amount_a, amount_b) = ctx.accounts.into_pool_amounts(&mint, amount)?;
+    let (invested_a, invested_b) = ctx.accounts.invest(amount_a, amount_b, min_tokens_back)?;
+    msg!("invested a: {}, invested b: {}", invested_a, invested_b);
+
+    ctx.accounts.mint_tranche_lp(lp_amount)?;
+
+    ctx.accounts
+        .vault_mut()
+        .get_tranche_mut(asset.tranche_id)?
+        .add_live_deposit(lp_amount)?;
+    verify_leverage(ctx.accounts.vault(), ctx.accounts.pool_price()?)
+}
+
+/// Compute the number of tranche tokens to mint for a mid-cycle deposit.
+///
+/// @dev formula is amount / NAV, where NAV = value / supply. more succintly,
+///      lp_amount = (amount * supply) / value
+///
+pub fn compute_live_lp_amount(amount: u64, supply: u64, value: u64) -> OraResult<u64> {
+    require!(value > 0, ErrorCode::ExpectedNonzeroReturns);
+
+    let lp_amount = (amount as u128)
+        .checked_mul(supply as u128)
+        .ok_or_else(math_error!())?
+        .checked_div(value as u128)
+        .ok_or_else(math_error!())?;
+
+    Ok(u64::try_from(lp_amount).map_err(|_| math_error!()())?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        constant::MAX_BPS,
+        state::{Asset, ReturnTerms, Vault},
+    };
+
+    #[test]
+    fn test_compute_live_lp_amount() {
+        // at a NAV of 1, 1 tranche token per unit deposited
+        assert_eq!(compute_live_lp_amount(100, 1_000, 1_000).unwrap(), 100);
+        // the tranche gained 10%, so the deposit buys fewer tranche tokens, rounded down
+        assert_eq!(compute_live_lp_amount(100, 1_000, 1_100).unwrap(), 90);
+        // the tranche lost half its value
+        assert_eq!(compute_live_lp_amount(100, 1_000, 500).unwrap(), 200);
+
+        assert!(compute_live_lp_amount(100, 1_000, 0).is_err());
+        // more tranche tokens than fit in a u64
+        assert!(compute_live_lp_amount(u64::MAX, 2, 1).is_err());
+    }
+
+    #[test]
+    fn test_live_deposit_priced_at_waterfall_nav() {
+        let mut vault = Vault::default();
+        vault.num_tranches = 2;
+        vault.tranches[0] = Asset {
+            mint: Pubkey::new_unique(),
+            total_invested: 1_000,
+            return_terms: Some(ReturnTerms {
+                floor_bps: 500,
+                cap_bps: 500,
+                yield_share_bps: 0,
+                first_loss_buffer_bps: None,
+            }),
+            ..Asset::default()
+        };
+        vault.tranches[1] = Asset {
+            mint: Pubkey::new_unique(),
+            total_invested: 1_000,
+            ..Asset::default()
+        };
+
+        // the senior tranche is owed 1_050 and the junior tranche takes the rest of the 2_400 position.
+        // each deposit is worth 100 tranche tokens at its tranche's NAV, regardless of the pool's split.
+        let values = compute_tranche_values(&vault, 1_600, 800, (1, 1), MAX_BPS).unwrap();
+        assert_eq!(values, vec![1_050, 1_350]);
+        assert_eq!(
+            compute_live_lp_amount(105, vault.tranches[0].total_invested, values[0]).unwrap(),
+            100
+        );
+        assert_eq!(
+            compute_live_lp_amount(135, vault.tranches[1].total_invested, values[1]).unwrap(),
+            100
+        );
+
+        // the tranche tokens minted are added to the tranche's supply. with the deposit in the position, the
+        // senior tranche's NAV is unchanged and the junior tranche is not diluted.
+        vault.tranches[0].add_live_deposit(100).unwrap();
+        assert_eq!(vault.tranches[0].total_invested, 1_100);
+        let values = compute_tranche_values(&vault, 1_705, 800, (1, 1), MAX_BPS).unwrap();
+        assert_eq!(values, vec![1_155, 1_350]);
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{
+    constant::MAX_BPS,
+    error::{ErrorCode, OraResult},
+    get_vault_nav::Valuation,
+    invest::verify_leverage,
+    oracle::{verify_pool_price, PoolPrice},
+    redeem::Redeem,
+    state::{HasVault, State},
+    util::get_current_timestamp,
+    waterfall::{compute_tranche_values, convert},
+};
+use anchor_lang::prelude::*;
+
+pub trait EarlyExit<'info>: Redeem<'info> + Valuation<'info> {
+    /// tranche token the holder is exiting
+    fn exit_lp(&self) -> Pubkey;
+
+    /// Total amount of the strategy's LP backing the vault's position, including LP that cannot be
+    /// redeemed directly, e.g. LP staked in a farm.
+    fn total_strategy_lp(&self) -> OraResult<u64>;
+
+    /// Thaw the holder's tranche token account if needed and burn the holder's tranche tokens
+    fn burn_tranche_lp(&self, amount: u64) -> ProgramResult;
+
+    /// Move the redeemed (alpha, beta) amounts from the vault to the holder
+    fn transfer_exit(&self, alpha_amount: u64, beta_amount: u64) -> ProgramResult;
+}
+
+/// Allow a tranche token holder to exit while the vault is live, instead of waiting for the withdraw state.
+///
+///   - value the holder's tranche tokens at the tranche's current NAV, i.e. apply the waterfall to the
+///     strategy's position with returns accrued over the part of the invest period that has elapsed. an exiting holder cannot take more than their tranche is entitled to, so
+///     senior tranches stay protected for everyone else.
+///   - take the vault's early exit penalty. the penalty is left invested and credited to the tranche's
+///     remaining holders.
+///   - redeem the share of the strategy's LP worth the remainder and pay it out in-kind
+///
+/// @dev the strategy's LP is redeemed for both of the pool's assets, so the holder receives both of the
+///      strategy's assets in the pool's current ratio rather than the tranche's asset alone.
+///
+/// @dev LP staked in a farm counts towards the vault's position but cannot be redeemed directly. exits
+///      larger than the vault's unstaked LP fail until the strategist reverts farm tokens to base LP.
+///
+/// @dev the vault's position after the exit must stay within the vault's leverage band.
+///
+pub fn handle<'info, T: EarlyExit<'info> + PoolPrice + HasVault>(
+    ctx: Context<'_, '_, '_, 'info, T>,
+    lp_amount: u64,
+    min_token_a: u64,
+    min_token_b: u64,
+) -> ProgramResult {
+    let vault = ctx.accounts.vault();
+    require!(vault.state() == State::Live, ErrorCode::InvalidVaultState);
+    msg!("vault state verified");
+
+    let penalty_bps = vault
+        .early_exit_penalty_bps
+        .ok_or(ErrorCode::EarlyExitDisabled)?;
+    require!(vault.tranche_lp_transferable(), ErrorCode::TrancheLpFrozen);
+    require!(lp_amount > 0, ErrorCode::CannotWithdrawWithoutLpTokens);
+
+    let asset = vault.get_tranche_by_lp(&ctx.accounts.exit_lp())?;
+    require!(
+        lp_amount <= asset.total_invested,
+        ErrorCode::InsufficientTokenBalance
+    );
+
+    let pool_price = ctx.accounts.pool_price()?;
+    verify_pool_price(vault, ctx.remaining_accounts, pool_price)?;
+    msg!("pool price verified");
+
+    let (alpha_value, beta_value) = ctx.accounts.strategy_value()?;
+    msg!("alpha value: {}, beta value: {}", alpha_value, beta_value);
+
+    let accrual_bps = vault.accrual_bps(get_current_timestamp()?);
+    msg!("accrual bps: {}", accrual_bps);
+    let tranche_value =
+        compute_tranche_values(vault, alpha_value, beta_value, pool_price, accrual_bps)?
+            [asset.tranche_id as usize];
+    let (exit_value, penalty) =
+        compute_exit_amounts(tranche_value, asset.total_invested, lp_amount, penalty_bps)?;
+    msg!("exit value: {}, penalty: {}", exit_value, penalty);
+
+    // the holder's share of the strategy's position, in units of the alpha asset
+    let (beta_amount, alpha_amount) = pool_price;
+    let total_value = alpha_value
+        .checked_add(convert(beta_value, alpha_amount, beta_amount)?)
+        .ok_or_else(math_error!())?;
+    let payout = exit_value.checked_sub(penalty).ok_or_else(math_error!())?;
+    let payout_value = match asset.mint == vault.alpha().mint {
+        true => payout,
+        false => convert(payout, alpha_amount, beta_amount)?,
+    };
+    require!(total_value > 0, ErrorCode::ExpectedNonzeroReturns);
+
+    let redeem_lp = convert(ctx.accounts.total_strategy_lp()?, payout_value, total_value)?;
+    msg!("redeem lp: {}", redeem_lp);
+    require!(redeem_lp > 0, ErrorCode::ExpectedNonzeroReturns);
+
+    ctx.accounts.burn_tranche_lp(lp_amount)?;
+
+    let (alpha_out, beta_out) = ctx.accounts.redeem(redeem_lp, min_token_a, min_token_b)?;
+    msg!("alpha out: {}, beta out: {}", alpha_out, beta_out);
+
+    ctx.accounts.transfer_exit(alpha_out, beta_out)?;
+
+    ctx.accounts
+        .vault_mut()
+        .get_tranche_mut(asset.tranche_id)?
+        .exit_early(lp_amount, penalty)?;
+    verify_leverage(ctx.accounts.vault(), pool_price)
+}
+
+/// Value of lp_amount tranche tokens given the tranche's value and supply, and the early exit penalty on
+/// that value. returned as (exit value, penalty), in the tranche's asset.
+pub fn compute_exit_amounts(
+    tranche_value: u64,
+    supply: u64,
+    lp_amount: u64,
+    penalty_bps: u16,
+) -> OraResult<(u64, u64)> {
+    let exit_value = convert(tranche_value, lp_amount, supply)?;
+    let penalty = convert(exit_value, penalty_bps as u64, MAX_BPS)?;
+
+    Ok((exit_value, penalty))
+}
@@ -0,0 +1,124 @@
+use crate::{
+    error::{ErrorCode, OraResult},
+    oracle::PoolPrice,
+    state::{HasVault, State},
+    util::get_current_timestamp,
+    waterfall::{compute_tranche_values, convert},
+};
+use anchor_lang::prelude::*;
+use solana_program::program::set_return_data;
+
+pub trait Valuation<'info> {
+    /// Amount of each tranche asset the strategy's position can be redeemed for at this point in time,
+    /// as (alpha amount, beta amount). For an AMM, this is the vault's share of the pool's reserves.
+    fn strategy_value(&self) -> OraResult<(u64, u64)>;
+}
+
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct TrancheNav {
+    /// amount of the tranche's asset the tranche is entitled to
+    pub value: u64,
+    /// amount of the tranche's asset 1 whole tranche token is entitled to
+    pub lp_price: u64,
+}
+
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct VaultNav {
+    /// ordered by tranche id
+    pub tranches: Vec<TrancheNav>,
+}
+
+/// Read-only instruction to compute what each tranche is worth at this point in time. The result is
+/// borsh serialized `VaultNav` returned via `set_return_data`, so that clients can simulate the
+/// transaction to read the vault's NAV.
+///
+///   - value the strategy's position + any assets already redeemed from the strategy
+///   - apply the waterfall: in order of seniority, each tranche with return terms is entitled to its
+///     invested amount plus its return, pro-rated to the share of the invest period that has elapsed
+///     while the vault is live. the most junior tranche is entitled to whatever remains.
+///   - divide by the tranche token supply to get a per tranche token price
+///
+/// @dev assets are converted between the strategy's pair of assets at the strategy pool's spot price.
+///
+/// @dev once the vault is in the withdraw state, received amounts have been rebalanced and are used as is.
+///
+pub fn handle<'info, T: Valuation<'info> + PoolPrice + HasVault>(
+    ctx: Context<'_, '_, '_, 'info, T>,
+) -> ProgramResult {
+    let vault = ctx.accounts.vault();
+
+    let values = match vault.state() {
+        State::Live | State::Redeem | State::Rebalance => {
+            // before redeeming, the strategy holds the vault's position. after redeeming, the strategy
+            // position is 0 and the vault's assets are reflected in received.
+            let (alpha_position, beta_position) = ctx.accounts.strategy_value()?;
+            msg!(
+                "alpha position: {}, beta position: {}",
+                alpha_position,
+                beta_position
+            );
+
+            let (alpha_value, beta_value) = match vault.is_single_asset() {
+                // every tranche is backed by the same asset, so there is only 1 side to value
+                true => (
+                    alpha_position
+                        .checked_add(beta_position)
+                        .ok_or_else(math_error!())?
+                        .checked_add(vault.received_for(&vault.alpha().mint)?)
+                        .ok_or_else(math_error!())?,
+                    0,
+                ),
+                false => (
+                    alpha_position
+                        .checked_add(vault.received_for(&vault.alpha().mint)?)
+                        .ok_or_else(math_error!())?,
+                    beta_position
+                        .checked_add(vault.received_for(&vault.beta().mint)?)
+                        .ok_or_else(math_error!())?,
+                ),
+            };
+
+            compute_tranche_values(
+                vault,
+                alpha_value,
+                beta_value,
+                ctx.accounts.pool_price()?,
+                vault.accrual_bps(get_current_timestamp()?),
+            )?
+        }
+        State::Withdraw => vault
+            .tranches()
+            .iter()
+            .map(|tranche| tranche.received)
+            .collect(),
+        _ => return Err(ErrorCode::InvalidVaultState.into()),
+    };
+
+    let mut nav = VaultNav::default();
+    for (tranche, value) in vault.tranches().iter().zip(values.into_iter()) {
+        nav.tranches.push(TrancheNav {
+            value,
+            lp_price: compute_lp_price(value, tranche.total_invested, tranche.decimals)?,
+        });
+    }
+    msg!("nav: {:?}", nav);
+
+    set_return_data(&nav.try_to_vec()?);
+
+    Ok(())
+}
+
+/// Amount of the tranche's asset a whole tranche token is entitled to
+pub fn compute_lp_price(value: u64, supply: u64, decimals: u8) -> OraResult<u64> {
+    if supply == 0 {
+        return Ok(0);
+    }
+
+    let one = 10u64
+        .checked_pow(decimals as u32)
+        .ok_or_else(math_error!())?;
+
+    convert(value, one, supply)
+}
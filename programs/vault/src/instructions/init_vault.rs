@@ -1,11 +1,20 @@
 use crate::{
-    constant::{GLOBAL_STATE_SEED, SOL_DECIMALS, SOL_PUBKEY, VAULT_SEED, VAULT_STORE_SEED},
+    constant::{
+        GLOBAL_STATE_SEED, MAX_BPS, MAX_TRANCHES, SOL_DECIMALS, SOL_PUBKEY, TRANCHE_MINT_SEED,
+        VAULT_SEED, VAULT_STORE_SEED,
+    },
     error::ErrorCode,
-    state::{asset::Asset, vault::Vault, vault::VaultConfig, GlobalProtocolState},
-    util::create_or_allocate_account_raw,
+    metadata::{create_tranche_metadata, generate_tranche_metadata},
+    state::{
+        asset::Asset,
+        vault::{AssetConfig, Vault, VaultConfig},
+        GlobalProtocolState,
+    },
+    util::{create_or_allocate_account_raw, get_tranche_mint_address_and_bump_seed},
 };
 use anchor_lang::prelude::*;
-use anchor_spl::token::Mint;
+use anchor_spl::token::{initialize_mint, InitializeMint, Token};
+use mpl_token_metadata::pda::find_metadata_account;
 use solana_program::program_pack::Pack;
 use spl_token::state::Mint as SplMint;
 use std::mem::size_of;
@@ -55,48 +64,64 @@ pub struct InitializeVault<'info> {
     #[account(mut)]
     pub vault_store: UncheckedAccount<'info>,
 
-    /// CHECK: can be wrapped wSOL, so cannot use Mint
-    #[account(mut)]
-    pub alpha_mint: UncheckedAccount<'info>,
-
-    #[account(
-        mut,
-        // decimals checked in instruction
-        constraint = alpha_lp.freeze_authority.unwrap() == vault.key(),
-        constraint = alpha_lp.mint_authority.unwrap() == vault.key(),
-    )]
-    pub alpha_lp: Box<Account<'info, Mint>>,
-
-    /// CHECK: can be wrapped wSOL, so cannot use Mint
-    #[account(mut)]
-    pub beta_mint: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
 
-    #[account(
-        mut,
-        // decimals checked in instruction
-        constraint = beta_lp.freeze_authority.unwrap() == vault.key(),
-        constraint = beta_lp.mint_authority.unwrap() == vault.key(),
-    )]
-    pub beta_lp: Box<Account<'info, Mint>>,
+    pub token_program: Program<'info, Token>,
 
-    pub system_program: Program<'info, System>,
+    /// CHECK: validate expected vs actual address
+    #[account(address = mpl_token_metadata::id())]
+    pub token_metadata_program: UncheckedAccount<'info>,
 
     pub rent: Sysvar<'info, Rent>,
 }
 
 /// Create the vault struct with the initial configuration data.
 ///
-/// @dev: on initialize, the vault requires the same number of decimals between the asset
-/// and the LP. This enables a 1-1 exchange rate between asset and LP. this currently happens
-/// off-chain and is enforced via context.
+/// @dev: on initialize, the vault creates each tranche's LP mint as a PDA with the same number of decimals
+/// as the tranche's asset. This enables a 1-1 exchange rate between asset and LP. the vault is the mint and
+/// freeze authority, and each LP mint gets Metaplex metadata generated from the vault's pair of assets, the
+/// tranche and the vault's maturity.
 ///
 /// @dev: the strategy referenced by the vault should be created before initializing the vault.
-pub fn handle(
-    ctx: Context<InitializeVault>,
+///
+/// @dev: each tranche's (mint, lp, lp metadata) accounts are passed via remaining accounts, in the same
+/// order as the tranche configs. the lp and lp metadata accounts are uninitialized PDAs. tranches are ordered
+/// from most senior to most junior. the strategy pairs the most senior and most junior tranches' assets, so
+/// any tranche in between must be backed by one of them.
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, InitializeVault<'info>>,
     vault_bump: u8,
     vault_store_bump: u8,
     vault_config: VaultConfig,
 ) -> ProgramResult {
+    require!(
+        (vault_config.max_slippage_bps as u64) <= MAX_BPS,
+        ErrorCode::InvalidSlippageBps
+    );
+
+    if let Some(oracle) = vault_config.oracle {
+        require!(
+            (oracle.max_deviation_bps as u64) <= MAX_BPS,
+            ErrorCode::InvalidSlippageBps
+        );
+    }
+
+    if let Some(leverage) = vault_config.leverage {
+        require!(
+            leverage.min_ratio_bps > 0
+                && leverage.min_ratio_bps <= leverage.target_ratio_bps
+                && leverage.target_ratio_bps <= leverage.max_ratio_bps,
+            ErrorCode::InvalidLeverageConfig
+        );
+    }
+
+    if let Some(penalty_bps) = vault_config.early_exit_penalty_bps {
+        require!(
+            (penalty_bps as u64) <= MAX_BPS,
+            ErrorCode::InvalidEarlyExitPenalty
+        );
+    }
+
     msg!("init vault_store");
 
     // prevent screwing ourselves over with re-init attacks by verifying account data is zeroed out
@@ -125,27 +150,124 @@ pub fn handle(
         vault_store_signer_seeds,
     )?;
 
-    verify_mint_lp_decimals_match(
-        ctx.accounts.alpha_mint.to_account_info(),
-        ctx.accounts.alpha_lp.decimals,
-    )?;
-    let alpha = Asset::builder()
-        .mint(ctx.accounts.alpha_mint.key())
-        .lp(ctx.accounts.alpha_lp.key())
-        .asset_cap(vault_config.alpha.asset_cap)
-        .user_cap(vault_config.alpha.user_cap)
-        .build()?;
-
-    verify_mint_lp_decimals_match(
-        ctx.accounts.beta_mint.to_account_info(),
-        ctx.accounts.beta_lp.decimals,
-    )?;
-    let beta = Asset::builder()
-        .mint(ctx.accounts.beta_mint.key())
-        .lp(ctx.accounts.beta_lp.key())
-        .asset_cap(vault_config.beta.asset_cap)
-        .user_cap(vault_config.beta.user_cap)
-        .build()?;
+    verify_tranche_configs(&vault_config.tranches)?;
+    require!(
+        ctx.remaining_accounts.len() == vault_config.tranches.len() * 3,
+        ErrorCode::InvalidTrancheConfig
+    );
+
+    // tranche tokens mature once the vault's assets are redeemed from the strategy
+    let maturity = vault_config
+        .start_at
+        .checked_add(vault_config.deposit_duration)
+        .ok_or_else(math_error!())?
+        .checked_add(vault_config.invest_duration)
+        .ok_or_else(math_error!())?;
+    let pair = (
+        vault_config.tranches[0].symbol.as_str(),
+        vault_config.tranches[vault_config.tranches.len() - 1]
+            .symbol
+            .as_str(),
+    );
+
+    let mut tranches: Vec<Asset> = Vec::with_capacity(vault_config.tranches.len());
+    for (idx, (tranche_config, accounts)) in vault_config
+        .tranches
+        .iter()
+        .zip(ctx.remaining_accounts.chunks(3))
+        .enumerate()
+    {
+        let tranche_id = idx as u8;
+        let mint = &accounts[0];
+        let lp = &accounts[1];
+        let lp_metadata = &accounts[2];
+
+        let (lp_address, lp_bump) = get_tranche_mint_address_and_bump_seed(&vault_key, tranche_id);
+        require!(lp.key() == lp_address, ErrorCode::InvalidLpMint);
+        require!(
+            lp_metadata.key() == find_metadata_account(&lp_address).0,
+            ErrorCode::PublicKeyMismatch
+        );
+
+        msg!("init tranche {} lp mint", tranche_id);
+        let decimals = get_mint_decimals(mint.clone());
+        create_or_allocate_account_raw(
+            spl_token::id(),
+            true,
+            lp,
+            &ctx.accounts.rent.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            SplMint::LEN,
+            0,
+            // authority is signer
+            &[],
+            &[
+                TRANCHE_MINT_SEED.as_bytes(),
+                vault_key.as_ref(),
+                &tranche_id.to_le_bytes(),
+                &[lp_bump],
+            ],
+        )?;
+        initialize_mint(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                InitializeMint {
+                    mint: lp.clone(),
+                    rent: ctx.accounts.rent.to_account_info(),
+                },
+            ),
+            decimals,
+            &vault_key,
+            Some(&vault_key),
+        )?;
+
+        msg!("init tranche {} lp metadata", tranche_id);
+        let tranche_metadata = generate_tranche_metadata(
+            &vault_key,
+            tranche_id,
+            vault_config.tranches.len(),
+            pair,
+            &tranche_config.symbol,
+            maturity,
+            &vault_config.metadata_uri,
+        )?;
+        create_tranche_metadata(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            lp_metadata.clone(),
+            lp.clone(),
+            ctx.accounts.vault.to_account_info(),
+            ctx.accounts.authority.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.rent.to_account_info(),
+            vault_bump,
+            tranche_metadata,
+        )?;
+
+        let tranche = Asset::builder()
+            .tranche_id(tranche_id)
+            .seniority(tranche_config.seniority)
+            .return_terms(tranche_config.return_terms)
+            .mint(mint.key())
+            .lp(lp_address)
+            .decimals(decimals)
+            .asset_cap(tranche_config.asset_cap)
+            .user_cap(tranche_config.user_cap)
+            .min_deposit(tranche_config.min_deposit)
+            .max_deposits_per_user(tranche_config.max_deposits_per_user)
+            .build()?;
+        tranches.push(tranche);
+    }
+
+    // the strategy invests a pair of assets, the most senior and most junior tranches' assets
+    let alpha_mint = tranches[0].mint;
+    let beta_mint = tranches[tranches.len() - 1].mint;
+    require!(
+        tranches
+            .iter()
+            .all(|tranche| tranche.mint == alpha_mint || tranche.mint == beta_mint),
+        ErrorCode::InvalidTrancheConfig
+    );
 
     msg!("initializing vault");
     ctx.accounts.vault.init(
@@ -154,19 +276,45 @@ pub fn handle(
         ctx.accounts.vault_store.key(),
         vault_store_bump,
         vault_config,
-        alpha,
-        beta,
+        tranches,
     );
 
     Ok(())
 }
 
-pub fn verify_mint_lp_decimals_match<'a>(mint: AccountInfo<'a>, lp_decimals: u8) -> ProgramResult {
+// tranches are ordered by strictly increasing seniority. every tranche but the most junior has return terms;
+// the most junior tranche holds the residual claim.
+pub fn verify_tranche_configs(tranches: &[AssetConfig]) -> ProgramResult {
     require!(
-        get_mint_decimals(mint) == lp_decimals,
-        ErrorCode::DecimalMismatch
+        tranches.len() >= 2 && tranches.len() <= MAX_TRANCHES,
+        ErrorCode::InvalidTrancheConfig
     );
 
+    for pair in tranches.windows(2) {
+        require!(
+            pair[0].seniority < pair[1].seniority,
+            ErrorCode::InvalidTrancheConfig
+        );
+    }
+
+    let (junior, seniors) = tranches.split_last().unwrap();
+    require!(
+        junior.return_terms.is_none(),
+        ErrorCode::InvalidTrancheConfig
+    );
+
+    for tranche in seniors {
+        let terms = tranche
+            .return_terms
+            .ok_or(ErrorCode::InvalidTrancheConfig)?;
+        require!(
+            terms.floor_bps <= terms.cap_bps
+                && (terms.yield_share_bps as u64) <= MAX_BPS
+                && (terms.first_loss_buffer_bps.unwrap_or_default() as u64) <= MAX_BPS,
+            ErrorCode::InvalidTrancheConfig
+        );
+    }
+
     Ok(())
 }
 
@@ -1,6 +1,9 @@
 use crate::{
+    constant::MAX_BPS,
     error::{ErrorCode, OraResult},
-    state::{HasVault, State},
+    oracle::{verify_pool_price, PoolPrice},
+    state::{HasVault, State, Vault},
+    waterfall::convert,
 };
 use anchor_lang::prelude::*;
 
@@ -11,10 +14,13 @@ pub trait Invest<'info> {
     /// @dev Because this functionality is can only be invoked by a particular keypair, we allow
     ///      to decide how much of each asset is invested.
     ///
+    /// @dev implementations record the invested amounts on the vault and then call `verify_leverage`
+    ///      so that the strategist cannot invest outside of the vault's leverage band.
+    ///
     fn invest(&mut self, amount_a: u64, amount_b: u64, min_out: u64) -> OraResult<(u64, u64)>;
 }
 
-pub fn handle<'info, T: Invest<'info> + HasVault>(
+pub fn handle<'info, T: Invest<'info> + PoolPrice + HasVault>(
     ctx: Context<'_, '_, '_, 'info, T>,
     investable_a: u64,
     investable_b: u64,
@@ -27,20 +33,27 @@ pub fn handle<'info, T: Invest<'info> + HasVault>(
     msg!("vault state verified");
 
     // no matter how many times the authority calls this instruction when the vault has 1 tranche with 0 deposits,
-    // we will set invested = 0 and excess = deposited on both sides so that we can process claims correctly.
+    // we will set invested = 0 and excess = deposited on every tranche so that we can process claims correctly.
     // this will then allow people to withdraw funds.
-    if !ctx.accounts.vault().has_dual_deposits() {
+    if !ctx.accounts.vault().has_deposits_in_all_tranches() {
         msg!("1 tranche has no deposits");
 
-        let mutable_vault = ctx.accounts.vault_mut();
-        mutable_vault.get_alpha_mut()?.make_investment(0)?;
-        mutable_vault.get_beta_mut()?.make_investment(0)?;
+        for tranche in ctx.accounts.vault_mut().tranches_mut() {
+            tranche.make_investment(0)?;
+        }
 
         return Ok(());
     }
 
-    // both sides of the vault must have deposits in order to inveest
-    msg!("verified deposits are on both sides");
+    // every tranche of the vault must have deposits in order to inveest
+    msg!("verified deposits are in all tranches");
+
+    verify_pool_price(
+        ctx.accounts.vault(),
+        ctx.remaining_accounts,
+        ctx.accounts.pool_price()?,
+    )?;
+    msg!("pool price verified");
 
     // todo: rename; beta is junior, alpha is senior
     let (invested_alpha, invested_beta) =
@@ -55,3 +68,56 @@ pub fn handle<'info, T: Invest<'info> + HasVault>(
 
     Ok(())
 }
+
+/// Verify the senior-to-junior value ratio of the vault's investments is within the vault's leverage band.
+/// Tranches backed by the beta asset are valued in the alpha asset at the pool price, (beta amount, alpha
+/// amount). Vaults without a leverage config accept any ratio.
+///
+/// @dev invested amounts are cumulative, so the check applies to the vault's total position after each
+///      investment.
+///
+pub fn verify_leverage(vault: &Vault, pool_price: (u64, u64)) -> ProgramResult {
+    let leverage = match vault.leverage {
+        Some(leverage) => leverage,
+        None => return Ok(()),
+    };
+
+    let (beta_amount, alpha_amount) = pool_price;
+    let alpha_mint = vault.alpha().mint;
+
+    let mut values = Vec::with_capacity(vault.tranches().len());
+    for tranche in vault.tranches() {
+        values.push(match tranche.mint == alpha_mint {
+            true => tranche.total_invested,
+            false => convert(tranche.total_invested, alpha_amount, beta_amount)?,
+        });
+    }
+
+    let (junior_value, senior_values) =
+        values.split_last().ok_or(ErrorCode::InvalidTrancheConfig)?;
+    let mut senior_value: u64 = 0;
+    for value in senior_values {
+        senior_value = senior_value.checked_add(*value).ok_or_else(math_error!())?;
+    }
+
+    let ratio_bps = compute_leverage_ratio_bps(senior_value, *junior_value)?;
+    msg!(
+        "leverage ratio bps: {}, target: {}",
+        ratio_bps,
+        leverage.target_ratio_bps
+    );
+
+    require!(
+        ratio_bps >= leverage.min_ratio_bps as u64 && ratio_bps <= leverage.max_ratio_bps as u64,
+        ErrorCode::LeverageRatioOutOfBounds
+    );
+
+    Ok(())
+}
+
+/// senior value / junior value, in basis points
+pub fn compute_leverage_ratio_bps(senior_value: u64, junior_value: u64) -> OraResult<u64> {
+    require!(junior_value > 0, ErrorCode::LeverageRatioOutOfBounds);
+
+    convert(senior_value, MAX_BPS, junior_value)
+}
@@ -1,33 +1,61 @@
+pub mod add_to_allowlist;
+pub mod cancel_vault;
 pub mod claim;
+pub mod claim_and_withdraw;
 pub mod convert_lp;
 pub mod deposit;
+pub mod deposit_live;
+pub mod early_exit;
+pub mod get_vault_nav;
 pub mod harvest;
 pub mod init_global_protocol_state;
 pub mod init_strategy;
 pub mod init_user_farm;
 pub mod init_vault;
 pub mod invest;
+pub mod opt_into_rollover;
 pub mod process_claims;
+pub mod process_rollover;
 pub mod rebalance;
 pub mod redeem;
+pub mod remove_from_allowlist;
 pub mod revert_lp;
+pub mod set_keeper;
+pub mod set_rollover_vault;
+pub mod set_tranche_lp_frozen;
 pub mod swap;
+pub mod thaw_tranche_lp;
 pub mod transition_vault;
 pub mod withdraw;
+pub mod withdraw_rollover;
 
+pub use add_to_allowlist::*;
+pub use cancel_vault::*;
 pub use claim::*;
+pub use claim_and_withdraw::*;
 pub use convert_lp::*;
 pub use deposit::*;
+pub use deposit_live::*;
+pub use early_exit::*;
+pub use get_vault_nav::*;
 pub use harvest::*;
 pub use init_global_protocol_state::*;
 pub use init_strategy::*;
 pub use init_user_farm::*;
 pub use init_vault::*;
 pub use invest::*;
+pub use opt_into_rollover::*;
 pub use process_claims::*;
+pub use process_rollover::*;
 pub use rebalance::*;
 pub use redeem::*;
+pub use remove_from_allowlist::*;
 pub use revert_lp::*;
+pub use set_keeper::*;
+pub use set_rollover_vault::*;
+pub use set_tranche_lp_frozen::*;
 pub use swap::*;
+pub use thaw_tranche_lp::*;
 pub use transition_vault::*;
 pub use withdraw::*;
+pub use withdraw_rollover::*;
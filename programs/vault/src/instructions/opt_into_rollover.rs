@@ -0,0 +1,163 @@
+use crate::{
+    constant::{GLOBAL_STATE_SEED, ROLLOVER_SEED, VAULT_SEED},
+    error::ErrorCode,
+    state::{vault::State, vault::Vault, GlobalProtocolState, Rollover},
+    util::set_token_account_frozen,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{burn, Burn, Mint, Token, TokenAccount};
+use std::mem::size_of;
+
+#[derive(Accounts)]
+#[instruction(tranche_id: u8)]
+pub struct OptIntoRollover<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: read-only account to validate vault address
+    pub authority: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [GLOBAL_STATE_SEED.as_bytes()],
+        bump,
+    )]
+    pub global_protocol_state: Box<Account<'info, GlobalProtocolState>>,
+
+    #[account(
+        seeds = [
+            VAULT_SEED.as_bytes(),
+            authority.key().to_bytes().as_ref()
+        ],
+        bump,
+        constraint = vault.authority == authority.key(),
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        init_if_needed,
+        seeds = [
+            ROLLOVER_SEED.as_bytes(),
+            vault.key().to_bytes().as_ref(),
+            &tranche_id.to_le_bytes(),
+            payer.key().to_bytes().as_ref(),
+        ],
+        bump,
+        payer = payer,
+        space = 8 + size_of::<Rollover>(),
+    )]
+    pub rollover: Box<Account<'info, Rollover>>,
+
+    pub mint: Box<Account<'info, Mint>>,
+
+    #[account(mut)]
+    pub lp: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint = source_lp.mint == lp.key(),
+        constraint = source_lp.owner == payer.key(),
+    )]
+    pub source_lp: Box<Account<'info, TokenAccount>>,
+
+    /// =============== PROGRAM ACCOUNTS ===============
+    pub system_program: Program<'info, System>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Commit tranche tokens to the rollover fund. Instead of withdrawing at the end of the vault's
+/// lifecycle, the underlying assets these tranche tokens are entitled to will be deposited into
+/// the vault's next epoch via `process_rollover`.
+///
+/// The tranche tokens are burned immediately. This is safe because withdrawals are priced against
+/// `total_invested` rather than the LP mint's supply. Tranche tokens that are still frozen are thawed
+/// first, unless the vault authority has frozen tranche tokens in an emergency.
+///
+/// optionally specify amount to commit. otherwise, default is to commit all of the user's LP tokens.
+///
+pub fn handle(
+    ctx: Context<OptIntoRollover>,
+    tranche_id: u8,
+    rollover_bump: u8,
+    amount: u64,
+) -> ProgramResult {
+    // tranche tokens only exist after the vault is live. once the vault is in the withdraw state, it's
+    // too late to opt into the rollover fund.
+    let state = ctx.accounts.vault.state;
+    require!(
+        state == State::Live || state == State::Redeem || state == State::Rebalance,
+        ErrorCode::InvalidVaultState
+    );
+
+    require!(
+        ctx.accounts.vault.rollover_vault.is_some(),
+        ErrorCode::InvalidRolloverVault
+    );
+
+    let asset = ctx.accounts.vault.get_tranche(tranche_id)?;
+    require!(
+        asset.mint == ctx.accounts.mint.key(),
+        ErrorCode::PublicKeyMismatch
+    );
+    require!(ctx.accounts.lp.key() == asset.lp, ErrorCode::InvalidLpMint);
+
+    ctx.accounts
+        .rollover
+        .init_if_needed(rollover_bump, &ctx.accounts.payer.key());
+    require!(
+        !ctx.accounts.rollover.processed,
+        ErrorCode::RolloverAlreadyProcessed
+    );
+
+    let num_lp_tokens_for_payer = ctx.accounts.source_lp.amount;
+    require!(
+        num_lp_tokens_for_payer > 0,
+        ErrorCode::CannotWithdrawWithoutLpTokens
+    );
+
+    // default to committing all LP tokens if none are specified
+    let lp_amount = match amount {
+        amount if amount > 0 => amount,
+        _ => num_lp_tokens_for_payer,
+    };
+    msg!("lp_amount: {}", lp_amount);
+
+    // tranche tokens minted before every tranche's claims were processed are still frozen, thaw them lazily
+    // like withdraw does. tokens under the authority's emergency freeze stay frozen and cannot be burned.
+    if ctx.accounts.source_lp.is_frozen() && ctx.accounts.vault.tranche_lp_transferable() {
+        let authority = ctx.accounts.authority.key();
+        let vault_signer_seeds = generate_vault_seeds!(authority.as_ref(), ctx.accounts.vault.bump);
+        set_token_account_frozen(
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.source_lp.to_account_info(),
+            ctx.accounts.lp.to_account_info(),
+            ctx.accounts.vault.to_account_info(),
+            vault_signer_seeds,
+            false,
+        )?;
+    }
+
+    burn(ctx.accounts.into_burn_reserve_token_context(), lp_amount)?;
+    ctx.accounts.rollover.add_lp(lp_amount)?;
+
+    Ok(())
+}
+
+impl<'info> OptIntoRollover<'info> {
+    pub fn into_burn_reserve_token_context(&self) -> CpiContext<'_, '_, '_, 'info, Burn<'info>> {
+        let cpi_program = self.token_program.to_account_info();
+
+        let cpi_accounts = Burn {
+            /// lp mint
+            mint: self.lp.to_account_info(),
+            /// payer ATA for lp mint
+            to: self.source_lp.to_account_info(),
+            /// payer committing tokens to the rollover fund
+            authority: self.payer.to_account_info(),
+        };
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
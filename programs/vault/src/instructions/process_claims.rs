@@ -1,6 +1,7 @@
 use crate::{
     constant::{GLOBAL_STATE_SEED, VAULT_SEED},
     error::ErrorCode,
+    math::compute_claim_amount,
     state::{vault::Vault, GlobalProtocolState, History, Receipt},
     util::{
         assert_valid_pda, get_history_address_and_bump_seed, get_receipt_address_and_bump_seed,
@@ -37,9 +38,6 @@ pub struct ProcessClaims<'info> {
         // constraint = vault.strategist == payer.key()
     )]
     pub vault: Box<Account<'info, Vault>>,
-
-    /// CHECK: read-only account to process claims for 1 side on the vault
-    pub mint: UncheckedAccount<'info>,
 }
 
 #[derive(Accounts)]
@@ -66,6 +64,9 @@ pub struct ProcessClaimInfo<'info> {
 ///       we'll need to invoke this instruction multiple times. The instruction saves intermediary
 ///       state and won't reprocess information when finished.
 ///   
+///  @dev deposits made by the rollover fund do not have receipts and are not walked here. see
+///       `History::settle_rollover`.
+///
 ///  @dev after the vaults are finalized and funds are returned, we can close history and claim
 ///       accounts to retrieve the rent funds. These can be returned to users or redirected to
 ///       the protocol as an additional fee.
@@ -86,11 +87,14 @@ pub struct ProcessClaimInfo<'info> {
 ///  relate n & n+1 accounts for user (deposit, history) -> update vault
 ///
 
-pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, ProcessClaims<'info>>) -> ProgramResult {
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, ProcessClaims<'info>>,
+    tranche_id: u8,
+) -> ProgramResult {
     // @dev: we intentionally avoid checking vault state at this point. we want to process claims
 
     let vault_key = ctx.accounts.vault.key();
-    let asset_to_process = ctx.accounts.vault.get_asset_mut(ctx.accounts.mint.key)?;
+    let asset_to_process = ctx.accounts.vault.get_tranche_mut(tranche_id)?;
 
     // no more claims to process, exit early. note that we cannot check if asset->excess == 0,
     // because it's possible the entire balance of a single tranche can be invested.
@@ -111,7 +115,9 @@ pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, ProcessClaims<'info>>) -> P
         .unwrap_or(asset_to_process.deposits);
     msg!("start: {}", start);
 
-    let asset_amount_invested = asset_to_process.invested;
+    // rollover deposits are invested first and settled pro-rata on claim, so only walk the
+    // receipts against the amount invested on behalf of direct depositors.
+    let asset_amount_invested = asset_to_process.user_invested();
     msg!("asset_amount_invested: {}", asset_amount_invested);
 
     let remaining_accounts = ctx.remaining_accounts;
@@ -135,7 +141,7 @@ pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, ProcessClaims<'info>>) -> P
         // todo: do we also need to check discriminator?
         let receipt_info = &process_claim_info.receipt.to_account_info();
         let (curr_receipt_address, curr_receipt_bump) =
-            get_receipt_address_and_bump_seed(&vault_key, &asset_to_process.mint, claim_idx);
+            get_receipt_address_and_bump_seed(&vault_key, tranche_id, claim_idx);
         assert_valid_pda(
             receipt_info,
             &curr_receipt_address,
@@ -145,7 +151,7 @@ pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, ProcessClaims<'info>>) -> P
         let history_info = &process_claim_info.history.to_account_info();
         let (curr_history_address, curr_history_bump) = get_history_address_and_bump_seed(
             &vault_key,
-            &asset_to_process.mint,
+            tranche_id,
             &process_claim_info.receipt.depositor,
         );
         assert_valid_pda(
@@ -160,7 +166,8 @@ pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, ProcessClaims<'info>>) -> P
         msg!("deposit_amount: {}", deposit_amount);
 
         let (amount, is_complete) =
-            compute_claim_amount(asset_amount_invested, cumulative_amount, deposit_amount)?;
+            compute_claim_amount(asset_amount_invested, cumulative_amount, deposit_amount)
+                .ok_or_else(math_error!())?;
 
         process_claim_info.history.add_claim(amount)?;
         process_claim_info
@@ -193,40 +200,3 @@ pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, ProcessClaims<'info>>) -> P
 
     Ok(())
 }
-
-// todo: is this right? verify with bpf tests + simulation
-pub fn compute_claim_amount(
-    invested: u64,
-    cumulative: u64,
-    deposited: u64,
-) -> Result<(u64, bool), ProgramError> {
-    let cumulative_after_deposit = cumulative
-        .checked_add(deposited)
-        .ok_or_else(math_error!())?;
-    msg!("cumulative_after_deposit: {}", cumulative_after_deposit);
-
-    if cumulative == invested {
-        msg!("cumulative == invested");
-        // cumulative == total amount invested, before investment. return full amount and denote finished processing
-        // == true because we hit the cross-over point exactly.
-        return Ok((deposited, true));
-    } else if cumulative > invested {
-        msg!("cumulative > invested");
-        // cumulative > total amount invested, before investment. return full amount and denote finished processing
-        // == false. we have not yet hit the cross-over point.
-        return Ok((deposited, false));
-    } else if cumulative_after_deposit > invested {
-        msg!("cumulative_after_deposit >= invested");
-        // return marginal amount; we found cross over point for claims
-        let claim_amount = cumulative_after_deposit
-            .checked_sub(invested)
-            .ok_or_else(math_error!())?;
-        msg!("claim_amount: {}", claim_amount);
-
-        return Ok((claim_amount, true));
-    }
-
-    msg!("claim_amount: {}", 0);
-
-    Ok((0, true))
-}
@@ -0,0 +1,192 @@
+use crate::{
+    constant::{GLOBAL_STATE_SEED, HISTORY_SEED, ROLLOVER_SEED, VAULT_SEED, VAULT_STORE_SEED},
+    error::ErrorCode,
+    math::compute_withdrawal_amount,
+    state::{vault::State, vault::Vault, GlobalProtocolState, History, Rollover},
+    util::transfer_with_verified_ata,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token};
+use std::mem::size_of;
+
+#[derive(Accounts)]
+#[instruction(tranche_id: u8)]
+pub struct ProcessRollover<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: read-only account to validate vault address
+    pub authority: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [GLOBAL_STATE_SEED.as_bytes()],
+        bump,
+    )]
+    pub global_protocol_state: Box<Account<'info, GlobalProtocolState>>,
+
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED.as_bytes(),
+            authority.key().to_bytes().as_ref()
+        ],
+        bump,
+        constraint = vault.authority == authority.key(),
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    /// CHECK: verified via instruction access_control
+    #[account(mut)]
+    pub vault_store: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            ROLLOVER_SEED.as_bytes(),
+            vault.key().to_bytes().as_ref(),
+            &tranche_id.to_le_bytes(),
+            depositor.key().to_bytes().as_ref(),
+        ],
+        bump,
+        constraint = rollover.depositor == depositor.key(),
+    )]
+    pub rollover: Box<Account<'info, Rollover>>,
+
+    /// CHECK: read-only account to validate rollover and history addresses
+    pub depositor: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = vault.rollover_vault == Some(next_vault.key()) @ ErrorCode::InvalidRolloverVault,
+    )]
+    pub next_vault: Box<Account<'info, Vault>>,
+
+    /// CHECK: verified via instruction access_control
+    #[account(mut)]
+    pub next_vault_store: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        seeds = [
+            HISTORY_SEED.as_bytes(),
+            next_vault.key().to_bytes().as_ref(),
+            &tranche_id.to_le_bytes(),
+            depositor.key().to_bytes().as_ref(),
+        ],
+        bump,
+        payer = payer,
+        space = 8 + size_of::<History>(),
+    )]
+    pub next_history: Box<Account<'info, History>>,
+
+    /// CHECK: can be wrapped wSOL, so not a Mint
+    #[account(mut)]
+    pub mint: UncheckedAccount<'info>,
+
+    /// read-only, only used to verify the tranche and its decimals
+    pub lp: Box<Account<'info, Mint>>,
+
+    /// CHECK: can be wrapped wSOL, so not a TokenAccount. Validation done via Token Program CPI.
+    #[account(mut)]
+    pub source_ata: UncheckedAccount<'info>,
+
+    /// CHECK: create and validate JIT in instruction. Validation done via Token Program CPI.
+    #[account(mut)]
+    pub destination_ata: UncheckedAccount<'info>,
+
+    /// =============== PROGRAM ACCOUNTS ===============
+    pub system_program: Program<'info, System>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// CHECK: validate expected vs actual address
+    #[account(address = spl_associated_token_account::ID)]
+    pub ata_program: UncheckedAccount<'info>,
+
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Carry a user's rollover position into the vault's next epoch. Anyone can crank this instruction
+/// once the current vault reaches the withdraw state, as long as the next vault is accepting deposits.
+///
+///   - compute the underlying assets the committed tranche tokens are entitled to, same as `withdraw`
+///   - transfer those assets from the current vault store to the next vault store
+///   - record the deposit on the user's history for the next vault
+///
+/// The rollover fund deposits do not create receipts. Instead, they are tracked separately on the next
+/// vault's asset and take priority when the next vault invests. Any portion that is not invested is
+/// refunded pro-rata when the user claims from the next vault.
+///
+/// @dev the position is carried into the tranche with the same id on the next vault. `set_rollover_vault`
+///      verifies both vaults' tranches are backed by the same assets.
+///
+/// @dev if the next vault can no longer take the position, `withdraw_rollover` pays it out to the user.
+///
+pub fn handle(ctx: Context<ProcessRollover>, tranche_id: u8, history_bump: u8) -> ProgramResult {
+    ctx.accounts.vault.try_transition()?;
+    require!(
+        ctx.accounts.vault.state == State::Withdraw,
+        ErrorCode::InvalidVaultState
+    );
+    require!(
+        ctx.accounts.next_vault.state == State::Deposit,
+        ErrorCode::InvalidVaultState
+    );
+    msg!("vault states verified");
+
+    let asset = ctx.accounts.vault.get_tranche(tranche_id)?;
+    require!(
+        asset.mint == ctx.accounts.mint.key(),
+        ErrorCode::PublicKeyMismatch
+    );
+    require!(ctx.accounts.lp.key() == asset.lp, ErrorCode::InvalidLpMint);
+    require!(
+        !ctx.accounts.rollover.processed,
+        ErrorCode::RolloverAlreadyProcessed
+    );
+
+    let lp_amount = ctx.accounts.rollover.lp_amount;
+    msg!("lp_amount: {}", lp_amount);
+    require!(lp_amount > 0, ErrorCode::CannotWithdrawWithoutLpTokens);
+
+    let rollover_amount = compute_withdrawal_amount(
+        asset.received,
+        asset.total_invested,
+        lp_amount,
+        ctx.accounts.lp.decimals,
+    )
+    .ok_or_else(math_error!())?;
+    msg!("rollover_amount: {}", rollover_amount);
+
+    let vault_key = ctx.accounts.vault.key();
+    let vault_store_signer_seeds =
+        generate_vault_store_seeds!(*vault_key.as_ref(), ctx.accounts.vault.vault_store_bump);
+
+    transfer_with_verified_ata(
+        ctx.accounts.source_ata.to_account_info(),
+        ctx.accounts.destination_ata.to_account_info(),
+        ctx.accounts.next_vault_store.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.ata_program.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.rent.to_account_info(),
+        &[],
+        ctx.accounts.vault_store.to_account_info(),
+        vault_store_signer_seeds,
+        rollover_amount,
+    )?;
+
+    ctx.accounts.next_history.init_if_needed(history_bump);
+    ctx.accounts.next_history.rollover_deposit(rollover_amount)?;
+
+    ctx.accounts
+        .next_vault
+        .get_tranche_mut(tranche_id)?
+        .add_rollover_deposit(rollover_amount)?;
+
+    ctx.accounts.rollover.mark_processed();
+
+    Ok(())
+}
@@ -1,6 +1,8 @@
 use crate::{
     error::{ErrorCode, OraResult},
-    state::{HasVault, State, Vault},
+    oracle::{verify_pool_price, PoolPrice},
+    state::{HasVault, State},
+    waterfall::settle_vault,
 };
 use anchor_lang::prelude::*;
 
@@ -9,7 +11,8 @@ use anchor_lang::prelude::*;
 pub struct SwapConfig {
     /// max number of tokens to put into the pool
     pub max_in: u64,
-    /// min number of tokens expected out of the pool
+    /// min number of tokens expected out of the pool. adapters will raise this to the spot amount minus
+    /// the vault's max slippage if the strategist's value is lower.
     pub min_out: u64,
     /// swap direction: alpha_to_beta swaps alpha for beta, !alpha_to_beta swaps beta for alpha
     /// this allows the strategist to compute swap info at the SDK layer and then pass that info
@@ -22,7 +25,13 @@ pub trait Rebalance<'info> {
     fn rebalance(&mut self, swap_config: SwapConfig) -> OraResult<(u64, u64, u64, u64)>;
 }
 
-pub fn handle<'info, T: Rebalance<'info> + HasVault>(
+/// Settle the vault's redeemed assets between its tranches. The strategist can optionally swap between
+/// the strategy's pair of assets so that each asset covers what the tranches it backs are owed. After
+/// any swap, received amounts are settled via the waterfall and the vault is marked as rebalanced.
+///
+/// @dev settlement can be repeated while the vault is in the rebalance state, e.g. after another swap.
+///
+pub fn handle<'info, T: Rebalance<'info> + PoolPrice + HasVault>(
     ctx: Context<'_, '_, '_, 'info, T>,
     swap_config: Option<SwapConfig>,
 ) -> ProgramResult {
@@ -32,70 +41,35 @@ pub fn handle<'info, T: Rebalance<'info> + HasVault>(
     );
     msg!("vault state verified");
 
-    // exit early without a valid swap config; this can happen if the redeemed assets naturally hit the expected returns distribution
-    let _swap_config = match swap_config {
-        Some(x) => x,
-        None => return Ok(()),
-    };
+    verify_pool_price(
+        ctx.accounts.vault(),
+        ctx.remaining_accounts,
+        ctx.accounts.pool_price()?,
+    )?;
+    msg!("pool price verified");
 
-    // only perform swap and update received values if max_in is non-zero and positive
-    if _swap_config.max_in == 0 {
-        return Ok(());
-    }
-
-    let alpha_to_beta = _swap_config.alpha_to_beta;
-    let (alpha_before, alpha_after, beta_before, beta_after) =
-        ctx.accounts.rebalance(_swap_config)?;
-
-    update_vault_after_rebalance(
-        ctx.accounts.vault_mut(),
-        alpha_to_beta,
-        alpha_before,
-        alpha_after,
-        beta_before,
-        beta_after,
-    )
-}
-
-// update received amounts for vault tranches
-fn update_vault_after_rebalance<'info>(
-    vault: &mut Vault,
-    alpha_to_beta: bool,
-    alpha_before: u64,
-    alpha_after: u64,
-    beta_before: u64,
-    beta_after: u64,
-) -> ProgramResult {
-    match alpha_to_beta {
-        // swapped for alpha for beta
-        true => {
-            // before > after => before - after > 0
-            let alpha_delta = alpha_before
-                .checked_sub(alpha_after)
-                .ok_or_else(math_error!())?;
-            vault.get_alpha_mut()?.sub_receipt(alpha_delta)?;
+    // only perform swap and update received values with a valid swap config where max_in is non-zero and
+    // positive. there might not be one if the redeemed assets naturally hit the expected returns distribution.
+    if let Some(_swap_config) = swap_config.filter(|config| config.max_in > 0) {
+        // tranches of a single-asset vault are settled by the waterfall alone, there is nothing to swap
+        require!(
+            !ctx.accounts.vault().is_single_asset(),
+            ErrorCode::InvalidVaultState
+        );
 
-            // after > before => after - before > 0
-            let beta_delta = beta_after
-                .checked_sub(beta_before)
-                .ok_or_else(math_error!())?;
-            vault.get_beta_mut()?.add_receipt(beta_delta)?;
-        }
-        // ^_swap_config.alpha_to_beta; swapped for beta for alpha
-        false => {
-            // after > before => after - before > 0
-            let alpha_delta = alpha_after
-                .checked_sub(alpha_before)
-                .ok_or_else(math_error!())?;
-            vault.get_alpha_mut()?.add_receipt(alpha_delta)?;
+        let alpha_to_beta = _swap_config.alpha_to_beta;
+        let (alpha_before, alpha_after, beta_before, beta_after) =
+            ctx.accounts.rebalance(_swap_config)?;
 
-            // before > after => before - after > 0
-            let beta_delta = beta_before
-                .checked_sub(beta_after)
-                .ok_or_else(math_error!())?;
-            vault.get_beta_mut()?.sub_receipt(beta_delta)?;
-        }
+        ctx.accounts.vault_mut().record_swap(
+            alpha_to_beta,
+            alpha_before,
+            alpha_after,
+            beta_before,
+            beta_after,
+        )?;
     }
 
-    Ok(())
+    let pool_price = ctx.accounts.pool_price()?;
+    settle_vault(ctx.accounts.vault_mut(), pool_price)
 }
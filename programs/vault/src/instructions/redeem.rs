@@ -1,7 +1,8 @@
 use anchor_spl::token::TokenAccount;
 use {
     crate::{
-        error::ErrorCode,
+        error::{ErrorCode, OraResult},
+        oracle::{verify_pool_price, PoolPrice},
         state::{HasVault, State},
     },
     anchor_lang::prelude::*,
@@ -28,7 +29,20 @@ pub fn verify_received(
 }
 
 pub trait Redeem<'info> {
-    fn redeem(&mut self, min_token_a: u64, min_token_b: u64) -> ProgramResult;
+    /// Amount of the strategy's LP held by the vault that can be redeemed
+    fn strategy_lp(&self) -> u64;
+
+    /// Burn lp_amount of the strategy's LP for the underlying assets, returned as (alpha amount, beta amount)
+    /// received by the vault.
+    ///
+    /// @dev implementations do not update the vault's received amounts; callers decide who the assets belong to.
+    ///
+    fn redeem(
+        &mut self,
+        lp_amount: u64,
+        min_token_a: u64,
+        min_token_b: u64,
+    ) -> OraResult<(u64, u64)>;
 }
 
 ///  Based on a vault's strategy, we will deserialize the related account state and call
@@ -36,7 +50,7 @@ pub trait Redeem<'info> {
 ///  actions. In the case of an AMM, it will burn an LP token in exchange for a relative
 ///  nuber of the pool's tokens.
 ///
-pub fn handle<'info, T: Redeem<'info> + HasVault>(
+pub fn handle<'info, T: Redeem<'info> + PoolPrice + HasVault>(
     ctx: Context<'_, '_, '_, 'info, T>,
     min_token_a: u64,
     min_token_b: u64,
@@ -47,11 +61,24 @@ pub fn handle<'info, T: Redeem<'info> + HasVault>(
     msg!("vault state verified");
 
     // if no assets were invested, return early. received initialized at 0.
-    if vault.alpha.invested == 0 && vault.beta.invested == 0 {
+    if vault.tranches().iter().all(|tranche| tranche.invested == 0) {
         msg!("no funds invested; returning early");
         return Ok(());
     }
 
+    verify_pool_price(
+        ctx.accounts.vault(),
+        ctx.remaining_accounts,
+        ctx.accounts.pool_price()?,
+    )?;
+    msg!("pool price verified");
+
     // burn LP for underlying assets in downstream protocol
-    ctx.accounts.redeem(min_token_a, min_token_b)
+    let lp_amount = ctx.accounts.strategy_lp();
+    let (alpha_amount, beta_amount) = ctx.accounts.redeem(lp_amount, min_token_a, min_token_b)?;
+    msg!("received alpha: {}, beta: {}", alpha_amount, beta_amount);
+
+    ctx.accounts
+        .vault_mut()
+        .add_receipts(alpha_amount, beta_amount)
 }
@@ -0,0 +1,50 @@
+use crate::{
+    constant::{ALLOWLIST_SEED, GLOBAL_STATE_SEED, VAULT_SEED},
+    state::{Allowlist, GlobalProtocolState, Vault},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RemoveFromAllowlist<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GLOBAL_STATE_SEED.as_bytes()],
+        bump,
+    )]
+    pub global_protocol_state: Box<Account<'info, GlobalProtocolState>>,
+
+    #[account(
+        seeds = [
+            VAULT_SEED.as_bytes(),
+            authority.key().to_bytes().as_ref()
+        ],
+        bump,
+        constraint = vault.authority == authority.key(),
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    /// CHECK: read-only account to derive allowlist address
+    pub user: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            ALLOWLIST_SEED.as_bytes(),
+            vault.key().to_bytes().as_ref(),
+            user.key().to_bytes().as_ref(),
+        ],
+        bump = allowlist.bump,
+        close = authority,
+    )]
+    pub allowlist: Box<Account<'info, Allowlist>>,
+}
+
+/// Revoke a user's approval to deposit into the vault. Rent is returned to the vault authority.
+///
+/// @dev deposits the user already made are not affected.
+///
+pub fn handle(_ctx: Context<RemoveFromAllowlist>) -> ProgramResult {
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use crate::{
+    constant::{GLOBAL_STATE_SEED, VAULT_SEED},
+    state::{vault::Vault, GlobalProtocolState, StrategistRole},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetKeeper<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GLOBAL_STATE_SEED.as_bytes()],
+        bump,
+    )]
+    pub global_protocol_state: Box<Account<'info, GlobalProtocolState>>,
+
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED.as_bytes(),
+            authority.key().to_bytes().as_ref()
+        ],
+        bump,
+        constraint = vault.authority == authority.key(),
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+}
+
+/// Delegate a subset of the strategist's roles to a keeper, replacing any previous keeper and its roles.
+/// `roles` is a `StrategistRole` bitmask; passing no keeper revokes the delegation.
+///
+/// @dev the strategist keeps every role, so a keeper only ever widens who can operate the strategy. a
+///      keeper is meant for routine operations like harvesting and converting farm rewards, while swaps,
+///      rebalancing and redemptions stay with the strategist, e.g. a multisig.
+///
+pub fn handle(ctx: Context<SetKeeper>, keeper: Option<Pubkey>, roles: u64) -> ProgramResult {
+    let roles = StrategistRole::roles_from_u64(roles)?;
+    ctx.accounts.vault.update_keeper(keeper, roles);
+    msg!("keeper: {:?}, roles: {:?}", keeper, roles);
+
+    Ok(())
+}
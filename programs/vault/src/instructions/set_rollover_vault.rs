@@ -0,0 +1,67 @@
+use crate::{
+    constant::{GLOBAL_STATE_SEED, VAULT_SEED},
+    error::ErrorCode,
+    state::{vault::State, vault::Vault, GlobalProtocolState},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetRolloverVault<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GLOBAL_STATE_SEED.as_bytes()],
+        bump,
+    )]
+    pub global_protocol_state: Box<Account<'info, GlobalProtocolState>>,
+
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED.as_bytes(),
+            authority.key().to_bytes().as_ref()
+        ],
+        bump,
+        constraint = vault.authority == authority.key(),
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(constraint = rollover_vault.key() != vault.key())]
+    pub rollover_vault: Box<Account<'info, Vault>>,
+}
+
+/// Point a vault at the vault for its next epoch. Positions committed to the rollover fund
+/// will be deposited into this vault once the current vault reaches the withdraw state.
+///
+/// @dev both vaults must have the same tranches backed by the same assets, a rolled over position
+///      is paid out in the tranche's underlying asset and deposited 1-1 into the tranche with the
+///      same id.
+///
+/// @dev the next vault is fixed once the vault leaves the deposit state. users can only opt into the
+///      rollover fund after that, so no position is ever committed against a different vault.
+pub fn handle(ctx: Context<SetRolloverVault>) -> ProgramResult {
+    let vault = &ctx.accounts.vault;
+    let rollover_vault = &ctx.accounts.rollover_vault;
+
+    require!(
+        vault.state == State::Inactive || vault.state == State::Deposit,
+        ErrorCode::InvalidVaultState
+    );
+
+    require!(
+        vault.num_tranches == rollover_vault.num_tranches
+            && vault
+                .tranches()
+                .iter()
+                .zip(rollover_vault.tranches().iter())
+                .all(|(tranche, next_tranche)| tranche.mint == next_tranche.mint),
+        ErrorCode::InvalidRolloverVault
+    );
+
+    ctx.accounts
+        .vault
+        .update_rollover_vault(Some(ctx.accounts.rollover_vault.key()));
+
+    Ok(())
+}
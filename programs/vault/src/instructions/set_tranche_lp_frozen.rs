@@ -0,0 +1,85 @@
+use crate::{
+    constant::{GLOBAL_STATE_SEED, VAULT_SEED},
+    error::ErrorCode,
+    state::{vault::Vault, GlobalProtocolState},
+    util::{assert_owned_by, get_spl_mint, set_token_account_frozen},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token};
+
+#[derive(Accounts)]
+pub struct SetTrancheLpFrozen<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GLOBAL_STATE_SEED.as_bytes()],
+        bump,
+    )]
+    pub global_protocol_state: Box<Account<'info, GlobalProtocolState>>,
+
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED.as_bytes(),
+            authority.key().to_bytes().as_ref()
+        ],
+        bump,
+        constraint = vault.authority == authority.key(),
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    pub lp: Box<Account<'info, Mint>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Emergency switch for the vault authority to freeze or unfreeze the vault's tranche tokens. While frozen,
+/// tranche token accounts cannot be thawed and tranche tokens minted to holders stay frozen.
+///
+/// when freezing, token accounts for the given tranche token passed via remaining accounts are frozen. the
+/// instruction can be repeated for each tranche token and batch of holders. when unfreezing, holders' token
+/// accounts are thawed via `thaw_tranche_lp`.
+///
+/// @dev this is not gated on the protocol being active so that tranche tokens can be frozen while the
+///      protocol is paused.
+///
+/// @dev the switch only affects transfers, thaws and early exits. `withdraw` thaws the holder's token
+///      account once the vault is in the withdraw state regardless.
+///
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetTrancheLpFrozen<'info>>,
+    frozen: bool,
+) -> ProgramResult {
+    ctx.accounts
+        .vault
+        .get_tranche_by_lp(&ctx.accounts.lp.key())?;
+    ctx.accounts.vault.update_tranche_lp_frozen(frozen);
+    msg!("tranche lp frozen: {}", frozen);
+
+    if !frozen {
+        return Ok(());
+    }
+
+    let authority = ctx.accounts.authority.key();
+    let vault_signer_seeds = generate_vault_seeds!(authority.as_ref(), ctx.accounts.vault.bump);
+
+    for account in ctx.remaining_accounts {
+        assert_owned_by(account, &spl_token::id())?;
+        require!(
+            get_spl_mint(account)? == ctx.accounts.lp.key(),
+            ErrorCode::InvalidLpMint
+        );
+
+        set_token_account_frozen(
+            ctx.accounts.token_program.to_account_info(),
+            account.clone(),
+            ctx.accounts.lp.to_account_info(),
+            ctx.accounts.vault.to_account_info(),
+            vault_signer_seeds,
+            true,
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use crate::{
+    constant::{GLOBAL_STATE_SEED, VAULT_SEED},
+    error::ErrorCode,
+    state::{vault::Vault, GlobalProtocolState},
+    util::{assert_owned_by, get_spl_mint, set_token_account_frozen},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token};
+
+#[derive(Accounts)]
+pub struct ThawTrancheLp<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: read-only account to validate vault address
+    pub authority: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [GLOBAL_STATE_SEED.as_bytes()],
+        bump,
+    )]
+    pub global_protocol_state: Box<Account<'info, GlobalProtocolState>>,
+
+    #[account(
+        seeds = [
+            VAULT_SEED.as_bytes(),
+            authority.key().to_bytes().as_ref()
+        ],
+        bump,
+        constraint = vault.authority == authority.key(),
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    pub lp: Box<Account<'info, Mint>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Thaw token accounts for a tranche token, passed via remaining accounts, once the tranche token is
+/// transferable. Tranche tokens are minted frozen until the vault is live and every tranche's claims are
+/// processed, so nobody can trade a tranche token before the assets backing it are known.
+///
+/// anyone can thaw any holder's token account; `withdraw` also thaws the holder's token account lazily.
+///
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, ThawTrancheLp<'info>>) -> ProgramResult {
+    ctx.accounts
+        .vault
+        .get_tranche_by_lp(&ctx.accounts.lp.key())?;
+    require!(
+        ctx.accounts.vault.tranche_lp_transferable(),
+        ErrorCode::TrancheLpFrozen
+    );
+
+    let authority = ctx.accounts.authority.key();
+    let vault_signer_seeds = generate_vault_seeds!(authority.as_ref(), ctx.accounts.vault.bump);
+
+    for account in ctx.remaining_accounts {
+        assert_owned_by(account, &spl_token::id())?;
+        require!(
+            get_spl_mint(account)? == ctx.accounts.lp.key(),
+            ErrorCode::InvalidLpMint
+        );
+
+        set_token_account_frozen(
+            ctx.accounts.token_program.to_account_info(),
+            account.clone(),
+            ctx.accounts.lp.to_account_info(),
+            ctx.accounts.vault.to_account_info(),
+            vault_signer_seeds,
+            false,
+        )?;
+    }
+
+    Ok(())
+}
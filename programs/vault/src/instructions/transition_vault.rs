@@ -1,13 +1,18 @@
 use crate::{
     constant::{GLOBAL_STATE_SEED, VAULT_SEED},
-    state::{vault::Vault, GlobalProtocolState},
+    state::{vault::Vault, GlobalProtocolState, State},
+    util::get_current_timestamp,
 };
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
 pub struct TransitionVault<'info> {
+    /// authority, strategist, or anyone once the vault is past its deadline; see `STATE_TRANSITIONS`
     #[account(mut)]
-    pub authority: Signer<'info>,
+    pub payer: Signer<'info>,
+
+    /// CHECK: read-only account to validate vault address
+    pub authority: UncheckedAccount<'info>,
 
     #[account(
         seeds = [GLOBAL_STATE_SEED.as_bytes()],
@@ -27,12 +32,20 @@ pub struct TransitionVault<'info> {
     pub vault: Box<Account<'info, Vault>>,
 }
 
-pub fn handle(
-    ctx: Context<TransitionVault>,
-    target_state: String,
-    timestamp: u64,
-) -> ProgramResult {
-    ctx.accounts.vault.transition(target_state, timestamp)?;
+pub fn handle(ctx: Context<TransitionVault>, target_state: State, timestamp: u64) -> ProgramResult {
+    let now = get_current_timestamp()?;
+    let signer = ctx.accounts.payer.key();
+    let vault = &mut ctx.accounts.vault;
+    vault.verify_transition_signer(target_state, &signer, now)?;
+
+    // only the authority and strategist pick the recorded time of a transition; anyone else triggering a
+    // transition past its deadline records the cluster time
+    let ts = if vault.is_transition_admin(&signer) {
+        timestamp
+    } else {
+        now
+    };
+    vault.transition(target_state, ts)?;
 
     Ok(())
 }
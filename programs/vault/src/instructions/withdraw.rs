@@ -1,9 +1,10 @@
 use crate::{
     constant::{GLOBAL_STATE_SEED, VAULT_SEED, VAULT_STORE_SEED},
-    error::{ErrorCode, OraResult},
+    error::ErrorCode,
+    math::compute_withdrawal_amount,
     state::vault::State,
     state::{vault::Vault, GlobalProtocolState},
-    util::spl_token_transfer,
+    util::{set_token_account_frozen, spl_token_transfer},
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token::{burn, Burn};
@@ -73,11 +74,15 @@ pub struct Withdraw<'info> {
 /// and use a ratio of LP supply + current token account balance to determine the number of
 /// underlying assets someone is entitled to.
 ///
+/// the tranche is identified by its tranche token, so tranches backed by the same asset, e.g. a
+/// single-asset vault, are withdrawn from independently.
+///
 /// optionally specify amount to withdraw. otherwise, default is to exchange LP tokens for underlying
 /// assets.
 ///
 /// @dev users MUST call claim before withdraw in order to (1) claim deposited assets not invested
-///      and (2) receive LP tokens to burn when actually withdrawing liquidity from the vault.
+///      and (2) receive LP tokens to burn when actually withdrawing liquidity from the vault. users
+///      that never want to hold the tranche token can call `claim_and_withdraw` instead.
 ///
 /// todo
 /// @dev include extra variable saying whether funds have been balanced? we want to prevent people
@@ -100,14 +105,23 @@ pub fn handle(ctx: Context<Withdraw>, amount: u64) -> ProgramResult {
     ctx.accounts.vault.try_transition()?;
     msg!("vault state: {:?}", ctx.accounts.vault.state);
 
-    // requires (alpha|beta).received to be > 0
+    // requires at least 1 tranche to have received > 0
     require!(
         ctx.accounts.vault.state == State::Withdraw,
         ErrorCode::InvalidVaultState
     );
     msg!("vault state verified");
 
-    let asset = ctx.accounts.vault.get_asset(&ctx.accounts.mint.key())?;
+    // the tranche is addressed by the tranche token being burned; you cannot make a withdrawal without
+    // valid LP tokens
+    let asset = ctx
+        .accounts
+        .vault
+        .get_tranche_by_lp(&ctx.accounts.lp.key())?;
+    require!(
+        asset.mint == ctx.accounts.mint.key(),
+        ErrorCode::PublicKeyMismatch
+    );
     msg!("asset: {:?}", asset);
     msg!("asset.lp: {:?}", asset.lp);
     msg!("ctx.accounts.lp.key(): {:?}", ctx.accounts.lp.key());
@@ -115,13 +129,27 @@ pub fn handle(ctx: Context<Withdraw>, amount: u64) -> ProgramResult {
     let num_lp_tokens_for_payer = ctx.accounts.source_lp.amount;
     msg!("num_lp_tokens_for_payer: {}", num_lp_tokens_for_payer);
 
-    // you cannot make a withdrawal without valid LP tokens
-    require!(ctx.accounts.lp.key() == asset.lp, ErrorCode::InvalidLpMint);
     require!(
         num_lp_tokens_for_payer > 0,
         ErrorCode::CannotWithdrawWithoutLpTokens
     );
 
+    // tranche tokens minted before every tranche's claims were processed are still frozen, thaw them lazily.
+    // the authority's emergency freeze does not apply here so that holders can always exit at the end of the
+    // vault's lifecycle.
+    if ctx.accounts.source_lp.is_frozen() {
+        let authority = ctx.accounts.authority.key();
+        let vault_signer_seeds = generate_vault_seeds!(authority.as_ref(), ctx.accounts.vault.bump);
+        set_token_account_frozen(
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.source_lp.to_account_info(),
+            ctx.accounts.lp.to_account_info(),
+            ctx.accounts.vault.to_account_info(),
+            vault_signer_seeds,
+            false,
+        )?;
+    }
+
     // default to withdrawing all LP tokens if none are specified
     let lp_amount = match amount {
         amount if amount > 0 => amount,
@@ -133,16 +161,19 @@ pub fn handle(ctx: Context<Withdraw>, amount: u64) -> ProgramResult {
     msg!("{:?} received for asset {:?}", asset.received, asset.mint);
 
     // @dev we cannot use `ctx.accounts.lp.supply` because it's possible not all LP tokens have been minted (during claim instruction).
-    //      instead we can use `vault.invested` since (1) tranche tokens and assets have the same decimals and (2) are minted at a 1-1 rate.
+    //      instead we can use `vault.total_invested` since (1) tranche tokens and assets have the same decimals, (2) are minted at a
+    //      1-1 rate for initial deposits and (3) any tranche tokens minted for mid-cycle deposits are added to total_invested.
     msg!("ctx.accounts.lp.supply: {}", ctx.accounts.lp.supply);
-    msg!("asset.invested: {}", asset.invested);
+    msg!("asset.total_invested: {}", asset.total_invested);
 
     let withdrawal_amount = compute_withdrawal_amount(
         asset.received,
-        asset.invested,
+        asset.total_invested,
         lp_amount,
         ctx.accounts.lp.decimals,
-    )?;
+    )
+    .ok_or_else(math_error!())?;
+    msg!("withdrawal_amount: {}", withdrawal_amount);
 
     let vault_key = ctx.accounts.vault.key();
     let vault_store_signer_seeds =
@@ -160,57 +191,6 @@ pub fn handle(ctx: Context<Withdraw>, amount: u64) -> ProgramResult {
     Ok(())
 }
 
-/// Compute a user's withdrawal amount based on the amount received in the tranche,
-/// the total supply of tranche tokens, and the users share of those tokens.
-///
-/// @dev assumption is that mint & lp decimals are equal. this is enforced on vault
-///      creation
-///
-/// @dev formula is computing NUM_ASSETS_PER_LP * NUM_LP, adjusted for the tokens'
-///      decimals to maintain precision in calculations. more succintly,
-///      amount = (((received * 10^decimals) / supply) * share) / 10^decimals,
-///      where supply = invested = total LP based on amount invested
-///
-pub fn compute_withdrawal_amount(
-    received: u64,
-    supply: u64,
-    share: u64,
-    decimals: u8,
-) -> OraResult<u64> {
-    let received_extended: u128 = received as u128;
-
-    msg!("decimals: {:?}", decimals);
-    msg!("received_extended: {:?}", received_extended);
-
-    let decimal_multiplier = 10u128
-        .checked_pow(decimals as u32)
-        .ok_or_else(math_error!())?;
-    msg!("decimal_multiplier: {:?}", decimal_multiplier);
-
-    let received_padded: u128 = received_extended
-        .checked_mul(decimal_multiplier)
-        .ok_or_else(math_error!())?;
-
-    msg!("received_padded: {:?}", received_padded);
-
-    let asset_per_lp: u128 = received_padded
-        .checked_div(supply as u128)
-        .ok_or_else(math_error!())?;
-    msg!("asset_per_lp: {:?}", asset_per_lp);
-
-    let assets_to_withdraw_padded: u128 = asset_per_lp
-        .checked_mul(share as u128)
-        .ok_or_else(math_error!())?;
-    msg!("assets_to_withdraw_padded: {:?}", assets_to_withdraw_padded);
-
-    let assets_to_withdraw: u128 = assets_to_withdraw_padded
-        .checked_div(decimal_multiplier)
-        .ok_or_else(math_error!())?;
-    msg!("assets_to_withdraw: {:?}", assets_to_withdraw);
-
-    Ok(assets_to_withdraw as u64)
-}
-
 impl<'info> Withdraw<'info> {
     pub fn into_burn_reserve_token_context(&self) -> CpiContext<'_, '_, '_, 'info, Burn<'info>> {
         let cpi_program = self.token_program.to_account_info();
@@ -0,0 +1,163 @@
+use crate::{
+    constant::{GLOBAL_STATE_SEED, ROLLOVER_SEED, VAULT_SEED, VAULT_STORE_SEED},
+    error::ErrorCode,
+    math::compute_withdrawal_amount,
+    state::{vault::State, vault::Vault, GlobalProtocolState, Rollover},
+    util::transfer_with_verified_ata,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token};
+
+#[derive(Accounts)]
+#[instruction(tranche_id: u8)]
+pub struct WithdrawRollover<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: read-only account to validate vault address
+    pub authority: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [GLOBAL_STATE_SEED.as_bytes()],
+        bump,
+    )]
+    pub global_protocol_state: Box<Account<'info, GlobalProtocolState>>,
+
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED.as_bytes(),
+            authority.key().to_bytes().as_ref()
+        ],
+        bump,
+        constraint = vault.authority == authority.key(),
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    /// CHECK: verified via instruction access_control
+    #[account(mut)]
+    pub vault_store: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            ROLLOVER_SEED.as_bytes(),
+            vault.key().to_bytes().as_ref(),
+            &tranche_id.to_le_bytes(),
+            depositor.key().to_bytes().as_ref(),
+        ],
+        bump,
+        constraint = rollover.depositor == depositor.key(),
+    )]
+    pub rollover: Box<Account<'info, Rollover>>,
+
+    /// CHECK: read-only account to validate rollover address and the destination ATA's owner
+    pub depositor: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = vault.rollover_vault == Some(next_vault.key()) @ ErrorCode::InvalidRolloverVault,
+    )]
+    pub next_vault: Box<Account<'info, Vault>>,
+
+    /// CHECK: can be wrapped wSOL, so not a Mint
+    #[account(mut)]
+    pub mint: UncheckedAccount<'info>,
+
+    /// read-only, only used to verify the tranche and its decimals
+    pub lp: Box<Account<'info, Mint>>,
+
+    /// CHECK: can be wrapped wSOL, so not a TokenAccount. Validation done via Token Program CPI.
+    #[account(mut)]
+    pub source_ata: UncheckedAccount<'info>,
+
+    /// CHECK: create and validate JIT in instruction. Validation done via Token Program CPI.
+    #[account(mut)]
+    pub destination_ata: UncheckedAccount<'info>,
+
+    /// =============== PROGRAM ACCOUNTS ===============
+    pub system_program: Program<'info, System>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// CHECK: validate expected vs actual address
+    #[account(address = spl_associated_token_account::ID)]
+    pub ata_program: UncheckedAccount<'info>,
+
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Pay a user's rollover position out to the user, same as `withdraw`, when the next vault can no
+/// longer take it. Anyone can crank this instruction once the current vault reaches the withdraw state
+/// and the next vault
+///
+///   - has been cancelled
+///   - has moved past the deposit state
+///   - would exceed the tranche's asset cap with the position
+///
+/// The committed tranche tokens were burned when the user opted into the rollover fund, so without
+/// this the position would be stuck in the vault store.
+///
+pub fn handle(ctx: Context<WithdrawRollover>, tranche_id: u8) -> ProgramResult {
+    ctx.accounts.vault.try_transition()?;
+    require!(
+        ctx.accounts.vault.state == State::Withdraw,
+        ErrorCode::InvalidVaultState
+    );
+    msg!("vault state verified");
+
+    let asset = ctx.accounts.vault.get_tranche(tranche_id)?;
+    require!(
+        asset.mint == ctx.accounts.mint.key(),
+        ErrorCode::PublicKeyMismatch
+    );
+    require!(ctx.accounts.lp.key() == asset.lp, ErrorCode::InvalidLpMint);
+    require!(
+        !ctx.accounts.rollover.processed,
+        ErrorCode::RolloverAlreadyProcessed
+    );
+
+    let lp_amount = ctx.accounts.rollover.lp_amount;
+    msg!("lp_amount: {}", lp_amount);
+    require!(lp_amount > 0, ErrorCode::CannotWithdrawWithoutLpTokens);
+
+    let withdrawal_amount = compute_withdrawal_amount(
+        asset.received,
+        asset.total_invested,
+        lp_amount,
+        ctx.accounts.lp.decimals,
+    )
+    .ok_or_else(math_error!())?;
+    msg!("withdrawal_amount: {}", withdrawal_amount);
+
+    require!(
+        !ctx.accounts
+            .next_vault
+            .can_accept_rollover(tranche_id, withdrawal_amount)?,
+        ErrorCode::RolloverVaultAcceptingDeposits
+    );
+    msg!("rollover vault cannot accept the position");
+
+    let vault_key = ctx.accounts.vault.key();
+    let vault_store_signer_seeds =
+        generate_vault_store_seeds!(*vault_key.as_ref(), ctx.accounts.vault.vault_store_bump);
+
+    transfer_with_verified_ata(
+        ctx.accounts.source_ata.to_account_info(),
+        ctx.accounts.destination_ata.to_account_info(),
+        ctx.accounts.depositor.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.ata_program.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.rent.to_account_info(),
+        &[],
+        ctx.accounts.vault_store.to_account_info(),
+        vault_store_signer_seeds,
+        withdrawal_amount,
+    )?;
+
+    ctx.accounts.rollover.mark_processed();
+
+    Ok(())
+}
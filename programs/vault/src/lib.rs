@@ -4,16 +4,26 @@ use anchor_lang::prelude::*;
 mod macros;
 
 mod adapters;
-mod constant;
-mod error;
+pub mod constant;
+pub mod error;
 mod instructions;
-mod state;
+pub mod math;
+mod metadata;
+mod oracle;
+pub mod state;
 mod util;
+pub mod waterfall;
 
 use adapters::*;
 use error::ErrorCode;
 use instructions::*;
-use state::{GlobalProtocolState, Vault, VaultConfig};
+use state::{GlobalProtocolState, State, Vault, VaultConfig};
+
+// account and argument types, and validation, that live in private modules, exposed for off-chain clients
+pub use adapters::{OrcaStrategyDataV0, SaberStrategyDataV0};
+pub use instructions::{
+    verify_deposit_for_user, verify_leverage, verify_tranche_configs, SwapConfig,
+};
 
 declare_id!("CRDRY8VKkjPBBoyurn3jQdy7n2TjgexDqfePno5gnQxV");
 
@@ -32,8 +42,8 @@ pub mod vault {
 
     // todo: update sdk
     #[access_control(protocol_not_paused(&ctx.accounts.global_protocol_state))]
-    pub fn initialize_vault(
-        ctx: Context<InitializeVault>,
+    pub fn initialize_vault<'info>(
+        ctx: Context<'_, '_, '_, 'info, InitializeVault<'info>>,
         vault_bump: u8,
         vault_store_bump: u8,
         vault_config: VaultConfig,
@@ -44,32 +54,58 @@ pub mod vault {
     #[access_control(protocol_not_paused(&ctx.accounts.global_protocol_state))]
     pub fn transition_vault(
         ctx: Context<TransitionVault>,
-        target_state: String,
+        target_state: State,
         timestamp: u64,
     ) -> ProgramResult {
         instructions::transition_vault::handle(ctx, target_state, timestamp)
     }
 
+    #[access_control(protocol_not_paused(&ctx.accounts.global_protocol_state))]
+    pub fn cancel_vault(ctx: Context<CancelVault>) -> ProgramResult {
+        instructions::cancel_vault::handle(ctx)
+    }
+
     #[allow(unused_must_use)]
     #[access_control(
         protocol_not_paused(&ctx.accounts.global_protocol_state) &&
         verify_vault_store(&ctx.accounts.vault, ctx.accounts.vault_store.key)
     )]
-    pub fn deposit(
-        ctx: Context<Deposit>,
+    pub fn deposit<'info>(
+        ctx: Context<'_, '_, '_, 'info, Deposit<'info>>,
+        tranche_id: u8,
         deposit_index: u64,
         receipt_bump: u8,
         history_bump: u8,
         amount: u64,
+        proof: Vec<[u8; 32]>,
     ) -> ProgramResult {
-        instructions::deposit::handle(ctx, deposit_index, receipt_bump, history_bump, amount)
+        instructions::deposit::handle(
+            ctx,
+            tranche_id,
+            deposit_index,
+            receipt_bump,
+            history_bump,
+            amount,
+            proof,
+        )
+    }
+
+    #[access_control(protocol_not_paused(&ctx.accounts.global_protocol_state))]
+    pub fn add_to_allowlist(ctx: Context<AddToAllowlist>, allowlist_bump: u8) -> ProgramResult {
+        instructions::add_to_allowlist::handle(ctx, allowlist_bump)
+    }
+
+    #[access_control(protocol_not_paused(&ctx.accounts.global_protocol_state))]
+    pub fn remove_from_allowlist(ctx: Context<RemoveFromAllowlist>) -> ProgramResult {
+        instructions::remove_from_allowlist::handle(ctx)
     }
 
     #[access_control(protocol_not_paused(&ctx.accounts.global_protocol_state))]
     pub fn process_claims<'info>(
         ctx: Context<'_, '_, '_, 'info, ProcessClaims<'info>>,
+        tranche_id: u8,
     ) -> ProgramResult {
-        instructions::process_claims::handle(ctx)
+        instructions::process_claims::handle(ctx, tranche_id)
     }
 
     #[allow(unused_must_use)]
@@ -77,8 +113,8 @@ pub mod vault {
         protocol_not_paused(&ctx.accounts.global_protocol_state) &&
         verify_vault_store(&ctx.accounts.vault, ctx.accounts.vault_store.key)
     )]
-    pub fn claim(ctx: Context<Claim>) -> ProgramResult {
-        instructions::claim::handle(ctx)
+    pub fn claim(ctx: Context<Claim>, tranche_id: u8) -> ProgramResult {
+        instructions::claim::handle(ctx, tranche_id)
     }
 
     // todo: amounts correct on here?
@@ -91,6 +127,74 @@ pub mod vault {
         instructions::withdraw::handle(ctx, amount)
     }
 
+    #[allow(unused_must_use)]
+    #[access_control(
+        protocol_not_paused(&ctx.accounts.global_protocol_state) &&
+        verify_vault_store(&ctx.accounts.vault, ctx.accounts.vault_store.key)
+    )]
+    pub fn claim_and_withdraw(ctx: Context<ClaimAndWithdraw>, tranche_id: u8) -> ProgramResult {
+        instructions::claim_and_withdraw::handle(ctx, tranche_id)
+    }
+
+    #[access_control(protocol_not_paused(&ctx.accounts.global_protocol_state))]
+    pub fn set_keeper(
+        ctx: Context<SetKeeper>,
+        keeper: Option<Pubkey>,
+        roles: u64,
+    ) -> ProgramResult {
+        instructions::set_keeper::handle(ctx, keeper, roles)
+    }
+
+    #[access_control(protocol_not_paused(&ctx.accounts.global_protocol_state))]
+    pub fn set_rollover_vault(ctx: Context<SetRolloverVault>) -> ProgramResult {
+        instructions::set_rollover_vault::handle(ctx)
+    }
+
+    pub fn set_tranche_lp_frozen<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetTrancheLpFrozen<'info>>,
+        frozen: bool,
+    ) -> ProgramResult {
+        instructions::set_tranche_lp_frozen::handle(ctx, frozen)
+    }
+
+    #[access_control(protocol_not_paused(&ctx.accounts.global_protocol_state))]
+    pub fn thaw_tranche_lp<'info>(
+        ctx: Context<'_, '_, '_, 'info, ThawTrancheLp<'info>>,
+    ) -> ProgramResult {
+        instructions::thaw_tranche_lp::handle(ctx)
+    }
+
+    #[access_control(protocol_not_paused(&ctx.accounts.global_protocol_state))]
+    pub fn opt_into_rollover(
+        ctx: Context<OptIntoRollover>,
+        tranche_id: u8,
+        rollover_bump: u8,
+        amount: u64,
+    ) -> ProgramResult {
+        instructions::opt_into_rollover::handle(ctx, tranche_id, rollover_bump, amount)
+    }
+
+    #[access_control(
+        protocol_not_paused(&ctx.accounts.global_protocol_state) &&
+        verify_vault_store(&ctx.accounts.vault, ctx.accounts.vault_store.key) &&
+        verify_vault_store(&ctx.accounts.next_vault, ctx.accounts.next_vault_store.key)
+    )]
+    pub fn process_rollover(
+        ctx: Context<ProcessRollover>,
+        tranche_id: u8,
+        history_bump: u8,
+    ) -> ProgramResult {
+        instructions::process_rollover::handle(ctx, tranche_id, history_bump)
+    }
+
+    #[access_control(
+        protocol_not_paused(&ctx.accounts.global_protocol_state) &&
+        verify_vault_store(&ctx.accounts.vault, ctx.accounts.vault_store.key)
+    )]
+    pub fn withdraw_rollover(ctx: Context<WithdrawRollover>, tranche_id: u8) -> ProgramResult {
+        instructions::withdraw_rollover::handle(ctx, tranche_id)
+    }
+
     // ========= [ORCA] =========
 
     #[access_control(protocol_not_paused(&ctx.accounts.global_protocol_state))]
@@ -134,6 +238,42 @@ pub mod vault {
         instructions::invest::handle(ctx, investable_a, investable_b, min_tokens_back)
     }
 
+    #[allow(unused_must_use)]
+    #[access_control(
+        protocol_not_paused(&ctx.accounts.global_protocol_state) &&
+        verify_vault_store(&ctx.accounts.vault, ctx.accounts.vault_store.key)
+    )]
+    pub fn deposit_live_orca<'info>(
+        ctx: Context<'_, '_, '_, 'info, DepositLiveOrca<'info>>,
+        tranche_id: u8,
+        history_bump: u8,
+        amount: u64,
+        min_tokens_back: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> ProgramResult {
+        instructions::deposit_live::handle(
+            ctx,
+            tranche_id,
+            history_bump,
+            amount,
+            min_tokens_back,
+            proof,
+        )
+    }
+
+    #[access_control(
+        protocol_not_paused(&ctx.accounts.global_protocol_state) &&
+        verify_vault_store(&ctx.accounts.vault, ctx.accounts.vault_store.key)
+    )]
+    pub fn early_exit_orca<'info>(
+        ctx: Context<'_, '_, '_, 'info, EarlyExitOrca<'info>>,
+        lp_amount: u64,
+        min_token_a: u64,
+        min_token_b: u64,
+    ) -> ProgramResult {
+        instructions::early_exit::handle(ctx, lp_amount, min_token_a, min_token_b)
+    }
+
     // todo: update sdk; vault_store, not vault
     // todo: update logic that maps A/B pair to vault alpha/beta
     #[allow(unused_must_use)]
@@ -163,6 +303,13 @@ pub mod vault {
         instructions::swap::handle(ctx, amount_in, min_amount_out)
     }
 
+    #[access_control(verify_vault_store(&ctx.accounts.vault, ctx.accounts.vault_store.key))]
+    pub fn get_vault_nav_orca<'info>(
+        ctx: Context<'_, '_, '_, 'info, GetVaultNavOrca<'info>>,
+    ) -> ProgramResult {
+        instructions::get_vault_nav::handle(ctx)
+    }
+
     // ========= [ORCA :: FARM] =========
 
     #[allow(unused_must_use)]
@@ -236,6 +383,41 @@ pub mod vault {
         instructions::invest::handle(ctx, investable_a, investable_b, min_tokens_back)
     }
 
+    #[access_control(
+        protocol_not_paused(&ctx.accounts.global_protocol_state) &&
+        verify_vault_store(&ctx.accounts.vault, ctx.accounts.vault_store.key)
+    )]
+    pub fn deposit_live_saber<'info>(
+        ctx: Context<'_, '_, '_, 'info, DepositLiveSaber<'info>>,
+        tranche_id: u8,
+        history_bump: u8,
+        amount: u64,
+        min_tokens_back: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> ProgramResult {
+        instructions::deposit_live::handle(
+            ctx,
+            tranche_id,
+            history_bump,
+            amount,
+            min_tokens_back,
+            proof,
+        )
+    }
+
+    #[access_control(
+        protocol_not_paused(&ctx.accounts.global_protocol_state) &&
+        verify_vault_store(&ctx.accounts.vault, ctx.accounts.vault_store.key)
+    )]
+    pub fn early_exit_saber<'info>(
+        ctx: Context<'_, '_, '_, 'info, EarlyExitSaber<'info>>,
+        lp_amount: u64,
+        min_token_a: u64,
+        min_token_b: u64,
+    ) -> ProgramResult {
+        instructions::early_exit::handle(ctx, lp_amount, min_token_a, min_token_b)
+    }
+
     // todo: update logic that maps A/B pair to vault alpha/beta
     #[access_control(protocol_not_paused(&ctx.accounts.global_protocol_state))]
     pub fn redeem_saber<'info>(
@@ -245,6 +427,12 @@ pub mod vault {
     ) -> ProgramResult {
         instructions::redeem::handle(ctx, min_token_a, min_token_b)
     }
+
+    pub fn get_vault_nav_saber<'info>(
+        ctx: Context<'_, '_, '_, 'info, GetVaultNavSaber<'info>>,
+    ) -> ProgramResult {
+        instructions::get_vault_nav::handle(ctx)
+    }
 }
 
 fn protocol_not_paused(state: &Account<GlobalProtocolState>) -> ProgramResult {
@@ -262,3 +450,14 @@ fn verify_vault_store(vault: &Account<Vault>, vault_store: &Pubkey) -> ProgramRe
 
     Ok(())
 }
+
+/// The program's entry when it is linked as a library with its entrypoint disabled, e.g. by the
+/// solana-program-test suites of crates that depend on the `cpi` feature
+#[cfg(feature = "no-entrypoint")]
+pub fn entry(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    if data.len() < 8 {
+        return Err(anchor_lang::__private::ErrorCode::InstructionMissing.into());
+    }
+
+    dispatch(program_id, accounts, data)
+}
@@ -0,0 +1,371 @@
+//! Pure tranche accounting math. nothing here logs or touches the runtime, so it can be exercised
+//! off-chain as is. functions return `None` on overflow or invalid input, and callers map that to
+//! `ErrorCode::MathError`.
+
+use std::convert::TryFrom;
+
+/// Compute a user's withdrawal amount based on the amount received in the tranche,
+/// the total supply of tranche tokens, and the users share of those tokens.
+///
+/// @dev assumption is that mint & lp decimals are equal. this is enforced on vault
+///      creation
+///
+/// @dev formula is computing NUM_ASSETS_PER_LP * NUM_LP, adjusted for the tokens'
+///      decimals to maintain precision in calculations. more succintly,
+///      amount = (((received * 10^decimals) / supply) * share) / 10^decimals,
+///      where supply = total_invested = total LP based on amount invested + mid-cycle deposits
+///
+/// @dev both divisions round down, so the sum of all withdrawals never exceeds `received`. the
+///      dust left behind by the full supply is at most supply / 10^decimals + 1.
+///
+pub fn compute_withdrawal_amount(
+    received: u64,
+    supply: u64,
+    share: u64,
+    decimals: u8,
+) -> Option<u64> {
+    let decimal_multiplier = 10u128.checked_pow(decimals as u32)?;
+    let received_padded = (received as u128).checked_mul(decimal_multiplier)?;
+    let asset_per_lp = received_padded.checked_div(supply as u128)?;
+    let assets_to_withdraw_padded = asset_per_lp.checked_mul(share as u128)?;
+    let assets_to_withdraw = assets_to_withdraw_padded.checked_div(decimal_multiplier)?;
+
+    u64::try_from(assets_to_withdraw).ok()
+}
+
+/// Compute the amount refunded to a deposit during claims processing, and whether processing is complete.
+///
+/// deposits are walked from latest to earliest. `cumulative` is the amount deposited into the tranche before
+/// this deposit, so the deposit is refunded in full while `cumulative` is at least the amount invested. the
+/// deposit that straddles the amount invested is refunded the marginal amount, and processing stops there.
+///
+/// @dev walking every deposit until completion refunds exactly `sum(deposits) - invested`.
+///
+pub fn compute_claim_amount(invested: u64, cumulative: u64, deposited: u64) -> Option<(u64, bool)> {
+    let cumulative_after_deposit = cumulative.checked_add(deposited)?;
+
+    if cumulative == invested {
+        // cumulative == total amount invested, before investment. return full amount and denote finished processing
+        // == true because we hit the cross-over point exactly.
+        Some((deposited, true))
+    } else if cumulative > invested {
+        // cumulative > total amount invested, before investment. return full amount and denote finished processing
+        // == false. we have not yet hit the cross-over point.
+        Some((deposited, false))
+    } else if cumulative_after_deposit > invested {
+        // return marginal amount; we found cross over point for claims
+        Some((cumulative_after_deposit.checked_sub(invested)?, true))
+    } else {
+        Some((0, true))
+    }
+}
+
+/// Compute the amounts moved by a rebalance swap from the strategy's balances of each asset before and
+/// after the swap, as (sold, bought). alpha is sold when `alpha_to_beta`, beta otherwise.
+///
+/// @dev a swap that grows the sold balance or shrinks the bought balance is invalid.
+///
+pub fn compute_rebalance_deltas(
+    alpha_to_beta: bool,
+    alpha_before: u64,
+    alpha_after: u64,
+    beta_before: u64,
+    beta_after: u64,
+) -> Option<(u64, u64)> {
+    match alpha_to_beta {
+        true => Some((
+            alpha_before.checked_sub(alpha_after)?,
+            beta_after.checked_sub(beta_before)?,
+        )),
+        false => Some((
+            beta_before.checked_sub(beta_after)?,
+            alpha_after.checked_sub(alpha_before)?,
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn pow10(decimals: u8) -> u128 {
+        10u128.pow(decimals as u32)
+    }
+
+    // split `total` into at most `max_parts` non-zero parts
+    fn split(total: u64, max_parts: usize) -> impl Strategy<Value = Vec<u64>> {
+        prop::collection::vec(1..=total.max(1), 1..=max_parts).prop_map(move |mut cuts| {
+            cuts.push(total);
+            cuts.sort_unstable();
+            cuts.dedup();
+
+            let mut prev = 0;
+            cuts.into_iter()
+                .map(|cut| {
+                    let part = cut - prev;
+                    prev = cut;
+                    part
+                })
+                .collect()
+        })
+    }
+
+    // a non-zero lp supply split between up to 16 holders
+    fn supply_and_shares() -> impl Strategy<Value = (u64, Vec<u64>)> {
+        (1..=u64::MAX).prop_flat_map(|supply| (Just(supply), split(supply, 16)))
+    }
+
+    // up to 16 deposits, in deposit order, and an amount invested of at most their total
+    fn deposits_and_invested() -> impl Strategy<Value = (Vec<u64>, u64)> {
+        (1..=u64::MAX).prop_flat_map(|total| (split(total, 16), 0..=total))
+    }
+
+    fn edge_u64() -> impl Strategy<Value = u64> {
+        prop_oneof![
+            Just(0),
+            Just(1),
+            Just(u64::MAX - 1),
+            Just(u64::MAX),
+            any::<u64>()
+        ]
+    }
+
+    // walk deposits from latest to earliest, as process_claims does, returning the refund for each deposit
+    fn process_claims(invested: u64, deposits: &[u64]) -> Vec<u64> {
+        let mut cumulatives = Vec::with_capacity(deposits.len());
+        let mut cumulative = 0u64;
+        for deposit in deposits {
+            cumulatives.push(cumulative);
+            cumulative += deposit;
+        }
+
+        let mut claims = vec![0; deposits.len()];
+        for idx in (0..deposits.len()).rev() {
+            let (amount, is_complete) =
+                compute_claim_amount(invested, cumulatives[idx], deposits[idx]).unwrap();
+            claims[idx] = amount;
+            if is_complete {
+                break;
+            }
+        }
+
+        claims
+    }
+
+    #[test]
+    fn test_compute_withdrawal_amount() {
+        assert_eq!(compute_withdrawal_amount(1_100, 1_000, 500, 6), Some(550));
+        // 1/3 of an asset per lp is truncated to the lp's decimals
+        assert_eq!(compute_withdrawal_amount(100, 300, 300, 0), Some(0));
+        assert_eq!(compute_withdrawal_amount(100, 300, 300, 2), Some(99));
+        assert_eq!(compute_withdrawal_amount(100, 0, 0, 6), None);
+    }
+
+    #[test]
+    fn test_compute_claim_amount() {
+        // deposits of 100, 200, 300 with 250 invested: 300 and 50 are refunded
+        assert_eq!(compute_claim_amount(250, 300, 300), Some((300, false)));
+        assert_eq!(compute_claim_amount(250, 100, 200), Some((50, true)));
+        // cross-over point at the start of a deposit
+        assert_eq!(compute_claim_amount(300, 300, 300), Some((300, true)));
+        // everything invested
+        assert_eq!(compute_claim_amount(600, 300, 300), Some((0, true)));
+        assert_eq!(compute_claim_amount(0, u64::MAX, 1), None);
+    }
+
+    #[test]
+    fn test_compute_rebalance_deltas() {
+        assert_eq!(
+            compute_rebalance_deltas(true, 1_000, 900, 500, 598),
+            Some((100, 98))
+        );
+        assert_eq!(
+            compute_rebalance_deltas(false, 1_000, 1_098, 500, 400),
+            Some((100, 98))
+        );
+        assert_eq!(compute_rebalance_deltas(true, 1_000, 1_001, 500, 598), None);
+        assert_eq!(compute_rebalance_deltas(false, 1_000, 999, 500, 400), None);
+    }
+
+    proptest! {
+        #[test]
+        fn withdrawals_never_exceed_received(
+            received in any::<u64>(),
+            (supply, shares) in supply_and_shares(),
+            decimals in 0..=18u8,
+        ) {
+            let mut withdrawn: u128 = 0;
+            for share in shares {
+                let amount = compute_withdrawal_amount(received, supply, share, decimals);
+                prop_assert!(amount.is_some());
+                withdrawn += amount.unwrap() as u128;
+            }
+
+            prop_assert!(withdrawn <= received as u128);
+            // a single holder of the full supply
+            let full = compute_withdrawal_amount(received, supply, supply, decimals).unwrap();
+            prop_assert!(full <= received);
+        }
+
+        #[test]
+        fn withdrawal_monotonic_in_share_and_received(
+            received in any::<u64>(),
+            more_received in any::<u64>(),
+            supply in 1..=u64::MAX,
+            share in any::<u64>(),
+            more_share in any::<u64>(),
+            decimals in 0..=18u8,
+        ) {
+            let (share, more_share) = (share % supply, more_share % supply);
+            let (share, more_share) = (share.min(more_share), share.max(more_share));
+            let more_received = received.max(more_received);
+
+            let amount = compute_withdrawal_amount(received, supply, share, decimals).unwrap();
+            prop_assert!(
+                amount <= compute_withdrawal_amount(received, supply, more_share, decimals).unwrap()
+            );
+            prop_assert!(
+                amount <= compute_withdrawal_amount(more_received, supply, share, decimals).unwrap()
+            );
+        }
+
+        #[test]
+        fn withdrawal_handles_u64_extremes(
+            received in edge_u64(),
+            supply in edge_u64().prop_map(|supply| supply.max(1)),
+            decimals in 0..=18u8,
+        ) {
+            for share in [0, 1, supply / 2, supply] {
+                let amount = compute_withdrawal_amount(received, supply, share, decimals);
+                prop_assert!(amount.is_some());
+                prop_assert!(amount.unwrap() <= received);
+            }
+        }
+
+        #[test]
+        fn withdrawal_rounding_across_decimals(
+            received in any::<u64>(),
+            supply in 1..=u64::MAX,
+            decimals in 0..=18u8,
+        ) {
+            let full = compute_withdrawal_amount(received, supply, supply, decimals).unwrap();
+            let max_dust = supply as u128 / pow10(decimals) + 1;
+            prop_assert!((received - full) as u128 <= max_dust);
+
+            // at a 1-1 rate, each share withdraws exactly its size
+            let share = received / 3;
+            prop_assert_eq!(
+                compute_withdrawal_amount(received, received.max(1), share, decimals),
+                Some(share)
+            );
+        }
+
+        #[test]
+        fn withdrawal_rejects_unrepresentable_decimals(
+            received in 1..=u64::MAX,
+            supply in 1..=u64::MAX,
+            decimals in 39..=u8::MAX,
+        ) {
+            prop_assert_eq!(compute_withdrawal_amount(received, supply, supply, decimals), None);
+        }
+
+        #[test]
+        fn claims_plus_invested_equal_deposited(
+            (total, deposits, invested) in (1..=u64::MAX).prop_flat_map(|total| {
+                (Just(total), split(total, 16), 0..=total)
+            }),
+        ) {
+            let claims = process_claims(invested, &deposits);
+
+            let claimed: u128 = claims.iter().map(|claim| *claim as u128).sum();
+            prop_assert_eq!(claimed + invested as u128, total as u128);
+            for (claim, deposit) in claims.iter().zip(deposits.iter()) {
+                prop_assert!(claim <= deposit);
+            }
+        }
+
+        #[test]
+        fn claims_refund_latest_deposits_first(
+            (deposits, invested) in deposits_and_invested(),
+        ) {
+            let claims = process_claims(invested, &deposits);
+
+            // once a deposit is refunded, every later deposit is refunded in full
+            let first_refunded = claims.iter().position(|claim| *claim > 0).unwrap_or(claims.len());
+            let later = claims.iter().zip(deposits.iter()).skip(first_refunded + 1);
+            for (claim, deposit) in later {
+                prop_assert_eq!(claim, deposit);
+            }
+        }
+
+        #[test]
+        fn claim_non_increasing_in_invested(
+            invested in any::<u64>(),
+            more_invested in any::<u64>(),
+            cumulative in any::<u64>(),
+            deposited in any::<u64>(),
+        ) {
+            let deposited = deposited.min(u64::MAX - cumulative);
+            let (invested, more_invested) =
+                (invested.min(more_invested), invested.max(more_invested));
+
+            let (amount, _) = compute_claim_amount(invested, cumulative, deposited).unwrap();
+            let (less, _) = compute_claim_amount(more_invested, cumulative, deposited).unwrap();
+            prop_assert!(less <= amount);
+            prop_assert!(amount <= deposited);
+        }
+
+        #[test]
+        fn claim_handles_u64_extremes(
+            invested in edge_u64(),
+            cumulative in edge_u64(),
+            deposited in edge_u64(),
+        ) {
+            let amount = compute_claim_amount(invested, cumulative, deposited);
+            prop_assert_eq!(amount.is_some(), cumulative.checked_add(deposited).is_some());
+        }
+
+        #[test]
+        fn rebalance_deltas_reconcile_balances(
+            alpha_to_beta in any::<bool>(),
+            sold_before in any::<u64>(),
+            sold in any::<u64>(),
+            bought_before in any::<u64>(),
+            bought in any::<u64>(),
+        ) {
+            let sold = sold.min(sold_before);
+            let bought = bought.min(u64::MAX - bought_before);
+            let (sold_after, bought_after) = (sold_before - sold, bought_before + bought);
+
+            let (alpha, beta) = match alpha_to_beta {
+                true => ((sold_before, sold_after), (bought_before, bought_after)),
+                false => ((bought_before, bought_after), (sold_before, sold_after)),
+            };
+            let deltas = compute_rebalance_deltas(alpha_to_beta, alpha.0, alpha.1, beta.0, beta.1);
+            prop_assert_eq!(deltas, Some((sold, bought)));
+        }
+
+        #[test]
+        fn rebalance_rejects_balances_moving_the_wrong_way(
+            alpha_to_beta in any::<bool>(),
+            alpha_before in any::<u64>(),
+            alpha_after in any::<u64>(),
+            beta_before in any::<u64>(),
+            beta_after in any::<u64>(),
+        ) {
+            let valid = match alpha_to_beta {
+                true => alpha_after <= alpha_before && beta_after >= beta_before,
+                false => alpha_after >= alpha_before && beta_after <= beta_before,
+            };
+            let deltas = compute_rebalance_deltas(
+                alpha_to_beta,
+                alpha_before,
+                alpha_after,
+                beta_before,
+                beta_after,
+            );
+            prop_assert_eq!(deltas.is_some(), valid);
+        }
+    }
+}
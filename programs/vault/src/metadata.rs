@@ -0,0 +1,140 @@
+use crate::{
+    constant::{SECONDS_PER_DAY, VAULT_SEED},
+    error::{ErrorCode, OraResult},
+};
+use anchor_lang::prelude::*;
+use mpl_token_metadata::{
+    instruction::create_metadata_accounts_v2,
+    state::{MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH, MAX_URI_LENGTH},
+};
+use solana_program::program::invoke_signed;
+
+/// Name, symbol and URI of a tranche token as they will appear in wallets
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TrancheTokenMetadata {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+
+/// Generate a tranche token's metadata from the vault's pair of assets, the tranche's position in the
+/// capital structure and the vault's maturity, e.g. "Senior SOL-USDC 20220630" with symbol "SSOL" for
+/// a senior tranche backed by SOL.
+///
+/// @dev the uri points at `{base_uri}/{vault}/{tranche_id}.json`, served off-chain.
+///
+pub fn generate_tranche_metadata(
+    vault: &Pubkey,
+    tranche_id: u8,
+    num_tranches: usize,
+    pair: (&str, &str),
+    asset_symbol: &str,
+    maturity: u64,
+    base_uri: &str,
+) -> OraResult<TrancheTokenMetadata> {
+    let (label, prefix) = match tranche_id as usize {
+        0 => ("Senior", "S"),
+        id if id + 1 == num_tranches => ("Junior", "J"),
+        _ => ("Mezzanine", "M"),
+    };
+
+    let pair = match pair {
+        // single-asset vault
+        (alpha, beta) if alpha == beta => alpha.to_string(),
+        (alpha, beta) => format!("{}-{}", alpha, beta),
+    };
+
+    let metadata = TrancheTokenMetadata {
+        name: format!("{} {} {}", label, pair, format_date(maturity)),
+        symbol: format!("{}{}", prefix, asset_symbol),
+        uri: format!(
+            "{}/{}/{}.json",
+            base_uri.trim_end_matches('/'),
+            vault,
+            tranche_id
+        ),
+    };
+    msg!("tranche metadata: {:?}", metadata);
+
+    require!(
+        !asset_symbol.is_empty()
+            && metadata.name.len() <= MAX_NAME_LENGTH
+            && metadata.symbol.len() <= MAX_SYMBOL_LENGTH
+            && metadata.uri.len() <= MAX_URI_LENGTH,
+        ErrorCode::InvalidTrancheMetadata
+    );
+
+    Ok(metadata)
+}
+
+/// Format a unix timestamp as a YYYYMMDD date in UTC
+///
+/// @dev http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+///
+pub fn format_date(timestamp: u64) -> String {
+    let days = (timestamp / SECONDS_PER_DAY) as i64 + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = match shifted_month < 10 {
+        true => shifted_month + 3,
+        false => shifted_month - 9,
+    };
+    let year = match month <= 2 {
+        true => year_of_era + era * 400 + 1,
+        false => year_of_era + era * 400,
+    };
+
+    format!("{:04}{:02}{:02}", year, month, day)
+}
+
+/// Create the Metaplex metadata account for a tranche mint. The vault signs as the mint authority and the
+/// vault authority is set as the update authority so that metadata can be corrected after the fact.
+pub fn create_tranche_metadata<'info>(
+    token_metadata_program: AccountInfo<'info>,
+    metadata: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    vault: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    rent: AccountInfo<'info>,
+    vault_bump: u8,
+    tranche_metadata: TrancheTokenMetadata,
+) -> ProgramResult {
+    let vault_signer_seeds = generate_vault_seeds!(*authority.key.as_ref(), vault_bump);
+
+    invoke_signed(
+        &create_metadata_accounts_v2(
+            *token_metadata_program.key,
+            *metadata.key,
+            *mint.key,
+            *vault.key,
+            *authority.key,
+            *authority.key,
+            tranche_metadata.name,
+            tranche_metadata.symbol,
+            tranche_metadata.uri,
+            None,
+            0,
+            true,
+            true,
+            None,
+            None,
+        ),
+        &[
+            metadata,
+            mint,
+            vault,
+            authority.clone(),
+            authority,
+            system_program,
+            rent,
+        ],
+        &[vault_signer_seeds],
+    )
+}
@@ -0,0 +1,355 @@
+use crate::{
+    constant::MAX_BPS,
+    error::{ErrorCode, OraResult},
+    state::{OracleConfig, OracleSource, Vault},
+};
+use anchor_lang::prelude::*;
+use std::convert::TryInto;
+
+// https://github.com/pyth-network/pyth-sdk-rs/blob/main/pyth-sdk-solana/src/state.rs
+const PYTH_MAGIC: u32 = 0xa1b2c3d4;
+const PYTH_VERSION: u32 = 2;
+const PYTH_ACCOUNT_TYPE_PRICE: u32 = 3;
+const PYTH_STATUS_TRADING: u32 = 1;
+
+const PYTH_MAGIC_OFFSET: usize = 0;
+const PYTH_VERSION_OFFSET: usize = 4;
+const PYTH_ACCOUNT_TYPE_OFFSET: usize = 8;
+const PYTH_EXPO_OFFSET: usize = 20;
+const PYTH_AGG_PRICE_OFFSET: usize = 208;
+const PYTH_AGG_STATUS_OFFSET: usize = 224;
+const PYTH_AGG_PUB_SLOT_OFFSET: usize = 232;
+const PYTH_MIN_LEN: usize = 240;
+
+// switchboard v2 `AggregatorAccountData`, including the 8 byte anchor discriminator. the latest confirmed
+// round starts at byte 341.
+// https://github.com/switchboard-xyz/switchboard-v2/blob/main/libraries/rs/src/aggregator.rs
+const SWITCHBOARD_ROUND_OPEN_SLOT_OFFSET: usize = 350;
+const SWITCHBOARD_RESULT_MANTISSA_OFFSET: usize = 366;
+const SWITCHBOARD_RESULT_SCALE_OFFSET: usize = 382;
+const SWITCHBOARD_MIN_LEN: usize = 386;
+
+/// Price reported by an oracle, where the price is `price * 10^expo`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OraclePrice {
+    pub price: i128,
+    pub expo: i32,
+    /// slot at which the price was last updated
+    pub slot: u64,
+}
+
+pub trait PoolPrice {
+    /// Price of the vault's alpha asset denominated in the beta asset according to the strategy's pool,
+    /// as a (beta amount, alpha amount) pair in raw token units.
+    fn pool_price(&self) -> OraResult<(u64, u64)>;
+}
+
+/// If the vault has an oracle configured, verify the pool-implied price of the alpha asset in the beta
+/// asset is within the vault's max deviation of the oracle-implied price. The alpha and beta oracle
+/// price accounts are expected as the first 2 remaining accounts.
+///
+/// @dev oracle prices for both assets must be quoted in the same currency, e.g. USD.
+///
+pub fn verify_pool_price<'info>(
+    vault: &Vault,
+    remaining_accounts: &[AccountInfo<'info>],
+    pool_price: (u64, u64),
+) -> ProgramResult {
+    let oracle = match vault.oracle {
+        Some(oracle) => oracle,
+        None => return Ok(()),
+    };
+
+    let alpha_oracle_info = remaining_accounts
+        .get(0)
+        .ok_or(ErrorCode::InvalidOracleAccount)?;
+    let beta_oracle_info = remaining_accounts
+        .get(1)
+        .ok_or(ErrorCode::InvalidOracleAccount)?;
+    require!(
+        alpha_oracle_info.key() == oracle.alpha_oracle
+            && beta_oracle_info.key() == oracle.beta_oracle,
+        ErrorCode::InvalidOracleAccount
+    );
+
+    let current_slot = Clock::get()?.slot;
+    let alpha_price =
+        load_oracle_price(&oracle, &alpha_oracle_info.try_borrow_data()?, current_slot)?;
+    let beta_price =
+        load_oracle_price(&oracle, &beta_oracle_info.try_borrow_data()?, current_slot)?;
+    msg!("alpha oracle price: {:?}", alpha_price);
+    msg!("beta oracle price: {:?}", beta_price);
+
+    let (beta_amount, alpha_amount) = pool_price;
+    let deviation_bps = compute_deviation_bps(
+        beta_amount,
+        alpha_amount,
+        &alpha_price,
+        &beta_price,
+        vault.alpha().decimals,
+        vault.beta().decimals,
+    )?;
+    msg!("oracle deviation bps: {}", deviation_bps);
+
+    require!(
+        deviation_bps <= oracle.max_deviation_bps as u64,
+        ErrorCode::OraclePriceDeviationExceeded
+    );
+
+    Ok(())
+}
+
+pub fn load_oracle_price(
+    oracle: &OracleConfig,
+    data: &[u8],
+    current_slot: u64,
+) -> OraResult<OraclePrice> {
+    let price = match oracle.source {
+        OracleSource::Pyth => load_pyth_price(data)?,
+        OracleSource::Switchboard => load_switchboard_price(data)?,
+    };
+
+    require!(price.price > 0, ErrorCode::InvalidOracleAccount);
+    require!(
+        current_slot.saturating_sub(price.slot) <= oracle.max_staleness_slots,
+        ErrorCode::StaleOraclePrice
+    );
+
+    Ok(price)
+}
+
+pub fn load_pyth_price(data: &[u8]) -> OraResult<OraclePrice> {
+    require!(data.len() >= PYTH_MIN_LEN, ErrorCode::InvalidOracleAccount);
+    require!(
+        read_u32(data, PYTH_MAGIC_OFFSET) == PYTH_MAGIC
+            && read_u32(data, PYTH_VERSION_OFFSET) == PYTH_VERSION
+            && read_u32(data, PYTH_ACCOUNT_TYPE_OFFSET) == PYTH_ACCOUNT_TYPE_PRICE,
+        ErrorCode::InvalidOracleAccount
+    );
+
+    // any status other than trading means the aggregate price is not currently updating
+    require!(
+        read_u32(data, PYTH_AGG_STATUS_OFFSET) == PYTH_STATUS_TRADING,
+        ErrorCode::StaleOraclePrice
+    );
+
+    Ok(OraclePrice {
+        price: i64::from_le_bytes(
+            data[PYTH_AGG_PRICE_OFFSET..PYTH_AGG_PRICE_OFFSET + 8]
+                .try_into()
+                .unwrap(),
+        ) as i128,
+        expo: i32::from_le_bytes(
+            data[PYTH_EXPO_OFFSET..PYTH_EXPO_OFFSET + 4]
+                .try_into()
+                .unwrap(),
+        ),
+        slot: read_u64(data, PYTH_AGG_PUB_SLOT_OFFSET),
+    })
+}
+
+pub fn load_switchboard_price(data: &[u8]) -> OraResult<OraclePrice> {
+    require!(
+        data.len() >= SWITCHBOARD_MIN_LEN,
+        ErrorCode::InvalidOracleAccount
+    );
+
+    let mantissa = i128::from_le_bytes(
+        data[SWITCHBOARD_RESULT_MANTISSA_OFFSET..SWITCHBOARD_RESULT_MANTISSA_OFFSET + 16]
+            .try_into()
+            .unwrap(),
+    );
+    let scale = read_u32(data, SWITCHBOARD_RESULT_SCALE_OFFSET);
+
+    Ok(OraclePrice {
+        price: mantissa,
+        expo: -(scale as i32),
+        slot: read_u64(data, SWITCHBOARD_ROUND_OPEN_SLOT_OFFSET),
+    })
+}
+
+/// Deviation between the pool-implied and oracle-implied price of the alpha asset denominated in the
+/// beta asset, in basis points of the oracle-implied price.
+///
+/// @dev floating point is fine here since the result is only compared against a bps threshold.
+///
+pub fn compute_deviation_bps(
+    beta_amount: u64,
+    alpha_amount: u64,
+    alpha_price: &OraclePrice,
+    beta_price: &OraclePrice,
+    alpha_decimals: u8,
+    beta_decimals: u8,
+) -> OraResult<u64> {
+    require!(alpha_amount > 0 && beta_amount > 0, ErrorCode::MathError);
+
+    // price of 1 whole alpha token in whole beta tokens
+    let pool_price = (beta_amount as f64 / 10f64.powi(beta_decimals as i32))
+        / (alpha_amount as f64 / 10f64.powi(alpha_decimals as i32));
+    let oracle_price = (alpha_price.price as f64 * 10f64.powi(alpha_price.expo))
+        / (beta_price.price as f64 * 10f64.powi(beta_price.expo));
+
+    let deviation = ((pool_price - oracle_price).abs() / oracle_price) * MAX_BPS as f64;
+
+    Ok(deviation as u64)
+}
+
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap())
+}
+
+fn read_u64(data: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mock_pyth_price(price: i64, expo: i32, status: u32, pub_slot: u64) -> Vec<u8> {
+        let mut data = vec![0u8; 3312];
+        data[PYTH_MAGIC_OFFSET..PYTH_MAGIC_OFFSET + 4].copy_from_slice(&PYTH_MAGIC.to_le_bytes());
+        data[PYTH_VERSION_OFFSET..PYTH_VERSION_OFFSET + 4]
+            .copy_from_slice(&PYTH_VERSION.to_le_bytes());
+        data[PYTH_ACCOUNT_TYPE_OFFSET..PYTH_ACCOUNT_TYPE_OFFSET + 4]
+            .copy_from_slice(&PYTH_ACCOUNT_TYPE_PRICE.to_le_bytes());
+        data[PYTH_EXPO_OFFSET..PYTH_EXPO_OFFSET + 4].copy_from_slice(&expo.to_le_bytes());
+        data[PYTH_AGG_PRICE_OFFSET..PYTH_AGG_PRICE_OFFSET + 8]
+            .copy_from_slice(&price.to_le_bytes());
+        data[PYTH_AGG_STATUS_OFFSET..PYTH_AGG_STATUS_OFFSET + 4]
+            .copy_from_slice(&status.to_le_bytes());
+        data[PYTH_AGG_PUB_SLOT_OFFSET..PYTH_AGG_PUB_SLOT_OFFSET + 8]
+            .copy_from_slice(&pub_slot.to_le_bytes());
+        data
+    }
+
+    fn mock_switchboard_price(mantissa: i128, scale: u32, round_open_slot: u64) -> Vec<u8> {
+        let mut data = vec![0u8; 3851];
+        data[SWITCHBOARD_ROUND_OPEN_SLOT_OFFSET..SWITCHBOARD_ROUND_OPEN_SLOT_OFFSET + 8]
+            .copy_from_slice(&round_open_slot.to_le_bytes());
+        data[SWITCHBOARD_RESULT_MANTISSA_OFFSET..SWITCHBOARD_RESULT_MANTISSA_OFFSET + 16]
+            .copy_from_slice(&mantissa.to_le_bytes());
+        data[SWITCHBOARD_RESULT_SCALE_OFFSET..SWITCHBOARD_RESULT_SCALE_OFFSET + 4]
+            .copy_from_slice(&scale.to_le_bytes());
+        data
+    }
+
+    fn oracle_config(source: OracleSource) -> OracleConfig {
+        OracleConfig {
+            source,
+            alpha_oracle: Pubkey::new_unique(),
+            beta_oracle: Pubkey::new_unique(),
+            max_deviation_bps: 100,
+            max_staleness_slots: 25,
+        }
+    }
+
+    #[test]
+    fn test_load_pyth_price() {
+        let data = mock_pyth_price(2_512_345_678, -8, PYTH_STATUS_TRADING, 1_000);
+        let price = load_oracle_price(&oracle_config(OracleSource::Pyth), &data, 1_010).unwrap();
+
+        assert_eq!(
+            price,
+            OraclePrice {
+                price: 2_512_345_678,
+                expo: -8,
+                slot: 1_000,
+            }
+        );
+    }
+
+    #[test]
+    fn test_load_pyth_price_rejects_stale_price() {
+        let data = mock_pyth_price(2_512_345_678, -8, PYTH_STATUS_TRADING, 1_000);
+        let result = load_oracle_price(&oracle_config(OracleSource::Pyth), &data, 1_026);
+
+        assert_eq!(result, Err(ErrorCode::StaleOraclePrice.into()));
+    }
+
+    #[test]
+    fn test_load_pyth_price_rejects_halted_price() {
+        let data = mock_pyth_price(2_512_345_678, -8, 2, 1_000);
+        let result = load_oracle_price(&oracle_config(OracleSource::Pyth), &data, 1_000);
+
+        assert_eq!(result, Err(ErrorCode::StaleOraclePrice.into()));
+    }
+
+    #[test]
+    fn test_load_pyth_price_rejects_invalid_account() {
+        let mut data = mock_pyth_price(2_512_345_678, -8, PYTH_STATUS_TRADING, 1_000);
+        data[PYTH_MAGIC_OFFSET] = 0;
+
+        assert_eq!(
+            load_pyth_price(&data),
+            Err(ErrorCode::InvalidOracleAccount.into())
+        );
+        assert_eq!(
+            load_pyth_price(&data[..PYTH_MIN_LEN - 1]),
+            Err(ErrorCode::InvalidOracleAccount.into())
+        );
+    }
+
+    #[test]
+    fn test_load_switchboard_price() {
+        let data = mock_switchboard_price(99_980_000, 8, 500);
+        let price =
+            load_oracle_price(&oracle_config(OracleSource::Switchboard), &data, 510).unwrap();
+
+        assert_eq!(
+            price,
+            OraclePrice {
+                price: 99_980_000,
+                expo: -8,
+                slot: 500,
+            }
+        );
+    }
+
+    #[test]
+    fn test_load_switchboard_price_rejects_non_positive_price() {
+        let data = mock_switchboard_price(0, 8, 500);
+        let result = load_oracle_price(&oracle_config(OracleSource::Switchboard), &data, 500);
+
+        assert_eq!(result, Err(ErrorCode::InvalidOracleAccount.into()));
+    }
+
+    #[test]
+    fn test_compute_deviation_bps() {
+        // 1 alpha = $25, 1 beta = $1
+        let alpha_price = OraclePrice {
+            price: 2_500_000_000,
+            expo: -8,
+            slot: 0,
+        };
+        let beta_price = OraclePrice {
+            price: 100_000_000,
+            expo: -8,
+            slot: 0,
+        };
+
+        // alpha has 9 decimals, beta has 6 decimals. pool holds 1,000 alpha and 25,000 beta.
+        let deviation = compute_deviation_bps(
+            25_000_000_000,
+            1_000_000_000_000,
+            &alpha_price,
+            &beta_price,
+            9,
+            6,
+        )
+        .unwrap();
+        assert_eq!(deviation, 0);
+
+        // pool holds 1,000 alpha and 25,500 beta; pool price is 2% above the oracle
+        let deviation = compute_deviation_bps(
+            25_500_000_000,
+            1_000_000_000_000,
+            &alpha_price,
+            &beta_price,
+            9,
+            6,
+        )
+        .unwrap();
+        assert!((199..=200).contains(&deviation));
+    }
+}
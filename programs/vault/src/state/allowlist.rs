@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+/**
+ * Account denoting a user is approved to deposit into a vault gated by an allowlist.
+ * Created and closed by the vault's authority.
+ *
+ * PDA address is derived from the following seeds:
+ *  - "allowlist"
+ *  - vault pubkey
+ *  - user pubkey
+ */
+#[account]
+#[derive(Debug, Default, PartialEq)]
+pub struct Allowlist {
+    /// bump
+    pub bump: u8,
+    /// vault the user is approved to deposit into
+    pub vault: Pubkey,
+    /// approved depositor
+    pub user: Pubkey,
+}
+
+impl Allowlist {
+    pub fn init(&mut self, bump: u8, vault: &Pubkey, user: &Pubkey) {
+        self.bump = bump;
+        self.vault = *vault;
+        self.user = *user;
+    }
+}
@@ -1,18 +1,31 @@
-use crate::error::ErrorCode;
+use crate::{error::ErrorCode, state::vault::ReturnTerms};
 use anchor_lang::prelude::*;
 use std::result::Result;
 
 #[repr(C)]
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
 pub struct Asset {
+    /// index of the tranche in the vault's tranches
+    pub tranche_id: u8,
+    /// payout priority of the tranche, 0 being the most senior
+    pub seniority: u8,
+    /// return owed to the tranche before junior tranches are paid out. none if the tranche holds the
+    /// residual claim
+    pub return_terms: Option<ReturnTerms>,
     /// asset's mint
     pub mint: Pubkey,
     /// token representing a stake in this asset
     pub lp: Pubkey,
+    /// decimals for both the asset's mint and lp
+    pub decimals: u8,
     /// cap on assets cumulative deposits
     pub asset_cap: Option<u64>,
     /// cap on any user's deposits
     pub user_cap: Option<u64>,
+    /// floor on the size of any single deposit
+    pub min_deposit: Option<u64>,
+    /// cap on the number of deposits any user can make
+    pub max_deposits_per_user: Option<u64>,
     /// number of independent deposits made into vault.
     pub deposits: u64,
     /// amount deposited
@@ -26,11 +39,14 @@ pub struct Asset {
     // plus posssibly adjusting for fixed return in the senior tranche
     pub received: u64,
 
-    // todo: update these values in code after midcycle LP deposits enabled
-    /// invested (+ any midterm LP token deposits)
+    /// number of tranche tokens backed by the invested position; invested (+ any tranche tokens
+    /// minted for mid-cycle deposits)
     pub total_invested: u64,
     /// amount deposited by the rollover fund, with priority over other deposits
     pub rollover_deposited: u64,
+    /// amount of the tranche's asset retained from early exit penalties. it stays invested and is owed to
+    /// the tranche's remaining holders on top of their investment.
+    pub exit_credit: u64,
 
     /// boolean indicating whether or not depositors can refund excess deposits + tranche tokens
     pub claims_processed: bool,
@@ -47,6 +63,29 @@ impl Asset {
         return self.deposited > 0;
     }
 
+    /// amount deposited by users directly, excluding deposits made by the rollover fund
+    pub fn user_deposited(&self) -> Result<u64, ProgramError> {
+        Ok(self
+            .deposited
+            .checked_sub(self.rollover_deposited)
+            .ok_or_else(math_error!())?)
+    }
+
+    /// rollover deposits have priority over other deposits, so they are the first to be invested
+    pub fn rollover_invested(&self) -> u64 {
+        return self.invested.min(self.rollover_deposited);
+    }
+
+    /// amount invested on behalf of users that deposited directly
+    pub fn user_invested(&self) -> u64 {
+        return self.invested.saturating_sub(self.rollover_deposited);
+    }
+
+    /// amount deposited by the rollover fund but not invested
+    pub fn rollover_excess(&self) -> u64 {
+        return self.rollover_deposited - self.rollover_invested();
+    }
+
     pub fn increment_deposits(&mut self) -> Result<(), ProgramError> {
         self.deposits = self.deposits.checked_add(1).ok_or_else(math_error!())?;
 
@@ -71,6 +110,28 @@ impl Asset {
         }
     }
 
+    // the rollover fund does not create receipts, so we only track the amount. these deposits
+    // are still subject to the asset cap.
+    pub fn add_rollover_deposit(&mut self, deposited: u64) -> Result<(), ProgramError> {
+        self.rollover_deposited = self
+            .rollover_deposited
+            .checked_add(deposited)
+            .ok_or_else(math_error!())?;
+        self.deposited = self
+            .deposited
+            .checked_add(deposited)
+            .ok_or_else(math_error!())?;
+
+        match self.asset_cap {
+            Some(asset_cap) => {
+                require!(self.deposited <= asset_cap, ErrorCode::AssetCapExceeded);
+
+                Ok(())
+            }
+            None => Ok(()),
+        }
+    }
+
     pub fn set_investment(&mut self, invested: u64) -> Result<(), ProgramError> {
         self.invested = invested;
 
@@ -98,6 +159,7 @@ impl Asset {
 
     pub fn _set_initial_investment(&mut self, amount: u64) -> ProgramResult {
         self.set_investment(amount)?;
+        self.total_invested = amount;
         self.verify_invested_amount(self.invested)?;
         self.set_excess(
             self.deposited
@@ -113,6 +175,10 @@ impl Asset {
     pub fn _make_additional_investment(&mut self, amount: u64) -> ProgramResult {
         self.add_investment(amount)?;
         self.verify_invested_amount(self.invested)?;
+        self.total_invested = self
+            .total_invested
+            .checked_add(amount)
+            .ok_or_else(math_error!())?;
 
         Ok(())
     }
@@ -124,6 +190,32 @@ impl Asset {
         }
     }
 
+    // tranche tokens minted for a mid-cycle deposit are backed by the invested position, but not
+    // by the initial deposits. we only track them via total_invested.
+    pub fn add_live_deposit(&mut self, lp_amount: u64) -> ProgramResult {
+        self.total_invested = self
+            .total_invested
+            .checked_add(lp_amount)
+            .ok_or_else(math_error!())?;
+
+        Ok(())
+    }
+
+    // tranche tokens burned for an early exit no longer back the invested position. the penalty stays with
+    // the tranche, so the remaining holders' share of the tranche grows.
+    pub fn exit_early(&mut self, lp_amount: u64, penalty: u64) -> ProgramResult {
+        self.total_invested = self
+            .total_invested
+            .checked_sub(lp_amount)
+            .ok_or_else(math_error!())?;
+        self.exit_credit = self
+            .exit_credit
+            .checked_add(penalty)
+            .ok_or_else(math_error!())?;
+
+        Ok(())
+    }
+
     pub fn set_excess(&mut self, excess: u64) -> Result<(), ProgramError> {
         self.excess = excess;
 
@@ -149,6 +241,13 @@ impl Asset {
         Ok(())
     }
 
+    pub fn set_receipt(&mut self, amount: u64) -> Result<(), ProgramError> {
+        self.received = amount;
+        msg!("{:?} received amount now = {:?}", self.mint, self.received);
+
+        Ok(())
+    }
+
     pub fn sub_receipt(&mut self, amount: u64) -> Result<(), ProgramError> {
         self.received = self
             .received
@@ -178,22 +277,49 @@ impl Asset {
 }
 
 pub struct AssetBuilder {
+    pub tranche_id: Option<u8>,
+    pub seniority: Option<u8>,
+    pub return_terms: Option<ReturnTerms>,
     pub mint: Option<Pubkey>,
     pub lp: Option<Pubkey>,
+    pub decimals: Option<u8>,
     pub asset_cap: Option<u64>,
     pub user_cap: Option<u64>,
+    pub min_deposit: Option<u64>,
+    pub max_deposits_per_user: Option<u64>,
 }
 
 impl AssetBuilder {
     pub fn new() -> Self {
         Self {
+            tranche_id: None,
+            seniority: None,
+            return_terms: None,
             mint: None,
             lp: None,
+            decimals: None,
             asset_cap: None,
             user_cap: None,
+            min_deposit: None,
+            max_deposits_per_user: None,
         }
     }
 
+    pub fn tranche_id(mut self, tranche_id: u8) -> Self {
+        self.tranche_id = Some(tranche_id);
+        self
+    }
+
+    pub fn seniority(mut self, seniority: u8) -> Self {
+        self.seniority = Some(seniority);
+        self
+    }
+
+    pub fn return_terms(mut self, return_terms: impl Into<Option<ReturnTerms>>) -> Self {
+        self.return_terms = return_terms.into();
+        self
+    }
+
     pub fn mint(mut self, mint: impl Into<Pubkey>) -> Self {
         self.mint = Some(mint.into());
         self
@@ -204,6 +330,11 @@ impl AssetBuilder {
         self
     }
 
+    pub fn decimals(mut self, decimals: u8) -> Self {
+        self.decimals = Some(decimals);
+        self
+    }
+
     pub fn asset_cap(mut self, amount: impl Into<Option<u64>>) -> Self {
         self.asset_cap = amount.into();
         self
@@ -214,8 +345,28 @@ impl AssetBuilder {
         self
     }
 
+    pub fn min_deposit(mut self, amount: impl Into<Option<u64>>) -> Self {
+        self.min_deposit = amount.into();
+        self
+    }
+
+    pub fn max_deposits_per_user(mut self, count: impl Into<Option<u64>>) -> Self {
+        self.max_deposits_per_user = count.into();
+        self
+    }
+
     pub fn build(self) -> Result<Asset, ProgramError> {
         // todo: turn into macro; maybe https://github.com/saber-hq/vipers/blob/e127d6d1772839adc19b41b1dbe3045d231da7b9/vipers/src/assert.rs#L652
+        let _tranche_id = match self.tranche_id {
+            Some(tranche_id) => tranche_id,
+            None => return Err(ErrorCode::MissingRequiredField.into()),
+        };
+
+        let _seniority = match self.seniority {
+            Some(seniority) => seniority,
+            None => return Err(ErrorCode::MissingRequiredField.into()),
+        };
+
         let _mint = match self.mint {
             Some(mint) => mint,
             None => return Err(ErrorCode::MissingRequiredField.into()),
@@ -226,11 +377,22 @@ impl AssetBuilder {
             None => return Err(ErrorCode::MissingRequiredField.into()),
         };
 
+        let _decimals = match self.decimals {
+            Some(decimals) => decimals,
+            None => return Err(ErrorCode::MissingRequiredField.into()),
+        };
+
         Ok(Asset {
+            tranche_id: _tranche_id,
+            seniority: _seniority,
+            return_terms: self.return_terms,
             mint: _mint,
             lp: _lp,
+            decimals: _decimals,
             asset_cap: self.asset_cap,
             user_cap: self.user_cap,
+            min_deposit: self.min_deposit,
+            max_deposits_per_user: self.max_deposits_per_user,
             deposits: 0,
             deposited: 0,
             invested: 0,
@@ -238,6 +400,7 @@ impl AssetBuilder {
             received: 0,
             total_invested: 0,
             rollover_deposited: 0,
+            exit_credit: 0,
 
             // excess related metadata; value set at time of investment
             claims_processed: false,
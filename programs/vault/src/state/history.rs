@@ -8,7 +8,7 @@ use std::result::Result;
  * PDA address is derived from the following seeds:
  *  - "history"
  *  - vault pubkey
- *  - mint pubkey
+ *  - tranche id, as a single little endian byte
  *  - user pubkey
  */
 #[account]
@@ -27,6 +27,13 @@ pub struct History {
     pub claim: u64,
     /// user has right to claim LP tokens representing their position in the tranche
     pub can_claim_tranche_lp: bool,
+    /// portion of cumulative deposited on the user's behalf by the rollover fund, not yet settled
+    pub rollover: u64,
+    /// count of deposits made by the user after the vault went live
+    pub live_deposits: u64,
+    /// cumulative amount deposited after the vault went live. tranche tokens for these deposits are
+    /// minted at deposit time, so claims never read this.
+    pub live_cumulative: u64,
 }
 
 impl History {
@@ -38,6 +45,9 @@ impl History {
             self.cumulative = 0;
             self.claim = 0;
             self.can_claim_tranche_lp = true;
+            self.rollover = 0;
+            self.live_deposits = 0;
+            self.live_cumulative = 0;
         }
     }
 
@@ -57,6 +67,72 @@ impl History {
         Ok(())
     }
 
+    pub fn live_deposit(&mut self, amount: u64) -> Result<(), ProgramError> {
+        self.live_deposits = self
+            .live_deposits
+            .checked_add(1)
+            .ok_or_else(math_error!())?;
+        self.live_cumulative = self
+            .live_cumulative
+            .checked_add(amount)
+            .ok_or_else(math_error!())?;
+
+        Ok(())
+    }
+
+    // deposit limits apply across initial and live deposits
+    pub fn total_deposits(&self) -> Result<u64, ProgramError> {
+        Ok(self
+            .deposits
+            .checked_add(self.live_deposits)
+            .ok_or_else(math_error!())?)
+    }
+
+    pub fn total_cumulative(&self) -> Result<u64, ProgramError> {
+        Ok(self
+            .cumulative
+            .checked_add(self.live_cumulative)
+            .ok_or_else(math_error!())?)
+    }
+
+    // rollover deposits do not count towards the user's number of deposits
+    pub fn rollover_deposit(&mut self, amount: u64) -> Result<(), ProgramError> {
+        self.cumulative = self
+            .cumulative
+            .checked_add(amount)
+            .ok_or_else(math_error!())?;
+        self.rollover = self
+            .rollover
+            .checked_add(amount)
+            .ok_or_else(math_error!())?;
+
+        Ok(())
+    }
+
+    // rollover deposits are not walked by process_claims. if the rollover fund was not fully invested,
+    // every rolled over position is refunded pro-rata.
+    pub fn settle_rollover(
+        &mut self,
+        rollover_excess: u64,
+        rollover_deposited: u64,
+    ) -> Result<(), ProgramError> {
+        if self.rollover == 0 || rollover_excess == 0 {
+            self.rollover = 0;
+            return Ok(());
+        }
+
+        let amount = (self.rollover as u128)
+            .checked_mul(rollover_excess as u128)
+            .ok_or_else(math_error!())?
+            .checked_div(rollover_deposited as u128)
+            .ok_or_else(math_error!())?;
+
+        self.add_claim(amount as u64)?;
+        self.rollover = 0;
+
+        Ok(())
+    }
+
     pub fn has_claim(&self) -> bool {
         return self.claim > 0;
     }
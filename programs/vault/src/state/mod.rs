@@ -1,13 +1,19 @@
+pub mod allowlist;
 pub mod asset;
 pub mod global_protocol_state;
 pub mod history;
 pub mod receipt;
+pub mod roles;
+pub mod rollover;
 pub mod strategy;
 pub mod vault;
 
+pub use allowlist::*;
 pub use asset::*;
 pub use global_protocol_state::*;
 pub use history::*;
 pub use receipt::*;
+pub use roles::*;
+pub use rollover::*;
 pub use strategy::*;
 pub use vault::*;
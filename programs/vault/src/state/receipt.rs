@@ -10,10 +10,10 @@ use std::result::Result;
  * PDA address is derived from the following seeds:
  *  - "receipt"
  *  - vault pubkey
- *  - asset pubkey
+ *  - tranche id, as a single little endian byte
  *  - deposit idx
  *
- * @dev vault and asset pubkeys are not struct attributes because they are
+ * @dev vault pubkey and tranche id are not struct attributes because they are
  * encoded via the PDA seeds.
  */
 #[account]
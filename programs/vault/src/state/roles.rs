@@ -0,0 +1,28 @@
+use crate::error::{ErrorCode, OraResult};
+use anchor_lang::prelude::*;
+use enumflags2::BitFlags;
+
+/// Operations on a vault's strategy. The strategist holds every role; the vault authority can delegate a
+/// subset of them to a lower privilege keeper, e.g. harvesting and converting farm rewards, while swaps,
+/// rebalancing and redemptions stay with the strategist.
+#[derive(Copy, Clone, BitFlags, Debug, Eq, PartialEq)]
+#[repr(u64)]
+pub enum StrategistRole {
+    Invest = 1u64 << 0,
+    Redeem = 1u64 << 1,
+    Rebalance = 1u64 << 2,
+    Swap = 1u64 << 3,
+    /// convert base LP to farm LP, including initializing the vault's farm account
+    Convert = 1u64 << 4,
+    Harvest = 1u64 << 5,
+    /// revert farm LP to base LP
+    Revert = 1u64 << 6,
+}
+
+impl StrategistRole {
+    pub fn roles_from_u64(roles: u64) -> OraResult<BitFlags<StrategistRole>> {
+        BitFlags::from_bits(roles)
+            .map_err(|_| ErrorCode::InvalidStrategistRoles.into())
+            .map(Into::into)
+    }
+}
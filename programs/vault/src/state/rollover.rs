@@ -0,0 +1,53 @@
+use crate::error::ErrorCode;
+use anchor_lang::prelude::*;
+use std::result::Result;
+
+/**
+ * Account to track a user's tranche position that should be carried into the vault's
+ * next epoch, rather than withdrawn.
+ *
+ * PDA address is derived from the following seeds:
+ *  - "rollover"
+ *  - vault pubkey
+ *  - tranche id, as a single little endian byte
+ *  - user pubkey
+ */
+#[account]
+#[derive(Debug, Default, PartialEq)]
+pub struct Rollover {
+    /// bump
+    pub bump: u8,
+    /// attribute explicitly saying whether or not an account has been initialized
+    pub initialized: bool,
+    /// entity whose position is rolled over
+    pub depositor: Pubkey,
+    /// number of tranche tokens committed to the rollover fund
+    pub lp_amount: u64,
+    /// position has been paid out into the next vault
+    pub processed: bool,
+}
+
+impl Rollover {
+    pub fn init_if_needed(&mut self, bump: u8, depositor: &Pubkey) {
+        if !self.initialized {
+            self.bump = bump;
+            self.initialized = true;
+            self.depositor = *depositor;
+            self.lp_amount = 0;
+            self.processed = false;
+        }
+    }
+
+    pub fn add_lp(&mut self, amount: u64) -> Result<(), ProgramError> {
+        self.lp_amount = self
+            .lp_amount
+            .checked_add(amount)
+            .ok_or_else(math_error!())?;
+
+        Ok(())
+    }
+
+    pub fn mark_processed(&mut self) {
+        self.processed = true;
+    }
+}
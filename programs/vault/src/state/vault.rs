@@ -1,11 +1,11 @@
 use crate::{
-    constant::{
-        DEPOSIT_STATE, INACTIVE_STATE, LIVE_STATE, REBALANCE_STATE, REDEEM_STATE, WITHDRAW_STATE,
-    },
+    constant::{MAX_BPS, MAX_TRANCHES},
     error::ErrorCode,
-    state::asset::Asset,
+    math::compute_rebalance_deltas,
+    state::{asset::Asset, roles::StrategistRole},
 };
 use anchor_lang::prelude::*;
+use enumflags2::BitFlags;
 use std::result::Result;
 
 #[account]
@@ -20,8 +20,16 @@ pub trait HasVault {
 #[repr(C)]
 #[derive(AnchorSerialize, AnchorDeserialize, Debug, Default, PartialEq)]
 pub struct AssetConfig {
+    // payout priority, 0 being the most senior
+    pub seniority: u8,
+    // ticker of the tranche's underlying asset, used to generate the tranche token's metadata
+    pub symbol: String,
+    // return offered to the tranche; none for the tranche holding the residual claim
+    pub return_terms: Option<ReturnTerms>,
     pub user_cap: Option<u64>,
     pub asset_cap: Option<u64>,
+    pub min_deposit: Option<u64>,
+    pub max_deposits_per_user: Option<u64>,
 }
 
 #[repr(C)]
@@ -30,13 +38,106 @@ pub struct VaultConfig {
     pub strategy: Pubkey,
     pub authority: Pubkey,
     pub strategist: Pubkey,
-    pub alpha: AssetConfig,
-    pub beta: AssetConfig,
-    pub fixed_rate: u16,
+    // ordered from most senior to most junior
+    pub tranches: Vec<AssetConfig>,
     pub start_at: u64,
     // duration for which the vault will be in their respective states
     pub deposit_duration: u64,
     pub invest_duration: u64,
+    // restrict which users can deposit into the vault; defaults to open
+    pub deposit_gate: Option<DepositGate>,
+    // max slippage against the pool's spot price the vault accepts when interacting with a strategy
+    pub max_slippage_bps: u16,
+    // optional price sanity checks against oracles when the strategy interacts with a pool
+    pub oracle: Option<OracleConfig>,
+    // optional band for the senior-to-junior value ratio of the vault's investments
+    pub leverage: Option<LeverageConfig>,
+    // base uri for tranche token metadata; each tranche token points at {metadata_uri}/{vault}/{tranche_id}.json
+    pub metadata_uri: String,
+    // penalty on early exits, in basis points of the exit value; none disables early exits
+    pub early_exit_penalty_bps: Option<u16>,
+}
+
+/// Return owed to a tranche before any tranche junior to it is paid out. A flat return is expressed
+/// as floor_bps == cap_bps and yield_share_bps == 0.
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct ReturnTerms {
+    /// min return over the lifetime of the vault, in basis points
+    pub floor_bps: u16,
+    /// max return over the lifetime of the vault, in basis points
+    pub cap_bps: u16,
+    /// share of the realized yield of the vault's assets paid as return, in basis points. the return
+    /// is bounded by the floor and cap.
+    pub yield_share_bps: u16,
+    /// max amount of the junior tranche's investment that absorbs the tranche's losses, in basis points
+    /// of the junior tranche. none if the junior tranche covers losses up to all of its assets.
+    pub first_loss_buffer_bps: Option<u16>,
+}
+
+impl ReturnTerms {
+    /// Terms with the floor and cap pro-rated to the share of the invest period that has elapsed, in basis
+    /// points. the share of the realized yield is kept as is; the yield realized so far already reflects the
+    /// time elapsed.
+    pub fn accrued(&self, accrual_bps: u64) -> ReturnTerms {
+        let accrue = |bps: u16| (bps as u64 * accrual_bps.min(MAX_BPS) / MAX_BPS) as u16;
+
+        ReturnTerms {
+            floor_bps: accrue(self.floor_bps),
+            cap_bps: accrue(self.cap_bps),
+            ..*self
+        }
+    }
+}
+
+/// Leverage offered to the junior tranche, expressed as the value of the senior tranches relative to the
+/// value of the junior tranche. e.g. 30_000 bps means 3 units of senior value for every unit of junior value.
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct LeverageConfig {
+    /// senior-to-junior value ratio advertised to depositors, in basis points
+    pub target_ratio_bps: u32,
+    /// min senior-to-junior value ratio the strategist can invest at, in basis points
+    pub min_ratio_bps: u32,
+    /// max senior-to-junior value ratio the strategist can invest at, in basis points
+    pub max_ratio_bps: u32,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum OracleSource {
+    Pyth,
+    Switchboard,
+}
+
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct OracleConfig {
+    /// oracle program that owns both price accounts
+    pub source: OracleSource,
+    /// price account for the alpha asset
+    pub alpha_oracle: Pubkey,
+    /// price account for the beta asset, quoted in the same currency as the alpha asset
+    pub beta_oracle: Pubkey,
+    /// max deviation between the pool-implied and oracle-implied price, in basis points
+    pub max_deviation_bps: u16,
+    /// max number of slots since the oracle price was last updated
+    pub max_staleness_slots: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum DepositGate {
+    /// anyone can deposit
+    Open,
+    /// only users with an allowlist PDA created by the vault authority can deposit
+    Allowlist,
+    /// only users that can prove membership in the merkle tree with the given root can deposit
+    MerkleRoot([u8; 32]),
+}
+
+impl Default for DepositGate {
+    fn default() -> Self {
+        DepositGate::Open
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
@@ -55,6 +156,52 @@ impl Default for State {
     }
 }
 
+/// Signers allowed to trigger a state transition. the vault authority can trigger every transition.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TransitionRole {
+    /// the vault's strategist
+    Strategist,
+    /// anyone once the vault's schedule is past the deadline for leaving its current state; only the
+    /// strategist before that
+    AnyoneAfterDeadline,
+}
+
+/// Edges of the vault's state machine and who may trigger them. there is no edge back into inactive; the
+/// authority returns a vault to inactive by cancelling it, see `Vault::cancel`.
+pub const STATE_TRANSITIONS: [(State, State, TransitionRole); 5] = [
+    (
+        State::Inactive,
+        State::Deposit,
+        TransitionRole::AnyoneAfterDeadline,
+    ),
+    (
+        State::Deposit,
+        State::Live,
+        TransitionRole::AnyoneAfterDeadline,
+    ),
+    (
+        State::Live,
+        State::Redeem,
+        TransitionRole::AnyoneAfterDeadline,
+    ),
+    (State::Redeem, State::Rebalance, TransitionRole::Strategist),
+    (
+        State::Rebalance,
+        State::Withdraw,
+        TransitionRole::Strategist,
+    ),
+];
+
+impl State {
+    /// role allowed to move a vault from this state into the target state; none if there is no such edge
+    pub fn transition_role(&self, target: State) -> Option<TransitionRole> {
+        STATE_TRANSITIONS
+            .iter()
+            .find(|(from, to, _)| from == self && *to == target)
+            .map(|(_, _, role)| *role)
+    }
+}
+
 #[account]
 #[derive(Debug, Default, PartialEq)]
 pub struct Vault {
@@ -67,16 +214,15 @@ pub struct Vault {
     /// vault_store bump since vault_store can't hold any data; and so that we don't have
     /// to pass into every instruction
     pub vault_store_bump: u8,
-    /// asset with a fixed return from vault
-    pub alpha: Asset,
-    /// asset with a variable return from vault
-    pub beta: Asset,
+    /// tranches ordered by payout priority, from most senior to most junior. every tranche but the
+    /// most junior has return terms; the most junior tranche holds the residual claim.
+    pub tranches: [Asset; MAX_TRANCHES],
+    /// number of tranches in use
+    pub num_tranches: u8,
     /// defines interactions with downstream protocols
     pub strategy: Pubkey,
     /// entity with the right to invoke functions defined by the strategy
     pub strategist: Pubkey,
-    /// return offered to the alpha asset, in basis points
-    pub fixed_rate: u16,
     /// current state of vault
     pub state: State,
     /// timestamp at which the vault should start accepting deposits
@@ -95,6 +241,27 @@ pub struct Vault {
     pub rebalanced_at: Option<u64>,
     /// boolean indicating whether or not funds have been rebalanced based on expected returns
     pub reblanced: bool,
+    /// vault for the next epoch; positions in the rollover fund are deposited into this vault
+    pub rollover_vault: Option<Pubkey>,
+    /// restricts which users can deposit into the vault
+    pub deposit_gate: DepositGate,
+    /// max slippage against the pool's spot price, in basis points, for strategy invest, redeem and swaps
+    pub max_slippage_bps: u16,
+    /// optional oracle config used to sanity check pool prices
+    pub oracle: Option<OracleConfig>,
+    /// optional band for the senior-to-junior value ratio, verified when the strategist invests
+    pub leverage: Option<LeverageConfig>,
+    /// tranche tokens frozen by the vault authority, e.g. during an emergency pause
+    pub tranche_lp_frozen: bool,
+    /// penalty on early exits, in basis points of the exit value, kept by the exiting tranche's remaining
+    /// holders. none if early exits are disabled.
+    pub early_exit_penalty_bps: Option<u16>,
+    /// timestamp at which the authority cancelled the vault; every deposit is refunded through claims
+    pub cancelled_at: Option<u64>,
+    /// lower privilege key the authority delegates some of the strategist's roles to
+    pub keeper: Option<Pubkey>,
+    /// `StrategistRole` bitmask of the roles delegated to the keeper
+    pub keeper_roles: u64,
 }
 
 impl Vault {
@@ -105,14 +272,15 @@ impl Vault {
         vault_store: Pubkey,
         vault_store_bump: u8,
         config: VaultConfig,
-        alpha: Asset,
-        beta: Asset,
+        tranches: Vec<Asset>,
     ) {
         self.bump = bump;
 
-        // vault assets
-        self.alpha = alpha;
-        self.beta = beta;
+        // vault tranches
+        self.num_tranches = tranches.len() as u8;
+        for (idx, tranche) in tranches.into_iter().enumerate() {
+            self.tranches[idx] = tranche;
+        }
 
         // vault config
         self.authority = authority;
@@ -120,7 +288,6 @@ impl Vault {
         self.vault_store_bump = vault_store_bump;
         self.strategy = config.strategy;
         self.strategist = config.strategist;
-        self.fixed_rate = config.fixed_rate;
         self.start_at = config.start_at;
         self.started_at = None;
         self.deposit_duration = config.deposit_duration;
@@ -129,6 +296,16 @@ impl Vault {
         self.redeemed_at = None;
         self.rebalanced_at = None;
         self.reblanced = false;
+        self.rollover_vault = None;
+        self.deposit_gate = config.deposit_gate.unwrap_or_default();
+        self.max_slippage_bps = config.max_slippage_bps;
+        self.oracle = config.oracle;
+        self.leverage = config.leverage;
+        self.tranche_lp_frozen = false;
+        self.early_exit_penalty_bps = config.early_exit_penalty_bps;
+        self.cancelled_at = None;
+        self.keeper = None;
+        self.keeper_roles = 0;
         self.state = State::Inactive;
     }
 
@@ -140,89 +317,206 @@ impl Vault {
         self.strategist = strategist;
     }
 
-    pub fn has_dual_deposits(&self) -> bool {
-        return self.alpha.has_deposits() && self.beta.has_deposits();
+    pub fn update_keeper(&mut self, keeper: Option<Pubkey>, roles: BitFlags<StrategistRole>) {
+        self.keeper = keeper;
+        self.keeper_roles = match keeper {
+            Some(_) => roles.bits(),
+            None => 0,
+        };
+    }
+
+    /// whether the signer can perform the strategy operation; the strategist holds every role
+    pub fn has_strategist_role(&self, signer: &Pubkey, role: StrategistRole) -> bool {
+        return *signer == self.strategist
+            || (self.keeper == Some(*signer)
+                && BitFlags::<StrategistRole>::from_bits_truncate(self.keeper_roles)
+                    .contains(role));
+    }
+
+    pub fn update_rollover_vault(&mut self, rollover_vault: Option<Pubkey>) {
+        self.rollover_vault = rollover_vault;
+    }
+
+    pub fn has_deposits_in_all_tranches(&self) -> bool {
+        return self.tranches().iter().all(|tranche| tranche.has_deposits());
     }
 
     pub fn state(&self) -> State {
         return self.state;
     }
 
+    pub fn mark_rebalanced(&mut self) {
+        self.reblanced = true;
+    }
+
     pub fn can_disperse_funds(&self) -> bool {
         // vault state is in rebalance state and funds have been reblanced
         return self.state == State::Rebalance && self.reblanced;
     }
 
-    // we allow non-linear transitions because `transition` is a permmissioned instruction
+    /// timestamp from which anyone can move the vault out of its current state, derived from the vault's
+    /// configured schedule rather than the recorded transition times. none if the current state has no
+    /// deadline.
+    pub fn transition_deadline(&self) -> Option<u64> {
+        let deposit_end = self.start_at.saturating_add(self.deposit_duration);
+        match self.state {
+            State::Inactive => Some(self.start_at),
+            State::Deposit => Some(deposit_end),
+            State::Live => Some(deposit_end.saturating_add(self.invest_duration)),
+            _ => None,
+        }
+    }
+
+    /// Share of the invest period elapsed at now, in basis points. tranches accrue their return terms over the
+    /// invest period, so a live vault is valued mid-cycle with pro-rated returns. once redeemed, tranches are
+    /// owed their full returns.
+    ///
+    /// @dev the invest period runs for invest_duration from the time the vault actually went live. a vault
+    ///      without an invest duration accrues its full returns as soon as it is live.
+    ///
+    pub fn accrual_bps(&self, now: u64) -> u64 {
+        match (self.state, self.invested_at) {
+            (State::Live, Some(invested_at)) if self.invest_duration > 0 => {
+                let elapsed = now.saturating_sub(invested_at).min(self.invest_duration);
+                (elapsed as u128 * MAX_BPS as u128 / self.invest_duration as u128) as u64
+            }
+            (State::Live | State::Redeem | State::Rebalance | State::Withdraw, _) => MAX_BPS,
+            _ => 0,
+        }
+    }
+
+    /// whether the signer can trigger transitions without waiting on the vault's schedule, and record the
+    /// time of the transition
+    pub fn is_transition_admin(&self, signer: &Pubkey) -> bool {
+        return *signer == self.authority || *signer == self.strategist;
+    }
+
+    /// Verify the signer may move the vault into the target state; see `STATE_TRANSITIONS`
+    pub fn verify_transition_signer(
+        &self,
+        target: State,
+        signer: &Pubkey,
+        now: u64,
+    ) -> ProgramResult {
+        let role = self
+            .state
+            .transition_role(target)
+            .ok_or(ErrorCode::MissingTransitionAtTimeForState)?;
+
+        let allowed = *signer == self.authority
+            || match role {
+                TransitionRole::Strategist => *signer == self.strategist,
+                TransitionRole::AnyoneAfterDeadline => {
+                    *signer == self.strategist
+                        || self
+                            .transition_deadline()
+                            .map_or(false, |deadline| now >= deadline)
+                }
+            };
+        require!(allowed, ErrorCode::UnauthorizedStateTransition);
+
+        Ok(())
+    }
+
+    // states only move along the edges of `STATE_TRANSITIONS`; who triggered the transition is verified
+    // by the caller.
     //
     // @dev: previously, we allowed linear state machine transition based mostly on timestamps, but
     //       we pivoted to this approach givent the possibile cluster time drift + the lack of oracles
-    //       providing timestamps
+    //       providing timestamps. the vault's schedule only decides when anyone, not just the authority
+    //       or strategist, can trigger a transition.
     //
-    // @dev: we don't enforrce time constraints but we do enforce order of state transitions. even the
-    //       strategist must by abide by some order.
-    //
-    pub fn transition(&mut self, target: String, ts: u64) -> ProgramResult {
-        let _target = target.trim().to_lowercase();
-
-        match &_target as &str {
-            INACTIVE_STATE => self.state = State::Inactive,
-            DEPOSIT_STATE => {
-                if self.state != State::Inactive {
-                    return Err(ErrorCode::MissingTransitionAtTimeForState.into());
-                }
-                self.state = State::Deposit;
-                self.started_at = Some(ts);
-            }
-            LIVE_STATE => {
-                if self.state != State::Deposit {
-                    return Err(ErrorCode::MissingTransitionAtTimeForState.into());
-                }
-                self.state = State::Live;
-                self.invested_at = Some(ts);
-            }
-            REDEEM_STATE => {
-                if self.state != State::Live {
-                    return Err(ErrorCode::MissingTransitionAtTimeForState.into());
-                }
-                self.state = State::Redeem;
-                self.redeemed_at = Some(ts);
-            }
-            REBALANCE_STATE => {
-                if self.state != State::Redeem {
-                    return Err(ErrorCode::MissingTransitionAtTimeForState.into());
-                }
+    pub fn transition(&mut self, target: State, ts: u64) -> ProgramResult {
+        require!(!self.is_cancelled(), ErrorCode::VaultCancelled);
+        require!(
+            self.state.transition_role(target).is_some(),
+            ErrorCode::MissingTransitionAtTimeForState
+        );
+
+        match target {
+            State::Deposit => self.started_at = Some(ts),
+            State::Live => self.invested_at = Some(ts),
+            State::Redeem => self.redeemed_at = Some(ts),
+            State::Rebalance => {
                 // enforce non-zero returns in at least 1 tranche before moving to rebalance state
-                if self.alpha.received == 0 && self.beta.received == 0 {
+                if self.tranches().iter().all(|tranche| tranche.received == 0) {
                     return Err(ErrorCode::ExpectedNonzeroReturns.into());
                 }
-                self.state = State::Rebalance;
                 self.rebalanced_at = Some(ts);
             }
-            WITHDRAW_STATE => {
-                if self.state != State::Rebalance {
-                    return Err(ErrorCode::MissingTransitionAtTimeForState.into());
-                }
-
+            State::Withdraw => {
                 if !self.can_disperse_funds() {
                     return Err(ErrorCode::InvalidVaultState.into());
                 }
-
-                self.state = State::Withdraw
             }
-            _ => return Err(ErrorCode::MissingTransitionAtTimeForState.into()),
+            // no edge leads back into inactive
+            State::Inactive => {}
+        }
+        self.state = target;
+
+        Ok(())
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        return self.cancelled_at.is_some();
+    }
+
+    /// a vault can be cancelled until any of its deposits are invested
+    pub fn can_cancel(&self) -> bool {
+        return !self.is_cancelled()
+            && matches!(self.state, State::Inactive | State::Deposit | State::Live)
+            && self
+                .tranches()
+                .iter()
+                .all(|tranche| tranche.invested == 0 && tranche.total_invested == 0);
+    }
+
+    /// Cancel the vault and move it back to inactive. every tranche's deposits become excess, so once claims
+    /// are processed, each depositor's claim refunds their full deposits and mints no tranche tokens.
+    ///
+    /// @dev a cancelled vault can't transition again.
+    ///
+    pub fn cancel(&mut self, ts: u64) -> ProgramResult {
+        require!(self.can_cancel(), ErrorCode::VaultNotCancellable);
+
+        for tranche in self.tranches_mut() {
+            tranche.set_excess(tranche.deposited)?;
         }
+        self.state = State::Inactive;
+        self.cancelled_at = Some(ts);
 
         Ok(())
     }
 
+    /// whether a rollover position of amount could still be deposited into the tranche. a vault that has not
+    /// opened for deposits yet may still accept the position later; a cancelled vault, a vault past the deposit
+    /// state or a tranche whose asset cap the position would exceed cannot.
+    pub fn can_accept_rollover(&self, tranche_id: u8, amount: u64) -> Result<bool, ProgramError> {
+        if self.is_cancelled() || !matches!(self.state, State::Inactive | State::Deposit) {
+            return Ok(false);
+        }
+
+        let tranche = self.get_tranche(tranche_id)?;
+        match tranche.asset_cap {
+            Some(asset_cap) => {
+                let deposited = tranche
+                    .deposited
+                    .checked_add(amount)
+                    .ok_or_else(math_error!())?;
+
+                Ok(deposited <= asset_cap)
+            }
+            None => Ok(true),
+        }
+    }
+
     // allow vault to transition from -> into specific states such that the authority doesn't have to explicitly
     // transition vault state. this approach is only applicable for the following states, not based on timestamp.
     pub fn try_transition(&mut self) -> ProgramResult {
         match self.state {
             State::Rebalance => {
                 if self.can_disperse_funds() {
-                    self.transition(WITHDRAW_STATE.to_string(), 0)?;
+                    self.transition(State::Withdraw, 0)?;
                 }
 
                 Ok(())
@@ -231,50 +525,229 @@ impl Vault {
         }
     }
 
-    pub fn get_deposits_for(&self, mint: &Pubkey) -> Result<u64, ProgramError> {
-        Ok(self.get_asset(mint)?.deposits)
+    pub fn get_deposits_for(&self, tranche_id: u8) -> Result<u64, ProgramError> {
+        Ok(self.get_tranche(tranche_id)?.deposits)
     }
 
-    pub fn get_asset(&self, mint: &Pubkey) -> Result<Asset, ProgramError> {
-        let asset = match *mint {
-            m if self.alpha.mint == m => self.alpha,
-            m if self.beta.mint == m => self.beta,
-            _ => return Err(ErrorCode::NonexistentAsset.into()),
-        };
+    pub fn tranches(&self) -> &[Asset] {
+        &self.tranches[..self.num_tranches as usize]
+    }
+
+    pub fn get_tranche(&self, tranche_id: u8) -> Result<Asset, ProgramError> {
+        match self.tranches().get(tranche_id as usize) {
+            Some(tranche) => Ok(*tranche),
+            None => Err(ErrorCode::NonexistentAsset.into()),
+        }
+    }
+
+    pub fn tranches_mut(&mut self) -> &mut [Asset] {
+        let num_tranches = self.num_tranches as usize;
+        &mut self.tranches[..num_tranches]
+    }
+
+    pub fn get_tranche_mut<'a>(
+        &'a mut self,
+        tranche_id: u8,
+    ) -> Result<&'a mut Asset, ProgramError> {
+        match self.tranches_mut().get_mut(tranche_id as usize) {
+            Some(tranche) => Ok(tranche),
+            None => Err(ErrorCode::NonexistentAsset.into()),
+        }
+    }
 
-        Ok(asset)
+    /// tranches can also be addressed by their tranche token, which is unique per tranche
+    pub fn get_tranche_by_lp(&self, lp: &Pubkey) -> Result<Asset, ProgramError> {
+        match self.tranches().iter().find(|tranche| tranche.lp == *lp) {
+            Some(tranche) => Ok(*tranche),
+            None => Err(ErrorCode::InvalidLpMint.into()),
+        }
     }
 
-    pub fn get_alpha_mut<'a>(&'a mut self) -> Result<&'a mut Asset, ProgramError> {
-        Ok(&mut self.alpha)
+    /// most senior tranche. strategies pair its asset with the most junior tranche's asset.
+    pub fn alpha(&self) -> &Asset {
+        &self.tranches[0]
     }
 
-    pub fn get_beta_mut<'a>(&'a mut self) -> Result<&'a mut Asset, ProgramError> {
-        Ok(&mut self.beta)
+    /// most junior tranche, holding the residual claim
+    pub fn beta(&self) -> &Asset {
+        &self.tranches[(self.num_tranches as usize).saturating_sub(1)]
     }
 
-    pub fn get_asset_mut<'a>(&'a mut self, mint: &Pubkey) -> Result<&'a mut Asset, ProgramError> {
-        let asset = match *mint {
-            m if self.alpha.mint == m => &mut self.alpha,
-            m if self.beta.mint == m => &mut self.beta,
-            _ => return Err(ErrorCode::NonexistentAsset.into()),
+    /// whether every tranche is backed by the same asset, e.g. a senior and junior tranche over a
+    /// single-asset lending strategy
+    pub fn is_single_asset(&self) -> bool {
+        return self.alpha().mint == self.beta().mint;
+    }
+
+    // indices of the tranches backed by the given asset, in payout order
+    fn tranche_ids_for(&self, mint: &Pubkey) -> Result<Vec<usize>, ProgramError> {
+        let ids: Vec<usize> = self
+            .tranches()
+            .iter()
+            .enumerate()
+            .filter(|(_, tranche)| tranche.mint == *mint)
+            .map(|(idx, _)| idx)
+            .collect();
+        require!(!ids.is_empty(), ErrorCode::NonexistentAsset);
+
+        Ok(ids)
+    }
+
+    /// Record an investment of an asset across the tranches it backs. senior tranches are invested
+    /// first, up to their deposits; the most junior tranche backed by the asset is invested the rest.
+    ///
+    /// @dev follow-on investments, e.g. live deposits, are attributed to the most junior tranche backed
+    ///      by the asset. tranche tokens minted for live deposits are tracked via total_invested.
+    pub fn make_investment(&mut self, mint: &Pubkey, amount: u64) -> ProgramResult {
+        let ids = self.tranche_ids_for(mint)?;
+        let (last, senior_ids) = ids.split_last().ok_or(ErrorCode::NonexistentAsset)?;
+
+        let mut remaining = amount;
+        for idx in senior_ids {
+            let tranche = &mut self.tranches[*idx];
+            let allocated = match tranche.invested {
+                0 => remaining.min(tranche.deposited),
+                _ => 0,
+            };
+            tranche.make_investment(allocated)?;
+            remaining = remaining.checked_sub(allocated).ok_or_else(math_error!())?;
+        }
+
+        self.tranches[*last].make_investment(remaining)
+    }
+
+    /// Record an investment of the strategy's pair of assets. in a single-asset vault, both amounts are
+    /// the same asset and are invested across all tranches at once.
+    pub fn make_investments(&mut self, alpha_amount: u64, beta_amount: u64) -> ProgramResult {
+        let alpha_mint = self.alpha().mint;
+        let beta_mint = self.beta().mint;
+
+        if self.is_single_asset() {
+            return self.make_investment(
+                &alpha_mint,
+                alpha_amount
+                    .checked_add(beta_amount)
+                    .ok_or_else(math_error!())?,
+            );
+        }
+
+        self.make_investment(&alpha_mint, alpha_amount)?;
+        self.make_investment(&beta_mint, beta_amount)
+    }
+
+    /// Record an amount of an asset received from the strategy. received amounts are credited to the most
+    /// junior tranche backed by the asset until the vault is settled via the waterfall during rebalance.
+    pub fn add_receipt(&mut self, mint: &Pubkey, amount: u64) -> ProgramResult {
+        let ids = self.tranche_ids_for(mint)?;
+        let last = ids.last().ok_or(ErrorCode::NonexistentAsset)?;
+
+        self.tranches[*last].add_receipt(amount)
+    }
+
+    /// total amount received across the tranches backed by the given asset
+    pub fn received_for(&self, mint: &Pubkey) -> Result<u64, ProgramError> {
+        let mut received: u64 = 0;
+        for idx in self.tranche_ids_for(mint)? {
+            received = received
+                .checked_add(self.tranches[idx].received)
+                .ok_or_else(math_error!())?;
+        }
+
+        Ok(received)
+    }
+
+    /// Record amounts of the strategy's pair of assets received from the strategy. in a single-asset
+    /// vault, both amounts are the same asset and are paid out across all tranches at once.
+    pub fn add_receipts(&mut self, alpha_amount: u64, beta_amount: u64) -> ProgramResult {
+        let alpha_mint = self.alpha().mint;
+        let beta_mint = self.beta().mint;
+
+        if self.is_single_asset() {
+            return self.add_receipt(
+                &alpha_mint,
+                alpha_amount
+                    .checked_add(beta_amount)
+                    .ok_or_else(math_error!())?,
+            );
+        }
+
+        self.add_receipt(&alpha_mint, alpha_amount)?;
+        self.add_receipt(&beta_mint, beta_amount)
+    }
+
+    /// Remove an amount of an asset from the tranches it backs, in reverse payout order. the most junior
+    /// tranche backed by the asset is drawn down first.
+    pub fn sub_receipt(&mut self, mint: &Pubkey, amount: u64) -> ProgramResult {
+        let ids = self.tranche_ids_for(mint)?;
+        let (first, junior_ids) = ids.split_first().ok_or(ErrorCode::NonexistentAsset)?;
+
+        let mut remaining = amount;
+        for idx in junior_ids.iter().rev() {
+            let tranche = &mut self.tranches[*idx];
+            let deducted = remaining.min(tranche.received);
+            tranche.sub_receipt(deducted)?;
+            remaining = remaining.checked_sub(deducted).ok_or_else(math_error!())?;
+        }
+
+        self.tranches[*first].sub_receipt(remaining)
+    }
+
+    /// Record a swap between the strategy's pair of assets, given the vault's balance of each asset before and
+    /// after the swap. the sold amount is taken from the most junior tranches backed by the sold asset first and
+    /// the bought amount is credited to the most junior tranche backed by the bought asset. the waterfall
+    /// settles the rest.
+    pub fn record_swap(
+        &mut self,
+        alpha_to_beta: bool,
+        alpha_before: u64,
+        alpha_after: u64,
+        beta_before: u64,
+        beta_after: u64,
+    ) -> ProgramResult {
+        let (sold_mint, bought_mint) = match alpha_to_beta {
+            true => (self.alpha().mint, self.beta().mint),
+            false => (self.beta().mint, self.alpha().mint),
         };
 
-        Ok(asset)
+        let (sold, bought) = compute_rebalance_deltas(
+            alpha_to_beta,
+            alpha_before,
+            alpha_after,
+            beta_before,
+            beta_after,
+        )
+        .ok_or_else(math_error!())?;
+        msg!("sold: {}, bought: {}", sold, bought);
+
+        self.sub_receipt(&sold_mint, sold)?;
+        self.add_receipt(&bought_mint, bought)
     }
 
-    pub fn update_deposit<'a>(&'a mut self, mint: &Pubkey, amount: u64) -> ProgramResult {
-        self.get_asset_mut(mint)?.add_deposit(amount)
+    pub fn update_deposit(&mut self, tranche_id: u8, amount: u64) -> ProgramResult {
+        self.get_tranche_mut(tranche_id)?.add_deposit(amount)
     }
 
-    pub fn update_receipt(&mut self, mint: &Pubkey, amount: u64) -> ProgramResult {
-        self.get_asset_mut(mint)?.add_receipt(amount)
+    pub fn update_tranche_lp_frozen(&mut self, frozen: bool) {
+        self.tranche_lp_frozen = frozen;
     }
 
+    /// tranche tokens can be moved once the vault is live and every tranche's claims are processed, i.e. once
+    /// the assets backing each tranche token are known. the vault authority can override this in an emergency.
+    pub fn tranche_lp_transferable(&self) -> bool {
+        !self.tranche_lp_frozen
+            && self.state != State::Deposit
+            && self.state != State::Inactive
+            && self
+                .tranches()
+                .iter()
+                .all(|tranche| tranche.claims_already_processed())
+    }
+
+    // a cancelled vault is back in the inactive state, but its deposits are claimable as refunds
     pub fn in_claimable_state(&self, asset: &Asset) -> bool {
         return asset.claims_already_processed()
-            && self.state != State::Deposit
-            && self.state != State::Inactive;
+            && (self.is_cancelled()
+                || (self.state != State::Deposit && self.state != State::Inactive));
     }
 
     // pub fn can_perform_swap(&self) -> bool {
@@ -1,13 +1,13 @@
 use {
     crate::{
-        constant::{HISTORY_SEED, RECEIPT_SEED},
+        constant::{ALLOWLIST_SEED, HISTORY_SEED, MAX_BPS, RECEIPT_SEED, TRANCHE_MINT_SEED},
         error::{ErrorCode, OraResult},
         id,
     },
     anchor_lang::{
         prelude::*,
         solana_program::{
-            clock,
+            clock, keccak,
             program::{invoke, invoke_signed},
             program_memory::sol_memcmp,
             program_pack::{IsInitialized, Pack},
@@ -16,10 +16,13 @@ use {
             system_program::ID as SYSTEM_PROGRAM_ID,
         },
     },
-    anchor_spl::token::{mint_to, transfer, MintTo, Transfer},
+    anchor_spl::token::{
+        freeze_account, mint_to, thaw_account, transfer, FreezeAccount, MintTo, ThawAccount,
+        Transfer,
+    },
     spl_associated_token_account::get_associated_token_address,
-    spl_token::state::Account as SplAccount,
-    std::convert::TryInto,
+    spl_token::state::{Account as SplAccount, Mint as SplMint},
+    std::convert::{TryFrom, TryInto},
 };
 
 pub fn get_spl_account<'a>(token_account: &AccountInfo<'a>) -> OraResult<SplAccount> {
@@ -34,7 +37,142 @@ pub fn get_spl_mint<'a>(token_account: &AccountInfo<'a>) -> OraResult<Pubkey> {
     Ok(get_spl_account(&token_account)?.mint)
 }
 
-pub fn _get_current_timestamp() -> Result<u64, ProgramError> {
+pub fn get_spl_supply<'a>(mint: &AccountInfo<'a>) -> OraResult<u64> {
+    Ok(SplMint::unpack_from_slice(&mint.data.borrow())?.supply)
+}
+
+/// Amount of a pool's reserve that an LP position can be redeemed for, ignoring fees.
+///
+/// @dev value = (lp_amount * reserve) / supply
+pub fn compute_lp_value(lp_amount: u64, reserve: u64, supply: u64) -> OraResult<u64> {
+    if supply == 0 {
+        return Ok(0);
+    }
+
+    let value = (lp_amount as u128)
+        .checked_mul(reserve as u128)
+        .ok_or_else(math_error!())?
+        .checked_div(supply as u128)
+        .ok_or_else(math_error!())?;
+
+    Ok(value as u64)
+}
+
+/// Number of LP tokens a balanced deposit should receive at the pool's spot price, ignoring fees.
+///
+/// @dev lp = min(amount_a * supply / reserve_a, amount_b * supply / reserve_b). an imbalanced or
+///      single-sided deposit is valued by its smaller side, so the result is a conservative floor.
+pub fn compute_lp_for_deposit(
+    amount_a: u64,
+    amount_b: u64,
+    reserve_a: u64,
+    reserve_b: u64,
+    supply: u64,
+) -> OraResult<u64> {
+    if reserve_a == 0 || reserve_b == 0 {
+        return Ok(0);
+    }
+
+    let lp_for_a = compute_lp_value(amount_a, supply, reserve_a)?;
+    let lp_for_b = compute_lp_value(amount_b, supply, reserve_b)?;
+
+    Ok(lp_for_a.min(lp_for_b))
+}
+
+/// Fee charged by a pool, as a fraction of the amount it applies to
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PoolFee {
+    pub numerator: u64,
+    pub denominator: u64,
+}
+
+impl PoolFee {
+    /// fee on amount, rounded up so that amounts net of the fee are never overestimated
+    pub fn compute(&self, amount: u64) -> OraResult<u64> {
+        if self.numerator == 0 || self.denominator == 0 {
+            return Ok(0);
+        }
+
+        let fee = (amount as u128)
+            .checked_mul(self.numerator as u128)
+            .ok_or_else(math_error!())?
+            .checked_add(self.denominator as u128 - 1)
+            .ok_or_else(math_error!())?
+            .checked_div(self.denominator as u128)
+            .ok_or_else(math_error!())?;
+
+        Ok(fee as u64)
+    }
+
+    /// amount less the fee
+    pub fn apply(&self, amount: u64) -> OraResult<u64> {
+        Ok(amount
+            .checked_sub(self.compute(amount)?)
+            .ok_or_else(math_error!())?)
+    }
+}
+
+/// Amount out of a swap at the pool's spot price, net of the pool's fees but ignoring price impact. every
+/// fee is charged on the full amount in.
+///
+/// @dev amount_out = (amount_in - fees) * reserve_out / reserve_in
+pub fn compute_spot_amount_out(
+    amount_in: u64,
+    reserve_in: u64,
+    reserve_out: u64,
+    fees: &[PoolFee],
+) -> OraResult<u64> {
+    let mut amount_in_less_fees = amount_in;
+    for fee in fees {
+        amount_in_less_fees = amount_in_less_fees
+            .checked_sub(fee.compute(amount_in)?)
+            .ok_or_else(math_error!())?;
+    }
+    if reserve_in == 0 {
+        return Ok(0);
+    }
+
+    let amount_out = (amount_in_less_fees as u128)
+        .checked_mul(reserve_out as u128)
+        .ok_or_else(math_error!())?
+        .checked_div(reserve_in as u128)
+        .ok_or_else(math_error!())?;
+
+    Ok(u64::try_from(amount_out).map_err(|_| math_error!()())?)
+}
+
+/// Reduce an amount by the given slippage, in basis points.
+pub fn apply_slippage(amount: u64, slippage_bps: u16) -> OraResult<u64> {
+    require!(
+        (slippage_bps as u64) <= MAX_BPS,
+        ErrorCode::InvalidSlippageBps
+    );
+
+    let amount_after_slippage = (amount as u128)
+        .checked_mul(
+            MAX_BPS
+                .checked_sub(slippage_bps as u64)
+                .ok_or_else(math_error!())? as u128,
+        )
+        .ok_or_else(math_error!())?
+        .checked_div(MAX_BPS as u128)
+        .ok_or_else(math_error!())?;
+
+    Ok(amount_after_slippage as u64)
+}
+
+/// Minimum amount out the vault will accept. The strategist can be stricter than the vault's max
+/// slippage against the spot amount, but never looser. the spot amount should already be net of the
+/// pool's fees, so that the max slippage bounds price impact and price moves.
+pub fn compute_min_out(
+    strategist_min_out: u64,
+    spot_amount: u64,
+    slippage_bps: u16,
+) -> OraResult<u64> {
+    Ok(strategist_min_out.max(apply_slippage(spot_amount, slippage_bps)?))
+}
+
+pub fn get_current_timestamp() -> Result<u64, ProgramError> {
     // i64 -> u64 ok to unwrap
     Ok(clock::Clock::get()?.unix_timestamp.try_into().unwrap())
 }
@@ -271,6 +409,9 @@ pub fn transfer_with_verified_ata<'info>(
     Ok(())
 }
 
+// Mint tokens to the destination ATA after creating it if it DNE. The mint authority is expected to also be
+// the freeze authority: a frozen destination is thawed to receive the tokens, and the destination is frozen
+// again after minting if `freeze` is set.
 pub fn mint_with_verified_ata<'info>(
     destination: AccountInfo<'info>,
     wallet: AccountInfo<'info>,
@@ -284,6 +425,7 @@ pub fn mint_with_verified_ata<'info>(
     mint_authority: AccountInfo<'info>,
     mint_authority_seeds: &[&[u8]],
     amount: u64,
+    freeze: bool,
 ) -> ProgramResult {
     verify_ata(
         destination.clone(),
@@ -297,31 +439,88 @@ pub fn mint_with_verified_ata<'info>(
         fee_payer_seeds,
     )?;
 
+    set_token_account_frozen(
+        token_program.clone(),
+        destination.clone(),
+        mint.clone(),
+        mint_authority.clone(),
+        mint_authority_seeds,
+        false,
+    )?;
+
     let mint_to_accounts = MintTo {
-        mint: mint,
-        to: destination,
-        authority: mint_authority,
+        mint: mint.clone(),
+        to: destination.clone(),
+        authority: mint_authority.clone(),
     };
 
     // make sure mint authority signs
     mint_to(
-        CpiContext::new(token_program, mint_to_accounts).with_signer(&[mint_authority_seeds]),
+        CpiContext::new(token_program.clone(), mint_to_accounts)
+            .with_signer(&[mint_authority_seeds]),
         amount,
     )?;
 
-    Ok(())
+    set_token_account_frozen(
+        token_program,
+        destination,
+        mint,
+        mint_authority,
+        mint_authority_seeds,
+        freeze,
+    )
+}
+
+// Freeze or thaw a token account if it is not already in the expected state. freeze_authority must be the
+// mint's freeze authority.
+pub fn set_token_account_frozen<'info>(
+    token_program: AccountInfo<'info>,
+    account: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    freeze_authority: AccountInfo<'info>,
+    freeze_authority_seeds: &[&[u8]],
+    frozen: bool,
+) -> ProgramResult {
+    if get_spl_account(&account)?.is_frozen() == frozen {
+        return Ok(());
+    }
+
+    match frozen {
+        true => freeze_account(
+            CpiContext::new(
+                token_program,
+                FreezeAccount {
+                    account,
+                    mint,
+                    authority: freeze_authority,
+                },
+            )
+            .with_signer(&[freeze_authority_seeds]),
+        ),
+        false => thaw_account(
+            CpiContext::new(
+                token_program,
+                ThawAccount {
+                    account,
+                    mint,
+                    authority: freeze_authority,
+                },
+            )
+            .with_signer(&[freeze_authority_seeds]),
+        ),
+    }
 }
 
 pub fn get_receipt_address_and_bump_seed(
     vault: &Pubkey,
-    asset: &Pubkey,
+    tranche_id: u8,
     index: u64,
 ) -> (Pubkey, u8) {
     Pubkey::find_program_address(
         &[
             RECEIPT_SEED.as_bytes(),
             &vault.to_bytes(),
-            &asset.to_bytes(),
+            &tranche_id.to_le_bytes(),
             &index.to_le_bytes(),
         ],
         &id(),
@@ -330,20 +529,57 @@ pub fn get_receipt_address_and_bump_seed(
 
 pub fn get_history_address_and_bump_seed(
     vault: &Pubkey,
-    asset: &Pubkey,
+    tranche_id: u8,
     user: &Pubkey,
 ) -> (Pubkey, u8) {
     Pubkey::find_program_address(
         &[
             HISTORY_SEED.as_bytes(),
             &vault.to_bytes(),
-            &asset.to_bytes(),
+            &tranche_id.to_le_bytes(),
+            &user.to_bytes(),
+        ],
+        &id(),
+    )
+}
+
+pub fn get_tranche_mint_address_and_bump_seed(vault: &Pubkey, tranche_id: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            TRANCHE_MINT_SEED.as_bytes(),
+            &vault.to_bytes(),
+            &tranche_id.to_le_bytes(),
+        ],
+        &id(),
+    )
+}
+
+pub fn get_allowlist_address_and_bump_seed(vault: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            ALLOWLIST_SEED.as_bytes(),
+            &vault.to_bytes(),
             &user.to_bytes(),
         ],
         &id(),
     )
 }
 
+/// Verify a leaf is part of the merkle tree with the given root. Each pair of nodes is sorted
+/// before hashing, so the proof does not need to encode whether a sibling is on the left or right.
+pub fn verify_merkle_proof(proof: &[[u8; 32]], root: [u8; 32], leaf: [u8; 32]) -> bool {
+    let mut computed_hash = leaf;
+    for node in proof.iter() {
+        computed_hash = if computed_hash <= *node {
+            keccak::hashv(&[&computed_hash, node]).0
+        } else {
+            keccak::hashv(&[node, &computed_hash]).0
+        };
+    }
+
+    computed_hash == root
+}
+
 pub fn assert_valid_pda(
     account_info: &AccountInfo,
     expected_pubkey: &Pubkey,
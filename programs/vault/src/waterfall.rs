@@ -0,0 +1,425 @@
+use crate::{
+    constant::MAX_BPS,
+    error::{ErrorCode, OraResult},
+    state::{ReturnTerms, Vault},
+};
+use anchor_lang::prelude::*;
+
+/// A tranche as seen by the waterfall. amounts are denominated in a common unit.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct WaterfallTranche {
+    /// amount invested into the tranche
+    pub invested: u64,
+    /// amount owed to the tranche on top of its investment. it is part of the vault's capital but does
+    /// not earn the tranche's return terms.
+    pub credit: u64,
+    /// return owed before junior tranches are paid out; none for the residual claim
+    pub terms: Option<ReturnTerms>,
+}
+
+/// Split the value of the vault's assets between tranches ordered from most senior to most junior.
+///
+///   - the realized yield is the vault's value relative to the total amount invested
+///   - each tranche with return terms is owed its investment plus a return indexed to the realized
+///     yield, bounded by the floor and cap, plus its credit
+///   - with a first-loss buffer, junior capital only covers a tranche's losses up to the buffer. the
+///     tranche is owed at most its pro-rata share of the vault's value plus the buffer.
+///   - tranches are paid out in order of seniority; the residual claim receives whatever remains
+///
+pub fn compute_waterfall(tranches: &[WaterfallTranche], total_value: u64) -> OraResult<Vec<u64>> {
+    let mut total_invested: u64 = 0;
+    for tranche in tranches {
+        total_invested = total_invested
+            .checked_add(tranche.capital()?)
+            .ok_or_else(math_error!())?;
+    }
+
+    let realized_yield_bps = compute_realized_yield_bps(total_invested, total_value);
+    msg!("realized yield bps: {}", realized_yield_bps);
+
+    // the residual claim is held by the most junior tranche
+    let junior_invested = match tranches.last() {
+        Some(tranche) => tranche.capital()?,
+        None => 0,
+    };
+
+    let mut remaining = total_value;
+    let mut values = Vec::with_capacity(tranches.len());
+    for tranche in tranches {
+        let value = match tranche.terms {
+            Some(terms) => {
+                let rate_bps = compute_rate_bps(&terms, realized_yield_bps);
+                let mut owed = convert(tranche.invested, MAX_BPS + rate_bps, MAX_BPS)?
+                    .checked_add(tranche.credit)
+                    .ok_or_else(math_error!())?;
+
+                if let Some(buffer_bps) = terms.first_loss_buffer_bps {
+                    let pro_rata = match total_invested {
+                        0 => 0,
+                        _ => convert(total_value, tranche.capital()?, total_invested)?,
+                    };
+                    let buffer = convert(junior_invested, buffer_bps as u64, MAX_BPS)?;
+                    owed = owed.min(pro_rata.checked_add(buffer).ok_or_else(math_error!())?);
+                }
+
+                owed.min(remaining)
+            }
+            None => remaining,
+        };
+
+        remaining = remaining.checked_sub(value).ok_or_else(math_error!())?;
+        values.push(value);
+    }
+
+    Ok(values)
+}
+
+impl WaterfallTranche {
+    /// capital the tranche holds in the vault; its investment plus its credit
+    pub fn capital(&self) -> OraResult<u64> {
+        Ok(self
+            .invested
+            .checked_add(self.credit)
+            .ok_or_else(math_error!())?)
+    }
+}
+
+/// Realized yield on the amount invested, in basis points. negative if the vault lost value.
+pub fn compute_realized_yield_bps(invested: u64, value: u64) -> i128 {
+    if invested == 0 {
+        return 0;
+    }
+
+    (value as i128 - invested as i128) * MAX_BPS as i128 / invested as i128
+}
+
+/// Return owed for the given realized yield, in basis points. the share of the yield is bounded by the
+/// floor and cap; a flat return has floor == cap.
+pub fn compute_rate_bps(terms: &ReturnTerms, realized_yield_bps: i128) -> u64 {
+    let indexed = realized_yield_bps.max(0) * terms.yield_share_bps as i128 / MAX_BPS as i128;
+
+    indexed
+        .max(terms.floor_bps as i128)
+        .min(terms.cap_bps as i128) as u64
+}
+
+/// Value owed to each tranche, denominated in the tranche's own asset, given the value of each side of
+/// the strategy's pair of assets. pool_price is the price of the alpha asset in the beta asset,
+/// (beta amount, alpha amount). return terms are pro-rated to accrual_bps, the share of the invest period
+/// that has elapsed; see `Vault::accrual_bps`.
+///
+/// @dev the waterfall is computed in units of the alpha asset.
+pub fn compute_tranche_values(
+    vault: &Vault,
+    alpha_value: u64,
+    beta_value: u64,
+    pool_price: (u64, u64),
+    accrual_bps: u64,
+) -> OraResult<Vec<u64>> {
+    let (beta_amount, alpha_amount) = pool_price;
+    require!(
+        beta_amount > 0 && alpha_amount > 0,
+        ErrorCode::ExpectedNonzeroReturns
+    );
+
+    let alpha_mint = vault.alpha().mint;
+    let total_value = alpha_value
+        .checked_add(convert(beta_value, alpha_amount, beta_amount)?)
+        .ok_or_else(math_error!())?;
+
+    let mut tranches = Vec::with_capacity(vault.tranches().len());
+    for tranche in vault.tranches() {
+        let to_alpha = |amount: u64| match tranche.mint == alpha_mint {
+            true => Ok(amount),
+            false => convert(amount, alpha_amount, beta_amount),
+        };
+        tranches.push(WaterfallTranche {
+            invested: to_alpha(tranche.total_invested)?,
+            // penalties retained from early exits stay in the vault on behalf of the tranche, but the
+            // tranche's holders never invested them. they are owed back without a return.
+            credit: to_alpha(tranche.exit_credit)?,
+            terms: tranche.return_terms.map(|terms| terms.accrued(accrual_bps)),
+        });
+    }
+
+    let mut values = Vec::with_capacity(tranches.len());
+    for (tranche, value) in vault
+        .tranches()
+        .iter()
+        .zip(compute_waterfall(&tranches, total_value)?)
+    {
+        values.push(match tranche.mint == alpha_mint {
+            true => value,
+            false => convert(value, beta_amount, alpha_amount)?,
+        });
+    }
+
+    Ok(values)
+}
+
+/// Pay out each asset's received amount, in order of seniority, to the tranches it backs up to what the
+/// waterfall says they are owed. the most junior tranche backed by the asset receives the rest.
+///
+/// @dev a tranche can be owed more than its asset holds if the strategist did not swap enough of the
+///      other asset. in that case the tranche receives what is available.
+pub fn settle_vault(vault: &mut Vault, pool_price: (u64, u64)) -> ProgramResult {
+    let alpha_mint = vault.alpha().mint;
+    let alpha_received = vault.received_for(&alpha_mint)?;
+    let beta_received = match vault.is_single_asset() {
+        true => 0,
+        false => vault.received_for(&vault.beta().mint)?,
+    };
+    msg!(
+        "alpha received: {}, beta received: {}",
+        alpha_received,
+        beta_received
+    );
+
+    // the vault has been redeemed, so tranches are owed their full returns
+    let owed = compute_tranche_values(vault, alpha_received, beta_received, pool_price, MAX_BPS)?;
+    msg!("owed: {:?}", owed);
+
+    let mut remaining = [alpha_received, beta_received];
+    let tranches = vault.tranches_mut();
+    for idx in 0..tranches.len() {
+        let side = match tranches[idx].mint == alpha_mint {
+            true => 0,
+            false => 1,
+        };
+        let is_most_junior_for_asset = tranches[idx + 1..]
+            .iter()
+            .all(|tranche| tranche.mint != tranches[idx].mint);
+
+        let received = match is_most_junior_for_asset {
+            true => remaining[side],
+            false => owed[idx].min(remaining[side]),
+        };
+        remaining[side] -= received;
+
+        tranches[idx].set_receipt(received)?;
+    }
+
+    vault.mark_rebalanced();
+
+    Ok(())
+}
+
+/// amount * numerator / denominator
+pub fn convert(amount: u64, numerator: u64, denominator: u64) -> OraResult<u64> {
+    let converted = (amount as u128)
+        .checked_mul(numerator as u128)
+        .ok_or_else(math_error!())?
+        .checked_div(denominator as u128)
+        .ok_or_else(math_error!())?;
+
+    Ok(converted as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{Asset, State};
+
+    fn flat(rate_bps: u16) -> ReturnTerms {
+        ReturnTerms {
+            floor_bps: rate_bps,
+            cap_bps: rate_bps,
+            yield_share_bps: 0,
+            first_loss_buffer_bps: None,
+        }
+    }
+
+    fn tranche(invested: u64, terms: Option<ReturnTerms>) -> WaterfallTranche {
+        WaterfallTranche {
+            invested,
+            terms,
+            ..WaterfallTranche::default()
+        }
+    }
+
+    #[test]
+    fn test_flat_rate_surplus_goes_to_junior() {
+        let tranches = [tranche(1_000, Some(flat(500))), tranche(1_000, None)];
+
+        assert_eq!(
+            compute_waterfall(&tranches, 2_400).unwrap(),
+            vec![1_050, 1_350]
+        );
+    }
+
+    #[test]
+    fn test_flat_rate_shortfall_covered_by_junior() {
+        let tranches = [tranche(1_000, Some(flat(500))), tranche(1_000, None)];
+
+        assert_eq!(
+            compute_waterfall(&tranches, 1_500).unwrap(),
+            vec![1_050, 450]
+        );
+        // junior is wiped out before the senior tranche takes a loss
+        assert_eq!(compute_waterfall(&tranches, 900).unwrap(), vec![900, 0]);
+    }
+
+    #[test]
+    fn test_rate_indexed_to_realized_yield() {
+        let terms = ReturnTerms {
+            floor_bps: 100,
+            cap_bps: 800,
+            yield_share_bps: 5_000,
+            first_loss_buffer_bps: None,
+        };
+        let tranches = [tranche(1_000, Some(terms)), tranche(1_000, None)];
+
+        // 10% realized yield, senior is owed half of it
+        assert_eq!(
+            compute_waterfall(&tranches, 2_200).unwrap(),
+            vec![1_050, 1_150]
+        );
+        // 40% realized yield, senior return is capped at 8%
+        assert_eq!(
+            compute_waterfall(&tranches, 2_800).unwrap(),
+            vec![1_080, 1_720]
+        );
+        // negative yield, senior return is floored at 1%
+        assert_eq!(
+            compute_waterfall(&tranches, 1_800).unwrap(),
+            vec![1_010, 790]
+        );
+    }
+
+    #[test]
+    fn test_first_loss_buffer_limits_junior_coverage() {
+        let terms = ReturnTerms {
+            first_loss_buffer_bps: Some(2_000),
+            ..flat(500)
+        };
+        let tranches = [tranche(1_000, Some(terms)), tranche(1_000, None)];
+
+        // pro-rata share of 800 + buffer of 20% of the junior tranche's 1_000
+        assert_eq!(
+            compute_waterfall(&tranches, 1_600).unwrap(),
+            vec![1_000, 600]
+        );
+        // buffer does not apply when the senior tranche is made whole
+        assert_eq!(
+            compute_waterfall(&tranches, 2_000).unwrap(),
+            vec![1_050, 950]
+        );
+    }
+
+    #[test]
+    fn test_three_tranches_paid_in_order() {
+        let tranches = [
+            tranche(1_000, Some(flat(300))),
+            tranche(1_000, Some(flat(800))),
+            tranche(1_000, None),
+        ];
+
+        assert_eq!(
+            compute_waterfall(&tranches, 3_300).unwrap(),
+            vec![1_030, 1_080, 1_190]
+        );
+        assert_eq!(
+            compute_waterfall(&tranches, 1_500).unwrap(),
+            vec![1_030, 470, 0]
+        );
+    }
+
+    #[test]
+    fn test_no_investment() {
+        let tranches = [tranche(0, Some(flat(500))), tranche(0, None)];
+
+        assert_eq!(compute_waterfall(&tranches, 100).unwrap(), vec![0, 100]);
+    }
+
+    #[test]
+    fn test_compute_rate_bps() {
+        let terms = ReturnTerms {
+            floor_bps: 200,
+            cap_bps: 1_000,
+            yield_share_bps: 2_500,
+            first_loss_buffer_bps: None,
+        };
+
+        assert_eq!(compute_rate_bps(&terms, -500), 200);
+        assert_eq!(compute_rate_bps(&terms, 2_000), 500);
+        assert_eq!(compute_rate_bps(&terms, 10_000), 1_000);
+    }
+
+    #[test]
+    fn test_compute_tranche_values_across_assets() {
+        let mut vault = Vault::default();
+        vault.num_tranches = 2;
+        vault.tranches[0] = Asset {
+            mint: Pubkey::new_unique(),
+            total_invested: 1_000,
+            return_terms: Some(flat(1_000)),
+            ..Asset::default()
+        };
+        vault.tranches[1] = Asset {
+            mint: Pubkey::new_unique(),
+            total_invested: 2_000,
+            ..Asset::default()
+        };
+
+        // 1 alpha = 2 beta. the senior tranche is owed 1_100 alpha, 200 of which is covered by beta
+        let values = compute_tranche_values(&vault, 900, 2_400, (2, 1), MAX_BPS).unwrap();
+        assert_eq!(values, vec![1_100, 2_000]);
+    }
+
+    #[test]
+    fn test_exit_credit_earns_no_return() {
+        let mut vault = Vault::default();
+        vault.num_tranches = 2;
+        vault.tranches[0] = Asset {
+            mint: Pubkey::new_unique(),
+            total_invested: 1_000,
+            exit_credit: 100,
+            return_terms: Some(flat(1_000)),
+            ..Asset::default()
+        };
+        vault.tranches[1] = Asset {
+            mint: vault.tranches[0].mint,
+            total_invested: 1_000,
+            ..Asset::default()
+        };
+
+        // the senior tranche is owed 10% on its 1_000 invested, and its 100 of credit back as is
+        let values = compute_tranche_values(&vault, 2_400, 0, (1, 1), MAX_BPS).unwrap();
+        assert_eq!(values, vec![1_200, 1_200]);
+    }
+
+    #[test]
+    fn test_return_accrues_over_invest_period() {
+        let mut vault = Vault::default();
+        vault.num_tranches = 2;
+        vault.tranches[0] = Asset {
+            mint: Pubkey::new_unique(),
+            total_invested: 1_000,
+            return_terms: Some(flat(1_000)),
+            ..Asset::default()
+        };
+        vault.tranches[1] = Asset {
+            mint: vault.tranches[0].mint,
+            total_invested: 1_000,
+            ..Asset::default()
+        };
+        vault.state = State::Live;
+        vault.invested_at = Some(100);
+        vault.invest_duration = 1_000;
+
+        // nothing accrued as the vault goes live, half way through the senior tranche is owed half its return
+        assert_eq!(vault.accrual_bps(100), 0);
+        let values = compute_tranche_values(&vault, 2_400, 0, (1, 1), vault.accrual_bps(100));
+        assert_eq!(values.unwrap(), vec![1_000, 1_400]);
+        assert_eq!(vault.accrual_bps(600), 5_000);
+        let values = compute_tranche_values(&vault, 2_400, 0, (1, 1), vault.accrual_bps(600));
+        assert_eq!(values.unwrap(), vec![1_050, 1_350]);
+
+        // past the invest period or once redeemed, the full return is owed
+        assert_eq!(vault.accrual_bps(2_000), MAX_BPS);
+        vault.state = State::Redeem;
+        assert_eq!(vault.accrual_bps(600), MAX_BPS);
+        let values = compute_tranche_values(&vault, 2_400, 0, (1, 1), vault.accrual_bps(600));
+        assert_eq!(values.unwrap(), vec![1_100, 1_300]);
+    }
+}
@@ -0,0 +1,93 @@
+//! A vault cancelled before investing refunds every deposit in full:
+//! deposit → cancel → process_claims → claim
+
+mod common;
+
+use common::*;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+use spl_associated_token_account::get_associated_token_address;
+use vault::state::State;
+
+const SENIOR_DEPOSIT: u64 = 1_000_000;
+const JUNIOR_DEPOSIT: u64 = 600_000;
+
+#[tokio::test]
+async fn cancelled_vault_refunds_deposits() {
+    let mut program_test = program_test();
+
+    let senior_mint = Pubkey::new_unique();
+    let junior_mint = Pubkey::new_unique();
+    for mint in [&senior_mint, &junior_mint] {
+        add_mint(&mut program_test, mint, &Pubkey::new_unique(), 0, DECIMALS);
+    }
+
+    let alice = add_user(&mut program_test, &[(senior_mint, SENIOR_DEPOSIT)]);
+    let bob = add_user(&mut program_test, &[(junior_mint, JUNIOR_DEPOSIT)]);
+
+    let mut context = program_test.start_with_context().await;
+    init_global_protocol_state(&mut context).await;
+
+    // the vault is never invested, so it doesn't need a strategy
+    let fixture = VaultFixture::init(
+        &mut context,
+        Pubkey::new_unique(),
+        vec![
+            (senior_tranche_config(0, "SNR", 500), senior_mint),
+            (tranche_config(1, "JNR"), junior_mint),
+        ],
+        100,
+    )
+    .await;
+
+    // ====== deposit ======
+    fixture
+        .transition(&mut context, State::Deposit)
+        .await
+        .unwrap();
+    fixture
+        .deposit(&mut context, &alice, 0, SENIOR_DEPOSIT)
+        .await
+        .unwrap();
+    fixture
+        .deposit(&mut context, &bob, 1, JUNIOR_DEPOSIT)
+        .await
+        .unwrap();
+
+    // ====== cancel ======
+    fixture.cancel(&mut context).await.unwrap();
+
+    let vault = fixture.vault(&mut context).await;
+    assert_eq!(vault.state, State::Inactive);
+    assert!(vault.cancelled_at.is_some());
+    assert_eq!(vault.tranches[0].excess, SENIOR_DEPOSIT);
+    assert_eq!(vault.tranches[1].excess, JUNIOR_DEPOSIT);
+
+    // a cancelled vault can't be reopened, even though it's past its start
+    assert!(fixture
+        .transition_as(&mut context, &bob, State::Deposit)
+        .await
+        .is_err());
+
+    // ====== process claims + claim ======
+    fixture
+        .process_claims(&mut context, 0, &[alice.pubkey()])
+        .await
+        .unwrap();
+    fixture
+        .process_claims(&mut context, 1, &[bob.pubkey()])
+        .await
+        .unwrap();
+
+    for (user, tranche_id, mint, deposit) in [
+        (&alice, 0, senior_mint, SENIOR_DEPOSIT),
+        (&bob, 1, junior_mint, JUNIOR_DEPOSIT),
+    ] {
+        fixture.claim(&mut context, user, tranche_id).await.unwrap();
+
+        let refund = get_associated_token_address(&user.pubkey(), &mint);
+        let lp = get_associated_token_address(&user.pubkey(), &fixture.lp(tranche_id));
+        assert_eq!(token_balance(&mut context, &refund).await, deposit);
+        assert_eq!(token_balance(&mut context, &lp).await, 0);
+    }
+}
@@ -0,0 +1,292 @@
+//! Runs the vault and the programs it invokes as native builtins. solana-program-test 1.9 only supports
+//! cross program invocations between builtins that neither leave out the invoked program's account nor
+//! resize accounts, and anchor does both.
+
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::{ProcessInstruction, ProgramResult},
+    instruction::{Instruction, InstructionError},
+    program_error::ProgramError,
+    program_stubs::{self, SyscallStubs},
+    program_utils::limited_deserialize,
+    pubkey::Pubkey,
+    system_instruction::SystemInstruction,
+    system_program,
+};
+use solana_program_test::InvokeContext;
+use solana_sdk::{
+    account::WritableAccount,
+    keyed_account::{keyed_account_at_index, KeyedAccount},
+    packet::PACKET_DATA_SIZE,
+};
+use std::{cell::RefCell, mem::transmute, sync::Once};
+
+thread_local! {
+    // account infos of the builtin instructions in flight, innermost last; see `BuiltinSyscallStubs` and
+    // `process_system_instruction`
+    static BUILTIN_ACCOUNTS: RefCell<Vec<Vec<AccountInfo<'static>>>> = const { RefCell::new(vec![]) };
+}
+
+/// solana-program-test's syscall stubs, which need the invoked program's account info in every cross
+/// program invocation. anchor-spl leaves it out, so it is added from the calling instruction's accounts.
+struct BuiltinSyscallStubs {
+    inner: Box<dyn SyscallStubs>,
+}
+
+impl BuiltinSyscallStubs {
+    /// wrap the stubs solana-program-test installs when the first bank starts
+    fn install() {
+        static INSTALL: Once = Once::new();
+
+        INSTALL.call_once(|| {
+            let inner = program_stubs::set_syscall_stubs(Box::new(BuiltinSyscallStubs {
+                inner: Box::new(DefaultSyscallStubs {}),
+            }));
+            program_stubs::set_syscall_stubs(Box::new(BuiltinSyscallStubs { inner }));
+        });
+    }
+}
+
+fn in_builtin_instruction() -> bool {
+    BUILTIN_ACCOUNTS.with(|stack| !stack.borrow().is_empty())
+}
+
+struct DefaultSyscallStubs {}
+impl SyscallStubs for DefaultSyscallStubs {}
+
+impl SyscallStubs for BuiltinSyscallStubs {
+    // the program's math also runs off chain, e.g. in the keeper, where there is no instruction to log to
+    fn sol_log(&self, message: &str) {
+        if in_builtin_instruction() {
+            self.inner.sol_log(message)
+        } else {
+            DefaultSyscallStubs {}.sol_log(message)
+        }
+    }
+
+    fn sol_log_compute_units(&self) {
+        if in_builtin_instruction() {
+            self.inner.sol_log_compute_units()
+        }
+    }
+
+    fn sol_invoke_signed(
+        &self,
+        instruction: &Instruction,
+        account_infos: &[AccountInfo],
+        signers_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        if account_infos
+            .iter()
+            .any(|info| *info.key == instruction.program_id)
+        {
+            return self
+                .inner
+                .sol_invoke_signed(instruction, account_infos, signers_seeds);
+        }
+
+        let program_info = BUILTIN_ACCOUNTS.with(|stack| {
+            stack
+                .borrow()
+                .iter()
+                .flatten()
+                .find(|info| *info.key == instruction.program_id)
+                .cloned()
+                .ok_or(ProgramError::NotEnoughAccountKeys)
+        })?;
+        let mut account_infos = unsafe {
+            transmute::<Vec<AccountInfo>, Vec<AccountInfo<'static>>>(account_infos.to_vec())
+        };
+        account_infos.push(program_info);
+
+        self.inner
+            .sol_invoke_signed(instruction, &account_infos, signers_seeds)
+    }
+
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        self.inner.sol_get_clock_sysvar(var_addr)
+    }
+
+    fn sol_get_epoch_schedule_sysvar(&self, var_addr: *mut u8) -> u64 {
+        self.inner.sol_get_epoch_schedule_sysvar(var_addr)
+    }
+
+    fn sol_get_fees_sysvar(&self, var_addr: *mut u8) -> u64 {
+        self.inner.sol_get_fees_sysvar(var_addr)
+    }
+
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        self.inner.sol_get_rent_sysvar(var_addr)
+    }
+
+    unsafe fn sol_memcpy(&self, dst: *mut u8, src: *const u8, n: usize) {
+        self.inner.sol_memcpy(dst, src, n)
+    }
+
+    unsafe fn sol_memmove(&self, dst: *mut u8, src: *const u8, n: usize) {
+        self.inner.sol_memmove(dst, src, n)
+    }
+
+    unsafe fn sol_memcmp(&self, s1: *const u8, s2: *const u8, n: usize, result: *mut i32) {
+        self.inner.sol_memcmp(s1, s2, n, result)
+    }
+
+    unsafe fn sol_memset(&self, s: *mut u8, c: u8, n: usize) {
+        self.inner.sol_memset(s, c, n)
+    }
+
+    fn sol_get_return_data(&self) -> Option<(Pubkey, Vec<u8>)> {
+        self.inner.sol_get_return_data()
+    }
+
+    fn sol_set_return_data(&self, data: &[u8]) {
+        self.inner.sol_set_return_data(data)
+    }
+
+    fn sol_log_data(&self, fields: &[&[u8]]) {
+        if in_builtin_instruction() {
+            self.inner.sol_log_data(fields)
+        } else {
+            DefaultSyscallStubs {}.sol_log_data(fields)
+        }
+    }
+
+    fn sol_get_processed_sibling_instruction(&self, index: usize) -> Option<Instruction> {
+        self.inner.sol_get_processed_sibling_instruction(index)
+    }
+
+    fn sol_get_stack_height(&self) -> u64 {
+        self.inner.sol_get_stack_height()
+    }
+}
+
+/// Run a builtin program's instruction with its account infos on `BUILTIN_ACCOUNTS`
+pub fn process_builtin_instruction(
+    process_instruction: ProcessInstruction,
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    BuiltinSyscallStubs::install();
+
+    // the account infos are only read while the instruction runs
+    let infos =
+        unsafe { transmute::<Vec<AccountInfo>, Vec<AccountInfo<'static>>>(accounts.to_vec()) };
+    BUILTIN_ACCOUNTS.with(|stack| stack.borrow_mut().push(infos));
+
+    let result = process_instruction(program_id, accounts, data);
+
+    BUILTIN_ACCOUNTS.with(|stack| stack.borrow_mut().pop());
+    result
+}
+
+// anchor's entry ties the accounts slice to the accounts' lifetime, which the builtin processor
+// signature does not guarantee. the accounts outlive the instruction, so leaking the copy is fine in tests.
+pub fn process_vault_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    process_builtin_instruction(
+        |program_id, accounts, data| {
+            let accounts = Box::leak(Box::new(accounts.to_vec()));
+            vault::entry(program_id, accounts, data)
+        },
+        program_id,
+        accounts,
+        data,
+    )
+}
+
+/// The system instructions the vault and the associated token account program invoke. solana-program-test
+/// cannot copy a resized account back into a builtin caller's account infos, so the system program is
+/// replaced by one that also resizes the callers' copies of the accounts it allocates.
+pub fn process_system_instruction(
+    first_instruction_account: usize,
+    data: &[u8],
+    invoke_context: &mut InvokeContext,
+) -> Result<(), InstructionError> {
+    let keyed_accounts = invoke_context.get_keyed_accounts()?;
+    let account =
+        |index: usize| keyed_account_at_index(keyed_accounts, first_instruction_account + index);
+
+    match limited_deserialize(data, PACKET_DATA_SIZE as u64)? {
+        SystemInstruction::CreateAccount {
+            lamports,
+            space,
+            owner,
+        } => {
+            system_transfer(account(0)?, account(1)?, lamports)?;
+            system_allocate(account(1)?, space)?;
+            system_assign(account(1)?, &owner)
+        }
+        SystemInstruction::Transfer { lamports } => {
+            system_transfer(account(0)?, account(1)?, lamports)
+        }
+        SystemInstruction::Allocate { space } => system_allocate(account(0)?, space),
+        SystemInstruction::Assign { owner } => system_assign(account(0)?, &owner),
+        _ => Err(InstructionError::InvalidInstructionData),
+    }
+}
+
+fn system_transfer(
+    from: &KeyedAccount,
+    to: &KeyedAccount,
+    lamports: u64,
+) -> Result<(), InstructionError> {
+    if from.signer_key().is_none() {
+        return Err(InstructionError::MissingRequiredSignature);
+    }
+    if !from.data_is_empty()? || from.owner()? != system_program::ID {
+        return Err(InstructionError::InvalidArgument);
+    }
+
+    let from_lamports = from
+        .lamports()?
+        .checked_sub(lamports)
+        .ok_or(InstructionError::InsufficientFunds)?;
+    from.try_account_ref_mut()?.set_lamports(from_lamports);
+
+    let to_lamports = to
+        .lamports()?
+        .checked_add(lamports)
+        .ok_or(InstructionError::ArithmeticOverflow)?;
+    to.try_account_ref_mut()?.set_lamports(to_lamports);
+
+    Ok(())
+}
+
+fn system_allocate(account: &KeyedAccount, space: u64) -> Result<(), InstructionError> {
+    if account.signer_key().is_none() {
+        return Err(InstructionError::MissingRequiredSignature);
+    }
+    if !account.data_is_empty()? || account.owner()? != system_program::ID {
+        return Err(InstructionError::AccountAlreadyInitialized);
+    }
+
+    let space = space as usize;
+    account.try_account_ref_mut()?.set_data(vec![0; space]);
+
+    // the callers copy their account infos back once the instruction returns; give them room for the data
+    BUILTIN_ACCOUNTS.with(|stack| {
+        for info in stack.borrow().iter().flatten() {
+            if info.key == account.unsigned_key() && info.data_len() != space {
+                *info.data.borrow_mut() = Box::leak(vec![0; space].into_boxed_slice());
+            }
+        }
+    });
+
+    Ok(())
+}
+
+fn system_assign(account: &KeyedAccount, owner: &Pubkey) -> Result<(), InstructionError> {
+    if account.owner()? == *owner {
+        return Ok(());
+    }
+    if account.signer_key().is_none() {
+        return Err(InstructionError::MissingRequiredSignature);
+    }
+
+    account.try_account_ref_mut()?.set_owner(*owner);
+    Ok(())
+}
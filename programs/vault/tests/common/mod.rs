@@ -0,0 +1,1439 @@
+//! Shared harness for the vault's `solana-program-test` suites. The vault runs as a builtin next to the
+//! SPL token programs and the mock AMM, which stands in for the Orca token swap, Saber StableSwap and Metaplex token metadata
+//! programs. Mints, pools and user token accounts are written at genesis so each test only sends the
+//! vault instructions it cares about.
+
+#![allow(dead_code)]
+
+mod builtins;
+
+use anchor_lang::AccountDeserialize;
+use builtins::{
+    process_builtin_instruction, process_system_instruction, process_vault_instruction,
+};
+use mock_amm::{
+    instruction::orca_swap,
+    pool_authority,
+    state::{OrcaPool, StableSwapPool},
+};
+use solana_program::{
+    clock::Clock,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    program_error::ProgramError,
+    program_option::COption,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    rent::Rent,
+    system_instruction, system_program, sysvar,
+};
+use solana_program_test::{processor, ProgramTest, ProgramTestBanksClientExt, ProgramTestContext};
+use solana_sdk::{
+    account::Account,
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+    transport::TransportError,
+};
+use spl_associated_token_account::{create_associated_token_account, get_associated_token_address};
+use spl_token::state::{Account as TokenAccount, AccountState, Mint};
+use vault::{
+    accounts,
+    state::{
+        AssetConfig, History, OracleConfig, OracleSource, ReturnTerms, Rollover, State,
+        StrategyFlag, Vault, VaultConfig,
+    },
+    SwapConfig,
+};
+use vault_client::{
+    get_allowlist_address_and_bump_seed, get_global_protocol_state_address_and_bump_seed,
+    get_history_address_and_bump_seed, get_orca_strategy_address_and_bump_seed,
+    get_receipt_address_and_bump_seed, get_rollover_address_and_bump_seed,
+    get_tranche_mint_address_and_bump_seed, get_vault_address_and_bump_seed,
+    get_vault_store_address_and_bump_seed, instructions,
+};
+
+pub const DECIMALS: u8 = 6;
+pub const SOL: u64 = 1_000_000_000;
+
+/// Vault program plus the SPL token programs and the mock AMM, registered at the Orca, StableSwap and token
+/// metadata program ids, all as builtins
+pub fn program_test() -> ProgramTest {
+    let mut program_test =
+        ProgramTest::new("vault", vault::ID, processor!(process_vault_instruction));
+    program_test.prefer_bpf(false);
+    program_test.add_builtin_program(
+        "system_program",
+        system_program::ID,
+        process_system_instruction,
+    );
+    // in place of the bundled bpf builds
+    program_test.add_program(
+        "spl_token",
+        spl_token::ID,
+        processor!(|program_id, accounts, data| process_builtin_instruction(
+            spl_token::processor::Processor::process,
+            program_id,
+            accounts,
+            data
+        )),
+    );
+    program_test.add_program(
+        "spl_associated_token_account",
+        spl_associated_token_account::ID,
+        processor!(|program_id, accounts, data| process_builtin_instruction(
+            spl_associated_token_account::processor::process_instruction,
+            program_id,
+            accounts,
+            data
+        )),
+    );
+
+    for program_id in [
+        mock_amm::ID,
+        mock_amm::stable_swap::ID,
+        mock_amm::token_metadata::ID,
+    ] {
+        program_test.add_program(
+            "mock_amm",
+            program_id,
+            processor!(|program_id, accounts, data| process_builtin_instruction(
+                mock_amm::processor::process_instruction,
+                program_id,
+                accounts,
+                data
+            )),
+        );
+    }
+
+    program_test
+}
+
+fn rent_exempt_account(data: Vec<u8>, owner: Pubkey) -> Account {
+    Account {
+        lamports: Rent::default().minimum_balance(data.len()),
+        data,
+        owner,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+pub fn add_mint(
+    program_test: &mut ProgramTest,
+    mint: &Pubkey,
+    authority: &Pubkey,
+    supply: u64,
+    decimals: u8,
+) {
+    let mut data = vec![0; Mint::LEN];
+    Mint {
+        mint_authority: COption::Some(*authority),
+        supply,
+        decimals,
+        is_initialized: true,
+        freeze_authority: COption::None,
+    }
+    .pack_into_slice(&mut data);
+
+    program_test.add_account(*mint, rent_exempt_account(data, spl_token::ID));
+}
+
+pub fn add_token_account(
+    program_test: &mut ProgramTest,
+    address: &Pubkey,
+    mint: &Pubkey,
+    owner: &Pubkey,
+    amount: u64,
+) {
+    let mut data = vec![0; TokenAccount::LEN];
+    TokenAccount {
+        mint: *mint,
+        owner: *owner,
+        amount,
+        state: AccountState::Initialized,
+        ..TokenAccount::default()
+    }
+    .pack_into_slice(&mut data);
+
+    program_test.add_account(*address, rent_exempt_account(data, spl_token::ID));
+}
+
+/// A funded user holding `amount` of each mint in its associated token accounts
+pub fn add_user(program_test: &mut ProgramTest, balances: &[(Pubkey, u64)]) -> Keypair {
+    let user = Keypair::new();
+    program_test.add_account(
+        user.pubkey(),
+        Account {
+            lamports: 10 * SOL,
+            owner: system_program::ID,
+            ..Account::default()
+        },
+    );
+
+    for (mint, amount) in balances {
+        add_token_account(
+            program_test,
+            &get_associated_token_address(&user.pubkey(), mint),
+            mint,
+            &user.pubkey(),
+            *amount,
+        );
+    }
+
+    user
+}
+
+/// Pool reserves and LP mint owned by the mock's pool authority, with `initial_lp` minted to an
+/// outside liquidity provider so the vault is never the only LP
+fn add_pool_accounts(
+    program_test: &mut ProgramTest,
+    authority: &Pubkey,
+    (token_a_mint, token_b_mint): (&Pubkey, &Pubkey),
+    (reserve_a, reserve_b): (u64, u64),
+    initial_lp: u64,
+) -> (Pubkey, Pubkey, Pubkey) {
+    let token_a = Pubkey::new_unique();
+    let token_b = Pubkey::new_unique();
+    let pool_mint = Pubkey::new_unique();
+
+    add_token_account(program_test, &token_a, token_a_mint, authority, reserve_a);
+    add_token_account(program_test, &token_b, token_b_mint, authority, reserve_b);
+    add_mint(program_test, &pool_mint, authority, initial_lp, DECIMALS);
+    add_token_account(
+        program_test,
+        &Pubkey::new_unique(),
+        &pool_mint,
+        &Pubkey::new_unique(),
+        initial_lp,
+    );
+
+    (token_a, token_b, pool_mint)
+}
+
+pub struct OrcaPoolFixture {
+    pub address: Pubkey,
+    pub authority: Pubkey,
+    pub state: OrcaPool,
+}
+
+pub fn add_orca_pool(
+    program_test: &mut ProgramTest,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    reserves: (u64, u64),
+    initial_lp: u64,
+) -> OrcaPoolFixture {
+    let address = Pubkey::new_unique();
+    let (authority, bump) = pool_authority(&mock_amm::ID, &address);
+    let (token_a, token_b, pool_mint) = add_pool_accounts(
+        program_test,
+        &authority,
+        (token_a_mint, token_b_mint),
+        reserves,
+        initial_lp,
+    );
+
+    let fee_account = Pubkey::new_unique();
+    add_token_account(program_test, &fee_account, &pool_mint, &authority, 0);
+
+    let state = OrcaPool {
+        bump,
+        token_a,
+        token_b,
+        pool_mint,
+        token_a_mint: *token_a_mint,
+        token_b_mint: *token_b_mint,
+        fee_account,
+    };
+    program_test.add_account(address, rent_exempt_account(state.pack(), mock_amm::ID));
+
+    OrcaPoolFixture {
+        address,
+        authority,
+        state,
+    }
+}
+
+#[derive(Clone)]
+pub struct StableSwapPoolFixture {
+    pub address: Pubkey,
+    pub authority: Pubkey,
+    pub state: StableSwapPool,
+}
+
+pub fn add_stable_swap_pool(
+    program_test: &mut ProgramTest,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    reserves: (u64, u64),
+    initial_lp: u64,
+) -> StableSwapPoolFixture {
+    let address = Pubkey::new_unique();
+    let (authority, nonce) = pool_authority(&mock_amm::stable_swap::ID, &address);
+    let (token_a, token_b, pool_mint) = add_pool_accounts(
+        program_test,
+        &authority,
+        (token_a_mint, token_b_mint),
+        reserves,
+        initial_lp,
+    );
+
+    let admin_fee_a = Pubkey::new_unique();
+    let admin_fee_b = Pubkey::new_unique();
+    add_token_account(program_test, &admin_fee_a, token_a_mint, &authority, 0);
+    add_token_account(program_test, &admin_fee_b, token_b_mint, &authority, 0);
+
+    let state = StableSwapPool {
+        nonce,
+        token_a,
+        token_a_mint: *token_a_mint,
+        admin_fee_a,
+        token_b,
+        token_b_mint: *token_b_mint,
+        admin_fee_b,
+        pool_mint,
+    };
+    program_test.add_account(
+        address,
+        rent_exempt_account(state.pack(), mock_amm::stable_swap::ID),
+    );
+
+    StableSwapPoolFixture {
+        address,
+        authority,
+        state,
+    }
+}
+
+/// A trading Pyth price account quoting `price * 10^expo`, last updated at slot 0
+pub fn add_pyth_price(program_test: &mut ProgramTest, price: i64, expo: i32) -> Pubkey {
+    let address = Pubkey::new_unique();
+    let mut data = vec![0; 240];
+    data[0..4].copy_from_slice(&0xa1b2c3d4u32.to_le_bytes());
+    data[4..8].copy_from_slice(&2u32.to_le_bytes());
+    data[8..12].copy_from_slice(&3u32.to_le_bytes());
+    data[20..24].copy_from_slice(&expo.to_le_bytes());
+    data[208..216].copy_from_slice(&price.to_le_bytes());
+    data[224..228].copy_from_slice(&1u32.to_le_bytes());
+
+    program_test.add_account(address, rent_exempt_account(data, Pubkey::new_unique()));
+    address
+}
+
+/// Pyth oracle over the given price accounts that never goes stale
+pub fn oracle_config(
+    alpha_oracle: Pubkey,
+    beta_oracle: Pubkey,
+    max_deviation_bps: u16,
+) -> OracleConfig {
+    OracleConfig {
+        source: OracleSource::Pyth,
+        alpha_oracle,
+        beta_oracle,
+        max_deviation_bps,
+        max_staleness_slots: u64::MAX,
+    }
+}
+
+pub async fn process(
+    context: &mut ProgramTestContext,
+    instructions: &[Instruction],
+    signers: &[&Keypair],
+) -> Result<(), TransportError> {
+    let mut all_signers = vec![&context.payer];
+    all_signers.extend_from_slice(signers);
+
+    let transaction = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&context.payer.pubkey()),
+        &all_signers,
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(transaction).await
+}
+
+/// Move to a new blockhash so resending a transaction that already failed is not deduplicated
+pub async fn refresh_blockhash(context: &mut ProgramTestContext) {
+    context.last_blockhash = context
+        .banks_client
+        .get_new_latest_blockhash(&context.last_blockhash)
+        .await
+        .unwrap();
+}
+
+/// Move the cluster's clock to the given unix timestamp, e.g. part way through a vault's invest period
+pub async fn warp_to_timestamp(context: &mut ProgramTestContext, unix_timestamp: u64) {
+    let mut clock: Clock = context.banks_client.get_sysvar().await.unwrap();
+    clock.unix_timestamp = unix_timestamp as i64;
+    context.set_sysvar(&clock);
+}
+
+/// Assert the transaction failed with the given program error, e.g. a vault or anchor error code
+pub fn assert_program_error(result: Result<(), TransportError>, expected: impl Into<ProgramError>) {
+    let expected = expected.into();
+    match result {
+        Err(TransportError::TransactionError(TransactionError::InstructionError(
+            _,
+            InstructionError::Custom(code),
+        ))) => assert_eq!(ProgramError::Custom(code), expected),
+        other => panic!("expected {:?}, got {:?}", expected, other),
+    }
+}
+
+pub async fn get_account(context: &mut ProgramTestContext, address: &Pubkey) -> Account {
+    context
+        .banks_client
+        .get_account(*address)
+        .await
+        .unwrap()
+        .unwrap_or_else(|| panic!("account {} does not exist", address))
+}
+
+pub async fn token_balance(context: &mut ProgramTestContext, address: &Pubkey) -> u64 {
+    let account = get_account(context, address).await;
+    TokenAccount::unpack(&account.data).unwrap().amount
+}
+
+pub async fn token_account_frozen(context: &mut ProgramTestContext, address: &Pubkey) -> bool {
+    let account = get_account(context, address).await;
+    TokenAccount::unpack(&account.data).unwrap().is_frozen()
+}
+
+/// Balance of a token account, or none if the account was never created
+pub async fn try_token_balance(context: &mut ProgramTestContext, address: &Pubkey) -> Option<u64> {
+    let account = context.banks_client.get_account(*address).await.unwrap()?;
+    Some(TokenAccount::unpack(&account.data).unwrap().amount)
+}
+
+pub async fn fetch<T: AccountDeserialize>(context: &mut ProgramTestContext, address: &Pubkey) -> T {
+    let account = get_account(context, address).await;
+    T::try_deserialize(&mut account.data.as_slice()).unwrap()
+}
+
+/// Account deserialized as `T`, or none if the account was never created
+pub async fn try_fetch<T: AccountDeserialize>(
+    context: &mut ProgramTestContext,
+    address: &Pubkey,
+) -> Option<T> {
+    let account = context.banks_client.get_account(*address).await.unwrap()?;
+    Some(T::try_deserialize(&mut account.data.as_slice()).unwrap())
+}
+
+/// Global protocol state owned by the context's payer
+pub async fn init_global_protocol_state(context: &mut ProgramTestContext) {
+    let (global_protocol_state, bump) = get_global_protocol_state_address_and_bump_seed();
+    let ix = instructions::initialize_global_protocol_state(
+        accounts::InitializeGlobalProtocolState {
+            authority: context.payer.pubkey(),
+            global_protocol_state,
+            treasury: Pubkey::new_unique(),
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        bump,
+    )
+    .build();
+
+    process(context, &[ix], &[]).await.unwrap();
+}
+
+pub fn tranche_config(seniority: u8, symbol: &str) -> AssetConfig {
+    AssetConfig {
+        seniority,
+        symbol: symbol.to_string(),
+        ..AssetConfig::default()
+    }
+}
+
+/// A senior tranche owed a flat rate on its investment
+pub fn senior_tranche_config(seniority: u8, symbol: &str, rate_bps: u16) -> AssetConfig {
+    AssetConfig {
+        return_terms: Some(ReturnTerms {
+            floor_bps: rate_bps,
+            cap_bps: rate_bps,
+            yield_share_bps: 0,
+            first_loss_buffer_bps: None,
+        }),
+        ..tranche_config(seniority, symbol)
+    }
+}
+
+/// A vault under test. The context's payer is the gps authority and the strategist.
+pub struct VaultFixture {
+    pub authority: Keypair,
+    pub address: Pubkey,
+    pub vault_store: Pubkey,
+    pub mints: Vec<Pubkey>,
+    pub oracle: Option<OracleConfig>,
+}
+
+impl VaultFixture {
+    pub async fn init(
+        context: &mut ProgramTestContext,
+        strategy: Pubkey,
+        tranches: Vec<(AssetConfig, Pubkey)>,
+        max_slippage_bps: u16,
+    ) -> Self {
+        Self::init_with(context, strategy, tranches, max_slippage_bps, |_| {}).await
+    }
+
+    /// init with the vault config adjusted by `configure`, e.g. to set an oracle or a deposit gate
+    pub async fn init_with(
+        context: &mut ProgramTestContext,
+        strategy: Pubkey,
+        tranches: Vec<(AssetConfig, Pubkey)>,
+        max_slippage_bps: u16,
+        configure: impl FnOnce(&mut VaultConfig),
+    ) -> Self {
+        let authority = Keypair::new();
+        let (address, vault_bump) = get_vault_address_and_bump_seed(&authority.pubkey());
+        let (vault_store, vault_store_bump) = get_vault_store_address_and_bump_seed(&address);
+
+        let fund = system_instruction::transfer(&context.payer.pubkey(), &authority.pubkey(), SOL);
+        let (configs, mints): (Vec<AssetConfig>, Vec<Pubkey>) = tranches.into_iter().unzip();
+        let mut vault_config = VaultConfig {
+            strategy,
+            authority: authority.pubkey(),
+            strategist: context.payer.pubkey(),
+            tranches: configs,
+            start_at: 0,
+            deposit_duration: 0,
+            invest_duration: 0,
+            deposit_gate: None,
+            max_slippage_bps,
+            oracle: None,
+            leverage: None,
+            metadata_uri: "https://example.com".to_string(),
+            early_exit_penalty_bps: None,
+        };
+        configure(&mut vault_config);
+        let oracle = vault_config.oracle;
+        let ix = instructions::initialize_vault(
+            accounts::InitializeVault {
+                authority: authority.pubkey(),
+                gps_authority: context.payer.pubkey(),
+                global_protocol_state: get_global_protocol_state_address_and_bump_seed().0,
+                vault: address,
+                vault_store,
+                system_program: system_program::ID,
+                token_program: spl_token::ID,
+                token_metadata_program: mock_amm::token_metadata::ID,
+                rent: sysvar::rent::ID,
+            },
+            vault_bump,
+            vault_store_bump,
+            vault_config,
+        )
+        .remaining_accounts(instructions::tranche_init_accounts(&address, &mints))
+        .build();
+
+        process(context, &[fund, ix], &[&authority]).await.unwrap();
+
+        VaultFixture {
+            authority,
+            address,
+            vault_store,
+            mints,
+            oracle,
+        }
+    }
+
+    pub async fn vault(&self, context: &mut ProgramTestContext) -> Vault {
+        fetch(context, &self.address).await
+    }
+
+    pub fn lp(&self, tranche_id: u8) -> Pubkey {
+        get_tranche_mint_address_and_bump_seed(&self.address, tranche_id).0
+    }
+
+    pub fn vault_store_ata(&self, mint: &Pubkey) -> Pubkey {
+        get_associated_token_address(&self.vault_store, mint)
+    }
+
+    pub fn global_protocol_state(&self) -> Pubkey {
+        get_global_protocol_state_address_and_bump_seed().0
+    }
+
+    /// Remaining accounts for instructions that verify the pool price against the vault's oracle
+    pub fn oracle_accounts(&self) -> Vec<AccountMeta> {
+        match &self.oracle {
+            Some(oracle) => vec![
+                AccountMeta::new_readonly(oracle.alpha_oracle, false),
+                AccountMeta::new_readonly(oracle.beta_oracle, false),
+            ],
+            None => vec![],
+        }
+    }
+
+    pub async fn transition(
+        &self,
+        context: &mut ProgramTestContext,
+        target_state: State,
+    ) -> Result<(), TransportError> {
+        self.transition_as(context, &self.authority, target_state)
+            .await
+    }
+
+    /// transition signed by someone other than the vault authority
+    pub async fn transition_as(
+        &self,
+        context: &mut ProgramTestContext,
+        signer: &Keypair,
+        target_state: State,
+    ) -> Result<(), TransportError> {
+        let ix = instructions::transition_vault(
+            accounts::TransitionVault {
+                payer: signer.pubkey(),
+                authority: self.authority.pubkey(),
+                global_protocol_state: self.global_protocol_state(),
+                vault: self.address,
+            },
+            target_state,
+            0,
+        )
+        .build();
+
+        process(context, &[ix], &[signer]).await
+    }
+
+    pub async fn cancel(&self, context: &mut ProgramTestContext) -> Result<(), TransportError> {
+        let ix = instructions::cancel_vault(accounts::CancelVault {
+            authority: self.authority.pubkey(),
+            global_protocol_state: self.global_protocol_state(),
+            vault: self.address,
+        })
+        .build();
+
+        process(context, &[ix], &[&self.authority]).await
+    }
+
+    pub async fn set_keeper(
+        &self,
+        context: &mut ProgramTestContext,
+        keeper: Option<Pubkey>,
+        roles: u64,
+    ) -> Result<(), TransportError> {
+        let ix = instructions::set_keeper(
+            accounts::SetKeeper {
+                authority: self.authority.pubkey(),
+                global_protocol_state: self.global_protocol_state(),
+                vault: self.address,
+            },
+            keeper,
+            roles,
+        )
+        .build();
+
+        process(context, &[ix], &[&self.authority]).await
+    }
+
+    pub async fn deposit(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        tranche_id: u8,
+        amount: u64,
+    ) -> Result<(), TransportError> {
+        self.deposit_with(context, user, tranche_id, amount, vec![], vec![])
+            .await
+    }
+
+    /// deposit through the vault's deposit gate, with the user's merkle proof and the gate's remaining accounts
+    pub async fn deposit_with(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        tranche_id: u8,
+        amount: u64,
+        proof: Vec<[u8; 32]>,
+        gate_accounts: Vec<AccountMeta>,
+    ) -> Result<(), TransportError> {
+        let vault = self.vault(context).await;
+        let ix = self.gated_deposit_ix(
+            &vault,
+            &user.pubkey(),
+            tranche_id,
+            amount,
+            proof,
+            gate_accounts,
+        );
+
+        process(context, &[ix], &[user]).await
+    }
+
+    /// deposit as the next receipt of the tranche, given the vault's current state
+    pub fn deposit_ix(
+        &self,
+        vault: &Vault,
+        user: &Pubkey,
+        tranche_id: u8,
+        amount: u64,
+    ) -> Instruction {
+        self.gated_deposit_ix(vault, user, tranche_id, amount, vec![], vec![])
+    }
+
+    /// deposit_ix with the user's merkle proof and the gate's remaining accounts
+    pub fn gated_deposit_ix(
+        &self,
+        vault: &Vault,
+        user: &Pubkey,
+        tranche_id: u8,
+        amount: u64,
+        proof: Vec<[u8; 32]>,
+        gate_accounts: Vec<AccountMeta>,
+    ) -> Instruction {
+        let tranche = vault.get_tranche(tranche_id).unwrap();
+        let deposit_index = tranche.deposits + 1;
+        let (receipt, receipt_bump) =
+            get_receipt_address_and_bump_seed(&self.address, tranche_id, deposit_index);
+        let (history, history_bump) =
+            get_history_address_and_bump_seed(&self.address, tranche_id, user);
+
+        instructions::deposit(
+            accounts::Deposit {
+                payer: *user,
+                authority: self.authority.pubkey(),
+                global_protocol_state: self.global_protocol_state(),
+                vault: self.address,
+                vault_store: self.vault_store,
+                receipt,
+                history,
+                mint: tranche.mint,
+                source_ata: get_associated_token_address(user, &tranche.mint),
+                destination_ata: self.vault_store_ata(&tranche.mint),
+                system_program: system_program::ID,
+                token_program: spl_token::ID,
+                ata_program: spl_associated_token_account::ID,
+                rent: sysvar::rent::ID,
+            },
+            tranche_id,
+            deposit_index,
+            receipt_bump,
+            history_bump,
+            amount,
+            proof,
+        )
+        .remaining_accounts(gate_accounts)
+        .build()
+    }
+
+    pub fn allowlist(&self, user: &Pubkey) -> Pubkey {
+        get_allowlist_address_and_bump_seed(&self.address, user).0
+    }
+
+    pub async fn add_to_allowlist(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Pubkey,
+    ) -> Result<(), TransportError> {
+        let (allowlist, allowlist_bump) = get_allowlist_address_and_bump_seed(&self.address, user);
+        let ix = instructions::add_to_allowlist(
+            accounts::AddToAllowlist {
+                authority: self.authority.pubkey(),
+                global_protocol_state: self.global_protocol_state(),
+                vault: self.address,
+                user: *user,
+                allowlist,
+                system_program: system_program::ID,
+                rent: sysvar::rent::ID,
+            },
+            allowlist_bump,
+        )
+        .build();
+
+        process(context, &[ix], &[&self.authority]).await
+    }
+
+    pub async fn remove_from_allowlist(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Pubkey,
+    ) -> Result<(), TransportError> {
+        let ix = instructions::remove_from_allowlist(accounts::RemoveFromAllowlist {
+            authority: self.authority.pubkey(),
+            global_protocol_state: self.global_protocol_state(),
+            vault: self.address,
+            user: *user,
+            allowlist: self.allowlist(user),
+        })
+        .build();
+
+        process(context, &[ix], &[&self.authority]).await
+    }
+
+    /// Process every claim of a tranche in one batch, walking receipts from the latest deposit down
+    pub async fn process_claims(
+        &self,
+        context: &mut ProgramTestContext,
+        tranche_id: u8,
+        depositors: &[Pubkey],
+    ) -> Result<(), TransportError> {
+        let claims: Vec<(u64, Pubkey)> = depositors
+            .iter()
+            .enumerate()
+            .rev()
+            .map(|(idx, depositor)| (idx as u64 + 1, *depositor))
+            .collect();
+
+        self.process_claims_with(context, tranche_id, &claims).await
+    }
+
+    /// Process claims with the given (deposit index, depositor) pairs as the receipt and history accounts,
+    /// in order. The program expects receipts from the tranche's claims index down.
+    pub async fn process_claims_with(
+        &self,
+        context: &mut ProgramTestContext,
+        tranche_id: u8,
+        claims: &[(u64, Pubkey)],
+    ) -> Result<(), TransportError> {
+        let remaining_accounts = claims
+            .iter()
+            .flat_map(|(deposit_index, depositor)| {
+                let receipt =
+                    get_receipt_address_and_bump_seed(&self.address, tranche_id, *deposit_index).0;
+                let history =
+                    get_history_address_and_bump_seed(&self.address, tranche_id, depositor).0;
+
+                vec![
+                    AccountMeta::new_readonly(receipt, false),
+                    AccountMeta::new(history, false),
+                ]
+            })
+            .collect();
+
+        let ix = instructions::process_claims(
+            accounts::ProcessClaims {
+                payer: context.payer.pubkey(),
+                authority: self.authority.pubkey(),
+                global_protocol_state: self.global_protocol_state(),
+                vault: self.address,
+            },
+            tranche_id,
+        )
+        .remaining_accounts(remaining_accounts)
+        .build();
+
+        process(context, &[ix], &[]).await
+    }
+
+    /// Claim the user's excess deposit and tranche tokens. The tranche token account is created first if
+    /// missing
+    pub async fn claim(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        tranche_id: u8,
+    ) -> Result<(), TransportError> {
+        let mint = self.mints[tranche_id as usize];
+        let lp = self.lp(tranche_id);
+
+        let destination_lp_ata = get_associated_token_address(&user.pubkey(), &lp);
+        let mut ixs = vec![];
+        if try_token_balance(context, &destination_lp_ata)
+            .await
+            .is_none()
+        {
+            ixs.push(create_associated_token_account(
+                &user.pubkey(),
+                &user.pubkey(),
+                &lp,
+            ));
+        }
+
+        ixs.push(
+            instructions::claim(
+                accounts::Claim {
+                    payer: user.pubkey(),
+                    authority: self.authority.pubkey(),
+                    global_protocol_state: self.global_protocol_state(),
+                    vault: self.address,
+                    vault_store: self.vault_store,
+                    history: get_history_address_and_bump_seed(
+                        &self.address,
+                        tranche_id,
+                        &user.pubkey(),
+                    )
+                    .0,
+                    mint,
+                    lp,
+                    source_ata: self.vault_store_ata(&mint),
+                    destination_ata: get_associated_token_address(&user.pubkey(), &mint),
+                    destination_lp_ata,
+                    system_program: system_program::ID,
+                    token_program: spl_token::ID,
+                    ata_program: spl_associated_token_account::ID,
+                    rent: sysvar::rent::ID,
+                },
+                tranche_id,
+            )
+            .build(),
+        );
+
+        process(context, &ixs, &[user]).await
+    }
+
+    /// Burn all of the user's tranche tokens for their share of what the tranche received
+    pub async fn withdraw(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        tranche_id: u8,
+    ) -> Result<(), TransportError> {
+        let mint = self.mints[tranche_id as usize];
+        let lp = self.lp(tranche_id);
+
+        let ix = instructions::withdraw(
+            accounts::Withdraw {
+                payer: user.pubkey(),
+                authority: self.authority.pubkey(),
+                global_protocol_state: self.global_protocol_state(),
+                vault: self.address,
+                vault_store: self.vault_store,
+                mint,
+                lp,
+                source_lp: get_associated_token_address(&user.pubkey(), &lp),
+                source_ata: self.vault_store_ata(&mint),
+                destination_ata: get_associated_token_address(&user.pubkey(), &mint),
+                system_program: system_program::ID,
+                token_program: spl_token::ID,
+                ata_program: spl_associated_token_account::ID,
+                rent: sysvar::rent::ID,
+            },
+            0,
+        )
+        .build();
+
+        process(context, &[ix], &[user]).await
+    }
+
+    /// Pay out the user's claim and the withdrawal for their unclaimed tranche tokens in one transfer
+    pub async fn claim_and_withdraw(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        tranche_id: u8,
+    ) -> Result<(), TransportError> {
+        let mint = self.mints[tranche_id as usize];
+
+        let ix = instructions::claim_and_withdraw(
+            accounts::ClaimAndWithdraw {
+                payer: user.pubkey(),
+                authority: self.authority.pubkey(),
+                global_protocol_state: self.global_protocol_state(),
+                vault: self.address,
+                vault_store: self.vault_store,
+                history: get_history_address_and_bump_seed(
+                    &self.address,
+                    tranche_id,
+                    &user.pubkey(),
+                )
+                .0,
+                mint,
+                lp: self.lp(tranche_id),
+                source_ata: self.vault_store_ata(&mint),
+                destination_ata: get_associated_token_address(&user.pubkey(), &mint),
+                system_program: system_program::ID,
+                token_program: spl_token::ID,
+            },
+            tranche_id,
+        )
+        .build();
+
+        process(context, &[ix], &[user]).await
+    }
+
+    /// Point the vault at the vault for its next epoch
+    pub async fn set_rollover_vault(
+        &self,
+        context: &mut ProgramTestContext,
+        next_vault: &VaultFixture,
+    ) -> Result<(), TransportError> {
+        let ix = instructions::set_rollover_vault(accounts::SetRolloverVault {
+            authority: self.authority.pubkey(),
+            global_protocol_state: self.global_protocol_state(),
+            vault: self.address,
+            rollover_vault: next_vault.address,
+        })
+        .build();
+
+        process(context, &[ix], &[&self.authority]).await
+    }
+
+    /// Commit the user's tranche tokens to the rollover fund, all of them if `amount` is 0
+    pub async fn opt_into_rollover(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        tranche_id: u8,
+        amount: u64,
+    ) -> Result<(), TransportError> {
+        let lp = self.lp(tranche_id);
+        let (rollover, rollover_bump) =
+            get_rollover_address_and_bump_seed(&self.address, tranche_id, &user.pubkey());
+
+        let ix = instructions::opt_into_rollover(
+            accounts::OptIntoRollover {
+                payer: user.pubkey(),
+                authority: self.authority.pubkey(),
+                global_protocol_state: self.global_protocol_state(),
+                vault: self.address,
+                rollover,
+                mint: self.mints[tranche_id as usize],
+                lp,
+                source_lp: get_associated_token_address(&user.pubkey(), &lp),
+                system_program: system_program::ID,
+                token_program: spl_token::ID,
+                rent: sysvar::rent::ID,
+            },
+            tranche_id,
+            rollover_bump,
+            amount,
+        )
+        .build();
+
+        process(context, &[ix], &[user]).await
+    }
+
+    /// Deposit the depositor's rollover position into the next vault, cranked by the context's payer
+    pub async fn process_rollover(
+        &self,
+        context: &mut ProgramTestContext,
+        next_vault: &VaultFixture,
+        depositor: &Pubkey,
+        tranche_id: u8,
+    ) -> Result<(), TransportError> {
+        let mint = self.mints[tranche_id as usize];
+        let (next_history, history_bump) =
+            get_history_address_and_bump_seed(&next_vault.address, tranche_id, depositor);
+
+        let ix = instructions::process_rollover(
+            accounts::ProcessRollover {
+                payer: context.payer.pubkey(),
+                authority: self.authority.pubkey(),
+                global_protocol_state: self.global_protocol_state(),
+                vault: self.address,
+                vault_store: self.vault_store,
+                rollover: get_rollover_address_and_bump_seed(&self.address, tranche_id, depositor)
+                    .0,
+                depositor: *depositor,
+                next_vault: next_vault.address,
+                next_vault_store: next_vault.vault_store,
+                next_history,
+                mint,
+                lp: self.lp(tranche_id),
+                source_ata: self.vault_store_ata(&mint),
+                destination_ata: next_vault.vault_store_ata(&mint),
+                system_program: system_program::ID,
+                token_program: spl_token::ID,
+                ata_program: spl_associated_token_account::ID,
+                rent: sysvar::rent::ID,
+            },
+            tranche_id,
+            history_bump,
+        )
+        .build();
+
+        process(context, &[ix], &[]).await
+    }
+
+    /// Pay the depositor's rollover position out to them, cranked by the context's payer
+    pub async fn withdraw_rollover(
+        &self,
+        context: &mut ProgramTestContext,
+        next_vault: &VaultFixture,
+        depositor: &Pubkey,
+        tranche_id: u8,
+    ) -> Result<(), TransportError> {
+        let mint = self.mints[tranche_id as usize];
+
+        let ix = instructions::withdraw_rollover(
+            accounts::WithdrawRollover {
+                payer: context.payer.pubkey(),
+                authority: self.authority.pubkey(),
+                global_protocol_state: self.global_protocol_state(),
+                vault: self.address,
+                vault_store: self.vault_store,
+                rollover: get_rollover_address_and_bump_seed(&self.address, tranche_id, depositor)
+                    .0,
+                depositor: *depositor,
+                next_vault: next_vault.address,
+                mint,
+                lp: self.lp(tranche_id),
+                source_ata: self.vault_store_ata(&mint),
+                destination_ata: get_associated_token_address(depositor, &mint),
+                system_program: system_program::ID,
+                token_program: spl_token::ID,
+                ata_program: spl_associated_token_account::ID,
+                rent: sysvar::rent::ID,
+            },
+            tranche_id,
+        )
+        .build();
+
+        process(context, &[ix], &[]).await
+    }
+
+    pub async fn history(
+        &self,
+        context: &mut ProgramTestContext,
+        tranche_id: u8,
+        user: &Pubkey,
+    ) -> History {
+        let address = get_history_address_and_bump_seed(&self.address, tranche_id, user).0;
+        fetch(context, &address).await
+    }
+
+    pub async fn rollover(
+        &self,
+        context: &mut ProgramTestContext,
+        tranche_id: u8,
+        user: &Pubkey,
+    ) -> Rollover {
+        let address = get_rollover_address_and_bump_seed(&self.address, tranche_id, user).0;
+        fetch(context, &address).await
+    }
+}
+
+/// Orca LP strategy over a mock Orca pool. Token A of the pool backs the vault's most senior tranche.
+pub struct OrcaStrategyFixture {
+    pub address: Pubkey,
+    pub pool: OrcaPoolFixture,
+}
+
+impl OrcaStrategyFixture {
+    pub async fn init(context: &mut ProgramTestContext, pool: OrcaPoolFixture) -> Self {
+        let flag = StrategyFlag::OrcaLpStrategyV0 as u64;
+        let (address, bump) = get_orca_strategy_address_and_bump_seed(
+            flag,
+            0,
+            &pool.state.token_a_mint,
+            &pool.state.token_b_mint,
+            &pool.address,
+            &pool.state.pool_mint,
+            &Pubkey::default(),
+            &Pubkey::default(),
+        );
+        let ix = instructions::initialize_orca(
+            accounts::InitializeOrca {
+                authority: context.payer.pubkey(),
+                global_protocol_state: get_global_protocol_state_address_and_bump_seed().0,
+                strategy: address,
+                token_a: pool.state.token_a_mint,
+                token_b: pool.state.token_b_mint,
+                swap_program: mock_amm::ID,
+                farm_program: Pubkey::default(),
+                pool: pool.address,
+                base_lp: pool.state.pool_mint,
+                farm: Pubkey::default(),
+                farm_lp: Pubkey::default(),
+                double_dip_farm_lp: Pubkey::default(),
+                system_program: system_program::ID,
+                rent: sysvar::rent::ID,
+            },
+            bump,
+            flag,
+            0,
+        )
+        .build();
+        process(context, &[ix], &[]).await.unwrap();
+
+        OrcaStrategyFixture { address, pool }
+    }
+
+    /// The vault store's pool LP account, which the strategy invests into
+    pub async fn create_lp_store(
+        &self,
+        context: &mut ProgramTestContext,
+        vault: &VaultFixture,
+    ) -> Result<(), TransportError> {
+        let ix = create_associated_token_account(
+            &context.payer.pubkey(),
+            &vault.vault_store,
+            &self.pool.state.pool_mint,
+        );
+
+        process(context, &[ix], &[]).await
+    }
+
+    pub async fn invest(
+        &self,
+        context: &mut ProgramTestContext,
+        vault: &VaultFixture,
+        investable_a: u64,
+        investable_b: u64,
+    ) -> Result<(), TransportError> {
+        let ix = instructions::invest_orca(
+            accounts::InvestOrca {
+                payer: context.payer.pubkey(),
+                authority: vault.authority.pubkey(),
+                global_protocol_state: vault.global_protocol_state(),
+                vault: vault.address,
+                vault_store: vault.vault_store,
+                strategy: self.address,
+                system_program: system_program::ID,
+                token_program: spl_token::ID,
+                rent: sysvar::rent::ID,
+                orca_swap_program: mock_amm::ID,
+                orca_pool: self.pool.address,
+                orca_authority: self.pool.authority,
+                source_token_a: vault.vault_store_ata(&self.pool.state.token_a_mint),
+                source_token_b: vault.vault_store_ata(&self.pool.state.token_b_mint),
+                into_a: self.pool.state.token_a,
+                into_b: self.pool.state.token_b,
+                pool_token: self.pool.state.pool_mint,
+                pool_account: vault.vault_store_ata(&self.pool.state.pool_mint),
+            },
+            investable_a,
+            investable_b,
+            0,
+        )
+        .remaining_accounts(vault.oracle_accounts())
+        .build();
+
+        process(context, &[ix], &[]).await
+    }
+
+    /// Deposit into a tranche of a live vault, investing the deposit single-sided into the pool
+    pub async fn deposit_live(
+        &self,
+        context: &mut ProgramTestContext,
+        vault: &VaultFixture,
+        user: &Keypair,
+        tranche_id: u8,
+        amount: u64,
+    ) -> Result<(), TransportError> {
+        let mint = vault.mints[tranche_id as usize];
+        let lp = vault.lp(tranche_id);
+        let (history, history_bump) =
+            get_history_address_and_bump_seed(&vault.address, tranche_id, &user.pubkey());
+
+        let ix = instructions::deposit_live_orca(
+            accounts::DepositLiveOrca {
+                payer: user.pubkey(),
+                authority: vault.authority.pubkey(),
+                global_protocol_state: vault.global_protocol_state(),
+                vault: vault.address,
+                vault_store: vault.vault_store,
+                history,
+                strategy: self.address,
+                mint,
+                lp,
+                source_ata: get_associated_token_address(&user.pubkey(), &mint),
+                destination_lp_ata: get_associated_token_address(&user.pubkey(), &lp),
+                system_program: system_program::ID,
+                token_program: spl_token::ID,
+                ata_program: spl_associated_token_account::ID,
+                rent: sysvar::rent::ID,
+                orca_swap_program: mock_amm::ID,
+                orca_pool: self.pool.address,
+                orca_authority: self.pool.authority,
+                source_token_a: vault.vault_store_ata(&self.pool.state.token_a_mint),
+                source_token_b: vault.vault_store_ata(&self.pool.state.token_b_mint),
+                into_a: self.pool.state.token_a,
+                into_b: self.pool.state.token_b,
+                pool_token: self.pool.state.pool_mint,
+                pool_account: vault.vault_store_ata(&self.pool.state.pool_mint),
+                farm_account: vault.vault_store_ata(&Pubkey::default()),
+                double_dip_farm_account: vault.vault_store_ata(&Pubkey::default()),
+            },
+            tranche_id,
+            history_bump,
+            amount,
+            0,
+            vec![],
+        )
+        .remaining_accounts(vault.oracle_accounts())
+        .build();
+
+        process(context, &[ix], &[user]).await
+    }
+
+    pub async fn early_exit(
+        &self,
+        context: &mut ProgramTestContext,
+        vault: &VaultFixture,
+        user: &Keypair,
+        tranche_id: u8,
+        lp_amount: u64,
+    ) -> Result<(), TransportError> {
+        let lp = vault.lp(tranche_id);
+
+        let ix = instructions::early_exit_orca(
+            accounts::EarlyExitOrca {
+                payer: user.pubkey(),
+                authority: vault.authority.pubkey(),
+                global_protocol_state: vault.global_protocol_state(),
+                vault: vault.address,
+                vault_store: vault.vault_store,
+                strategy: self.address,
+                system_program: system_program::ID,
+                token_program: spl_token::ID,
+                rent: sysvar::rent::ID,
+                lp,
+                source_lp: get_associated_token_address(&user.pubkey(), &lp),
+                destination_token_a: get_associated_token_address(
+                    &user.pubkey(),
+                    &self.pool.state.token_a_mint,
+                ),
+                destination_token_b: get_associated_token_address(
+                    &user.pubkey(),
+                    &self.pool.state.token_b_mint,
+                ),
+                orca_swap_program: mock_amm::ID,
+                orca_pool: self.pool.address,
+                orca_authority: self.pool.authority,
+                pool_mint: self.pool.state.pool_mint,
+                source_pool_account: vault.vault_store_ata(&self.pool.state.pool_mint),
+                from_a: self.pool.state.token_a,
+                from_b: self.pool.state.token_b,
+                source_token_a: vault.vault_store_ata(&self.pool.state.token_a_mint),
+                source_token_b: vault.vault_store_ata(&self.pool.state.token_b_mint),
+                fee_account: self.pool.state.fee_account,
+                farm_account: vault.vault_store_ata(&Pubkey::default()),
+                double_dip_farm_account: vault.vault_store_ata(&Pubkey::default()),
+            },
+            lp_amount,
+            0,
+            0,
+        )
+        .remaining_accounts(vault.oracle_accounts())
+        .build();
+
+        process(context, &[ix], &[user]).await
+    }
+
+    pub async fn redeem(
+        &self,
+        context: &mut ProgramTestContext,
+        vault: &VaultFixture,
+    ) -> Result<(), TransportError> {
+        let ix = self.redeem_ix(vault, context.payer.pubkey());
+        process(context, &[ix], &[]).await
+    }
+
+    /// redeem signed by someone other than the strategist, e.g. the vault's keeper
+    pub async fn redeem_as(
+        &self,
+        context: &mut ProgramTestContext,
+        vault: &VaultFixture,
+        signer: &Keypair,
+    ) -> Result<(), TransportError> {
+        let ix = self.redeem_ix(vault, signer.pubkey());
+        process(context, &[ix], &[signer]).await
+    }
+
+    fn redeem_ix(&self, vault: &VaultFixture, payer: Pubkey) -> Instruction {
+        instructions::redeem_orca(
+            accounts::RedeemOrca {
+                payer,
+                authority: vault.authority.pubkey(),
+                global_protocol_state: vault.global_protocol_state(),
+                vault: vault.address,
+                vault_store: vault.vault_store,
+                strategy: self.address,
+                system_program: system_program::ID,
+                token_program: spl_token::ID,
+                rent: sysvar::rent::ID,
+                orca_swap_program: mock_amm::ID,
+                orca_pool: self.pool.address,
+                orca_authority: self.pool.authority,
+                pool_mint: self.pool.state.pool_mint,
+                source_pool_account: vault.vault_store_ata(&self.pool.state.pool_mint),
+                from_a: self.pool.state.token_a,
+                from_b: self.pool.state.token_b,
+                source_token_a: vault.vault_store_ata(&self.pool.state.token_a_mint),
+                source_token_b: vault.vault_store_ata(&self.pool.state.token_b_mint),
+                fee_account: self.pool.state.fee_account,
+            },
+            0,
+            0,
+        )
+        .remaining_accounts(vault.oracle_accounts())
+        .build()
+    }
+
+    /// Settle the vault, swapping through the pool first if a swap config is given
+    pub async fn rebalance(
+        &self,
+        context: &mut ProgramTestContext,
+        vault: &VaultFixture,
+        swap_config: Option<SwapConfig>,
+    ) -> Result<(), TransportError> {
+        let store_a = vault.vault_store_ata(&self.pool.state.token_a_mint);
+        let store_b = vault.vault_store_ata(&self.pool.state.token_b_mint);
+        let alpha_to_beta = swap_config
+            .as_ref()
+            .map_or(true, |config| config.alpha_to_beta);
+        let (user_source, pool_source, pool_destination, user_destination) = match alpha_to_beta {
+            true => (
+                store_a,
+                self.pool.state.token_a,
+                self.pool.state.token_b,
+                store_b,
+            ),
+            false => (
+                store_b,
+                self.pool.state.token_b,
+                self.pool.state.token_a,
+                store_a,
+            ),
+        };
+
+        let ix = instructions::rebalance_orca(
+            accounts::RebalanceOrca {
+                payer: context.payer.pubkey(),
+                authority: vault.authority.pubkey(),
+                global_protocol_state: vault.global_protocol_state(),
+                vault: vault.address,
+                vault_store: vault.vault_store,
+                strategy: self.address,
+                token_program: spl_token::ID,
+                orca_swap_program: mock_amm::ID,
+                orca_pool: self.pool.address,
+                orca_authority: self.pool.authority,
+                user_source,
+                pool_source,
+                pool_destination,
+                user_destination,
+                pool_mint: self.pool.state.pool_mint,
+                fee_account: self.pool.state.fee_account,
+            },
+            swap_config,
+        )
+        .remaining_accounts(vault.oracle_accounts())
+        .build();
+
+        process(context, &[ix], &[]).await
+    }
+
+    /// Trade through the pool from outside the vault, moving its price
+    pub async fn swap(
+        &self,
+        context: &mut ProgramTestContext,
+        trader: &Keypair,
+        a_to_b: bool,
+        amount_in: u64,
+    ) -> Result<(), TransportError> {
+        let trader_a =
+            get_associated_token_address(&trader.pubkey(), &self.pool.state.token_a_mint);
+        let trader_b =
+            get_associated_token_address(&trader.pubkey(), &self.pool.state.token_b_mint);
+        let (user_source, user_destination) = match a_to_b {
+            true => (trader_a, trader_b),
+            false => (trader_b, trader_a),
+        };
+
+        let ix = orca_swap(
+            &self.pool.address,
+            &self.pool.state,
+            &trader.pubkey(),
+            &user_source,
+            &user_destination,
+            a_to_b,
+            amount_in,
+            0,
+        );
+
+        process(context, &[ix], &[trader]).await
+    }
+}
@@ -0,0 +1,260 @@
+//! Deposits into vaults gated by an allowlist or a merkle root of the allowed depositors
+
+mod common;
+
+use common::*;
+use solana_program::{instruction::AccountMeta, keccak, pubkey::Pubkey};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::signature::{Keypair, Signer};
+use vault::{
+    error::ErrorCode,
+    state::{Allowlist, DepositGate, State},
+};
+
+const DEPOSIT: u64 = 1_000_000;
+
+struct GatedVault {
+    context: ProgramTestContext,
+    fixture: VaultFixture,
+    alice: Keypair,
+    bob: Keypair,
+    carol: Keypair,
+}
+
+/// A senior / junior vault in the deposit state behind the gate built for alice and bob. Alice, bob and carol
+/// each hold 2 deposits of the senior asset.
+async fn gated_vault(deposit_gate: impl FnOnce(&Pubkey, &Pubkey) -> DepositGate) -> GatedVault {
+    let mut program_test = program_test();
+
+    let senior_mint = Pubkey::new_unique();
+    let junior_mint = Pubkey::new_unique();
+    for mint in [&senior_mint, &junior_mint] {
+        add_mint(&mut program_test, mint, &Pubkey::new_unique(), 0, DECIMALS);
+    }
+
+    let alice = add_user(&mut program_test, &[(senior_mint, 2 * DEPOSIT)]);
+    let bob = add_user(&mut program_test, &[(senior_mint, 2 * DEPOSIT)]);
+    let carol = add_user(&mut program_test, &[(senior_mint, 2 * DEPOSIT)]);
+
+    let deposit_gate = deposit_gate(&alice.pubkey(), &bob.pubkey());
+
+    let mut context = program_test.start_with_context().await;
+    init_global_protocol_state(&mut context).await;
+
+    // the vault is never invested, so it doesn't need a strategy
+    let fixture = VaultFixture::init_with(
+        &mut context,
+        Pubkey::new_unique(),
+        vec![
+            (senior_tranche_config(0, "SNR", 500), senior_mint),
+            (tranche_config(1, "JNR"), junior_mint),
+        ],
+        100,
+        |config| config.deposit_gate = Some(deposit_gate),
+    )
+    .await;
+    fixture
+        .transition(&mut context, State::Deposit)
+        .await
+        .unwrap();
+
+    GatedVault {
+        context,
+        fixture,
+        alice,
+        bob,
+        carol,
+    }
+}
+
+fn merkle_leaf(user: &Pubkey) -> [u8; 32] {
+    keccak::hash(&user.to_bytes()).0
+}
+
+/// Hash a pair of nodes the way the program verifies them, smaller node first
+fn merkle_parent(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+    if a <= b {
+        keccak::hashv(&[&a, &b]).0
+    } else {
+        keccak::hashv(&[&b, &a]).0
+    }
+}
+
+/// Root of a merkle tree over 4 depositors, and the proof of each depositor
+fn merkle_tree(depositors: [Pubkey; 4]) -> ([u8; 32], Vec<Vec<[u8; 32]>>) {
+    let leaves: Vec<[u8; 32]> = depositors.iter().map(merkle_leaf).collect();
+    let left = merkle_parent(leaves[0], leaves[1]);
+    let right = merkle_parent(leaves[2], leaves[3]);
+
+    let proofs = vec![
+        vec![leaves[1], right],
+        vec![leaves[0], right],
+        vec![leaves[3], left],
+        vec![leaves[2], left],
+    ];
+
+    (merkle_parent(left, right), proofs)
+}
+
+#[tokio::test]
+async fn allowlist_gate() {
+    let GatedVault {
+        mut context,
+        fixture,
+        alice,
+        bob,
+        carol,
+    } = gated_vault(|_, _| DepositGate::Allowlist).await;
+
+    fixture
+        .add_to_allowlist(&mut context, &alice.pubkey())
+        .await
+        .unwrap();
+    fixture
+        .add_to_allowlist(&mut context, &bob.pubkey())
+        .await
+        .unwrap();
+    let allowlist = |user: &Pubkey| vec![AccountMeta::new_readonly(fixture.allowlist(user), false)];
+
+    // ====== unlisted ======
+    // carol was never allowlisted, whether or not she passes her allowlist PDA
+    assert_program_error(
+        fixture.deposit(&mut context, &carol, 0, DEPOSIT).await,
+        ErrorCode::DepositorNotAllowlisted,
+    );
+    assert_program_error(
+        fixture
+            .deposit_with(
+                &mut context,
+                &carol,
+                0,
+                DEPOSIT,
+                vec![],
+                allowlist(&carol.pubkey()),
+            )
+            .await,
+        ErrorCode::DepositorNotAllowlisted,
+    );
+    // nor can she deposit through someone else's allowlist PDA
+    assert_program_error(
+        fixture
+            .deposit_with(
+                &mut context,
+                &carol,
+                0,
+                DEPOSIT,
+                vec![],
+                allowlist(&alice.pubkey()),
+            )
+            .await,
+        ErrorCode::PublicKeyMismatch,
+    );
+
+    // ====== allowlisted ======
+    for user in [&alice, &bob] {
+        fixture
+            .deposit_with(
+                &mut context,
+                user,
+                0,
+                DEPOSIT,
+                vec![],
+                allowlist(&user.pubkey()),
+            )
+            .await
+            .unwrap();
+    }
+
+    // ====== revoked ======
+    // bob's deposit so far stands, but he can't deposit again
+    fixture
+        .remove_from_allowlist(&mut context, &bob.pubkey())
+        .await
+        .unwrap();
+    assert!(
+        try_fetch::<Allowlist>(&mut context, &fixture.allowlist(&bob.pubkey()))
+            .await
+            .is_none()
+    );
+    assert_program_error(
+        fixture
+            .deposit_with(
+                &mut context,
+                &bob,
+                0,
+                DEPOSIT,
+                vec![],
+                allowlist(&bob.pubkey()),
+            )
+            .await,
+        ErrorCode::DepositorNotAllowlisted,
+    );
+
+    let vault = fixture.vault(&mut context).await;
+    assert_eq!(vault.tranches[0].deposits, 2);
+    assert_eq!(vault.tranches[0].deposited, 2 * DEPOSIT);
+    assert_eq!(
+        fixture
+            .history(&mut context, 0, &bob.pubkey())
+            .await
+            .cumulative,
+        DEPOSIT
+    );
+}
+
+#[tokio::test]
+async fn merkle_root_gate() {
+    // alice and bob are in a tree of 4 depositors, carol is not
+    let others = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let tree = |alice: &Pubkey, bob: &Pubkey| merkle_tree([*alice, *bob, others[0], others[1]]);
+
+    let GatedVault {
+        mut context,
+        fixture,
+        alice,
+        bob,
+        carol,
+    } = gated_vault(|alice, bob| DepositGate::MerkleRoot(tree(alice, bob).0)).await;
+    let (_, proofs) = tree(&alice.pubkey(), &bob.pubkey());
+    let (alice_proof, bob_proof) = (proofs[0].clone(), proofs[1].clone());
+
+    // ====== bad proofs ======
+    assert_program_error(
+        fixture.deposit(&mut context, &alice, 0, DEPOSIT).await,
+        ErrorCode::InvalidMerkleProof,
+    );
+    assert_program_error(
+        fixture
+            .deposit_with(&mut context, &alice, 0, DEPOSIT, bob_proof.clone(), vec![])
+            .await,
+        ErrorCode::InvalidMerkleProof,
+    );
+    // carol can't reuse the proof of another depositor
+    assert_program_error(
+        fixture
+            .deposit_with(
+                &mut context,
+                &carol,
+                0,
+                DEPOSIT,
+                alice_proof.clone(),
+                vec![],
+            )
+            .await,
+        ErrorCode::InvalidMerkleProof,
+    );
+
+    // ====== valid proofs ======
+    fixture
+        .deposit_with(&mut context, &alice, 0, DEPOSIT, alice_proof, vec![])
+        .await
+        .unwrap();
+    fixture
+        .deposit_with(&mut context, &bob, 0, DEPOSIT, bob_proof, vec![])
+        .await
+        .unwrap();
+
+    let vault = fixture.vault(&mut context).await;
+    assert_eq!(vault.tranches[0].deposits, 2);
+    assert_eq!(vault.tranches[0].deposited, 2 * DEPOSIT);
+}
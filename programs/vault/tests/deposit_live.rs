@@ -0,0 +1,307 @@
+//! Deposits into and early exits from a live Orca vault:
+//! deposit → invest → process_claims → claim → deposit_live / early_exit → claim
+
+mod common;
+
+use common::*;
+use solana_program::pubkey::Pubkey;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_associated_token_account::get_associated_token_address;
+use vault::{constant::MAX_BPS, error::ErrorCode, state::State};
+
+const POOL_RESERVE: u64 = 100_000_000;
+const DEPOSIT: u64 = 1_000_000;
+const LIVE_DEPOSIT: u64 = 100_000;
+const MAX_SLIPPAGE_BPS: u16 = 100;
+const MAX_DEVIATION_BPS: u16 = 100;
+const INVEST_DURATION: u64 = 30 * 24 * 60 * 60;
+const EXIT_PENALTY_BPS: u16 = 100;
+
+struct LiveVault {
+    context: ProgramTestContext,
+    fixture: VaultFixture,
+    strategy: OrcaStrategyFixture,
+    /// holds every senior tranche token
+    alice: Keypair,
+    /// has not deposited before the vault went live
+    dave: Keypair,
+    trader: Keypair,
+}
+
+/// A senior / junior vault invested 1:1 in a pool priced at 1:1, with every claim processed and claimed.
+/// With an oracle, both assets are quoted at $1. The senior tranche is owed 5% over the invest period.
+async fn live_vault(with_oracle: bool) -> LiveVault {
+    let mut program_test = program_test();
+
+    let senior_mint = Pubkey::new_unique();
+    let junior_mint = Pubkey::new_unique();
+    for mint in [&senior_mint, &junior_mint] {
+        add_mint(&mut program_test, mint, &Pubkey::new_unique(), 0, DECIMALS);
+    }
+    let pool = add_orca_pool(
+        &mut program_test,
+        &senior_mint,
+        &junior_mint,
+        (POOL_RESERVE, POOL_RESERVE),
+        POOL_RESERVE,
+    );
+    let oracle = oracle_config(
+        add_pyth_price(&mut program_test, 100_000_000, -8),
+        add_pyth_price(&mut program_test, 100_000_000, -8),
+        MAX_DEVIATION_BPS,
+    );
+
+    let alice = add_user(
+        &mut program_test,
+        &[(senior_mint, DEPOSIT), (junior_mint, 0)],
+    );
+    let bob = add_user(&mut program_test, &[(junior_mint, DEPOSIT)]);
+    let dave = add_user(
+        &mut program_test,
+        &[(senior_mint, LIVE_DEPOSIT), (junior_mint, LIVE_DEPOSIT)],
+    );
+    let trader = add_user(
+        &mut program_test,
+        &[(senior_mint, POOL_RESERVE), (junior_mint, 0)],
+    );
+
+    let mut context = program_test.start_with_context().await;
+    init_global_protocol_state(&mut context).await;
+
+    let strategy = OrcaStrategyFixture::init(&mut context, pool).await;
+    let fixture = VaultFixture::init_with(
+        &mut context,
+        strategy.address,
+        vec![
+            (senior_tranche_config(0, "SNR", 500), senior_mint),
+            (tranche_config(1, "JNR"), junior_mint),
+        ],
+        MAX_SLIPPAGE_BPS,
+        |config| {
+            config.invest_duration = INVEST_DURATION;
+            config.early_exit_penalty_bps = Some(EXIT_PENALTY_BPS);
+            if with_oracle {
+                config.oracle = Some(oracle);
+            }
+        },
+    )
+    .await;
+
+    fixture
+        .transition(&mut context, State::Deposit)
+        .await
+        .unwrap();
+    fixture
+        .deposit(&mut context, &alice, 0, DEPOSIT)
+        .await
+        .unwrap();
+    fixture
+        .deposit(&mut context, &bob, 1, DEPOSIT)
+        .await
+        .unwrap();
+    fixture.transition(&mut context, State::Live).await.unwrap();
+
+    strategy
+        .create_lp_store(&mut context, &fixture)
+        .await
+        .unwrap();
+    strategy
+        .invest(&mut context, &fixture, DEPOSIT, DEPOSIT)
+        .await
+        .unwrap();
+
+    // a live deposit is priced against the tranche's final supply, so it waits on process_claims
+    assert!(strategy
+        .deposit_live(&mut context, &fixture, &dave, 0, LIVE_DEPOSIT)
+        .await
+        .is_err());
+
+    fixture
+        .process_claims(&mut context, 0, &[alice.pubkey()])
+        .await
+        .unwrap();
+    fixture
+        .process_claims(&mut context, 1, &[bob.pubkey()])
+        .await
+        .unwrap();
+    for (user, tranche_id) in [(&alice, 0), (&bob, 1)] {
+        fixture.claim(&mut context, user, tranche_id).await.unwrap();
+    }
+
+    // same transaction as the rejected attempt above
+    refresh_blockhash(&mut context).await;
+
+    LiveVault {
+        context,
+        fixture,
+        strategy,
+        alice,
+        dave,
+        trader,
+    }
+}
+
+/// Move the clock to the given share of the vault's invest period, in basis points
+async fn warp_into_invest_period(live: &mut LiveVault, elapsed_bps: u64) {
+    let invested_at = live
+        .fixture
+        .vault(&mut live.context)
+        .await
+        .invested_at
+        .unwrap();
+    warp_to_timestamp(
+        &mut live.context,
+        invested_at + INVEST_DURATION * elapsed_bps / MAX_BPS,
+    )
+    .await;
+}
+
+/// Live deposit LIVE_DEPOSIT into the tranche, returning the tranche tokens minted for it
+async fn deposit_live_for_lp(live: &mut LiveVault, tranche_id: u8) -> u64 {
+    let total_invested =
+        live.fixture.vault(&mut live.context).await.tranches[tranche_id as usize].total_invested;
+    live.strategy
+        .deposit_live(
+            &mut live.context,
+            &live.fixture,
+            &live.dave,
+            tranche_id,
+            LIVE_DEPOSIT,
+        )
+        .await
+        .unwrap();
+
+    let lp = get_associated_token_address(&live.dave.pubkey(), &live.fixture.lp(tranche_id));
+    let lp_amount = token_balance(&mut live.context, &lp).await;
+    assert_eq!(
+        live.fixture.vault(&mut live.context).await.tranches[tranche_id as usize].total_invested,
+        total_invested + lp_amount
+    );
+
+    lp_amount
+}
+
+#[tokio::test]
+async fn live_deposit_is_not_claimable() {
+    let mut live = live_vault(true).await;
+    let dave = live.dave.pubkey();
+
+    // ====== deposit live ======
+    let total_invested = live.fixture.vault(&mut live.context).await.tranches[0].total_invested;
+    let live_lp = deposit_live_for_lp(&mut live, 0).await;
+    assert!(live_lp > 0);
+
+    let history = live.fixture.history(&mut live.context, 0, &dave).await;
+    assert_eq!(history.cumulative, 0);
+    assert_eq!(history.live_deposits, 1);
+    assert_eq!(history.live_cumulative, LIVE_DEPOSIT);
+
+    // ====== claim ======
+    // the live deposit's tranche tokens were minted up front, claiming must not mint them again
+    live.fixture
+        .claim(&mut live.context, &live.dave, 0)
+        .await
+        .unwrap();
+    let dave_lp = get_associated_token_address(&dave, &live.fixture.lp(0));
+    assert_eq!(token_balance(&mut live.context, &dave_lp).await, live_lp);
+    assert_eq!(
+        live.fixture.vault(&mut live.context).await.tranches[0].total_invested,
+        total_invested + live_lp
+    );
+}
+
+// both tranches invested 990_000 and the strategy's position is worth 1_980_000 at the pool's price. half way
+// through the invest period, the senior tranche is owed half of its 5% on top of its investment, the junior
+// tranche takes what remains.
+const SENIOR_VALUE: u64 = 990_000 + 990_000 * 5 / 100 / 2;
+const JUNIOR_VALUE: u64 = 2 * 990_000 - SENIOR_VALUE;
+
+#[tokio::test]
+async fn live_deposit_priced_at_senior_nav() {
+    let mut live = live_vault(true).await;
+    warp_into_invest_period(&mut live, MAX_BPS / 2).await;
+
+    assert_eq!(
+        deposit_live_for_lp(&mut live, 0).await,
+        LIVE_DEPOSIT * 990_000 / SENIOR_VALUE
+    );
+}
+
+#[tokio::test]
+async fn live_deposit_priced_at_junior_nav() {
+    let mut live = live_vault(true).await;
+    warp_into_invest_period(&mut live, MAX_BPS / 2).await;
+
+    assert_eq!(
+        deposit_live_for_lp(&mut live, 1).await,
+        LIVE_DEPOSIT * 990_000 / JUNIOR_VALUE
+    );
+}
+
+#[tokio::test]
+async fn live_deposit_requires_oracle() {
+    let LiveVault {
+        mut context,
+        fixture,
+        strategy,
+        dave,
+        ..
+    } = live_vault(false).await;
+
+    assert_program_error(
+        strategy
+            .deposit_live(&mut context, &fixture, &dave, 0, LIVE_DEPOSIT)
+            .await,
+        ErrorCode::OracleRequired,
+    );
+}
+
+#[tokio::test]
+async fn live_deposit_rejects_manipulated_pool_price() {
+    let LiveVault {
+        mut context,
+        fixture,
+        strategy,
+        dave,
+        trader,
+        ..
+    } = live_vault(true).await;
+
+    // dumping the senior asset into the pool would make the senior tranche's position look cheaper
+    strategy
+        .swap(&mut context, &trader, true, POOL_RESERVE / 10)
+        .await
+        .unwrap();
+
+    assert_program_error(
+        strategy
+            .deposit_live(&mut context, &fixture, &dave, 0, LIVE_DEPOSIT)
+            .await,
+        ErrorCode::OraclePriceDeviationExceeded,
+    );
+}
+
+#[tokio::test]
+async fn early_exit_at_start_earns_no_return() {
+    let mut live = live_vault(true).await;
+    warp_into_invest_period(&mut live, 0).await;
+    let alice = live.alice.pubkey();
+    let alice_senior = get_associated_token_address(&alice, &live.fixture.mints[0]);
+    let alice_junior = get_associated_token_address(&alice, &live.fixture.mints[1]);
+    let senior_before = token_balance(&mut live.context, &alice_senior).await;
+
+    // as the vault goes live, the senior tranche has accrued none of its return. the exit is worth what was
+    // invested, paid out in both of the pool's assets at 1:1.
+    let lp_amount = 990_000 / 2;
+    live.strategy
+        .early_exit(&mut live.context, &live.fixture, &live.alice, 0, lp_amount)
+        .await
+        .unwrap();
+
+    let payout = lp_amount - lp_amount * EXIT_PENALTY_BPS as u64 / MAX_BPS;
+    let received = token_balance(&mut live.context, &alice_senior).await - senior_before
+        + token_balance(&mut live.context, &alice_junior).await;
+    // rounding leaves less than 3 units of dust behind
+    assert!(received <= payout && payout - received <= 2);
+}
@@ -0,0 +1,297 @@
+//! Full lifecycle of a 2 tranche vault investing in an Orca pool served by the mock AMM:
+//! deposit → invest → process_claims → claim → redeem → rebalance → withdraw
+
+mod common;
+
+use common::*;
+use mock_amm::error::MockAmmError;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+use spl_associated_token_account::get_associated_token_address;
+use vault::{
+    state::{ReturnTerms, State, StrategistRole},
+    SwapConfig,
+};
+
+const POOL_RESERVE: u64 = 100_000_000;
+const SENIOR_DEPOSIT: u64 = 1_000_000;
+const JUNIOR_DEPOSIT: u64 = 600_000;
+const MAX_SLIPPAGE_BPS: u16 = 50;
+
+#[tokio::test]
+async fn orca_vault_lifecycle() {
+    let mut program_test = program_test();
+
+    let senior_mint = Pubkey::new_unique();
+    let junior_mint = Pubkey::new_unique();
+    for mint in [&senior_mint, &junior_mint] {
+        add_mint(&mut program_test, mint, &Pubkey::new_unique(), 0, DECIMALS);
+    }
+    let pool = add_orca_pool(
+        &mut program_test,
+        &senior_mint,
+        &junior_mint,
+        (POOL_RESERVE, POOL_RESERVE),
+        POOL_RESERVE,
+    );
+
+    let alice = add_user(&mut program_test, &[(senior_mint, SENIOR_DEPOSIT)]);
+    let bob = add_user(&mut program_test, &[(junior_mint, JUNIOR_DEPOSIT)]);
+    let carol = add_user(&mut program_test, &[(junior_mint, JUNIOR_DEPOSIT)]);
+    let trader = add_user(
+        &mut program_test,
+        &[(senior_mint, POOL_RESERVE), (junior_mint, 0)],
+    );
+
+    let mut context = program_test.start_with_context().await;
+    init_global_protocol_state(&mut context).await;
+
+    // ====== strategy + vault ======
+    let strategy = OrcaStrategyFixture::init(&mut context, pool).await;
+
+    let mut senior = tranche_config(0, "SNR");
+    senior.return_terms = Some(ReturnTerms {
+        floor_bps: 500,
+        cap_bps: 500,
+        yield_share_bps: 0,
+        first_loss_buffer_bps: None,
+    });
+    let fixture = VaultFixture::init(
+        &mut context,
+        strategy.address,
+        vec![
+            (senior, senior_mint),
+            (tranche_config(1, "JNR"), junior_mint),
+        ],
+        MAX_SLIPPAGE_BPS,
+    )
+    .await;
+
+    // ====== deposit ======
+    fixture
+        .transition(&mut context, State::Deposit)
+        .await
+        .unwrap();
+    fixture
+        .deposit(&mut context, &alice, 0, SENIOR_DEPOSIT)
+        .await
+        .unwrap();
+    fixture
+        .deposit(&mut context, &bob, 1, JUNIOR_DEPOSIT)
+        .await
+        .unwrap();
+    fixture
+        .deposit(&mut context, &carol, 1, JUNIOR_DEPOSIT)
+        .await
+        .unwrap();
+    fixture.transition(&mut context, State::Live).await.unwrap();
+
+    // ====== invest ======
+    let senior_store = fixture.vault_store_ata(&senior_mint);
+    let junior_store = fixture.vault_store_ata(&junior_mint);
+    let lp_store = fixture.vault_store_ata(&strategy.pool.state.pool_mint);
+    strategy
+        .create_lp_store(&mut context, &fixture)
+        .await
+        .unwrap();
+    strategy
+        .invest(&mut context, &fixture, SENIOR_DEPOSIT, 2 * JUNIOR_DEPOSIT)
+        .await
+        .unwrap();
+
+    // the balanced deposit is capped by the senior side, less the vault's max slippage
+    let expected_lp = SENIOR_DEPOSIT * (10_000 - MAX_SLIPPAGE_BPS as u64) / 10_000;
+    assert_eq!(token_balance(&mut context, &lp_store).await, expected_lp);
+
+    let vault = fixture.vault(&mut context).await;
+    assert_eq!(vault.state, State::Live);
+    assert_eq!(vault.tranches[0].invested, expected_lp);
+    assert_eq!(vault.tranches[0].excess, SENIOR_DEPOSIT - expected_lp);
+    assert_eq!(vault.tranches[1].invested, expected_lp);
+    assert_eq!(vault.tranches[1].excess, 2 * JUNIOR_DEPOSIT - expected_lp);
+
+    // an invested vault can no longer be cancelled
+    assert!(fixture.cancel(&mut context).await.is_err());
+
+    // ====== process claims + claim ======
+    fixture
+        .process_claims(&mut context, 0, &[alice.pubkey()])
+        .await
+        .unwrap();
+    fixture
+        .process_claims(&mut context, 1, &[bob.pubkey(), carol.pubkey()])
+        .await
+        .unwrap();
+    let vault = fixture.vault(&mut context).await;
+    assert!(vault
+        .tranches()
+        .iter()
+        .all(|t| t.claims_already_processed()));
+
+    // the latest deposits are refunded first, so carol's deposit absorbs the junior tranche's excess
+    assert_eq!(
+        fixture.history(&mut context, 1, &bob.pubkey()).await.claim,
+        0
+    );
+    assert_eq!(
+        fixture
+            .history(&mut context, 1, &carol.pubkey())
+            .await
+            .claim,
+        2 * JUNIOR_DEPOSIT - expected_lp
+    );
+
+    for (user, tranche_id) in [(&alice, 0), (&bob, 1), (&carol, 1)] {
+        fixture.claim(&mut context, user, tranche_id).await.unwrap();
+    }
+
+    let alice_lp = get_associated_token_address(&alice.pubkey(), &fixture.lp(0));
+    let bob_lp = get_associated_token_address(&bob.pubkey(), &fixture.lp(1));
+    let carol_lp = get_associated_token_address(&carol.pubkey(), &fixture.lp(1));
+    assert_eq!(token_balance(&mut context, &alice_lp).await, expected_lp);
+    assert_eq!(token_balance(&mut context, &bob_lp).await, JUNIOR_DEPOSIT);
+    assert_eq!(
+        token_balance(&mut context, &carol_lp).await,
+        expected_lp - JUNIOR_DEPOSIT
+    );
+
+    let alice_senior = get_associated_token_address(&alice.pubkey(), &senior_mint);
+    let carol_junior = get_associated_token_address(&carol.pubkey(), &junior_mint);
+    assert_eq!(
+        token_balance(&mut context, &alice_senior).await,
+        SENIOR_DEPOSIT - expected_lp
+    );
+    assert_eq!(
+        token_balance(&mut context, &carol_junior).await,
+        2 * JUNIOR_DEPOSIT - expected_lp
+    );
+
+    // ====== price moves while the vault is live ======
+    strategy
+        .swap(&mut context, &trader, true, POOL_RESERVE / 10)
+        .await
+        .unwrap();
+
+    // ====== redeem ======
+    // the vault is past its invest deadline, so anyone can move it to redeem
+    fixture
+        .transition_as(&mut context, &trader, State::Redeem)
+        .await
+        .unwrap();
+    // redeeming can be delegated to a keeper holding the redeem role
+    fixture
+        .set_keeper(
+            &mut context,
+            Some(trader.pubkey()),
+            StrategistRole::Harvest as u64 | StrategistRole::Convert as u64,
+        )
+        .await
+        .unwrap();
+    assert!(strategy
+        .redeem_as(&mut context, &fixture, &trader)
+        .await
+        .is_err());
+    fixture
+        .set_keeper(
+            &mut context,
+            Some(bob.pubkey()),
+            StrategistRole::Redeem as u64,
+        )
+        .await
+        .unwrap();
+    strategy
+        .redeem_as(&mut context, &fixture, &bob)
+        .await
+        .unwrap();
+
+    assert_eq!(token_balance(&mut context, &lp_store).await, 0);
+    let vault = fixture.vault(&mut context).await;
+    assert_eq!(
+        vault.received_for(&senior_mint).unwrap(),
+        token_balance(&mut context, &senior_store).await
+    );
+    assert_eq!(
+        vault.received_for(&junior_mint).unwrap(),
+        token_balance(&mut context, &junior_store).await
+    );
+
+    // ====== rebalance ======
+    // the trader sold the senior asset into the pool, so swap some of the junior tranche's assets back
+    // only the authority or strategist can move to rebalance, and a vault only returns to inactive by being cancelled
+    assert!(fixture
+        .transition_as(&mut context, &trader, State::Rebalance)
+        .await
+        .is_err());
+    assert!(fixture
+        .transition(&mut context, State::Inactive)
+        .await
+        .is_err());
+    fixture
+        .transition(&mut context, State::Rebalance)
+        .await
+        .unwrap();
+    // swapping all of the junior tranche's assets moves the pool's price by more than the vault's max slippage.
+    // the strategist asked for no minimum, but the pool is handed the vault's floor
+    let junior_balance = token_balance(&mut context, &junior_store).await;
+    assert_program_error(
+        strategy
+            .rebalance(
+                &mut context,
+                &fixture,
+                Some(SwapConfig {
+                    max_in: junior_balance,
+                    min_out: 0,
+                    alpha_to_beta: false,
+                }),
+            )
+            .await,
+        MockAmmError::ExceededSlippage,
+    );
+    strategy
+        .rebalance(
+            &mut context,
+            &fixture,
+            Some(SwapConfig {
+                max_in: 10_000,
+                min_out: 0,
+                alpha_to_beta: false,
+            }),
+        )
+        .await
+        .unwrap();
+
+    let vault = fixture.vault(&mut context).await;
+    assert!(vault.reblanced);
+    let senior_received = vault.tranches[0].received;
+    let junior_received = vault.tranches[1].received;
+    assert_eq!(
+        senior_received,
+        token_balance(&mut context, &senior_store).await
+    );
+    assert_eq!(
+        junior_received,
+        token_balance(&mut context, &junior_store).await
+    );
+
+    // ====== withdraw ======
+    let alice_before = token_balance(&mut context, &alice_senior).await;
+    fixture.withdraw(&mut context, &alice, 0).await.unwrap();
+    assert_eq!(fixture.vault(&mut context).await.state, State::Withdraw);
+    // alice holds every senior tranche token, rounding may leave dust behind
+    let alice_withdrawn = token_balance(&mut context, &alice_senior).await - alice_before;
+    assert!(alice_withdrawn <= senior_received && senior_received - alice_withdrawn <= 1);
+    assert_eq!(token_balance(&mut context, &alice_lp).await, 0);
+
+    let bob_junior = get_associated_token_address(&bob.pubkey(), &junior_mint);
+    let bob_before = token_balance(&mut context, &bob_junior).await;
+    let carol_before = token_balance(&mut context, &carol_junior).await;
+    fixture.withdraw(&mut context, &bob, 1).await.unwrap();
+    fixture.withdraw(&mut context, &carol, 1).await.unwrap();
+
+    let bob_withdrawn = token_balance(&mut context, &bob_junior).await - bob_before;
+    let carol_withdrawn = token_balance(&mut context, &carol_junior).await - carol_before;
+    assert!(bob_withdrawn > carol_withdrawn);
+    assert!(bob_withdrawn + carol_withdrawn <= junior_received);
+    // rounding leaves less than 3 units of dust behind
+    assert!(junior_received - (bob_withdrawn + carol_withdrawn) <= 2);
+}
@@ -0,0 +1,230 @@
+//! Rolling positions of an Orca vault over into the vault for its next epoch:
+//! claim → opt_into_rollover → redeem → rebalance → process_rollover / withdraw_rollover
+
+mod common;
+
+use common::*;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+use spl_associated_token_account::get_associated_token_address;
+use vault::{error::ErrorCode, state::State};
+
+const POOL_RESERVE: u64 = 100_000_000;
+const DEPOSIT: u64 = 1_000_000;
+const MAX_SLIPPAGE_BPS: u16 = 100;
+
+#[tokio::test]
+async fn rollover_into_next_vault() {
+    let mut program_test = program_test();
+
+    let senior_mint = Pubkey::new_unique();
+    let junior_mint = Pubkey::new_unique();
+    for mint in [&senior_mint, &junior_mint] {
+        add_mint(&mut program_test, mint, &Pubkey::new_unique(), 0, DECIMALS);
+    }
+    let pool = add_orca_pool(
+        &mut program_test,
+        &senior_mint,
+        &junior_mint,
+        (POOL_RESERVE, POOL_RESERVE),
+        POOL_RESERVE,
+    );
+
+    let alice = add_user(&mut program_test, &[(senior_mint, DEPOSIT)]);
+    let bob = add_user(&mut program_test, &[(junior_mint, DEPOSIT)]);
+
+    let mut context = program_test.start_with_context().await;
+    init_global_protocol_state(&mut context).await;
+
+    // ====== vault + next vault ======
+    let strategy = OrcaStrategyFixture::init(&mut context, pool).await;
+    let tranches = || {
+        vec![
+            (senior_tranche_config(0, "SNR", 500), senior_mint),
+            (tranche_config(1, "JNR"), junior_mint),
+        ]
+    };
+    let fixture =
+        VaultFixture::init(&mut context, strategy.address, tranches(), MAX_SLIPPAGE_BPS).await;
+    // the next vault's junior tranche is too small to take bob's position
+    let next_fixture = VaultFixture::init_with(
+        &mut context,
+        strategy.address,
+        tranches(),
+        MAX_SLIPPAGE_BPS,
+        |config| config.tranches[1].asset_cap = Some(DEPOSIT / 2),
+    )
+    .await;
+    fixture
+        .set_rollover_vault(&mut context, &next_fixture)
+        .await
+        .unwrap();
+
+    // ====== deposit + invest ======
+    fixture
+        .transition(&mut context, State::Deposit)
+        .await
+        .unwrap();
+    fixture
+        .deposit(&mut context, &alice, 0, DEPOSIT)
+        .await
+        .unwrap();
+    fixture
+        .deposit(&mut context, &bob, 1, DEPOSIT)
+        .await
+        .unwrap();
+    fixture.transition(&mut context, State::Live).await.unwrap();
+    strategy
+        .create_lp_store(&mut context, &fixture)
+        .await
+        .unwrap();
+    strategy
+        .invest(&mut context, &fixture, DEPOSIT, DEPOSIT)
+        .await
+        .unwrap();
+
+    // ====== process claims + claim ======
+    // alice claims before the junior tranche's claims are processed, so her tranche tokens are minted frozen
+    fixture
+        .process_claims(&mut context, 0, &[alice.pubkey()])
+        .await
+        .unwrap();
+    fixture.claim(&mut context, &alice, 0).await.unwrap();
+    fixture
+        .process_claims(&mut context, 1, &[bob.pubkey()])
+        .await
+        .unwrap();
+    fixture.claim(&mut context, &bob, 1).await.unwrap();
+
+    let alice_lp = get_associated_token_address(&alice.pubkey(), &fixture.lp(0));
+    let bob_lp = get_associated_token_address(&bob.pubkey(), &fixture.lp(1));
+    assert!(token_account_frozen(&mut context, &alice_lp).await);
+    let alice_lp_amount = token_balance(&mut context, &alice_lp).await;
+    let bob_lp_amount = token_balance(&mut context, &bob_lp).await;
+
+    // ====== opt into rollover ======
+    // alice's tranche tokens are thawed before they are burned
+    fixture
+        .opt_into_rollover(&mut context, &alice, 0, 0)
+        .await
+        .unwrap();
+    fixture
+        .opt_into_rollover(&mut context, &bob, 1, 0)
+        .await
+        .unwrap();
+    assert_eq!(token_balance(&mut context, &alice_lp).await, 0);
+    assert_eq!(token_balance(&mut context, &bob_lp).await, 0);
+    assert_eq!(
+        fixture
+            .rollover(&mut context, 0, &alice.pubkey())
+            .await
+            .lp_amount,
+        alice_lp_amount
+    );
+    assert_eq!(
+        fixture
+            .rollover(&mut context, 1, &bob.pubkey())
+            .await
+            .lp_amount,
+        bob_lp_amount
+    );
+
+    // positions are only rolled over once the vault reaches the withdraw state
+    next_fixture
+        .transition(&mut context, State::Deposit)
+        .await
+        .unwrap();
+    assert_program_error(
+        fixture
+            .process_rollover(&mut context, &next_fixture, &alice.pubkey(), 0)
+            .await,
+        ErrorCode::InvalidVaultState,
+    );
+
+    // ====== redeem + rebalance ======
+    fixture
+        .transition(&mut context, State::Redeem)
+        .await
+        .unwrap();
+    strategy.redeem(&mut context, &fixture).await.unwrap();
+    fixture
+        .transition(&mut context, State::Rebalance)
+        .await
+        .unwrap();
+    strategy
+        .rebalance(&mut context, &fixture, None)
+        .await
+        .unwrap();
+
+    let vault = fixture.vault(&mut context).await;
+    let senior_received = vault.tranches[0].received;
+    let junior_received = vault.tranches[1].received;
+
+    // ====== process rollover ======
+    // alice holds every senior tranche token, so the whole senior tranche moves to the next vault.
+    // same transaction as the rejected attempt above
+    refresh_blockhash(&mut context).await;
+    fixture
+        .process_rollover(&mut context, &next_fixture, &alice.pubkey(), 0)
+        .await
+        .unwrap();
+    assert_eq!(fixture.vault(&mut context).await.state, State::Withdraw);
+    assert!(
+        fixture
+            .rollover(&mut context, 0, &alice.pubkey())
+            .await
+            .processed
+    );
+
+    let next_senior_store = next_fixture.vault_store_ata(&senior_mint);
+    let rolled_over = token_balance(&mut context, &next_senior_store).await;
+    assert!(rolled_over <= senior_received && senior_received - rolled_over <= 1);
+    let next_vault = next_fixture.vault(&mut context).await;
+    assert_eq!(next_vault.tranches[0].rollover_deposited, rolled_over);
+    assert_eq!(next_vault.tranches[0].deposited, rolled_over);
+    let next_history = next_fixture.history(&mut context, 0, &alice.pubkey()).await;
+    assert_eq!(next_history.rollover, rolled_over);
+    assert_eq!(next_history.cumulative, rolled_over);
+
+    refresh_blockhash(&mut context).await;
+    assert_program_error(
+        fixture
+            .process_rollover(&mut context, &next_fixture, &alice.pubkey(), 0)
+            .await,
+        ErrorCode::RolloverAlreadyProcessed,
+    );
+    assert_program_error(
+        fixture
+            .withdraw_rollover(&mut context, &next_fixture, &alice.pubkey(), 0)
+            .await,
+        ErrorCode::RolloverAlreadyProcessed,
+    );
+
+    // ====== withdraw rollover ======
+    // bob's position exceeds the next vault's junior asset cap, so it is paid out to him instead
+    assert_program_error(
+        fixture
+            .process_rollover(&mut context, &next_fixture, &bob.pubkey(), 1)
+            .await,
+        ErrorCode::AssetCapExceeded,
+    );
+    let bob_junior = get_associated_token_address(&bob.pubkey(), &junior_mint);
+    let bob_before = token_balance(&mut context, &bob_junior).await;
+    fixture
+        .withdraw_rollover(&mut context, &next_fixture, &bob.pubkey(), 1)
+        .await
+        .unwrap();
+
+    let bob_withdrawn = token_balance(&mut context, &bob_junior).await - bob_before;
+    assert!(bob_withdrawn <= junior_received && junior_received - bob_withdrawn <= 1);
+    assert!(
+        fixture
+            .rollover(&mut context, 1, &bob.pubkey())
+            .await
+            .processed
+    );
+    assert_eq!(
+        next_fixture.vault(&mut context).await.tranches[1].deposited,
+        0
+    );
+}
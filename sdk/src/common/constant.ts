@@ -1,5 +1,6 @@
 import { OrcaU64 } from "@orca-so/sdk";
 import { u64 } from "@solana/spl-token";
+import { PublicKey } from "@solana/web3.js";
 
 export const LOCALNET = "localnet";
 export const TESTNET = "testnet";
@@ -21,3 +22,7 @@ export const DAY_IN_MS = HOUR_IN_MS * 24;
 
 export const SABER_LP_STRATEGY = "Saber LP Strategy";
 export const SOL_KEY_STR = "So11111111111111111111111111111111111111112";
+
+export const TOKEN_METADATA_PROGRAM_ID = new PublicKey(
+  "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
+);
@@ -69,34 +69,56 @@ export interface TokenSupply {
   decimals: number;
 }
 
+// what a tranche is owed from the vault's return, in bps of its investment
+export interface ReturnTerms {
+  floorBps: number;
+  capBps: number;
+  yieldShareBps: number;
+  firstLossBufferBps?: number;
+}
+
 export interface AssetConfig {
   mint: PublicKey;
+  seniority: number;
+  symbol: string;
+  returnTerms?: ReturnTerms;
   assetCap?: u64;
   userCap?: u64;
+  minDeposit?: u64;
+  maxDepositsPerUser?: u64;
 }
 
 export interface VaultConfig {
   authority: PublicKey;
   strategy: PublicKey;
   strategist: PublicKey;
-  alpha: AssetConfig;
-  beta: AssetConfig;
-  fixedRate: number;
+  // ordered from most senior to most junior
+  tranches: AssetConfig[];
   startAt: u64;
   depositDuration: u64;
   investDuration: u64;
+  maxSlippageBps: number;
+  metadataUri: string;
+  earlyExitPenaltyBps?: number;
 }
 
 export interface IAsset {
+  trancheId: number;
+  seniority: number;
+  returnTerms?: ReturnTerms;
   mint: PublicKey;
   lp: PublicKey;
+  decimals: number;
   assetCap?: u64;
   userCap?: u64;
+  minDeposit?: u64;
+  maxDepositsPerUser?: u64;
   deposits: u64;
   deposited: u64;
   invested: u64;
   excess: u64;
   received: u64;
+  totalInvested: u64;
   claimsProcessed: boolean;
   claimsIdx?: u64;
 }
@@ -105,6 +127,7 @@ export enum State {
   Inactive = "Inactive",
   Deposit = "Deposit",
   Live = "Live",
+  Rebalance = "Rebalance",
   Redeem = "Redeem",
   Withdraw = "Withdraw",
 }
@@ -112,11 +135,11 @@ export enum State {
 export interface IVault {
   bump: number;
   authority: PublicKey;
-  alpha: IAsset;
-  beta: IAsset;
+  // fixed size; only the first `numTranches` are in use, see `alphaTranche` and `betaTranche`
+  tranches: IAsset[];
+  numTranches: number;
   strategy: PublicKey;
   strategist: PublicKey;
-  fixedRate: number;
   state: State;
   startAt: u64;
   startedAt: u64;
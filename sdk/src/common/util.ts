@@ -17,6 +17,7 @@ import {
   SwapAmount,
   State,
   IVault,
+  IAsset,
 } from "./types";
 
 export function isKp(kp: PublicKey | Keypair) {
@@ -163,6 +164,8 @@ const toState = (state: string): State => {
       return State.Deposit;
     case "live":
       return State.Live;
+    case "rebalance":
+      return State.Rebalance;
     case "redeem":
       return State.Redeem;
     case "withdraw":
@@ -176,3 +179,16 @@ export const toIVault = (vault: any): IVault => {
   vault.state = toState(Object.keys(vault.state)[0]);
   return vault as IVault;
 };
+
+// the most senior tranche; its asset is paired against the most junior tranche's asset in the strategy
+export const alphaTranche = (vault: IVault): IAsset => vault.tranches[0];
+
+// the most junior tranche
+export const betaTranche = (vault: IVault): IAsset =>
+  vault.tranches[vault.numTranches - 1];
+
+// the return the senior tranche is guaranteed, in bps of its investment
+export const seniorReturnBps = (vault: IVault): number => {
+  const returnTerms = alphaTranche(vault).returnTerms;
+  return returnTerms ? returnTerms.floorBps : 0;
+};
@@ -67,27 +67,17 @@ export type Vault = {
           "isSigner": false
         },
         {
-          "name": "alphaMint",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "alphaLp",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "betaMint",
-          "isMut": true,
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "betaLp",
-          "isMut": true,
+          "name": "tokenProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "tokenMetadataProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -118,10 +108,15 @@ export type Vault = {
       "name": "transitionVault",
       "accounts": [
         {
-          "name": "authority",
+          "name": "payer",
           "isMut": true,
           "isSigner": true
         },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "globalProtocolState",
           "isMut": false,
@@ -136,7 +131,9 @@ export type Vault = {
       "args": [
         {
           "name": "targetState",
-          "type": "string"
+          "type": {
+            "defined": "State"
+          }
         },
         {
           "name": "timestamp",
@@ -144,6 +141,27 @@ export type Vault = {
         }
       ]
     },
+    {
+      "name": "cancelVault",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalProtocolState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
     {
       "name": "deposit",
       "accounts": [
@@ -219,6 +237,10 @@ export type Vault = {
         }
       ],
       "args": [
+        {
+          "name": "trancheId",
+          "type": "u8"
+        },
         {
           "name": "depositIndex",
           "type": "u64"
@@ -234,21 +256,73 @@ export type Vault = {
         {
           "name": "amount",
           "type": "u64"
+        },
+        {
+          "name": "proof",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
         }
       ]
     },
     {
-      "name": "processClaims",
+      "name": "addToAllowlist",
       "accounts": [
         {
-          "name": "payer",
+          "name": "authority",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "authority",
+          "name": "globalProtocolState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "user",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "allowlist",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "allowlistBump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "removeFromAllowlist",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
         },
         {
           "name": "globalProtocolState",
@@ -257,17 +331,53 @@ export type Vault = {
         },
         {
           "name": "vault",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "mint",
+          "name": "user",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "allowlist",
+          "isMut": true,
+          "isSigner": false
         }
       ],
       "args": []
     },
+    {
+      "name": "processClaims",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "globalProtocolState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "trancheId",
+          "type": "u8"
+        }
+      ]
+    },
     {
       "name": "claim",
       "accounts": [
@@ -347,7 +457,12 @@ export type Vault = {
           "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "trancheId",
+          "type": "u8"
+        }
+      ]
     },
     {
       "name": "withdraw",
@@ -431,124 +546,154 @@ export type Vault = {
       ]
     },
     {
-      "name": "initializeOrca",
+      "name": "claimAndWithdraw",
       "accounts": [
         {
-          "name": "authority",
+          "name": "payer",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "globalProtocolState",
+          "name": "authority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "strategy",
-          "isMut": true,
+          "name": "globalProtocolState",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenA",
-          "isMut": false,
+          "name": "vault",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenB",
-          "isMut": false,
+          "name": "vaultStore",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "swapProgram",
-          "isMut": false,
+          "name": "history",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "farmProgram",
+          "name": "mint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "pool",
+          "name": "lp",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "baseLp",
-          "isMut": false,
+          "name": "sourceAta",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "farm",
-          "isMut": false,
+          "name": "destinationAta",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "farmLp",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "doubleDipFarmLp",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "trancheId",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "setKeeper",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "systemProgram",
+          "name": "globalProtocolState",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rent",
-          "isMut": false,
+          "name": "vault",
+          "isMut": true,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "bump",
-          "type": "u8"
+          "name": "keeper",
+          "type": {
+            "option": "publicKey"
+          }
         },
         {
-          "name": "flag",
+          "name": "roles",
           "type": "u64"
-        },
-        {
-          "name": "version",
-          "type": "u16"
         }
       ]
     },
     {
-      "name": "rebalanceOrca",
+      "name": "setRolloverVault",
       "accounts": [
         {
-          "name": "payer",
+          "name": "authority",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "authority",
+          "name": "globalProtocolState",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "globalProtocolState",
-          "isMut": false,
+          "name": "vault",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "vault",
+          "name": "rolloverVault",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setTrancheLpFrozen",
+      "accounts": [
+        {
+          "name": "authority",
           "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalProtocolState",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "vaultStore",
+          "name": "vault",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "strategy",
+          "name": "lp",
           "isMut": false,
           "isSigner": false
         },
@@ -556,66 +701,127 @@ export type Vault = {
           "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "frozen",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "thawTrancheLp",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "orcaSwapProgram",
+          "name": "authority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "orcaPool",
+          "name": "globalProtocolState",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "orcaAuthority",
+          "name": "vault",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "userSource",
-          "isMut": true,
+          "name": "lp",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "poolSource",
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "optIntoRollover",
+      "accounts": [
+        {
+          "name": "payer",
           "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "poolDestination",
-          "isMut": true,
+          "name": "globalProtocolState",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "userDestination",
+          "name": "vault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rollover",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "poolMint",
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lp",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "feeAccount",
+          "name": "sourceLp",
           "isMut": true,
           "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "swapConfig",
-          "type": {
-            "option": {
-              "defined": "SwapConfig"
-            }
-          }
+          "name": "trancheId",
+          "type": "u8"
+        },
+        {
+          "name": "rolloverBump",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "investOrca",
+      "name": "processRollover",
       "accounts": [
         {
           "name": "payer",
@@ -643,88 +849,84 @@ export type Vault = {
           "isSigner": false
         },
         {
-          "name": "strategy",
-          "isMut": false,
+          "name": "rollover",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "depositor",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
-          "isMut": false,
+          "name": "nextVault",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "rent",
-          "isMut": false,
+          "name": "nextVaultStore",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "orcaSwapProgram",
-          "isMut": false,
+          "name": "nextHistory",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "orcaPool",
-          "isMut": false,
+          "name": "mint",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "orcaAuthority",
+          "name": "lp",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "sourceTokenA",
+          "name": "sourceAta",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "sourceTokenB",
+          "name": "destinationAta",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "intoA",
-          "isMut": true,
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "intoB",
-          "isMut": true,
+          "name": "tokenProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "poolToken",
-          "isMut": true,
+          "name": "ataProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "poolAccount",
-          "isMut": true,
+          "name": "rent",
+          "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "investableA",
-          "type": "u64"
-        },
-        {
-          "name": "investableB",
-          "type": "u64"
+          "name": "trancheId",
+          "type": "u8"
         },
         {
-          "name": "minTokensBack",
-          "type": "u64"
+          "name": "historyBump",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "redeemOrca",
+      "name": "withdrawRollover",
       "accounts": [
         {
           "name": "payer",
@@ -752,10 +954,40 @@ export type Vault = {
           "isSigner": false
         },
         {
-          "name": "strategy",
+          "name": "rollover",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "depositor",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nextVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lp",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "sourceAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destinationAta",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -766,75 +998,115 @@ export type Vault = {
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "ataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "rent",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "trancheId",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "initializeOrca",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "orcaSwapProgram",
+          "name": "globalProtocolState",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "orcaPool",
+          "name": "strategy",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenA",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "orcaAuthority",
+          "name": "tokenB",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "poolMint",
-          "isMut": true,
+          "name": "swapProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "sourcePoolAccount",
-          "isMut": true,
+          "name": "farmProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "fromA",
-          "isMut": true,
+          "name": "pool",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "fromB",
-          "isMut": true,
+          "name": "baseLp",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "sourceTokenA",
-          "isMut": true,
+          "name": "farm",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "sourceTokenB",
-          "isMut": true,
+          "name": "farmLp",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "feeAccount",
-          "isMut": true,
+          "name": "doubleDipFarmLp",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "minTokenA",
-          "type": "u64"
+          "name": "bump",
+          "type": "u8"
         },
         {
-          "name": "minTokenB",
+          "name": "flag",
           "type": "u64"
+        },
+        {
+          "name": "version",
+          "type": "u16"
         }
       ]
     },
     {
-      "name": "swapOrca",
+      "name": "rebalanceOrca",
       "accounts": [
         {
           "name": "payer",
@@ -919,17 +1191,17 @@ export type Vault = {
       ],
       "args": [
         {
-          "name": "amountIn",
-          "type": "u64"
-        },
-        {
-          "name": "minAmountOut",
-          "type": "u64"
+          "name": "swapConfig",
+          "type": {
+            "option": {
+              "defined": "SwapConfig"
+            }
+          }
         }
       ]
     },
     {
-      "name": "initializeUserFarmOrca",
+      "name": "investOrca",
       "accounts": [
         {
           "name": "payer",
@@ -966,213 +1238,243 @@ export type Vault = {
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "rent",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "aquafarmProgram",
+          "name": "orcaSwapProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "globalFarm",
-          "isMut": true,
+          "name": "orcaPool",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "userFarm",
-          "isMut": true,
+          "name": "orcaAuthority",
+          "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "convertOrcaLp",
-      "accounts": [
+        },
         {
-          "name": "payer",
+          "name": "sourceTokenA",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "authority",
-          "isMut": false,
+          "name": "sourceTokenB",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "globalProtocolState",
-          "isMut": false,
+          "name": "intoA",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "vault",
+          "name": "intoB",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "vaultStore",
+          "name": "poolToken",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "strategy",
-          "isMut": false,
+          "name": "poolAccount",
+          "isMut": true,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "investableA",
+          "type": "u64"
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "investableB",
+          "type": "u64"
         },
         {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "minTokensBack",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "depositLiveOrca",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "rent",
+          "name": "authority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "aquafarmProgram",
+          "name": "globalProtocolState",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "poolAccount",
+          "name": "vault",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userBaseAta",
+          "name": "vaultStore",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "globalBaseTokenVault",
+          "name": "history",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "farmTokenMint",
-          "isMut": true,
+          "name": "strategy",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "userFarmAta",
-          "isMut": true,
+          "name": "mint",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "globalFarm",
+          "name": "lp",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userFarm",
+          "name": "sourceAta",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "globalRewardTokenVault",
+          "name": "destinationLpAta",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userRewardAta",
-          "isMut": true,
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "farmAuthority",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "harvestOrca",
-      "accounts": [
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
         },
         {
-          "name": "authority",
+          "name": "ataProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "globalProtocolState",
+          "name": "rent",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "vault",
-          "isMut": true,
+          "name": "orcaSwapProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "vaultStore",
-          "isMut": true,
+          "name": "orcaPool",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "strategy",
+          "name": "orcaAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
-          "isMut": false,
+          "name": "sourceTokenA",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "aquafarmProgram",
-          "isMut": false,
+          "name": "sourceTokenB",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "globalFarm",
+          "name": "intoA",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userFarm",
+          "name": "intoB",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "globalBaseTokenVault",
+          "name": "poolToken",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "globalRewardTokenVault",
+          "name": "poolAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userRewardAta",
-          "isMut": true,
+          "name": "farmAccount",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "farmAuthority",
+          "name": "doubleDipFarmAccount",
           "isMut": false,
           "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "trancheId",
+          "type": "u8"
+        },
+        {
+          "name": "historyBump",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "minTokensBack",
+          "type": "u64"
+        },
+        {
+          "name": "proof",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        }
+      ]
     },
     {
-      "name": "revertOrcaLp",
+      "name": "earlyExitOrca",
       "accounts": [
         {
           "name": "payer",
@@ -1205,139 +1507,118 @@ export type Vault = {
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "aquafarmProgram",
+          "name": "rent",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "poolAccount",
+          "name": "lp",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userBaseAta",
+          "name": "sourceLp",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "globalBaseTokenVault",
+          "name": "destinationTokenA",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "farmTokenMint",
+          "name": "destinationTokenB",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userFarmAta",
-          "isMut": true,
+          "name": "orcaSwapProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "globalFarm",
-          "isMut": true,
+          "name": "orcaPool",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "userFarm",
-          "isMut": true,
+          "name": "orcaAuthority",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "globalRewardTokenVault",
+          "name": "poolMint",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userRewardAta",
+          "name": "sourcePoolAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "farmAuthority",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "initializeSaber",
-      "accounts": [
-        {
-          "name": "authority",
+          "name": "fromA",
           "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "globalProtocolState",
-          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "strategy",
+          "name": "fromB",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenA",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenB",
-          "isMut": false,
+          "name": "sourceTokenA",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "basePool",
-          "isMut": false,
+          "name": "sourceTokenB",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "poolLp",
-          "isMut": false,
+          "name": "feeAccount",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "farmAccount",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "doubleDipFarmAccount",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "bump",
-          "type": "u8"
+          "name": "lpAmount",
+          "type": "u64"
         },
         {
-          "name": "flag",
+          "name": "minTokenA",
           "type": "u64"
         },
         {
-          "name": "version",
-          "type": "u16"
+          "name": "minTokenB",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "investSaber",
+      "name": "redeemOrca",
       "accounts": [
         {
           "name": "payer",
@@ -1359,6 +1640,11 @@ export type Vault = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "vaultStore",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "strategy",
           "isMut": false,
@@ -1380,73 +1666,69 @@ export type Vault = {
           "isSigner": false
         },
         {
-          "name": "saberSwapCommon",
-          "accounts": [
-            {
-              "name": "swap",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "swapAuthority",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "sourceTokenA",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "reserveA",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "sourceTokenB",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "reserveB",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "poolMint",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "saberProgram",
-              "isMut": false,
-              "isSigner": false
-            }
-          ]
+          "name": "orcaSwapProgram",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "outputLp",
+          "name": "orcaPool",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "orcaAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sourcePoolAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "fromA",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "fromB",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sourceTokenA",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sourceTokenB",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeAccount",
           "isMut": true,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "investableA",
-          "type": "u64"
-        },
-        {
-          "name": "investableB",
+          "name": "minTokenA",
           "type": "u64"
         },
         {
-          "name": "minTokensBack",
+          "name": "minTokenB",
           "type": "u64"
         }
       ]
     },
     {
-      "name": "redeemSaber",
+      "name": "swapOrca",
       "accounts": [
         {
           "name": "payer",
@@ -1468,712 +1750,3305 @@ export type Vault = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "vaultStore",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "strategy",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "orcaSwapProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "orcaPool",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "saberSwapCommon",
-          "accounts": [
-            {
-              "name": "swap",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "swapAuthority",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "sourceTokenA",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "reserveA",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "sourceTokenB",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "reserveB",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "poolMint",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "saberProgram",
-              "isMut": false,
-              "isSigner": false
-            }
-          ]
+          "name": "orcaAuthority",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "inputLp",
+          "name": "userSource",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "outputAFees",
+          "name": "poolSource",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "outputBFees",
+          "name": "poolDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeAccount",
           "isMut": true,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "minTokenA",
+          "name": "amountIn",
           "type": "u64"
         },
         {
-          "name": "minTokenB",
+          "name": "minAmountOut",
           "type": "u64"
         }
       ]
-    }
-  ],
-  "accounts": [
-    {
-      "name": "orcaStrategyDataV0",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "flag",
-            "type": "u64"
-          },
-          {
-            "name": "version",
-            "type": "u16"
-          },
-          {
-            "name": "swapProgram",
-            "type": "publicKey"
-          },
-          {
-            "name": "farmProgram",
-            "type": "publicKey"
-          },
-          {
-            "name": "tokenA",
-            "type": "publicKey"
-          },
-          {
-            "name": "tokenB",
-            "type": "publicKey"
-          },
-          {
-            "name": "baseLp",
-            "type": "publicKey"
-          },
-          {
-            "name": "farmLp",
-            "type": "publicKey"
-          },
-          {
-            "name": "doubleDipLp",
-            "type": {
-              "option": "publicKey"
-            }
-          }
-        ]
-      }
-    },
-    {
-      "name": "saberStrategyDataV0",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "flag",
-            "type": "u64"
-          },
-          {
-            "name": "version",
-            "type": "u16"
-          },
-          {
-            "name": "baseLp",
-            "type": "publicKey"
-          },
-          {
-            "name": "farmLp",
-            "type": {
-              "option": "publicKey"
-            }
-          }
-        ]
-      }
     },
     {
-      "name": "globalProtocolState",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "authority",
-            "type": "publicKey"
-          },
-          {
-            "name": "active",
-            "type": "bool"
-          },
-          {
-            "name": "treasury",
-            "type": "publicKey"
-          }
-        ]
-      }
-    },
-    {
-      "name": "history",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "intialized",
-            "type": "bool"
-          },
-          {
-            "name": "deposits",
-            "type": "u64"
-          },
-          {
-            "name": "cumulative",
-            "type": "u64"
-          },
-          {
-            "name": "claim",
-            "type": "u64"
-          },
-          {
-            "name": "canClaimTrancheLp",
-            "type": "bool"
-          }
-        ]
-      }
-    },
-    {
-      "name": "receipt",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "amount",
-            "type": "u64"
-          },
-          {
-            "name": "cumulative",
-            "type": "u64"
-          },
-          {
-            "name": "depositor",
-            "type": "publicKey"
-          }
-        ]
-      }
+      "name": "getVaultNavOrca",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vaultStore",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "strategy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "orcaPool",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenA",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenB",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "doubleDipFarmAccount",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "name": "farmVault",
-      "type": {
-        "kind": "struct",
-        "fields": []
-      }
+      "name": "initializeUserFarmOrca",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "globalProtocolState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vaultStore",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "strategy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "aquafarmProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "globalFarm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userFarm",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "name": "vault",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "authority",
-            "type": "publicKey"
-          },
-          {
-            "name": "vaultStore",
-            "type": "publicKey"
-          },
-          {
-            "name": "vaultStoreBump",
-            "type": "u8"
-          },
-          {
-            "name": "alpha",
-            "type": {
-              "defined": "Asset"
-            }
-          },
-          {
-            "name": "beta",
-            "type": {
-              "defined": "Asset"
-            }
-          },
-          {
-            "name": "strategy",
-            "type": "publicKey"
-          },
-          {
-            "name": "strategist",
-            "type": "publicKey"
-          },
-          {
-            "name": "fixedRate",
-            "type": "u16"
-          },
-          {
-            "name": "state",
-            "type": {
-              "defined": "State"
-            }
-          },
-          {
-            "name": "startAt",
-            "type": "u64"
-          },
-          {
-            "name": "startedAt",
-            "type": {
-              "option": "u64"
-            }
-          },
-          {
-            "name": "depositDuration",
-            "type": "u64"
-          },
-          {
-            "name": "investedAt",
-            "type": {
-              "option": "u64"
-            }
-          },
-          {
-            "name": "investDuration",
-            "type": "u64"
-          },
-          {
-            "name": "redeemedAt",
-            "type": {
-              "option": "u64"
-            }
-          },
-          {
+      "name": "convertOrcaLp",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "globalProtocolState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vaultStore",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "strategy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "aquafarmProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userBaseAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalBaseTokenVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmTokenMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userFarmAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalFarm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userFarm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalRewardTokenVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userRewardAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "harvestOrca",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "globalProtocolState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vaultStore",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "strategy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "aquafarmProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "globalFarm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userFarm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalBaseTokenVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalRewardTokenVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userRewardAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "revertOrcaLp",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "globalProtocolState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vaultStore",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "strategy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "aquafarmProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userBaseAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalBaseTokenVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmTokenMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userFarmAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalFarm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userFarm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalRewardTokenVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userRewardAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeSaber",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalProtocolState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "strategy",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenA",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenB",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "basePool",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolLp",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "flag",
+          "type": "u64"
+        },
+        {
+          "name": "version",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "investSaber",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "globalProtocolState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "strategy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "saberSwapCommon",
+          "accounts": [
+            {
+              "name": "swap",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "swapAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "sourceTokenA",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "reserveA",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sourceTokenB",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "reserveB",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "poolMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "saberProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "outputLp",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "investableA",
+          "type": "u64"
+        },
+        {
+          "name": "investableB",
+          "type": "u64"
+        },
+        {
+          "name": "minTokensBack",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "depositLiveSaber",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "globalProtocolState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vaultStore",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "history",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "strategy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lp",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sourceAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destinationLpAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "saberSwapCommon",
+          "accounts": [
+            {
+              "name": "swap",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "swapAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "sourceTokenA",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "reserveA",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sourceTokenB",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "reserveB",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "poolMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "saberProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "outputLp",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "trancheId",
+          "type": "u8"
+        },
+        {
+          "name": "historyBump",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "minTokensBack",
+          "type": "u64"
+        },
+        {
+          "name": "proof",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "earlyExitSaber",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "globalProtocolState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vaultStore",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "strategy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lp",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sourceLp",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destinationTokenA",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destinationTokenB",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "saberSwapCommon",
+          "accounts": [
+            {
+              "name": "swap",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "swapAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "sourceTokenA",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "reserveA",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sourceTokenB",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "reserveB",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "poolMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "saberProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "inputLp",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outputAFees",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outputBFees",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "lpAmount",
+          "type": "u64"
+        },
+        {
+          "name": "minTokenA",
+          "type": "u64"
+        },
+        {
+          "name": "minTokenB",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "redeemSaber",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "globalProtocolState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "strategy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "saberSwapCommon",
+          "accounts": [
+            {
+              "name": "swap",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "swapAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "sourceTokenA",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "reserveA",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sourceTokenB",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "reserveB",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "poolMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "saberProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "inputLp",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outputAFees",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outputBFees",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "minTokenA",
+          "type": "u64"
+        },
+        {
+          "name": "minTokenB",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "getVaultNavSaber",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "strategy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swap",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "reserveA",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "reserveB",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "outputLp",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "allowlist",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "vault",
+            "type": "publicKey"
+          },
+          {
+            "name": "user",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "globalProtocolState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "active",
+            "type": "bool"
+          },
+          {
+            "name": "treasury",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "history",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "intialized",
+            "type": "bool"
+          },
+          {
+            "name": "deposits",
+            "type": "u64"
+          },
+          {
+            "name": "cumulative",
+            "type": "u64"
+          },
+          {
+            "name": "claim",
+            "type": "u64"
+          },
+          {
+            "name": "canClaimTrancheLp",
+            "type": "bool"
+          },
+          {
+            "name": "rollover",
+            "type": "u64"
+          },
+          {
+            "name": "liveDeposits",
+            "type": "u64"
+          },
+          {
+            "name": "liveCumulative",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "orcaStrategyDataV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "flag",
+            "type": "u64"
+          },
+          {
+            "name": "version",
+            "type": "u16"
+          },
+          {
+            "name": "swapProgram",
+            "type": "publicKey"
+          },
+          {
+            "name": "farmProgram",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenA",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenB",
+            "type": "publicKey"
+          },
+          {
+            "name": "baseLp",
+            "type": "publicKey"
+          },
+          {
+            "name": "farmLp",
+            "type": "publicKey"
+          },
+          {
+            "name": "doubleDipLp",
+            "type": {
+              "option": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "receipt",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "cumulative",
+            "type": "u64"
+          },
+          {
+            "name": "depositor",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "rollover",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "initialized",
+            "type": "bool"
+          },
+          {
+            "name": "depositor",
+            "type": "publicKey"
+          },
+          {
+            "name": "lpAmount",
+            "type": "u64"
+          },
+          {
+            "name": "processed",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "saberStrategyDataV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "flag",
+            "type": "u64"
+          },
+          {
+            "name": "version",
+            "type": "u16"
+          },
+          {
+            "name": "baseLp",
+            "type": "publicKey"
+          },
+          {
+            "name": "farmLp",
+            "type": {
+              "option": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "farmVault",
+      "type": {
+        "kind": "struct",
+        "fields": []
+      }
+    },
+    {
+      "name": "vault",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "vaultStore",
+            "type": "publicKey"
+          },
+          {
+            "name": "vaultStoreBump",
+            "type": "u8"
+          },
+          {
+            "name": "tranches",
+            "type": {
+              "array": [
+                {
+                  "defined": "Asset"
+                },
+                3
+              ]
+            }
+          },
+          {
+            "name": "numTranches",
+            "type": "u8"
+          },
+          {
+            "name": "strategy",
+            "type": "publicKey"
+          },
+          {
+            "name": "strategist",
+            "type": "publicKey"
+          },
+          {
+            "name": "state",
+            "type": {
+              "defined": "State"
+            }
+          },
+          {
+            "name": "startAt",
+            "type": "u64"
+          },
+          {
+            "name": "startedAt",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "depositDuration",
+            "type": "u64"
+          },
+          {
+            "name": "investedAt",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "investDuration",
+            "type": "u64"
+          },
+          {
+            "name": "redeemedAt",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
             "name": "rebalancedAt",
             "type": {
               "option": "u64"
             }
-          },
-          {
-            "name": "reblanced",
-            "type": "bool"
+          },
+          {
+            "name": "reblanced",
+            "type": "bool"
+          },
+          {
+            "name": "rolloverVault",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "depositGate",
+            "type": {
+              "defined": "DepositGate"
+            }
+          },
+          {
+            "name": "maxSlippageBps",
+            "type": "u16"
+          },
+          {
+            "name": "oracle",
+            "type": {
+              "option": {
+                "defined": "OracleConfig"
+              }
+            }
+          },
+          {
+            "name": "leverage",
+            "type": {
+              "option": {
+                "defined": "LeverageConfig"
+              }
+            }
+          },
+          {
+            "name": "trancheLpFrozen",
+            "type": "bool"
+          },
+          {
+            "name": "earlyExitPenaltyBps",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "cancelledAt",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "keeper",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "keeperRoles",
+            "type": "u64"
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "Asset",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "trancheId",
+            "type": "u8"
+          },
+          {
+            "name": "seniority",
+            "type": "u8"
+          },
+          {
+            "name": "returnTerms",
+            "type": {
+              "option": {
+                "defined": "ReturnTerms"
+              }
+            }
+          },
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "lp",
+            "type": "publicKey"
+          },
+          {
+            "name": "decimals",
+            "type": "u8"
+          },
+          {
+            "name": "assetCap",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "userCap",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "minDeposit",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "maxDepositsPerUser",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "deposits",
+            "type": "u64"
+          },
+          {
+            "name": "deposited",
+            "type": "u64"
+          },
+          {
+            "name": "invested",
+            "type": "u64"
+          },
+          {
+            "name": "excess",
+            "type": "u64"
+          },
+          {
+            "name": "received",
+            "type": "u64"
+          },
+          {
+            "name": "totalInvested",
+            "type": "u64"
+          },
+          {
+            "name": "rolloverDeposited",
+            "type": "u64"
+          },
+          {
+            "name": "exitCredit",
+            "type": "u64"
+          },
+          {
+            "name": "claimsProcessed",
+            "type": "bool"
+          },
+          {
+            "name": "claimsIdx",
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "TrancheNav",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "value",
+            "type": "u64"
+          },
+          {
+            "name": "lpPrice",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "VaultNav",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tranches",
+            "type": {
+              "vec": {
+                "defined": "TrancheNav"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxIn",
+            "type": "u64"
+          },
+          {
+            "name": "minOut",
+            "type": "u64"
+          },
+          {
+            "name": "alphaToBeta",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AssetConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "seniority",
+            "type": "u8"
+          },
+          {
+            "name": "symbol",
+            "type": "string"
+          },
+          {
+            "name": "returnTerms",
+            "type": {
+              "option": {
+                "defined": "ReturnTerms"
+              }
+            }
+          },
+          {
+            "name": "userCap",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "assetCap",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "minDeposit",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "maxDepositsPerUser",
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "VaultConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "strategy",
+            "type": "publicKey"
+          },
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "strategist",
+            "type": "publicKey"
+          },
+          {
+            "name": "tranches",
+            "type": {
+              "vec": {
+                "defined": "AssetConfig"
+              }
+            }
+          },
+          {
+            "name": "startAt",
+            "type": "u64"
+          },
+          {
+            "name": "depositDuration",
+            "type": "u64"
+          },
+          {
+            "name": "investDuration",
+            "type": "u64"
+          },
+          {
+            "name": "depositGate",
+            "type": {
+              "option": {
+                "defined": "DepositGate"
+              }
+            }
+          },
+          {
+            "name": "maxSlippageBps",
+            "type": "u16"
+          },
+          {
+            "name": "oracle",
+            "type": {
+              "option": {
+                "defined": "OracleConfig"
+              }
+            }
+          },
+          {
+            "name": "leverage",
+            "type": {
+              "option": {
+                "defined": "LeverageConfig"
+              }
+            }
+          },
+          {
+            "name": "metadataUri",
+            "type": "string"
+          },
+          {
+            "name": "earlyExitPenaltyBps",
+            "type": {
+              "option": "u16"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReturnTerms",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "floorBps",
+            "type": "u16"
+          },
+          {
+            "name": "capBps",
+            "type": "u16"
+          },
+          {
+            "name": "yieldShareBps",
+            "type": "u16"
+          },
+          {
+            "name": "firstLossBufferBps",
+            "type": {
+              "option": "u16"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "LeverageConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "targetRatioBps",
+            "type": "u32"
+          },
+          {
+            "name": "minRatioBps",
+            "type": "u32"
+          },
+          {
+            "name": "maxRatioBps",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OracleConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "source",
+            "type": {
+              "defined": "OracleSource"
+            }
+          },
+          {
+            "name": "alphaOracle",
+            "type": "publicKey"
+          },
+          {
+            "name": "betaOracle",
+            "type": "publicKey"
+          },
+          {
+            "name": "maxDeviationBps",
+            "type": "u16"
+          },
+          {
+            "name": "maxStalenessSlots",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "StrategistRole",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Invest"
+          },
+          {
+            "name": "Redeem"
+          },
+          {
+            "name": "Rebalance"
+          },
+          {
+            "name": "Swap"
+          },
+          {
+            "name": "Convert"
+          },
+          {
+            "name": "Harvest"
+          },
+          {
+            "name": "Revert"
+          }
+        ]
+      }
+    },
+    {
+      "name": "StrategyFlag",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "SaberLpStrategyV0"
+          },
+          {
+            "name": "OrcaLpStrategyV0"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OracleSource",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Pyth"
+          },
+          {
+            "name": "Switchboard"
+          }
+        ]
+      }
+    },
+    {
+      "name": "DepositGate",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Open"
+          },
+          {
+            "name": "Allowlist"
+          },
+          {
+            "name": "MerkleRoot",
+            "fields": [
+              {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "State",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Inactive"
+          },
+          {
+            "name": "Deposit"
+          },
+          {
+            "name": "Live"
+          },
+          {
+            "name": "Redeem"
+          },
+          {
+            "name": "Rebalance"
+          },
+          {
+            "name": "Withdraw"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransitionRole",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Strategist"
+          },
+          {
+            "name": "AnyoneAfterDeadline"
+          }
+        ]
+      }
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "ProtocolPaused",
+      "msg": "Protocol pausesd"
+    },
+    {
+      "code": 6001,
+      "name": "MathError",
+      "msg": "Math Error"
+    },
+    {
+      "code": 6002,
+      "name": "PublicKeyMismatch",
+      "msg": "PublicKeyMismatch"
+    },
+    {
+      "code": 6003,
+      "name": "BumpMismatch",
+      "msg": "BumpMismatch"
+    },
+    {
+      "code": 6004,
+      "name": "InvalidMintAuthority",
+      "msg": "InvalidMintAuthority"
+    },
+    {
+      "code": 6005,
+      "name": "UninitializedAccount",
+      "msg": "UninitializedAccount"
+    },
+    {
+      "code": 6006,
+      "name": "AlreadyInitializedAccount",
+      "msg": "AlreadyInitializedAccount"
+    },
+    {
+      "code": 6007,
+      "name": "IncorrectOwner",
+      "msg": "IncorrectOwner"
+    },
+    {
+      "code": 6008,
+      "name": "PublicKeysShouldBeUnique",
+      "msg": "PublicKeysShouldBeUnique"
+    },
+    {
+      "code": 6009,
+      "name": "AccountAlreadyInitialized",
+      "msg": "AccountAlreadyInitialized"
+    },
+    {
+      "code": 6010,
+      "name": "InsufficientTokenBalance",
+      "msg": "Insufficient token balance"
+    },
+    {
+      "code": 6011,
+      "name": "ImpossibleTokenRatioRequested",
+      "msg": "Impossible token ratio request"
+    },
+    {
+      "code": 6012,
+      "name": "InvalidStateTransition",
+      "msg": "Invalid state transition"
+    },
+    {
+      "code": 6013,
+      "name": "MissingTransitionAtTimeForState",
+      "msg": "Missing transition at time for state"
+    },
+    {
+      "code": 6014,
+      "name": "VaultHasNoDeposits",
+      "msg": "Vault has no deposits"
+    },
+    {
+      "code": 6015,
+      "name": "InvalidDepositForVault",
+      "msg": "Invalid deposit for vault"
+    },
+    {
+      "code": 6016,
+      "name": "WrongAccountOwner",
+      "msg": "Wrong account owner"
+    },
+    {
+      "code": 6017,
+      "name": "InvalidAccountData",
+      "msg": "Invalid account data"
+    },
+    {
+      "code": 6018,
+      "name": "InvalidStrategyFlag",
+      "msg": "Invalid strategy flag"
+    },
+    {
+      "code": 6019,
+      "name": "StrategyAlreadyExists",
+      "msg": "Strategy already exists"
+    },
+    {
+      "code": 6020,
+      "name": "InvalidVaultState",
+      "msg": "Invalid vault state"
+    },
+    {
+      "code": 6021,
+      "name": "InvalidVaultStore",
+      "msg": "Invalid vault store"
+    },
+    {
+      "code": 6022,
+      "name": "NonexistentAsset",
+      "msg": "Non-existent Asset"
+    },
+    {
+      "code": 6023,
+      "name": "InvalidLpMint",
+      "msg": "Invalid LP Mint"
+    },
+    {
+      "code": 6024,
+      "name": "DepositExceedsUserCap",
+      "msg": "Deposit exceeds user cap"
+    },
+    {
+      "code": 6025,
+      "name": "AssetCapExceeded",
+      "msg": "Asset cap exceeded"
+    },
+    {
+      "code": 6026,
+      "name": "CannotWithdrawWithoutLpTokens",
+      "msg": "Cannot redeem without LP tokens"
+    },
+    {
+      "code": 6027,
+      "name": "DataTypeMismatch",
+      "msg": "Data type mismatch"
+    },
+    {
+      "code": 6028,
+      "name": "SlippageTooHigh",
+      "msg": "Slippage too high"
+    },
+    {
+      "code": 6029,
+      "name": "DualSidedExcesssNotPossible",
+      "msg": "Dual-sided excesss is not possible"
+    },
+    {
+      "code": 6030,
+      "name": "DerivedKeyInvalid",
+      "msg": "Derived key invalid"
+    },
+    {
+      "code": 6031,
+      "name": "InvalidRemainingAccountsIndex",
+      "msg": "Invalid remaining accounts index"
+    },
+    {
+      "code": 6032,
+      "name": "MissingRequiredField",
+      "msg": "Missing required field"
+    },
+    {
+      "code": 6033,
+      "name": "MissingRequiredConfig",
+      "msg": "Missing required config"
+    },
+    {
+      "code": 6034,
+      "name": "UnexpectedAuthority",
+      "msg": "Unexpected authority"
+    },
+    {
+      "code": 6035,
+      "name": "DecimalMismatch",
+      "msg": "Decimal mismatch"
+    },
+    {
+      "code": 6036,
+      "name": "AlreadyClaimedLpTokens",
+      "msg": "Already claimed LP tokens"
+    },
+    {
+      "code": 6037,
+      "name": "UnableToWriteToRemainingAccount",
+      "msg": "Unable to write to remaining account"
+    },
+    {
+      "code": 6038,
+      "name": "ExpectedNonzeroReturns",
+      "msg": "Expected non-zero returns"
+    },
+    {
+      "code": 6039,
+      "name": "InvalidRolloverVault",
+      "msg": "Invalid rollover vault"
+    },
+    {
+      "code": 6040,
+      "name": "RolloverAlreadyProcessed",
+      "msg": "Rollover already processed"
+    },
+    {
+      "code": 6041,
+      "name": "DepositorNotAllowlisted",
+      "msg": "Depositor not allowlisted"
+    },
+    {
+      "code": 6042,
+      "name": "InvalidMerkleProof",
+      "msg": "Invalid merkle proof"
+    },
+    {
+      "code": 6043,
+      "name": "DepositBelowMinimum",
+      "msg": "Deposit below minimum"
+    },
+    {
+      "code": 6044,
+      "name": "DepositExceedsMaxDepositsPerUser",
+      "msg": "Deposit exceeds max deposits per user"
+    },
+    {
+      "code": 6045,
+      "name": "InvalidSlippageBps",
+      "msg": "Invalid slippage bps"
+    },
+    {
+      "code": 6046,
+      "name": "InvalidOracleAccount",
+      "msg": "Invalid oracle account"
+    },
+    {
+      "code": 6047,
+      "name": "StaleOraclePrice",
+      "msg": "Stale oracle price"
+    },
+    {
+      "code": 6048,
+      "name": "OraclePriceDeviationExceeded",
+      "msg": "Pool price deviates from oracle price"
+    },
+    {
+      "code": 6049,
+      "name": "InvalidTrancheConfig",
+      "msg": "Invalid tranche config"
+    },
+    {
+      "code": 6050,
+      "name": "InvalidLeverageConfig",
+      "msg": "Invalid leverage config"
+    },
+    {
+      "code": 6051,
+      "name": "LeverageRatioOutOfBounds",
+      "msg": "Senior-to-junior ratio outside of the vault's leverage band"
+    },
+    {
+      "code": 6052,
+      "name": "InvalidTrancheMetadata",
+      "msg": "Invalid tranche metadata"
+    },
+    {
+      "code": 6053,
+      "name": "TrancheLpFrozen",
+      "msg": "Tranche tokens are frozen"
+    },
+    {
+      "code": 6054,
+      "name": "InvalidEarlyExitPenalty",
+      "msg": "Invalid early exit penalty"
+    },
+    {
+      "code": 6055,
+      "name": "EarlyExitDisabled",
+      "msg": "Early exits are disabled for this vault"
+    },
+    {
+      "code": 6056,
+      "name": "UnauthorizedStateTransition",
+      "msg": "Signer may not trigger this state transition"
+    },
+    {
+      "code": 6057,
+      "name": "VaultCancelled",
+      "msg": "Vault has been cancelled"
+    },
+    {
+      "code": 6058,
+      "name": "VaultNotCancellable",
+      "msg": "Vault can only be cancelled before its deposits are invested"
+    },
+    {
+      "code": 6059,
+      "name": "InvalidStrategistRoles",
+      "msg": "Invalid strategist roles"
+    },
+    {
+      "code": 6060,
+      "name": "MissingStrategistRole",
+      "msg": "Signer does not hold the strategist role for this operation"
+    },
+    {
+      "code": 6061,
+      "name": "RolloverVaultAcceptingDeposits",
+      "msg": "Rollover vault can still accept the position"
+    },
+    {
+      "code": 6062,
+      "name": "OracleRequired",
+      "msg": "Vault must have an oracle configured"
+    }
+  ]
+};
+
+export const IDL: Vault = {
+  "version": "0.1.0",
+  "name": "vault",
+  "instructions": [
+    {
+      "name": "initializeGlobalProtocolState",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalProtocolState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "initializeVault",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "gpsAuthority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalProtocolState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vaultStore",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "vaultBump",
+          "type": "u8"
+        },
+        {
+          "name": "vaultStoreBump",
+          "type": "u8"
+        },
+        {
+          "name": "vaultConfig",
+          "type": {
+            "defined": "VaultConfig"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transitionVault",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "globalProtocolState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "targetState",
+          "type": {
+            "defined": "State"
+          }
+        },
+        {
+          "name": "timestamp",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "cancelVault",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalProtocolState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "deposit",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "globalProtocolState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vaultStore",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "receipt",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "history",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sourceAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destinationAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "trancheId",
+          "type": "u8"
+        },
+        {
+          "name": "depositIndex",
+          "type": "u64"
+        },
+        {
+          "name": "receiptBump",
+          "type": "u8"
+        },
+        {
+          "name": "historyBump",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "proof",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           }
-        ]
-      }
-    }
-  ],
-  "types": [
+        }
+      ]
+    },
+    {
+      "name": "addToAllowlist",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalProtocolState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "user",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "allowlist",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "allowlistBump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "removeFromAllowlist",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalProtocolState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "user",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "allowlist",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "processClaims",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "globalProtocolState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "trancheId",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "claim",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "globalProtocolState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vaultStore",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "history",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lp",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sourceAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destinationAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destinationLpAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "trancheId",
+          "type": "u8"
+        }
+      ]
+    },
     {
-      "name": "SwapConfig",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "maxIn",
-            "type": "u64"
-          },
-          {
-            "name": "minOut",
-            "type": "u64"
-          },
-          {
-            "name": "alphaToBeta",
-            "type": "bool"
-          }
-        ]
-      }
+      "name": "withdraw",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "globalProtocolState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vaultStore",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lp",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sourceLp",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sourceAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destinationAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "name": "Asset",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "mint",
-            "type": "publicKey"
-          },
-          {
-            "name": "lp",
-            "type": "publicKey"
-          },
-          {
-            "name": "assetCap",
-            "type": {
-              "option": "u64"
-            }
-          },
-          {
-            "name": "userCap",
-            "type": {
-              "option": "u64"
-            }
-          },
-          {
-            "name": "deposits",
-            "type": "u64"
-          },
-          {
-            "name": "deposited",
-            "type": "u64"
-          },
-          {
-            "name": "invested",
-            "type": "u64"
-          },
-          {
-            "name": "excess",
-            "type": "u64"
-          },
-          {
-            "name": "received",
-            "type": "u64"
-          },
-          {
-            "name": "totalInvested",
-            "type": "u64"
-          },
-          {
-            "name": "rolloverDeposited",
-            "type": "u64"
-          },
-          {
-            "name": "claimsProcessed",
-            "type": "bool"
-          },
-          {
-            "name": "claimsIdx",
-            "type": {
-              "option": "u64"
-            }
-          }
-        ]
-      }
+      "name": "claimAndWithdraw",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "globalProtocolState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vaultStore",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "history",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lp",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sourceAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destinationAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "trancheId",
+          "type": "u8"
+        }
+      ]
     },
     {
-      "name": "AssetConfig",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "userCap",
-            "type": {
-              "option": "u64"
-            }
-          },
-          {
-            "name": "assetCap",
-            "type": {
-              "option": "u64"
-            }
+      "name": "setKeeper",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalProtocolState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "keeper",
+          "type": {
+            "option": "publicKey"
           }
-        ]
-      }
+        },
+        {
+          "name": "roles",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "name": "VaultConfig",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "strategy",
-            "type": "publicKey"
-          },
-          {
-            "name": "authority",
-            "type": "publicKey"
-          },
-          {
-            "name": "strategist",
-            "type": "publicKey"
-          },
-          {
-            "name": "alpha",
-            "type": {
-              "defined": "AssetConfig"
-            }
-          },
-          {
-            "name": "beta",
-            "type": {
-              "defined": "AssetConfig"
-            }
-          },
-          {
-            "name": "fixedRate",
-            "type": "u16"
-          },
-          {
-            "name": "startAt",
-            "type": "u64"
-          },
-          {
-            "name": "depositDuration",
-            "type": "u64"
-          },
-          {
-            "name": "investDuration",
-            "type": "u64"
-          }
-        ]
-      }
+      "name": "setRolloverVault",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalProtocolState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rolloverVault",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "name": "ErrorCode",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "ProtocolPaused"
-          },
-          {
-            "name": "MathError"
-          },
-          {
-            "name": "PublicKeyMismatch"
-          },
-          {
-            "name": "BumpMismatch"
-          },
-          {
-            "name": "InvalidMintAuthority"
-          },
-          {
-            "name": "UninitializedAccount"
-          },
-          {
-            "name": "AlreadyInitializedAccount"
-          },
-          {
-            "name": "IncorrectOwner"
-          },
-          {
-            "name": "PublicKeysShouldBeUnique"
-          },
-          {
-            "name": "AccountAlreadyInitialized"
-          },
-          {
-            "name": "InsufficientTokenBalance"
-          },
-          {
-            "name": "ImpossibleTokenRatioRequested"
-          },
-          {
-            "name": "InvalidStateTransition"
-          },
-          {
-            "name": "MissingTransitionAtTimeForState"
-          },
-          {
-            "name": "VaultHasNoDeposits"
-          },
-          {
-            "name": "InvalidDepositForVault"
-          },
-          {
-            "name": "WrongAccountOwner"
-          },
-          {
-            "name": "InvalidAccountData"
-          },
-          {
-            "name": "InvalidStrategyFlag"
-          },
-          {
-            "name": "StrategyAlreadyExists"
-          },
-          {
-            "name": "InvalidVaultState"
-          },
-          {
-            "name": "InvalidVaultStore"
-          },
-          {
-            "name": "NonexistentAsset"
-          },
-          {
-            "name": "InvalidLpMint"
-          },
-          {
-            "name": "DepositExceedsUserCap"
-          },
-          {
-            "name": "AssetCapExceeded"
-          },
-          {
-            "name": "CannotWithdrawWithoutLpTokens"
-          },
-          {
-            "name": "DataTypeMismatch"
-          },
-          {
-            "name": "SlippageTooHigh"
-          },
-          {
-            "name": "DualSidedExcesssNotPossible"
-          },
-          {
-            "name": "DerivedKeyInvalid"
-          },
-          {
-            "name": "InvalidRemainingAccountsIndex"
-          },
-          {
-            "name": "MissingRequiredField"
-          },
-          {
-            "name": "MissingRequiredConfig"
-          },
-          {
-            "name": "UnexpectedAuthority"
-          },
-          {
-            "name": "DecimalMismatch"
-          },
-          {
-            "name": "AlreadyClaimedLpTokens"
-          },
-          {
-            "name": "UnableToWriteToRemainingAccount"
-          },
-          {
-            "name": "ExpectedNonzeroReturns"
-          }
-        ]
-      }
+      "name": "setTrancheLpFrozen",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalProtocolState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lp",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "frozen",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "thawTrancheLp",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "globalProtocolState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lp",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "name": "StrategyFlag",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "SaberLpStrategyV0"
-          },
-          {
-            "name": "OrcaLpStrategyV0"
-          }
-        ]
-      }
+      "name": "optIntoRollover",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "globalProtocolState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rollover",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lp",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sourceLp",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "trancheId",
+          "type": "u8"
+        },
+        {
+          "name": "rolloverBump",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "name": "State",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Inactive"
-          },
-          {
-            "name": "Deposit"
-          },
-          {
-            "name": "Live"
-          },
-          {
-            "name": "Redeem"
-          },
-          {
-            "name": "Rebalance"
-          },
-          {
-            "name": "Withdraw"
-          }
-        ]
-      }
-    }
-  ]
-};
-
-export const IDL: Vault = {
-  "version": "0.1.0",
-  "name": "vault",
-  "instructions": [
+      "name": "processRollover",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "globalProtocolState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vaultStore",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rollover",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "depositor",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nextVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nextVaultStore",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nextHistory",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lp",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sourceAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destinationAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "trancheId",
+          "type": "u8"
+        },
+        {
+          "name": "historyBump",
+          "type": "u8"
+        }
+      ]
+    },
     {
-      "name": "initializeGlobalProtocolState",
+      "name": "withdrawRollover",
       "accounts": [
         {
-          "name": "authority",
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "globalProtocolState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vaultStore",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rollover",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "globalProtocolState",
+          "name": "depositor",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nextVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "treasury",
+          "name": "lp",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "sourceAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destinationAta",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "rent",
           "isMut": false,
@@ -2182,13 +5057,13 @@ export const IDL: Vault = {
       ],
       "args": [
         {
-          "name": "bump",
+          "name": "trancheId",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "initializeVault",
+      "name": "initializeOrca",
       "accounts": [
         {
           "name": "authority",
@@ -2196,43 +5071,58 @@ export const IDL: Vault = {
           "isSigner": true
         },
         {
-          "name": "gpsAuthority",
+          "name": "globalProtocolState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "strategy",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "globalProtocolState",
+          "name": "tokenA",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "vault",
-          "isMut": true,
+          "name": "tokenB",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "vaultStore",
-          "isMut": true,
+          "name": "swapProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "alphaMint",
-          "isMut": true,
+          "name": "farmProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "alphaLp",
-          "isMut": true,
+          "name": "pool",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "betaMint",
-          "isMut": true,
+          "name": "baseLp",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "betaLp",
-          "isMut": true,
+          "name": "farm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmLp",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "doubleDipFarmLp",
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -2248,29 +5138,32 @@ export const IDL: Vault = {
       ],
       "args": [
         {
-          "name": "vaultBump",
+          "name": "bump",
           "type": "u8"
         },
         {
-          "name": "vaultStoreBump",
-          "type": "u8"
+          "name": "flag",
+          "type": "u64"
         },
         {
-          "name": "vaultConfig",
-          "type": {
-            "defined": "VaultConfig"
-          }
+          "name": "version",
+          "type": "u16"
         }
       ]
     },
     {
-      "name": "transitionVault",
+      "name": "rebalanceOrca",
       "accounts": [
         {
-          "name": "authority",
+          "name": "payer",
           "isMut": true,
           "isSigner": true
         },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "globalProtocolState",
           "isMut": false,
@@ -2280,84 +5173,119 @@ export const IDL: Vault = {
           "name": "vault",
           "isMut": true,
           "isSigner": false
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "targetState",
-          "type": "string"
+          "name": "vaultStore",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "timestamp",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "deposit",
-      "accounts": [
+          "name": "strategy",
+          "isMut": false,
+          "isSigner": false
+        },
         {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "authority",
+          "name": "orcaSwapProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "globalProtocolState",
+          "name": "orcaPool",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "vault",
+          "name": "orcaAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userSource",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "vaultStore",
+          "name": "poolSource",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "receipt",
+          "name": "poolDestination",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "history",
+          "name": "userDestination",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "mint",
+          "name": "poolMint",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "sourceAta",
+          "name": "feeAccount",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "swapConfig",
+          "type": {
+            "option": {
+              "defined": "SwapConfig"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "investOrca",
+      "accounts": [
+        {
+          "name": "payer",
           "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "destinationAta",
+          "name": "globalProtocolState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "vaultStore",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "strategy",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "ataProgram",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -2365,60 +5293,70 @@ export const IDL: Vault = {
           "name": "rent",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "depositIndex",
-          "type": "u64"
+          "name": "orcaSwapProgram",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "receiptBump",
-          "type": "u8"
+          "name": "orcaPool",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "historyBump",
-          "type": "u8"
+          "name": "orcaAuthority",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "amount",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "processClaims",
-      "accounts": [
+          "name": "sourceTokenA",
+          "isMut": true,
+          "isSigner": false
+        },
         {
-          "name": "payer",
+          "name": "sourceTokenB",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "authority",
-          "isMut": false,
+          "name": "intoA",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "globalProtocolState",
-          "isMut": false,
+          "name": "intoB",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "vault",
+          "name": "poolToken",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "mint",
-          "isMut": false,
+          "name": "poolAccount",
+          "isMut": true,
           "isSigner": false
         }
-      ],
-      "args": []
+      ],
+      "args": [
+        {
+          "name": "investableA",
+          "type": "u64"
+        },
+        {
+          "name": "investableB",
+          "type": "u64"
+        },
+        {
+          "name": "minTokensBack",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "name": "claim",
+      "name": "depositLiveOrca",
       "accounts": [
         {
           "name": "payer",
@@ -2451,22 +5389,22 @@ export const IDL: Vault = {
           "isSigner": false
         },
         {
-          "name": "mint",
+          "name": "strategy",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "lp",
-          "isMut": true,
+          "name": "mint",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "sourceAta",
+          "name": "lp",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "destinationAta",
+          "name": "sourceAta",
           "isMut": true,
           "isSigner": false
         },
@@ -2494,182 +5432,239 @@ export const IDL: Vault = {
           "name": "rent",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "withdraw",
-      "accounts": [
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
         },
         {
-          "name": "authority",
+          "name": "orcaSwapProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "globalProtocolState",
+          "name": "orcaPool",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "vault",
-          "isMut": true,
+          "name": "orcaAuthority",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "vaultStore",
+          "name": "sourceTokenA",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "mint",
+          "name": "sourceTokenB",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "lp",
+          "name": "intoA",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "sourceLp",
+          "name": "intoB",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "sourceAta",
+          "name": "poolToken",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "destinationAta",
+          "name": "poolAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "ataProgram",
+          "name": "farmAccount",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "doubleDipFarmAccount",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
+        {
+          "name": "trancheId",
+          "type": "u8"
+        },
+        {
+          "name": "historyBump",
+          "type": "u8"
+        },
         {
           "name": "amount",
           "type": "u64"
+        },
+        {
+          "name": "minTokensBack",
+          "type": "u64"
+        },
+        {
+          "name": "proof",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
         }
       ]
     },
     {
-      "name": "initializeOrca",
+      "name": "earlyExitOrca",
       "accounts": [
         {
-          "name": "authority",
+          "name": "payer",
           "isMut": true,
           "isSigner": true
         },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "globalProtocolState",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "strategy",
+          "name": "vault",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenA",
-          "isMut": false,
+          "name": "vaultStore",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenB",
+          "name": "strategy",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "swapProgram",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "farmProgram",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "pool",
+          "name": "rent",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "baseLp",
-          "isMut": false,
+          "name": "lp",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "farm",
+          "name": "sourceLp",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destinationTokenA",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destinationTokenB",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "orcaSwapProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "farmLp",
+          "name": "orcaPool",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "doubleDipFarmLp",
+          "name": "orcaAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "poolMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sourcePoolAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "fromA",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "fromB",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sourceTokenA",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sourceTokenB",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmAccount",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "doubleDipFarmAccount",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "bump",
-          "type": "u8"
+          "name": "lpAmount",
+          "type": "u64"
         },
         {
-          "name": "flag",
+          "name": "minTokenA",
           "type": "u64"
         },
         {
-          "name": "version",
-          "type": "u16"
+          "name": "minTokenB",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "rebalanceOrca",
+      "name": "redeemOrca",
       "accounts": [
         {
           "name": "payer",
@@ -2701,11 +5696,21 @@ export const IDL: Vault = {
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "orcaSwapProgram",
           "isMut": false,
@@ -2722,27 +5727,32 @@ export const IDL: Vault = {
           "isSigner": false
         },
         {
-          "name": "userSource",
+          "name": "poolMint",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "poolSource",
+          "name": "sourcePoolAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "poolDestination",
+          "name": "fromA",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userDestination",
+          "name": "fromB",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "poolMint",
+          "name": "sourceTokenA",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sourceTokenB",
           "isMut": true,
           "isSigner": false
         },
@@ -2754,17 +5764,17 @@ export const IDL: Vault = {
       ],
       "args": [
         {
-          "name": "swapConfig",
-          "type": {
-            "option": {
-              "defined": "SwapConfig"
-            }
-          }
+          "name": "minTokenA",
+          "type": "u64"
+        },
+        {
+          "name": "minTokenB",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "investOrca",
+      "name": "swapOrca",
       "accounts": [
         {
           "name": "payer",
@@ -2796,21 +5806,11 @@ export const IDL: Vault = {
           "isMut": false,
           "isSigner": false
         },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
         {
           "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        },
         {
           "name": "orcaSwapProgram",
           "isMut": false,
@@ -2827,163 +5827,171 @@ export const IDL: Vault = {
           "isSigner": false
         },
         {
-          "name": "sourceTokenA",
+          "name": "userSource",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "sourceTokenB",
+          "name": "poolSource",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "intoA",
+          "name": "poolDestination",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "intoB",
+          "name": "userDestination",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "poolToken",
+          "name": "poolMint",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "poolAccount",
+          "name": "feeAccount",
           "isMut": true,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "investableA",
-          "type": "u64"
-        },
-        {
-          "name": "investableB",
+          "name": "amountIn",
           "type": "u64"
         },
         {
-          "name": "minTokensBack",
+          "name": "minAmountOut",
           "type": "u64"
         }
       ]
     },
     {
-      "name": "redeemOrca",
+      "name": "getVaultNavOrca",
       "accounts": [
         {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "authority",
+          "name": "vault",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "globalProtocolState",
+          "name": "vaultStore",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "vault",
-          "isMut": true,
+          "name": "strategy",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "vaultStore",
-          "isMut": true,
+          "name": "orcaPool",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenA",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "strategy",
+          "name": "poolTokenB",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "poolMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "poolAccount",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "farmAccount",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "orcaSwapProgram",
+          "name": "doubleDipFarmAccount",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeUserFarmOrca",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "orcaPool",
+          "name": "authority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "orcaAuthority",
+          "name": "globalProtocolState",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "poolMint",
+          "name": "vault",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "sourcePoolAccount",
+          "name": "vaultStore",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "fromA",
-          "isMut": true,
+          "name": "strategy",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "fromB",
-          "isMut": true,
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "sourceTokenA",
-          "isMut": true,
+          "name": "rent",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "sourceTokenB",
-          "isMut": true,
+          "name": "aquafarmProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "feeAccount",
+          "name": "globalFarm",
           "isMut": true,
           "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "minTokenA",
-          "type": "u64"
         },
         {
-          "name": "minTokenB",
-          "type": "u64"
+          "name": "userFarm",
+          "isMut": true,
+          "isSigner": false
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "swapOrca",
+      "name": "convertOrcaLp",
       "accounts": [
         {
           "name": "payer",
@@ -3016,69 +6024,80 @@ export const IDL: Vault = {
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "orcaSwapProgram",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "orcaPool",
+          "name": "rent",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "orcaAuthority",
+          "name": "aquafarmProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "userSource",
+          "name": "poolAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "poolSource",
+          "name": "userBaseAta",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "poolDestination",
+          "name": "globalBaseTokenVault",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userDestination",
+          "name": "farmTokenMint",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "poolMint",
+          "name": "userFarmAta",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "feeAccount",
+          "name": "globalFarm",
           "isMut": true,
           "isSigner": false
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "amountIn",
-          "type": "u64"
+          "name": "userFarm",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "minAmountOut",
-          "type": "u64"
+          "name": "globalRewardTokenVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userRewardAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "initializeUserFarmOrca",
+      "name": "harvestOrca",
       "accounts": [
         {
           "name": "payer",
@@ -3111,12 +6130,7 @@ export const IDL: Vault = {
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "rent",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -3134,12 +6148,32 @@ export const IDL: Vault = {
           "name": "userFarm",
           "isMut": true,
           "isSigner": false
+        },
+        {
+          "name": "globalBaseTokenVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalRewardTokenVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userRewardAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": []
     },
     {
-      "name": "convertOrcaLp",
+      "name": "revertOrcaLp",
       "accounts": [
         {
           "name": "payer",
@@ -3171,18 +6205,13 @@ export const IDL: Vault = {
           "isMut": false,
           "isSigner": false
         },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
         {
           "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -3245,83 +6274,71 @@ export const IDL: Vault = {
       "args": []
     },
     {
-      "name": "harvestOrca",
+      "name": "initializeSaber",
       "accounts": [
         {
-          "name": "payer",
+          "name": "authority",
           "isMut": true,
           "isSigner": true
         },
-        {
-          "name": "authority",
-          "isMut": false,
-          "isSigner": false
-        },
         {
           "name": "globalProtocolState",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "vault",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "vaultStore",
+          "name": "strategy",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "strategy",
+          "name": "tokenA",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "tokenB",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "aquafarmProgram",
+          "name": "basePool",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "globalFarm",
-          "isMut": true,
+          "name": "poolLp",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "userFarm",
-          "isMut": true,
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "globalBaseTokenVault",
-          "isMut": true,
+          "name": "rent",
+          "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "globalRewardTokenVault",
-          "isMut": true,
-          "isSigner": false
+          "name": "bump",
+          "type": "u8"
         },
         {
-          "name": "userRewardAta",
-          "isMut": true,
-          "isSigner": false
+          "name": "flag",
+          "type": "u64"
         },
         {
-          "name": "farmAuthority",
-          "isMut": false,
-          "isSigner": false
+          "name": "version",
+          "type": "u16"
         }
-      ],
-      "args": []
+      ]
     },
     {
-      "name": "revertOrcaLp",
+      "name": "investSaber",
       "accounts": [
         {
           "name": "payer",
@@ -3344,12 +6361,12 @@ export const IDL: Vault = {
           "isSigner": false
         },
         {
-          "name": "vaultStore",
-          "isMut": true,
+          "name": "strategy",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "strategy",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -3359,134 +6376,237 @@ export const IDL: Vault = {
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "rent",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "aquafarmProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "saberSwapCommon",
+          "accounts": [
+            {
+              "name": "swap",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "swapAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "sourceTokenA",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "reserveA",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sourceTokenB",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "reserveB",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "poolMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "saberProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
         },
         {
-          "name": "poolAccount",
+          "name": "outputLp",
           "isMut": true,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "investableA",
+          "type": "u64"
         },
         {
-          "name": "userBaseAta",
-          "isMut": true,
-          "isSigner": false
+          "name": "investableB",
+          "type": "u64"
         },
         {
-          "name": "globalBaseTokenVault",
+          "name": "minTokensBack",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "depositLiveSaber",
+      "accounts": [
+        {
+          "name": "payer",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "farmTokenMint",
-          "isMut": true,
+          "name": "authority",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "userFarmAta",
-          "isMut": true,
+          "name": "globalProtocolState",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "globalFarm",
+          "name": "vault",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userFarm",
-          "isMut": true,
+          "name": "vaultStore",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "globalRewardTokenVault",
+          "name": "history",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userRewardAta",
-          "isMut": true,
+          "name": "strategy",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "farmAuthority",
+          "name": "mint",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "initializeSaber",
-      "accounts": [
+        },
         {
-          "name": "authority",
+          "name": "lp",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "globalProtocolState",
-          "isMut": false,
+          "name": "sourceAta",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "strategy",
+          "name": "destinationLpAta",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenA",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenB",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "basePool",
+          "name": "ataProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "poolLp",
+          "name": "rent",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "saberSwapCommon",
+          "accounts": [
+            {
+              "name": "swap",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "swapAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "sourceTokenA",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "reserveA",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sourceTokenB",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "reserveB",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "poolMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "saberProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
         },
         {
-          "name": "rent",
-          "isMut": false,
+          "name": "outputLp",
+          "isMut": true,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "bump",
+          "name": "trancheId",
           "type": "u8"
         },
         {
-          "name": "flag",
+          "name": "historyBump",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
           "type": "u64"
         },
         {
-          "name": "version",
-          "type": "u16"
+          "name": "minTokensBack",
+          "type": "u64"
+        },
+        {
+          "name": "proof",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
         }
       ]
     },
     {
-      "name": "investSaber",
+      "name": "earlyExitSaber",
       "accounts": [
         {
           "name": "payer",
@@ -3508,6 +6628,11 @@ export const IDL: Vault = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "vaultStore",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "strategy",
           "isMut": false,
@@ -3528,6 +6653,26 @@ export const IDL: Vault = {
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "lp",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sourceLp",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destinationTokenA",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destinationTokenB",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "saberSwapCommon",
           "accounts": [
@@ -3574,22 +6719,32 @@ export const IDL: Vault = {
           ]
         },
         {
-          "name": "outputLp",
+          "name": "inputLp",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outputAFees",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outputBFees",
           "isMut": true,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "investableA",
+          "name": "lpAmount",
           "type": "u64"
         },
         {
-          "name": "investableB",
+          "name": "minTokenA",
           "type": "u64"
         },
         {
-          "name": "minTokensBack",
+          "name": "minTokenB",
           "type": "u64"
         }
       ]
@@ -3708,11 +6863,101 @@ export const IDL: Vault = {
           "type": "u64"
         }
       ]
+    },
+    {
+      "name": "getVaultNavSaber",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "strategy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swap",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "reserveA",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "reserveB",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "outputLp",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
     }
   ],
   "accounts": [
     {
-      "name": "orcaStrategyDataV0",
+      "name": "allowlist",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "vault",
+            "type": "publicKey"
+          },
+          {
+            "name": "user",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "globalProtocolState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "active",
+            "type": "bool"
+          },
+          {
+            "name": "treasury",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "history",
       "type": {
         "kind": "struct",
         "fields": [
@@ -3721,48 +6966,42 @@ export const IDL: Vault = {
             "type": "u8"
           },
           {
-            "name": "flag",
-            "type": "u64"
-          },
-          {
-            "name": "version",
-            "type": "u16"
+            "name": "intialized",
+            "type": "bool"
           },
           {
-            "name": "swapProgram",
-            "type": "publicKey"
+            "name": "deposits",
+            "type": "u64"
           },
           {
-            "name": "farmProgram",
-            "type": "publicKey"
+            "name": "cumulative",
+            "type": "u64"
           },
           {
-            "name": "tokenA",
-            "type": "publicKey"
+            "name": "claim",
+            "type": "u64"
           },
           {
-            "name": "tokenB",
-            "type": "publicKey"
+            "name": "canClaimTrancheLp",
+            "type": "bool"
           },
           {
-            "name": "baseLp",
-            "type": "publicKey"
+            "name": "rollover",
+            "type": "u64"
           },
           {
-            "name": "farmLp",
-            "type": "publicKey"
+            "name": "liveDeposits",
+            "type": "u64"
           },
           {
-            "name": "doubleDipLp",
-            "type": {
-              "option": "publicKey"
-            }
+            "name": "liveCumulative",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "saberStrategyDataV0",
+      "name": "orcaStrategyDataV0",
       "type": {
         "kind": "struct",
         "fields": [
@@ -3778,12 +7017,32 @@ export const IDL: Vault = {
             "name": "version",
             "type": "u16"
           },
+          {
+            "name": "swapProgram",
+            "type": "publicKey"
+          },
+          {
+            "name": "farmProgram",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenA",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenB",
+            "type": "publicKey"
+          },
           {
             "name": "baseLp",
             "type": "publicKey"
           },
           {
             "name": "farmLp",
+            "type": "publicKey"
+          },
+          {
+            "name": "doubleDipLp",
             "type": {
               "option": "publicKey"
             }
@@ -3792,7 +7051,7 @@ export const IDL: Vault = {
       }
     },
     {
-      "name": "globalProtocolState",
+      "name": "receipt",
       "type": {
         "kind": "struct",
         "fields": [
@@ -3801,22 +7060,22 @@ export const IDL: Vault = {
             "type": "u8"
           },
           {
-            "name": "authority",
-            "type": "publicKey"
+            "name": "amount",
+            "type": "u64"
           },
           {
-            "name": "active",
-            "type": "bool"
+            "name": "cumulative",
+            "type": "u64"
           },
           {
-            "name": "treasury",
+            "name": "depositor",
             "type": "publicKey"
           }
         ]
       }
     },
     {
-      "name": "history",
+      "name": "rollover",
       "type": {
         "kind": "struct",
         "fields": [
@@ -3825,30 +7084,26 @@ export const IDL: Vault = {
             "type": "u8"
           },
           {
-            "name": "intialized",
+            "name": "initialized",
             "type": "bool"
           },
           {
-            "name": "deposits",
-            "type": "u64"
-          },
-          {
-            "name": "cumulative",
-            "type": "u64"
+            "name": "depositor",
+            "type": "publicKey"
           },
           {
-            "name": "claim",
+            "name": "lpAmount",
             "type": "u64"
           },
           {
-            "name": "canClaimTrancheLp",
+            "name": "processed",
             "type": "bool"
           }
         ]
       }
     },
     {
-      "name": "receipt",
+      "name": "saberStrategyDataV0",
       "type": {
         "kind": "struct",
         "fields": [
@@ -3857,16 +7112,22 @@ export const IDL: Vault = {
             "type": "u8"
           },
           {
-            "name": "amount",
+            "name": "flag",
             "type": "u64"
           },
           {
-            "name": "cumulative",
-            "type": "u64"
+            "name": "version",
+            "type": "u16"
           },
           {
-            "name": "depositor",
+            "name": "baseLp",
             "type": "publicKey"
+          },
+          {
+            "name": "farmLp",
+            "type": {
+              "option": "publicKey"
+            }
           }
         ]
       }
@@ -3900,16 +7161,19 @@ export const IDL: Vault = {
             "type": "u8"
           },
           {
-            "name": "alpha",
+            "name": "tranches",
             "type": {
-              "defined": "Asset"
+              "array": [
+                {
+                  "defined": "Asset"
+                },
+                3
+              ]
             }
           },
           {
-            "name": "beta",
-            "type": {
-              "defined": "Asset"
-            }
+            "name": "numTranches",
+            "type": "u8"
           },
           {
             "name": "strategy",
@@ -3919,10 +7183,6 @@ export const IDL: Vault = {
             "name": "strategist",
             "type": "publicKey"
           },
-          {
-            "name": "fixedRate",
-            "type": "u16"
-          },
           {
             "name": "state",
             "type": {
@@ -3968,6 +7228,64 @@ export const IDL: Vault = {
           {
             "name": "reblanced",
             "type": "bool"
+          },
+          {
+            "name": "rolloverVault",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "depositGate",
+            "type": {
+              "defined": "DepositGate"
+            }
+          },
+          {
+            "name": "maxSlippageBps",
+            "type": "u16"
+          },
+          {
+            "name": "oracle",
+            "type": {
+              "option": {
+                "defined": "OracleConfig"
+              }
+            }
+          },
+          {
+            "name": "leverage",
+            "type": {
+              "option": {
+                "defined": "LeverageConfig"
+              }
+            }
+          },
+          {
+            "name": "trancheLpFrozen",
+            "type": "bool"
+          },
+          {
+            "name": "earlyExitPenaltyBps",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "cancelledAt",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "keeper",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "keeperRoles",
+            "type": "u64"
           }
         ]
       }
@@ -3975,30 +7293,26 @@ export const IDL: Vault = {
   ],
   "types": [
     {
-      "name": "SwapConfig",
+      "name": "Asset",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "maxIn",
-            "type": "u64"
+            "name": "trancheId",
+            "type": "u8"
           },
           {
-            "name": "minOut",
-            "type": "u64"
+            "name": "seniority",
+            "type": "u8"
           },
           {
-            "name": "alphaToBeta",
-            "type": "bool"
-          }
-        ]
-      }
-    },
-    {
-      "name": "Asset",
-      "type": {
-        "kind": "struct",
-        "fields": [
+            "name": "returnTerms",
+            "type": {
+              "option": {
+                "defined": "ReturnTerms"
+              }
+            }
+          },
           {
             "name": "mint",
             "type": "publicKey"
@@ -4007,6 +7321,10 @@ export const IDL: Vault = {
             "name": "lp",
             "type": "publicKey"
           },
+          {
+            "name": "decimals",
+            "type": "u8"
+          },
           {
             "name": "assetCap",
             "type": {
@@ -4019,6 +7337,18 @@ export const IDL: Vault = {
               "option": "u64"
             }
           },
+          {
+            "name": "minDeposit",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "maxDepositsPerUser",
+            "type": {
+              "option": "u64"
+            }
+          },
           {
             "name": "deposits",
             "type": "u64"
@@ -4047,6 +7377,10 @@ export const IDL: Vault = {
             "name": "rolloverDeposited",
             "type": "u64"
           },
+          {
+            "name": "exitCredit",
+            "type": "u64"
+          },
           {
             "name": "claimsProcessed",
             "type": "bool"
@@ -4060,11 +7394,79 @@ export const IDL: Vault = {
         ]
       }
     },
+    {
+      "name": "TrancheNav",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "value",
+            "type": "u64"
+          },
+          {
+            "name": "lpPrice",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "VaultNav",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tranches",
+            "type": {
+              "vec": {
+                "defined": "TrancheNav"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxIn",
+            "type": "u64"
+          },
+          {
+            "name": "minOut",
+            "type": "u64"
+          },
+          {
+            "name": "alphaToBeta",
+            "type": "bool"
+          }
+        ]
+      }
+    },
     {
       "name": "AssetConfig",
       "type": {
         "kind": "struct",
         "fields": [
+          {
+            "name": "seniority",
+            "type": "u8"
+          },
+          {
+            "name": "symbol",
+            "type": "string"
+          },
+          {
+            "name": "returnTerms",
+            "type": {
+              "option": {
+                "defined": "ReturnTerms"
+              }
+            }
+          },
           {
             "name": "userCap",
             "type": {
@@ -4076,6 +7478,18 @@ export const IDL: Vault = {
             "type": {
               "option": "u64"
             }
+          },
+          {
+            "name": "minDeposit",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "maxDepositsPerUser",
+            "type": {
+              "option": "u64"
+            }
           }
         ]
       }
@@ -4098,21 +7512,13 @@ export const IDL: Vault = {
             "type": "publicKey"
           },
           {
-            "name": "alpha",
+            "name": "tranches",
             "type": {
-              "defined": "AssetConfig"
+              "vec": {
+                "defined": "AssetConfig"
+              }
             }
           },
-          {
-            "name": "beta",
-            "type": {
-              "defined": "AssetConfig"
-            }
-          },
-          {
-            "name": "fixedRate",
-            "type": "u16"
-          },
           {
             "name": "startAt",
             "type": "u64"
@@ -4124,145 +7530,202 @@ export const IDL: Vault = {
           {
             "name": "investDuration",
             "type": "u64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "ErrorCode",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "ProtocolPaused"
-          },
-          {
-            "name": "MathError"
-          },
-          {
-            "name": "PublicKeyMismatch"
-          },
-          {
-            "name": "BumpMismatch"
-          },
-          {
-            "name": "InvalidMintAuthority"
-          },
-          {
-            "name": "UninitializedAccount"
-          },
-          {
-            "name": "AlreadyInitializedAccount"
-          },
-          {
-            "name": "IncorrectOwner"
-          },
-          {
-            "name": "PublicKeysShouldBeUnique"
           },
           {
-            "name": "AccountAlreadyInitialized"
-          },
-          {
-            "name": "InsufficientTokenBalance"
+            "name": "depositGate",
+            "type": {
+              "option": {
+                "defined": "DepositGate"
+              }
+            }
           },
           {
-            "name": "ImpossibleTokenRatioRequested"
+            "name": "maxSlippageBps",
+            "type": "u16"
           },
           {
-            "name": "InvalidStateTransition"
+            "name": "oracle",
+            "type": {
+              "option": {
+                "defined": "OracleConfig"
+              }
+            }
           },
           {
-            "name": "MissingTransitionAtTimeForState"
+            "name": "leverage",
+            "type": {
+              "option": {
+                "defined": "LeverageConfig"
+              }
+            }
           },
           {
-            "name": "VaultHasNoDeposits"
+            "name": "metadataUri",
+            "type": "string"
           },
           {
-            "name": "InvalidDepositForVault"
-          },
+            "name": "earlyExitPenaltyBps",
+            "type": {
+              "option": "u16"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReturnTerms",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "WrongAccountOwner"
+            "name": "floorBps",
+            "type": "u16"
           },
           {
-            "name": "InvalidAccountData"
+            "name": "capBps",
+            "type": "u16"
           },
           {
-            "name": "InvalidStrategyFlag"
+            "name": "yieldShareBps",
+            "type": "u16"
           },
           {
-            "name": "StrategyAlreadyExists"
-          },
+            "name": "firstLossBufferBps",
+            "type": {
+              "option": "u16"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "LeverageConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "InvalidVaultState"
+            "name": "targetRatioBps",
+            "type": "u32"
           },
           {
-            "name": "InvalidVaultStore"
+            "name": "minRatioBps",
+            "type": "u32"
           },
           {
-            "name": "NonexistentAsset"
-          },
+            "name": "maxRatioBps",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OracleConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "InvalidLpMint"
+            "name": "source",
+            "type": {
+              "defined": "OracleSource"
+            }
           },
           {
-            "name": "DepositExceedsUserCap"
+            "name": "alphaOracle",
+            "type": "publicKey"
           },
           {
-            "name": "AssetCapExceeded"
+            "name": "betaOracle",
+            "type": "publicKey"
           },
           {
-            "name": "CannotWithdrawWithoutLpTokens"
+            "name": "maxDeviationBps",
+            "type": "u16"
           },
           {
-            "name": "DataTypeMismatch"
-          },
+            "name": "maxStalenessSlots",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "StrategistRole",
+      "type": {
+        "kind": "enum",
+        "variants": [
           {
-            "name": "SlippageTooHigh"
+            "name": "Invest"
           },
           {
-            "name": "DualSidedExcesssNotPossible"
+            "name": "Redeem"
           },
           {
-            "name": "DerivedKeyInvalid"
+            "name": "Rebalance"
           },
           {
-            "name": "InvalidRemainingAccountsIndex"
+            "name": "Swap"
           },
           {
-            "name": "MissingRequiredField"
+            "name": "Convert"
           },
           {
-            "name": "MissingRequiredConfig"
+            "name": "Harvest"
           },
           {
-            "name": "UnexpectedAuthority"
-          },
+            "name": "Revert"
+          }
+        ]
+      }
+    },
+    {
+      "name": "StrategyFlag",
+      "type": {
+        "kind": "enum",
+        "variants": [
           {
-            "name": "DecimalMismatch"
+            "name": "SaberLpStrategyV0"
           },
           {
-            "name": "AlreadyClaimedLpTokens"
-          },
+            "name": "OrcaLpStrategyV0"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OracleSource",
+      "type": {
+        "kind": "enum",
+        "variants": [
           {
-            "name": "UnableToWriteToRemainingAccount"
+            "name": "Pyth"
           },
           {
-            "name": "ExpectedNonzeroReturns"
+            "name": "Switchboard"
           }
         ]
       }
     },
     {
-      "name": "StrategyFlag",
+      "name": "DepositGate",
       "type": {
         "kind": "enum",
         "variants": [
           {
-            "name": "SaberLpStrategyV0"
+            "name": "Open"
           },
           {
-            "name": "OrcaLpStrategyV0"
+            "name": "Allowlist"
+          },
+          {
+            "name": "MerkleRoot",
+            "fields": [
+              {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            ]
           }
         ]
       }
@@ -4292,6 +7755,337 @@ export const IDL: Vault = {
           }
         ]
       }
+    },
+    {
+      "name": "TransitionRole",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Strategist"
+          },
+          {
+            "name": "AnyoneAfterDeadline"
+          }
+        ]
+      }
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "ProtocolPaused",
+      "msg": "Protocol pausesd"
+    },
+    {
+      "code": 6001,
+      "name": "MathError",
+      "msg": "Math Error"
+    },
+    {
+      "code": 6002,
+      "name": "PublicKeyMismatch",
+      "msg": "PublicKeyMismatch"
+    },
+    {
+      "code": 6003,
+      "name": "BumpMismatch",
+      "msg": "BumpMismatch"
+    },
+    {
+      "code": 6004,
+      "name": "InvalidMintAuthority",
+      "msg": "InvalidMintAuthority"
+    },
+    {
+      "code": 6005,
+      "name": "UninitializedAccount",
+      "msg": "UninitializedAccount"
+    },
+    {
+      "code": 6006,
+      "name": "AlreadyInitializedAccount",
+      "msg": "AlreadyInitializedAccount"
+    },
+    {
+      "code": 6007,
+      "name": "IncorrectOwner",
+      "msg": "IncorrectOwner"
+    },
+    {
+      "code": 6008,
+      "name": "PublicKeysShouldBeUnique",
+      "msg": "PublicKeysShouldBeUnique"
+    },
+    {
+      "code": 6009,
+      "name": "AccountAlreadyInitialized",
+      "msg": "AccountAlreadyInitialized"
+    },
+    {
+      "code": 6010,
+      "name": "InsufficientTokenBalance",
+      "msg": "Insufficient token balance"
+    },
+    {
+      "code": 6011,
+      "name": "ImpossibleTokenRatioRequested",
+      "msg": "Impossible token ratio request"
+    },
+    {
+      "code": 6012,
+      "name": "InvalidStateTransition",
+      "msg": "Invalid state transition"
+    },
+    {
+      "code": 6013,
+      "name": "MissingTransitionAtTimeForState",
+      "msg": "Missing transition at time for state"
+    },
+    {
+      "code": 6014,
+      "name": "VaultHasNoDeposits",
+      "msg": "Vault has no deposits"
+    },
+    {
+      "code": 6015,
+      "name": "InvalidDepositForVault",
+      "msg": "Invalid deposit for vault"
+    },
+    {
+      "code": 6016,
+      "name": "WrongAccountOwner",
+      "msg": "Wrong account owner"
+    },
+    {
+      "code": 6017,
+      "name": "InvalidAccountData",
+      "msg": "Invalid account data"
+    },
+    {
+      "code": 6018,
+      "name": "InvalidStrategyFlag",
+      "msg": "Invalid strategy flag"
+    },
+    {
+      "code": 6019,
+      "name": "StrategyAlreadyExists",
+      "msg": "Strategy already exists"
+    },
+    {
+      "code": 6020,
+      "name": "InvalidVaultState",
+      "msg": "Invalid vault state"
+    },
+    {
+      "code": 6021,
+      "name": "InvalidVaultStore",
+      "msg": "Invalid vault store"
+    },
+    {
+      "code": 6022,
+      "name": "NonexistentAsset",
+      "msg": "Non-existent Asset"
+    },
+    {
+      "code": 6023,
+      "name": "InvalidLpMint",
+      "msg": "Invalid LP Mint"
+    },
+    {
+      "code": 6024,
+      "name": "DepositExceedsUserCap",
+      "msg": "Deposit exceeds user cap"
+    },
+    {
+      "code": 6025,
+      "name": "AssetCapExceeded",
+      "msg": "Asset cap exceeded"
+    },
+    {
+      "code": 6026,
+      "name": "CannotWithdrawWithoutLpTokens",
+      "msg": "Cannot redeem without LP tokens"
+    },
+    {
+      "code": 6027,
+      "name": "DataTypeMismatch",
+      "msg": "Data type mismatch"
+    },
+    {
+      "code": 6028,
+      "name": "SlippageTooHigh",
+      "msg": "Slippage too high"
+    },
+    {
+      "code": 6029,
+      "name": "DualSidedExcesssNotPossible",
+      "msg": "Dual-sided excesss is not possible"
+    },
+    {
+      "code": 6030,
+      "name": "DerivedKeyInvalid",
+      "msg": "Derived key invalid"
+    },
+    {
+      "code": 6031,
+      "name": "InvalidRemainingAccountsIndex",
+      "msg": "Invalid remaining accounts index"
+    },
+    {
+      "code": 6032,
+      "name": "MissingRequiredField",
+      "msg": "Missing required field"
+    },
+    {
+      "code": 6033,
+      "name": "MissingRequiredConfig",
+      "msg": "Missing required config"
+    },
+    {
+      "code": 6034,
+      "name": "UnexpectedAuthority",
+      "msg": "Unexpected authority"
+    },
+    {
+      "code": 6035,
+      "name": "DecimalMismatch",
+      "msg": "Decimal mismatch"
+    },
+    {
+      "code": 6036,
+      "name": "AlreadyClaimedLpTokens",
+      "msg": "Already claimed LP tokens"
+    },
+    {
+      "code": 6037,
+      "name": "UnableToWriteToRemainingAccount",
+      "msg": "Unable to write to remaining account"
+    },
+    {
+      "code": 6038,
+      "name": "ExpectedNonzeroReturns",
+      "msg": "Expected non-zero returns"
+    },
+    {
+      "code": 6039,
+      "name": "InvalidRolloverVault",
+      "msg": "Invalid rollover vault"
+    },
+    {
+      "code": 6040,
+      "name": "RolloverAlreadyProcessed",
+      "msg": "Rollover already processed"
+    },
+    {
+      "code": 6041,
+      "name": "DepositorNotAllowlisted",
+      "msg": "Depositor not allowlisted"
+    },
+    {
+      "code": 6042,
+      "name": "InvalidMerkleProof",
+      "msg": "Invalid merkle proof"
+    },
+    {
+      "code": 6043,
+      "name": "DepositBelowMinimum",
+      "msg": "Deposit below minimum"
+    },
+    {
+      "code": 6044,
+      "name": "DepositExceedsMaxDepositsPerUser",
+      "msg": "Deposit exceeds max deposits per user"
+    },
+    {
+      "code": 6045,
+      "name": "InvalidSlippageBps",
+      "msg": "Invalid slippage bps"
+    },
+    {
+      "code": 6046,
+      "name": "InvalidOracleAccount",
+      "msg": "Invalid oracle account"
+    },
+    {
+      "code": 6047,
+      "name": "StaleOraclePrice",
+      "msg": "Stale oracle price"
+    },
+    {
+      "code": 6048,
+      "name": "OraclePriceDeviationExceeded",
+      "msg": "Pool price deviates from oracle price"
+    },
+    {
+      "code": 6049,
+      "name": "InvalidTrancheConfig",
+      "msg": "Invalid tranche config"
+    },
+    {
+      "code": 6050,
+      "name": "InvalidLeverageConfig",
+      "msg": "Invalid leverage config"
+    },
+    {
+      "code": 6051,
+      "name": "LeverageRatioOutOfBounds",
+      "msg": "Senior-to-junior ratio outside of the vault's leverage band"
+    },
+    {
+      "code": 6052,
+      "name": "InvalidTrancheMetadata",
+      "msg": "Invalid tranche metadata"
+    },
+    {
+      "code": 6053,
+      "name": "TrancheLpFrozen",
+      "msg": "Tranche tokens are frozen"
+    },
+    {
+      "code": 6054,
+      "name": "InvalidEarlyExitPenalty",
+      "msg": "Invalid early exit penalty"
+    },
+    {
+      "code": 6055,
+      "name": "EarlyExitDisabled",
+      "msg": "Early exits are disabled for this vault"
+    },
+    {
+      "code": 6056,
+      "name": "UnauthorizedStateTransition",
+      "msg": "Signer may not trigger this state transition"
+    },
+    {
+      "code": 6057,
+      "name": "VaultCancelled",
+      "msg": "Vault has been cancelled"
+    },
+    {
+      "code": 6058,
+      "name": "VaultNotCancellable",
+      "msg": "Vault can only be cancelled before its deposits are invested"
+    },
+    {
+      "code": 6059,
+      "name": "InvalidStrategistRoles",
+      "msg": "Invalid strategist roles"
+    },
+    {
+      "code": 6060,
+      "name": "MissingStrategistRole",
+      "msg": "Signer does not hold the strategist role for this operation"
+    },
+    {
+      "code": 6061,
+      "name": "RolloverVaultAcceptingDeposits",
+      "msg": "Rollover vault can still accept the position"
+    },
+    {
+      "code": 6062,
+      "name": "OracleRequired",
+      "msg": "Vault must have an oracle configured"
     }
   ]
 };
@@ -38,6 +38,7 @@ import {
   ZERO_U64,
   SOL_KEY_STR,
   ZERO_ORCA_U64,
+  TOKEN_METADATA_PROGRAM_ID,
 } from "./common/constant";
 import { PriceClient } from "./common/price";
 import {
@@ -52,12 +53,16 @@ import {
   Strategy,
   StrategyType,
   CompositeATAResult,
+  State,
 } from "./common/types";
 import {
   getSignersFromPayer,
   flattenValidInstructions,
   toIVault,
   getTimestamp,
+  alphaTranche,
+  betaTranche,
+  seniorReturnBps,
 } from "./common/util";
 import { Vault } from "./types/vault";
 import { getOrCreateATA } from "./common";
@@ -78,18 +83,14 @@ import { OrcaFarmParams } from "@orca-so/sdk/dist/model/orca/farm/farm-types";
 import { OrcaPoolParams } from "@orca-so/sdk/dist/model/orca/pool/pool-types";
 import { Orca } from "@orca-so/sdk/dist/public/main/types";
 
-export const INACTIVE_STATE = "inactive";
-export const DEPOSIT_STATE = "deposit";
-export const LIVE_STATE = "live";
-export const REDEEM_STATE = "redeem";
-export const WITHDRAW_STATE = "withdraw";
+// states a vault can be moved into with `transitionVault`. a vault only returns to inactive by being
+// cancelled, see `cancelVault`.
+export type TargetVaultState = Exclude<State, State.Inactive>;
 
-type TargetVaultState =
-  | typeof INACTIVE_STATE
-  | typeof DEPOSIT_STATE
-  | typeof LIVE_STATE
-  | typeof REDEEM_STATE
-  | typeof WITHDRAW_STATE;
+// anchor encodes the program's `State` enum as an object keyed by the variant's camel case name
+export const encodeVaultState = (state: State) => ({
+  [state.toLowerCase()]: {},
+});
 
 export const fixedRateToDecimal = (n: number) => n / 10_000;
 
@@ -127,14 +128,16 @@ export const computeSwapForVault = async (
   console.log("beta tranche scale: ", trancheTokens.beta.scale);
 
   const redeemableAlpha =
-    orcaInvestment.a.token.mint.toBase58() === vault.alpha.mint.toBase58()
+    orcaInvestment.a.token.mint.toBase58() ===
+    alphaTranche(vault).mint.toBase58()
       ? orcaInvestment.a.amountOrcaU64
       : orcaInvestment.b.amountOrcaU64;
   const _redeemableAlpha = redeemableAlpha ? redeemableAlpha.toNumber() : 0;
   console.log("_redeemableAlpha: ", _redeemableAlpha);
 
   const redeemableBeta =
-    orcaInvestment.a.token.mint.toBase58() === vault.beta.mint.toBase58()
+    orcaInvestment.a.token.mint.toBase58() ===
+    betaTranche(vault).mint.toBase58()
       ? orcaInvestment.a.amountOrcaU64
       : orcaInvestment.b.amountOrcaU64;
   const _redeemableBeta = redeemableBeta ? redeemableBeta.toNumber() : 0;
@@ -145,13 +148,13 @@ export const computeSwapForVault = async (
     return null;
   }
 
-  const fixedRateDecimal = fixedRateToDecimal(vault.fixedRate);
-  console.log("vault.fixedRate: ", vault.fixedRate);
+  const fixedRateDecimal = fixedRateToDecimal(seniorReturnBps(vault));
+  console.log("senior return bps: ", seniorReturnBps(vault));
   console.log("fixedRateDecimal: ", fixedRateDecimal);
 
   // form => amount * 10 ** decimals
   const requiredAlpha =
-    vault.alpha.invested.toNumber() * (1 + fixedRateDecimal);
+    alphaTranche(vault).invested.toNumber() * (1 + fixedRateDecimal);
   console.log("requiredAlpha: ", requiredAlpha);
 
   if (_redeemableAlpha < requiredAlpha) {
@@ -299,10 +302,14 @@ export const getTrancheAsOrcaTokens = (vault: IVault, pool: OrcaPool) => {
   const _tokenB = pool.getTokenB();
 
   const _alpha =
-    vault.alpha.mint.toBase58() === _tokenA.mint.toBase58() ? _tokenA : _tokenB;
+    alphaTranche(vault).mint.toBase58() === _tokenA.mint.toBase58()
+      ? _tokenA
+      : _tokenB;
 
   const _beta =
-    vault.beta.mint.toBase58() === _tokenA.mint.toBase58() ? _tokenA : _tokenB;
+    betaTranche(vault).mint.toBase58() === _tokenA.mint.toBase58()
+      ? _tokenA
+      : _tokenB;
 
   return {
     alpha: _alpha,
@@ -804,16 +811,48 @@ export class VaultClient extends AccountUtils {
     } as PdaDerivationResult;
   };
 
+  generateTrancheMintAddress = async (
+    vault: PublicKey,
+    trancheId: number,
+    programID: PublicKey = this.vaultProgram.programId
+  ): Promise<PdaDerivationResult> => {
+    const [addr, bump] = await this.findProgramAddress(programID, [
+      "tranchemint",
+      vault,
+      Buffer.from([trancheId]),
+    ]);
+
+    return {
+      addr,
+      bump,
+    } as PdaDerivationResult;
+  };
+
+  // metaplex metadata account of a tranche mint
+  generateTrancheMetadataAddress = async (
+    trancheMint: PublicKey
+  ): Promise<PdaDerivationResult> => {
+    const [addr, bump] = await this.findProgramAddress(
+      TOKEN_METADATA_PROGRAM_ID,
+      ["metadata", TOKEN_METADATA_PROGRAM_ID, trancheMint]
+    );
+
+    return {
+      addr,
+      bump,
+    } as PdaDerivationResult;
+  };
+
   generateHistoryAddress = async (
     vault: PublicKey,
-    mint: PublicKey,
+    trancheId: number,
     payer: PublicKey,
     programID: PublicKey = this.vaultProgram.programId
   ): Promise<PdaDerivationResult> => {
     const [addr, bump] = await this.findProgramAddress(programID, [
       "history",
       vault,
-      mint,
+      Buffer.from([trancheId]),
       payer,
     ]);
 
@@ -825,7 +864,7 @@ export class VaultClient extends AccountUtils {
 
   generateReceiptAddress = async (
     vault: PublicKey,
-    mint: PublicKey,
+    trancheId: number,
     depositIndex: u64,
     programID: PublicKey = this.vaultProgram.programId
   ): Promise<PdaDerivationResult> => {
@@ -837,7 +876,7 @@ export class VaultClient extends AccountUtils {
     const [addr, bump] = await this.findProgramAddress(programID, [
       "receipt",
       vault,
-      mint,
+      Buffer.from([trancheId]),
       _depositIndexBytes,
     ]);
 
@@ -847,6 +886,23 @@ export class VaultClient extends AccountUtils {
     } as PdaDerivationResult;
   };
 
+  generateAllowlistAddress = async (
+    vault: PublicKey,
+    user: PublicKey,
+    programID: PublicKey = this.vaultProgram.programId
+  ): Promise<PdaDerivationResult> => {
+    const [addr, bump] = await this.findProgramAddress(programID, [
+      "allowlist",
+      vault,
+      user,
+    ]);
+
+    return {
+      addr,
+      bump,
+    } as PdaDerivationResult;
+  };
+
   // ================================================
   // Fetch & deserialize objects
   // ================================================
@@ -928,11 +984,11 @@ export class VaultClient extends AccountUtils {
 
   fetchReceiptWithSeeds = async (
     vault: PublicKey,
-    mint: PublicKey,
+    trancheId: number,
     depositIndex: u64
   ) => {
     return this.fetchReceipt(
-      (await this.generateReceiptAddress(vault, mint, depositIndex)).addr
+      (await this.generateReceiptAddress(vault, trancheId, depositIndex)).addr
     );
   };
 
@@ -942,11 +998,11 @@ export class VaultClient extends AccountUtils {
 
   fetchHistoryWithSeeds = async (
     vault: PublicKey,
-    mint: PublicKey,
+    trancheId: number,
     payer: PublicKey
   ) => {
     return this.fetchHistory(
-      (await this.generateHistoryAddress(vault, mint, payer)).addr
+      (await this.generateHistoryAddress(vault, trancheId, payer)).addr
     );
   };
 
@@ -980,13 +1036,14 @@ export class VaultClient extends AccountUtils {
   };
 
   getAsset = (vault: IVault, mint: PublicKey): IAsset => {
-    if (mint.toBase58() === vault.alpha.mint.toBase58()) {
-      return vault.alpha;
-    } else if (mint.toBase58() === vault.beta.mint.toBase58()) {
-      return vault.beta;
-    } else {
+    const asset = vault.tranches
+      .slice(0, vault.numTranches)
+      .find((tranche) => tranche.mint.toBase58() === mint.toBase58());
+    if (!asset) {
       throw new Error("Invalid pubkey");
     }
+
+    return asset;
   };
 
   // fetch history accounts for a vault for a user, aka has a user made a deposit
@@ -1193,25 +1250,30 @@ export class VaultClient extends AccountUtils {
     const { addr: vaultStore, bump: vaultStoreBump } =
       await this.generateVaultStoreAddress(vault);
 
-    // todo: are these alpha/beta ATAs even needed????
-    const alpha = vaultConfig.alpha.mint;
-    const alphaDecimals = (await this.fetchTokenSupply(vaultConfig.alpha.mint))
-      .decimals;
-    const alphaLp = Keypair.generate();
-
-    const beta = vaultConfig.beta.mint;
-    const betaDecimals = (await this.fetchTokenSupply(vaultConfig.beta.mint))
-      .decimals;
-    const betaLp = Keypair.generate();
+    const mints = vaultConfig.tranches.map((tranche) => tranche.mint);
+    if (new Set(mints.map((mint) => mint.toBase58())).size < 2) {
+      throw new Error(
+        "the most senior and most junior tranche assets must have different mints"
+      );
+    }
 
-    console.log("alpha: ", alpha.toBase58());
-    console.log("alha lp: ", alphaLp.publicKey.toBase58());
-    console.log("beta: ", beta.toBase58());
-    console.log("beta lp: ", betaLp.publicKey.toBase58());
+    // each tranche's asset mint, tranche mint PDA and tranche mint metadata, in tranche order
+    const remainingAccounts: AccountMeta[] = [];
+    for (let trancheId = 0; trancheId < mints.length; trancheId++) {
+      const { addr: lp } = await this.generateTrancheMintAddress(
+        vault,
+        trancheId
+      );
+      const { addr: lpMetadata } = await this.generateTrancheMetadataAddress(
+        lp
+      );
+      console.log(`tranche ${trancheId}: `, mints[trancheId].toBase58());
+      console.log(`tranche ${trancheId} lp: `, lp.toBase58());
 
-    if (alpha.toBase58() === beta.toBase58()) {
-      throw new Error(
-        "alpha and beta tranche assets must have different mints"
+      remainingAccounts.push(
+        { pubkey: mints[trancheId], isSigner: false, isWritable: false },
+        { pubkey: lp, isSigner: false, isWritable: true },
+        { pubkey: lpMetadata, isSigner: false, isWritable: true }
       );
     }
 
@@ -1224,19 +1286,30 @@ export class VaultClient extends AccountUtils {
           authority: vaultConfig.authority,
           strategy: vaultConfig.strategy,
           strategist: vaultConfig.strategist,
-          alpha: {
-            userCap: vaultConfig.alpha.userCap,
-            assetCap: vaultConfig.alpha.assetCap,
-          },
-          beta: {
-            userCap: vaultConfig.beta.userCap,
-            assetCap: vaultConfig.beta.assetCap,
-          },
-          fixedRate: vaultConfig.fixedRate,
+          tranches: vaultConfig.tranches.map((tranche) => ({
+            seniority: tranche.seniority,
+            symbol: tranche.symbol,
+            returnTerms: tranche.returnTerms
+              ? {
+                  ...tranche.returnTerms,
+                  firstLossBufferBps:
+                    tranche.returnTerms.firstLossBufferBps ?? null,
+                }
+              : null,
+            userCap: tranche.userCap ?? null,
+            assetCap: tranche.assetCap ?? null,
+            minDeposit: tranche.minDeposit ?? null,
+            maxDepositsPerUser: tranche.maxDepositsPerUser ?? null,
+          })),
           startAt: vaultConfig.startAt,
-          // removed investAt and redeemAt
           depositDuration: vaultConfig.depositDuration,
           investDuration: vaultConfig.investDuration,
+          depositGate: null,
+          maxSlippageBps: vaultConfig.maxSlippageBps,
+          oracle: null,
+          leverage: null,
+          metadataUri: vaultConfig.metadataUri,
+          earlyExitPenaltyBps: vaultConfig.earlyExitPenaltyBps ?? null,
         } as any,
         {
           accounts: {
@@ -1245,39 +1318,13 @@ export class VaultClient extends AccountUtils {
             globalProtocolState: globalStateAddr,
             vault,
             vaultStore,
-            alphaMint: alpha,
-            alphaLp: alphaLp.publicKey,
-            betaMint: beta,
-            betaLp: betaLp.publicKey,
             systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
             rent: SYSVAR_RENT_PUBKEY,
           },
-          // todo: vault is mint/freeze authority for now
-          // todo: move to anchor?
-          preInstructions: [
-            ...(await this.mintTokens(
-              this.provider.connection,
-              signerInfo.payer,
-              alphaLp.publicKey,
-              vault,
-              vault,
-              alphaDecimals
-            )),
-            ...(await this.mintTokens(
-              this.provider.connection,
-              signerInfo.payer,
-              betaLp.publicKey,
-              vault,
-              vault,
-              betaDecimals
-            )),
-          ],
-          signers: [
-            alphaLp,
-            betaLp,
-            ...signerInfo.signers,
-            ...gpsAuthorityInfo.signers,
-          ],
+          remainingAccounts,
+          signers: [...signerInfo.signers, ...gpsAuthorityInfo.signers],
         }
       );
     }
@@ -1292,6 +1339,7 @@ export class VaultClient extends AccountUtils {
   ) => {
     const signerInfo: SignerInfo = getSignersFromPayer(payer);
     const { addr: globalStateAddr } = await this.generateGlobalStateAddress();
+    const _vault = await this.fetchVault(vault);
     const timestamp = new u64(getTimestamp(new Date()));
 
     console.log("vault: ", vault.toBase58());
@@ -1300,7 +1348,37 @@ export class VaultClient extends AccountUtils {
     console.log("timestamp: ", timestamp.toNumber());
 
     if (executeTransaction) {
-      return this.vaultProgram.rpc.transitionVault(target, timestamp as any, {
+      return this.vaultProgram.rpc.transitionVault(
+        encodeVaultState(target) as any,
+        timestamp as any,
+        {
+          accounts: {
+            payer: signerInfo.payer,
+            authority: _vault.authority,
+            globalProtocolState: globalStateAddr,
+            vault,
+          },
+          signers: signerInfo.signers,
+        }
+      );
+    }
+    return "nan";
+  };
+
+  // cancel a vault that has not been invested; depositors then withdraw their deposits
+  cancelVault = async (
+    vault: PublicKey,
+    payer: PublicKey | Keypair,
+    executeTransaction: boolean = true
+  ) => {
+    const signerInfo: SignerInfo = getSignersFromPayer(payer);
+    const { addr: globalStateAddr } = await this.generateGlobalStateAddress();
+
+    console.log("vault: ", vault.toBase58());
+    console.log("globalStateAddr: ", globalStateAddr.toBase58());
+
+    if (executeTransaction) {
+      return this.vaultProgram.rpc.cancelVault({
         accounts: {
           authority: signerInfo.payer,
           globalProtocolState: globalStateAddr,
@@ -1313,15 +1391,19 @@ export class VaultClient extends AccountUtils {
   };
 
   // todo: deposit directly into the vault_store
+  // proof is only needed for a vault gated by a merkle root, see `DepositGate`
   deposit = async (
     vault: PublicKey,
-    mint: PublicKey,
+    trancheId: number,
     amount: u64,
     payer: PublicKey | Keypair,
-    executeTransaction: boolean = true
+    executeTransaction: boolean = true,
+    proof: number[][] = []
   ) => {
     const signerInfo: SignerInfo = getSignersFromPayer(payer);
     const _vault = await this.fetchVault(vault);
+    const _tranche = _vault.tranches[trancheId];
+    const mint = _tranche.mint;
 
     const { addr: vaultStore } = await this.generateVaultStoreAddress(vault);
     console.log("vaultStore: ", vaultStore.toBase58());
@@ -1351,17 +1433,28 @@ export class VaultClient extends AccountUtils {
       destinationTokenAccount.address.toBase58()
     );
 
-    const _asset = await this.getAsset(toIVault(_vault), mint);
-    console.log("_asset: ", _asset);
-
     // current number of deposits + 1, for next deposit in sequence
-    const index = new u64(_asset.deposits.toNumber() + 1);
+    const index = new u64(_tranche.deposits.toNumber() + 1);
     console.log("index: ", index.toNumber());
 
     const { addr: receipt, bump: receiptBump } =
-      await this.generateReceiptAddress(vault, mint, index);
+      await this.generateReceiptAddress(vault, trancheId, index);
     const { addr: history, bump: historyBump } =
-      await this.generateHistoryAddress(vault, mint, signerInfo.payer);
+      await this.generateHistoryAddress(vault, trancheId, signerInfo.payer);
+
+    // an allowlisted vault checks the depositor's allowlist entry
+    const remainingAccounts: AccountMeta[] = [];
+    if ("allowlist" in _vault.depositGate) {
+      const { addr: allowlist } = await this.generateAllowlistAddress(
+        vault,
+        signerInfo.payer
+      );
+      remainingAccounts.push({
+        pubkey: allowlist,
+        isSigner: false,
+        isWritable: false,
+      });
+    }
 
     console.log("vault: ", vault.toBase58());
     console.log("receipt: ", receipt.toBase58(), ", bump: ", receiptBump);
@@ -1373,10 +1466,12 @@ export class VaultClient extends AccountUtils {
     if (executeTransaction) {
       // todo: wSOL account paradigm when mint is SOL; only for orca?
       return this.vaultProgram.rpc.deposit(
+        trancheId,
         index as any,
         receiptBump,
         historyBump,
         amount as any,
+        proof,
         {
           accounts: {
             payer: signerInfo.payer,
@@ -1394,6 +1489,7 @@ export class VaultClient extends AccountUtils {
             ataProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
             rent: SYSVAR_RENT_PUBKEY,
           },
+          remainingAccounts,
           preInstructions: [
             ...sourceTokenAccount.instructions,
             ...destinationTokenAccount.instructions,
@@ -1413,7 +1509,7 @@ export class VaultClient extends AccountUtils {
   // todo: claim directly into the vault_store
   claim = async (
     vault: PublicKey,
-    mint: PublicKey,
+    trancheId: number,
     payer: PublicKey | Keypair,
     executeTransaction: boolean = true
   ) => {
@@ -1424,8 +1520,9 @@ export class VaultClient extends AccountUtils {
 
     const { addr: globalStateAddr } = await this.generateGlobalStateAddress();
     const _vault = await this.fetchVault(vault);
-    const _asset = this.getAsset(toIVault(_vault), mint);
-    const _assetLp = _asset.lp;
+    const _tranche = _vault.tranches[trancheId];
+    const mint = _tranche.mint;
+    const _assetLp = _tranche.lp;
 
     const sourceAssetAta = await resolveAtaForPda(
       mint,
@@ -1450,12 +1547,12 @@ export class VaultClient extends AccountUtils {
 
     const { addr: history } = await this.generateHistoryAddress(
       vault,
-      mint,
+      trancheId,
       signerInfo.payer
     );
 
     if (executeTransaction) {
-      return this.vaultProgram.rpc.claim({
+      return this.vaultProgram.rpc.claim(trancheId, {
         accounts: {
           payer: signerInfo.payer,
           authority: _vault.authority,
@@ -1747,11 +1844,10 @@ export class VaultClient extends AccountUtils {
 
   _getAccountsForClaim = async (
     vaultAddress: PublicKey,
-    vault: IVault,
-    mint: PublicKey,
+    trancheId: number,
     count: number = 2
   ): Promise<AccountMeta[]> => {
-    const asset = this.getAsset(vault, mint);
+    const asset = (await this.fetchVault(vaultAddress)).tranches[trancheId];
 
     const remainingAccounts: AccountMeta[] = [];
     if (asset.claimsProcessed) return remainingAccounts;
@@ -1769,7 +1865,7 @@ export class VaultClient extends AccountUtils {
 
       const { addr: receipt } = await this.generateReceiptAddress(
         vaultAddress,
-        asset.mint,
+        trancheId,
         _claimsIndex
       );
 
@@ -1778,7 +1874,7 @@ export class VaultClient extends AccountUtils {
 
       const { addr: history } = await this.generateHistoryAddress(
         vaultAddress,
-        asset.mint,
+        trancheId,
         _receipt.depositor
       );
 
@@ -1806,7 +1902,7 @@ export class VaultClient extends AccountUtils {
    */
   processClaims = async (
     vault: PublicKey,
-    mint: PublicKey,
+    trancheId: number,
     payer: PublicKey | Keypair,
     executeTransaction: boolean = true
   ): Promise<ProcessClaimsResult> => {
@@ -1814,36 +1910,32 @@ export class VaultClient extends AccountUtils {
 
     const { addr: globalStateAddr } = await this.generateGlobalStateAddress();
     const _vault = await this.fetchVault(vault);
-    const _asset = await this.getAsset(toIVault(_vault), mint);
 
-    console.log("_asset: ", _asset.mint.toBase58());
+    console.log("_asset: ", _vault.tranches[trancheId].mint.toBase58());
 
     const remainingAccounts = await this._getAccountsForClaim(
       vault,
-      toIVault(_vault),
-      mint
+      trancheId
     );
     console.log("remainingAccounts: ", remainingAccounts.length);
 
     // note: we still need to submit the transaction when there are no claims to process
     // because we need to update state on-chain.
     if (executeTransaction) {
-      const tx = await this.vaultProgram.rpc.processClaims({
+      const tx = await this.vaultProgram.rpc.processClaims(trancheId, {
         accounts: {
           payer: signerInfo.payer,
           authority: _vault.authority,
           globalProtocolState: globalStateAddr,
           vault,
-          mint: _asset.mint,
         },
         remainingAccounts,
         signers: signerInfo.signers,
       });
 
-      const assetAfterProcessing = await this.getAsset(
-        toIVault(await this.fetchVault(vault)),
-        mint
-      );
+      const assetAfterProcessing = (await this.fetchVault(vault)).tranches[
+        trancheId
+      ];
 
       return {
         claimsProcessed: assetAfterProcessing.claimsProcessed,
@@ -1912,11 +2004,13 @@ export class VaultClient extends AccountUtils {
     const slippagePercentage = (MAX_BPS - slippageBps) / MAX_BPS;
 
     const minA = new u64(
-      _vault.alpha.deposited.toNumber() * slippagePercentage
+      alphaTranche(toIVault(_vault)).deposited.toNumber() * slippagePercentage
     );
     console.log("minA: ", minA.toNumber());
 
-    const minB = new u64(_vault.beta.deposited.toNumber() * slippagePercentage);
+    const minB = new u64(
+      betaTranche(toIVault(_vault)).deposited.toNumber() * slippagePercentage
+    );
     console.log("minB: ", minB.toNumber());
 
     if (executeTransaction) {
@@ -2573,8 +2667,8 @@ export const generatePoolConfigForVault = async (
 
   const _vault = toIVault(await client.fetchVault(vault));
   if (
-    poolTokenA.mint.toBase58() === _vault.alpha.mint.toBase58() &&
-    poolTokenB.mint.toBase58() === _vault.beta.mint.toBase58()
+    poolTokenA.mint.toBase58() === alphaTranche(_vault).mint.toBase58() &&
+    poolTokenB.mint.toBase58() === betaTranche(_vault).mint.toBase58()
   ) {
     console.log("a is alpha, b is beta");
     const vaultTokenA = await resolveAtaForPda(
@@ -2605,8 +2699,8 @@ export const generatePoolConfigForVault = async (
       dest: poolTokenB.addr,
     };
   } else if (
-    poolTokenA.mint.toBase58() === _vault.beta.mint.toBase58() &&
-    poolTokenB.mint.toBase58() === _vault.alpha.mint.toBase58()
+    poolTokenA.mint.toBase58() === betaTranche(_vault).mint.toBase58() &&
+    poolTokenB.mint.toBase58() === alphaTranche(_vault).mint.toBase58()
   ) {
     console.log("a is beta, b is alpha");
     const vaultTokenA = await resolveAtaForPda(
@@ -2815,8 +2909,8 @@ export const getRebalanceConfig = async (
   console.log("alpha tranche scale: ", trancheTokens.alpha.scale);
   console.log("beta tranche scale: ", trancheTokens.beta.scale);
 
-  const redeemableAlpha = vault.alpha.received.toNumber();
-  const redeemableBeta = vault.beta.received.toNumber();
+  const redeemableAlpha = alphaTranche(vault).received.toNumber();
+  const redeemableBeta = betaTranche(vault).received.toNumber();
 
   if (redeemableAlpha === 0 && redeemableBeta === 0) {
     console.log("Both redeemable values cannot be 0");
@@ -2825,13 +2919,13 @@ export const getRebalanceConfig = async (
   console.log("redeemableAlpha: ", redeemableAlpha);
   console.log("redeemableBeta: ", redeemableBeta);
 
-  const fixedRateDecimal = fixedRateToDecimal(vault.fixedRate);
-  console.log("vault.fixedRate: ", vault.fixedRate);
+  const fixedRateDecimal = fixedRateToDecimal(seniorReturnBps(vault));
+  console.log("senior return bps: ", seniorReturnBps(vault));
   console.log("fixedRateDecimal: ", fixedRateDecimal);
 
   // form => amount * 10 ** decimals
   const requiredAlpha =
-    vault.alpha.invested.toNumber() * (1 + fixedRateDecimal);
+    alphaTranche(vault).invested.toNumber() * (1 + fixedRateDecimal);
   console.log("requiredAlpha: ", requiredAlpha);
 
   if (redeemableAlpha < requiredAlpha) {
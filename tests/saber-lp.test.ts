@@ -1,7 +1,13 @@
 import * as anchor from "@project-serum/anchor";
 import { Keypair, LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
 import { expect } from "chai";
-import { asNumber, spinUntil, toIVault } from "../sdk/src";
+import {
+  alphaTranche,
+  asNumber,
+  betaTranche,
+  spinUntil,
+  toIVault,
+} from "../sdk/src";
 import { assertKeysEqual, TestContext, wrappedIt } from "./common/util";
 import {
   DepositConfig,
@@ -54,20 +60,20 @@ export const suite = (getContext: () => Promise<TestContext>) => {
       },
     } as InitVaultConfig);
 
-    const vault = await testClient.fetchVault(addr);
+    const vault = toIVault(await testClient.fetchVault(addr));
 
     // in this case, authority is both authority and strategist
     assertKeysEqual(vault.authority, authority.publicKey);
     assertKeysEqual(vault.strategist, authority.publicKey);
 
     // check mints on vault are collateral we previously created
-    assertKeysEqual(vault.alpha.mint, ctx.collateralA.publicKey);
-    assertKeysEqual(vault.beta.mint, ctx.collateralB.publicKey);
+    assertKeysEqual(alphaTranche(vault).mint, ctx.collateralA.publicKey);
+    assertKeysEqual(betaTranche(vault).mint, ctx.collateralB.publicKey);
 
-    expect(vault.alpha.userCap.toNumber()).to.equal(userCap);
-    expect(vault.alpha.assetCap).to.equal(null);
-    expect(vault.beta.userCap).to.equal(null);
-    expect(vault.beta.assetCap.toNumber()).to.equal(assetCap);
+    expect(alphaTranche(vault).userCap.toNumber()).to.equal(userCap);
+    expect(alphaTranche(vault).assetCap).to.equal(null);
+    expect(betaTranche(vault).userCap).to.equal(null);
+    expect(betaTranche(vault).assetCap.toNumber()).to.equal(assetCap);
   });
 
   // todo: verify receipt account state updated
@@ -111,7 +117,7 @@ export const suite = (getContext: () => Promise<TestContext>) => {
 
     const history = await testClient.fetchHistoryWithSeeds(
       vaultAddress,
-      tokenToDeposit,
+      await testClient.getTrancheId(tokenToDeposit),
       user1.publicKey
     );
     expect(history.intialized).to.be.true;
@@ -120,7 +126,7 @@ export const suite = (getContext: () => Promise<TestContext>) => {
 
     const receipt = await testClient.fetchReceiptWithSeeds(
       vaultAddress,
-      tokenToDeposit,
+      await testClient.getTrancheId(tokenToDeposit),
       asset.deposits
     );
     expect(receipt.cumulative.toNumber()).to.equal(0);
@@ -146,7 +152,7 @@ export const suite = (getContext: () => Promise<TestContext>) => {
 
     const history = await testClient.fetchHistoryWithSeeds(
       vaultAddress,
-      tokenToDeposit,
+      await testClient.getTrancheId(tokenToDeposit),
       user1.publicKey
     );
     expect(history.intialized).to.be.true;
@@ -155,7 +161,7 @@ export const suite = (getContext: () => Promise<TestContext>) => {
 
     const receipt = await testClient.fetchReceiptWithSeeds(
       vaultAddress,
-      tokenToDeposit,
+      await testClient.getTrancheId(tokenToDeposit),
       asset.deposits
     );
     expect(receipt.cumulative.toNumber()).to.equal(0);
@@ -165,15 +171,15 @@ export const suite = (getContext: () => Promise<TestContext>) => {
 
   wrappedIt("Invest funds via the strategy", getContext, async (ctx) => {
     const vaultAddress = testClient.getCurrentVaultAddress();
-    const vault = await testClient.fetchVault(vaultAddress);
+    const vault = toIVault(await testClient.fetchVault(vaultAddress));
 
     const vaultAlphaTokenBalanceBefore = await testClient.fetchTokenBalance(
-      vault.alpha.mint,
+      alphaTranche(vault).mint,
       vaultAddress
     );
 
     const vaultBetaTokenBalanceBefore = await testClient.fetchTokenBalance(
-      vault.beta.mint,
+      betaTranche(vault).mint,
       vaultAddress
     );
 
@@ -185,21 +191,21 @@ export const suite = (getContext: () => Promise<TestContext>) => {
 
     await testClient.investFunds({
       payer: strategist,
-      tokenA: vault.alpha.mint,
+      tokenA: alphaTranche(vault).mint,
       amountA,
-      tokenB: vault.beta.mint,
+      tokenB: betaTranche(vault).mint,
       amountB: amountB,
       minOut,
       swapAccount: ctx.stableSwapAccount.publicKey,
     } as InvestConfig);
 
     const vaultAlphaTokenBalanceAfter = await testClient.fetchTokenBalance(
-      vault.alpha.mint,
+      alphaTranche(vault).mint,
       vaultAddress
     );
 
     const vaultBetaTokenBalanceAfter = await testClient.fetchTokenBalance(
-      vault.beta.mint,
+      betaTranche(vault).mint,
       vaultAddress
     );
 
@@ -212,31 +218,34 @@ export const suite = (getContext: () => Promise<TestContext>) => {
     );
     console.log("vaultPoolLpToken: ", vaultPoolLpToken);
 
-    const vaultAfter = await testClient.fetchVault(vaultAddress);
+    const vaultAfter = toIVault(await testClient.fetchVault(vaultAddress));
     console.log(
-      "vaultAfter.alpha.invested.toNumber(): ",
-      vaultAfter.alpha.invested.toNumber()
+      "alpha invested: ",
+      alphaTranche(vaultAfter).invested.toNumber()
     );
     console.log(
-      "vaultAfter.beta.invested.toNumber(): ",
-      vaultAfter.beta.invested.toNumber()
+      "beta invested: ",
+      betaTranche(vaultAfter).invested.toNumber()
     );
 
     // console.log("vaultAfter: ", vaultAfter);
     console.log(
       "vaultAfter alpha deposits: ",
-      vaultAfter.alpha.deposits.toNumber()
+      alphaTranche(vaultAfter).deposits.toNumber()
     );
     console.log(
       "vaultAfter alpha excess?: ",
-      vaultAfter.alpha.excess.toNumber()
+      alphaTranche(vaultAfter).excess.toNumber()
     );
 
     console.log(
       "vaultAfter beta deposits: ",
-      vaultAfter.beta.deposits.toNumber()
+      betaTranche(vaultAfter).deposits.toNumber()
+    );
+    console.log(
+      "vaultAfter beta excess?: ",
+      betaTranche(vaultAfter).excess.toNumber()
     );
-    console.log("vaultAfter beta excess?: ", vaultAfter.beta.excess.toNumber());
   });
 
   // todo: claim process to get lp tokens
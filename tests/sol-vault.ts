@@ -2,7 +2,7 @@ import * as anchor from "@project-serum/anchor";
 import { Keypair, LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
 import { expect } from "chai";
 
-import { toIVault } from "../sdk/src";
+import { alphaTranche, betaTranche, toIVault } from "../sdk/src";
 import { SOL_KEY_STR } from "../sdk/src/common/constant";
 import { assertKeysEqual, TestContext, wrappedIt } from "./common/util";
 import { DepositConfig, InitVaultConfig, VaultTestClient } from "./test-client";
@@ -46,20 +46,20 @@ export const suite = (getContext: () => Promise<TestContext>) => {
       },
     } as InitVaultConfig);
 
-    const vault = await testClient.fetchVault(addr);
+    const vault = toIVault(await testClient.fetchVault(addr));
 
     // in this case, authority is both authority and strategist
     assertKeysEqual(vault.authority, authority.publicKey);
     assertKeysEqual(vault.strategist, authority.publicKey);
 
     // check mints on vault are collateral we previously created
-    assertKeysEqual(vault.alpha.mint, _solPubkey);
-    assertKeysEqual(vault.beta.mint, ctx.collateralB.publicKey);
+    assertKeysEqual(alphaTranche(vault).mint, _solPubkey);
+    assertKeysEqual(betaTranche(vault).mint, ctx.collateralB.publicKey);
 
-    expect(vault.alpha.userCap.toNumber()).to.equal(userCap);
-    expect(vault.alpha.assetCap).to.equal(null);
-    expect(vault.beta.userCap).to.equal(null);
-    expect(vault.beta.assetCap.toNumber()).to.equal(assetCap);
+    expect(alphaTranche(vault).userCap.toNumber()).to.equal(userCap);
+    expect(alphaTranche(vault).assetCap).to.equal(null);
+    expect(betaTranche(vault).userCap).to.equal(null);
+    expect(betaTranche(vault).assetCap.toNumber()).to.equal(assetCap);
   });
 
   wrappedIt(
@@ -92,7 +92,7 @@ export const suite = (getContext: () => Promise<TestContext>) => {
 
       const history = await testClient.fetchHistoryWithSeeds(
         vaultAddress,
-        tokenToDeposit,
+        await testClient.getTrancheId(tokenToDeposit),
         user1.publicKey
       );
       expect(history.intialized).to.be.true;
@@ -101,7 +101,7 @@ export const suite = (getContext: () => Promise<TestContext>) => {
 
       const receipt = await testClient.fetchReceiptWithSeeds(
         vaultAddress,
-        tokenToDeposit,
+        await testClient.getTrancheId(tokenToDeposit),
         asset.deposits
       );
       expect(receipt.cumulative.toNumber()).to.equal(0);
@@ -26,6 +26,8 @@ import {
   addSeconds,
   toU64,
   spinUntil,
+  alphaTranche,
+  betaTranche,
 } from "../sdk";
 import { AssetConfig } from "../sdk/src";
 import { ZERO_U64 } from "../sdk/src/common/constant";
@@ -40,13 +42,15 @@ export interface InitVaultConfigForAsset {
   userCap?: u64;
 }
 
+// a senior (alpha) and junior (beta) tranche vault
 export interface InitVaultConfig {
+  // also the global protocol state's authority
   payer: Keypair;
   strategy: PublicKey;
   strategist?: PublicKey; // possibly use different person than authority
   alpha: InitVaultConfigForAsset;
   beta: InitVaultConfigForAsset;
-  fixedRate?: number; // default to 1000 bps
+  fixedRate?: number; // senior tranche's return, default to 1000 bps
   // default to now
   startAt?: Date;
   // length of deposit state, live after
@@ -110,6 +114,7 @@ export interface ProcessClaimsConfig {
 // ==============================================
 
 export const DEFAULT_HURDLE_RATE = 1000;
+export const DEFAULT_MAX_SLIPPAGE_BPS = 100;
 export const DEFAULT_PERIOD_IN_SECONDS = 10;
 
 export class VaultTestClient extends VaultClient {
@@ -162,38 +167,47 @@ export class VaultTestClient extends VaultClient {
       config.startAt,
       addSeconds(new Date(), DEFAULT_PERIOD_IN_SECONDS)
     ); // getCurrentTimestamp
-    const _investAtDate = addSeconds(
-      _startAtDate,
-      getOrDefault(config.depositPeriodInSeconds, DEFAULT_PERIOD_IN_SECONDS)
-    );
-    const _redeemAtDate = addSeconds(
-      _investAtDate,
-      getOrDefault(config.livePeriodInSeconds, DEFAULT_PERIOD_IN_SECONDS)
-    );
+    const _fixedRate = getOrDefault(config.fixedRate, DEFAULT_HURDLE_RATE);
 
     const vaultConfig: VaultConfig = {
       authority: config.payer.publicKey,
       strategy: config.strategy,
       strategist: _strategist,
-      alpha: {
-        mint: config.alpha.mint,
-        userCap: config.alpha.userCap,
-        assetCap: config.alpha.assetCap,
-      },
-      beta: {
-        mint: config.beta.mint,
-        userCap: config.beta.userCap,
-        assetCap: config.beta.assetCap,
-      },
-      fixedRate: getOrDefault(config.fixedRate, DEFAULT_HURDLE_RATE),
+      tranches: [
+        {
+          mint: config.alpha.mint,
+          seniority: 0,
+          symbol: "A",
+          returnTerms: {
+            floorBps: _fixedRate,
+            capBps: _fixedRate,
+            yieldShareBps: 0,
+          },
+          userCap: config.alpha.userCap,
+          assetCap: config.alpha.assetCap,
+        },
+        {
+          mint: config.beta.mint,
+          seniority: 1,
+          symbol: "B",
+          userCap: config.beta.userCap,
+          assetCap: config.beta.assetCap,
+        },
+      ],
       startAt: new u64(getTimestamp(_startAtDate)),
-      investAt: new u64(getTimestamp(_investAtDate)),
-      redeemAt: new u64(getTimestamp(_redeemAtDate)),
+      depositDuration: new u64(
+        getOrDefault(config.depositPeriodInSeconds, DEFAULT_PERIOD_IN_SECONDS)
+      ),
+      investDuration: new u64(
+        getOrDefault(config.livePeriodInSeconds, DEFAULT_PERIOD_IN_SECONDS)
+      ),
+      maxSlippageBps: DEFAULT_MAX_SLIPPAGE_BPS,
+      metadataUri: "",
     };
 
     console.log("vaultConfig: ", vaultConfig);
 
-    await this.initializeVault(vaultConfig, config.payer);
+    await this.initializeVault(vaultConfig, config.payer, config.payer);
 
     const { addr } = await this.generateVaultAddress(config.payer.publicKey);
     this.vaultAddress = addr;
@@ -216,12 +230,25 @@ export class VaultTestClient extends VaultClient {
 
     await this.deposit(
       this.vaultAddress,
-      config.mint,
+      await this.getTrancheId(config.mint),
       toU64(config.amount),
       config.payer
     );
   };
 
+  // id of the vault's tranche that takes deposits of the mint
+  getTrancheId = async (mint: PublicKey): Promise<number> => {
+    const vault = await this.fetchVault(this.vaultAddress);
+    const trancheId = vault.tranches
+      .slice(0, vault.numTranches)
+      .findIndex((tranche) => tranche.mint.equals(mint));
+    if (trancheId < 0) {
+      throw new Error("Invalid pubkey");
+    }
+
+    return trancheId;
+  };
+
   makeWithdrawal = async (config: WithdrawConfig) => {
     const vaultAddress = this.vaultAddress;
 
@@ -230,17 +257,21 @@ export class VaultTestClient extends VaultClient {
 
   investFunds = async (config: InvestConfig) => {
     const vaultAddress = this.vaultAddress;
-    const vault = await this.fetchVault(vaultAddress);
+    const vault = toIVault(await this.fetchVault(vaultAddress));
 
     // wait for invest period
     if (!getOrDefault(config.applyImmediately, false)) {
-      await spinUntil(asNumber(vault.investAt), 3, true);
+      await spinUntil(
+        asNumber(vault.startAt.add(vault.depositDuration)),
+        3,
+        true
+      );
     }
 
     await this.investSaber(
       {
-        tokenA: vault.alpha.mint,
-        tokenB: vault.beta.mint,
+        tokenA: alphaTranche(vault).mint,
+        tokenB: betaTranche(vault).mint,
         investConfig: {
           tokenAmountA: config.amountA,
           tokenAmountB: config.amountB,
@@ -257,14 +288,14 @@ export class VaultTestClient extends VaultClient {
 
   redeemFunds = async (config: RedeemConfig) => {
     const vaultAddress = this.vaultAddress;
-    const vault = await this.fetchVault(vaultAddress);
+    const vault = toIVault(await this.fetchVault(vaultAddress));
 
     // todo
     const slippage = 0;
     await this.redeemSaber(
       {
-        tokenA: vault.alpha.mint,
-        tokenB: vault.alpha.mint,
+        tokenA: alphaTranche(vault).mint,
+        tokenB: betaTranche(vault).mint,
         swapAccount: config.swapAccount,
       },
       vaultAddress,
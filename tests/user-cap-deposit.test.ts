@@ -1,7 +1,7 @@
 import * as anchor from "@project-serum/anchor";
 import { Keypair, PublicKey } from "@solana/web3.js";
 import { expect } from "chai";
-import { toIVault } from "../sdk/src";
+import { alphaTranche, betaTranche, toIVault } from "../sdk/src";
 import {
   assertKeysEqual,
   expectThrowsAsync,
@@ -30,20 +30,20 @@ export const suite = (getContext: () => Promise<TestContext>) => {
       depositPeriodInSeconds: 3600, // 1 hour
     } as InitVaultConfig);
 
-    const vault = await testClient.fetchVault(addr);
+    const vault = toIVault(await testClient.fetchVault(addr));
 
     // in this case, authority is both authority and strategist
     assertKeysEqual(vault.authority, testClient.fetchAuthority());
     assertKeysEqual(vault.strategist, testClient.fetchAuthority());
 
     // check mints on vault are collateral we previously created
-    assertKeysEqual(vault.alpha.mint, ctx.collateralA.publicKey);
-    assertKeysEqual(vault.beta.mint, ctx.collateralB.publicKey);
+    assertKeysEqual(alphaTranche(vault).mint, ctx.collateralA.publicKey);
+    assertKeysEqual(betaTranche(vault).mint, ctx.collateralB.publicKey);
 
-    expect(vault.alpha.userCap.toNumber()).to.equal(userCap);
-    expect(vault.alpha.assetCap).to.equal(null);
-    expect(vault.beta.userCap).to.equal(null);
-    expect(vault.beta.assetCap).to.equal(null);
+    expect(alphaTranche(vault).userCap.toNumber()).to.equal(userCap);
+    expect(alphaTranche(vault).assetCap).to.equal(null);
+    expect(betaTranche(vault).userCap).to.equal(null);
+    expect(betaTranche(vault).assetCap).to.equal(null);
   });
 
   wrappedIt(